@@ -1,13 +1,25 @@
 use amaze::dungeon::{DungeonGrid, DungeonType, DungeonWalkGenerator, TileType};
+use amaze::fingerprint::identify;
+use amaze::generators::{
+    AldousBroder4, BinaryTree4, Eller4, GrowingTree4, HuntAndKill4, Kruskal4, MazeGenerator2D,
+    MixedCell, Prim4, RecursiveBacktracker4, RecursiveDivision4, Sidewinder4, Wilson4,
+};
 #[cfg(feature = "generators-hex")]
-use amaze::generators::{AldousBroder6, GrowingTree6, MazeGenerator6D, RecursiveBacktracker6};
 use amaze::generators::{
-    BinaryTree4, Eller4, GrowingTree4, HuntAndKill4, Kruskal4, MazeGenerator2D, MixedCell, Prim4,
-    RecursiveBacktracker4, Sidewinder4, Wilson4,
+    AldousBroder6, GrowingTree6, Kruskal6, MazeGenerator6D, Prim6, RecursiveBacktracker6, Wilson6,
 };
 use amaze::preamble::*;
-use amaze::renderers::{ImageRenderer, RenderStyle, UnicodeRenderer};
+use amaze::renderers::{
+    AnsiHeatmapRenderer, AsciiRenderer, BrailleRenderer, Gradient, HexLayout, ImageRenderer,
+    PngRenderer, RenderStyle, UnicodeRenderer,
+};
+#[cfg(feature = "generators-hex")]
+use amaze::renderers::{HexAsciiRenderer, HexUnicodeRenderer, UnicodeRenderStyle};
+use amaze::solvers::SolveStep;
+use amaze::storage::{MazeFormat, StorableGrid, load_wall4_grid, save_grid};
 use clap::{Arg, ArgAction, Command, value_parser};
+use std::io::Write;
+use std::path::PathBuf;
 
 fn main() {
     let matches = Command::new("amaze-cli")
@@ -39,9 +51,14 @@ fn main() {
                             "sidewinder",
                             "binary-tree",
                             "prim",
+                            "recursive-division",
+                            "aldous-broder",
                             "hex-recursive-backtracker",
                             "hex-growing-tree",
                             "hex-aldous-broder",
+                            "hex-wilson",
+                            "hex-kruskal",
+                            "hex-prim",
                         ])
                         .action(ArgAction::Set),
                 )
@@ -80,6 +97,65 @@ fn main() {
                         .value_parser(value_parser!(RenderStyle))
                         .default_value("heavy")
                         .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("output")
+                        .short('o')
+                        .long("output")
+                        .help("writes the rendered maze to a file instead of stdout (required for png)")
+                        .display_order(5)
+                        .value_parser(value_parser!(PathBuf))
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("save")
+                        .long("save")
+                        .help("also saves the maze to a .bin or .json file")
+                        .display_order(6)
+                        .value_parser(value_parser!(PathBuf))
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("heatmap")
+                        .long("heatmap")
+                        .help("colors cells by distance from an end of the longest path, as true-color terminal text or, with --style png, as an image")
+                        .display_order(7)
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("gradient")
+                        .long("gradient")
+                        .help("selects the heatmap colors: viridis, heat, gray, or comma-separated #rrggbb colors")
+                        .display_order(8)
+                        .value_parser(value_parser!(Gradient))
+                        .default_value("viridis")
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("solve")
+                        .long("solve")
+                        .help("draws the path from the top-left to the bottom-right cell found by the given solver, shading the cells it explored")
+                        .display_order(9)
+                        .value_parser(["bfs", "dfs", "astar", "dead-end"])
+                        .conflicts_with("heatmap")
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("color")
+                        .long("color")
+                        .help("colors the solution, its start and end, and the explored cells with ANSI escape codes")
+                        .display_order(10)
+                        .requires("solve")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("hex-layout")
+                        .long("hex-layout")
+                        .help("selects pointy or flat hexagons for hex mazes drawn as text")
+                        .display_order(11)
+                        .value_parser(value_parser!(HexLayout))
+                        .default_value("pointy")
+                        .action(ArgAction::Set),
                 ),
         )
         .subcommand(
@@ -186,6 +262,42 @@ fn main() {
                         .default_value("0")
                         .value_parser(value_parser!(usize))
                         .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("heatmap")
+                        .long("heatmap")
+                        .help("colors floor tiles by distance from an end of the longest path, as true-color terminal text")
+                        .display_order(11)
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("gradient")
+                        .long("gradient")
+                        .help("selects the heatmap colors: viridis, heat, gray, or comma-separated #rrggbb colors")
+                        .display_order(12)
+                        .value_parser(value_parser!(Gradient))
+                        .default_value("viridis")
+                        .action(ArgAction::Set),
+                ),
+        )
+        .subcommand(
+            Command::new("identify")
+                .about("Guess which algorithm generated a saved square maze.")
+                .arg(
+                    Arg::new("file")
+                        .help("the .bin or .json maze file to identify")
+                        .required(true)
+                        .value_parser(value_parser!(PathBuf))
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("top")
+                        .short('n')
+                        .long("top")
+                        .help("number of candidates to list")
+                        .default_value("5")
+                        .value_parser(value_parser!(usize))
+                        .action(ArgAction::Set),
                 ),
         )
         .get_matches();
@@ -203,6 +315,54 @@ fn main() {
             let style = gen_matches
                 .get_one::<RenderStyle>("style")
                 .unwrap_or(&default_style);
+            let output = gen_matches.get_one::<PathBuf>("output");
+            let save = gen_matches.get_one::<PathBuf>("save");
+            if *style == RenderStyle::Png && output.is_none() {
+                eprintln!("The png style writes binary data and needs an --output file.");
+                std::process::exit(2);
+            }
+            let heatmap = gen_matches.get_flag("heatmap").then(|| {
+                gen_matches
+                    .get_one::<Gradient>("gradient")
+                    .expect("defaulted")
+            });
+            if heatmap.is_some()
+                && matches!(
+                    style,
+                    RenderStyle::Image(_) | RenderStyle::Ascii | RenderStyle::Braille
+                )
+            {
+                eprintln!(
+                    "The heatmap is drawn as terminal text with the heavy, thin, double and hex styles, or with --style png."
+                );
+                std::process::exit(2);
+            }
+            if algorithm.starts_with("hex-") && *style == RenderStyle::Braille {
+                eprintln!("The braille style draws square mazes only.");
+                std::process::exit(2);
+            }
+            let solver = gen_matches.get_one::<String>("solve").map(String::as_str);
+            if solver.is_some()
+                && (!matches!(style, RenderStyle::Unicode(_) | RenderStyle::Png)
+                    || (algorithm.starts_with("hex-") && *style != RenderStyle::Png))
+            {
+                eprintln!(
+                    "The solution is drawn with the heavy, thin, double and hex styles, or with --style png for hex mazes."
+                );
+                std::process::exit(2);
+            }
+            let options = RenderOptions {
+                style,
+                heatmap,
+                solver,
+                colored: gen_matches.get_flag("color"),
+                #[cfg(feature = "generators-hex")]
+                hex_layout: *gen_matches
+                    .get_one::<HexLayout>("hex-layout")
+                    .expect("defaulted"),
+                output,
+                save,
+            };
 
             #[cfg(not(feature = "generators-hex"))]
             let grid =
@@ -237,31 +397,26 @@ fn main() {
                     "prim" => {
                         <Prim4 as MazeGenerator2D>::new_from_seed(seed).generate(*width, *height)
                     }
+                    "recursive-division" => {
+                        RecursiveDivision4::new_from_seed(seed).generate(*width, *height)
+                    }
+                    "aldous-broder" => AldousBroder4::new_from_seed(seed).generate(*width, *height),
                     _ => unreachable!(),
                 };
 
             #[cfg(not(feature = "generators-hex"))]
-            match style {
-                RenderStyle::Unicode(style) => {
-                    let renderer = UnicodeRenderer::new(*style, true);
-                    println!("{}", renderer.render(&grid).trim_end());
-                }
-                RenderStyle::Image(style) => {
-                    let renderer = ImageRenderer::new(*style);
-                    println!("{}", renderer.render(&grid).trim_end());
-                }
-            }
+            render_grid(&grid, &options);
 
             #[cfg(feature = "generators-hex")]
             match algorithm.as_str() {
                 "recursive-backtracker" => {
                     let grid = RecursiveBacktracker4::new_from_seed(seed).generate(*width, *height);
-                    render_grid(&grid, style);
+                    render_grid(&grid, &options);
                 }
                 "growing-tree" => {
                     let grid = <GrowingTree4 as MazeGenerator2D>::new_from_seed(seed)
                         .generate(*width, *height);
-                    render_grid(&grid, style);
+                    render_grid(&grid, &options);
                 }
                 "growing-tree-mixed" => {
                     let grid = GrowingTree4::new_from_seed_with_selector(
@@ -271,55 +426,75 @@ fn main() {
                         },
                     )
                     .generate(*width, *height);
-                    render_grid(&grid, style);
+                    render_grid(&grid, &options);
                 }
                 "kruskal" => {
                     let grid = <Kruskal4 as MazeGenerator2D>::new_from_seed(seed)
                         .generate(*width, *height);
-                    render_grid(&grid, style);
+                    render_grid(&grid, &options);
                 }
                 "eller" => {
                     let grid =
                         <Eller4 as MazeGenerator2D>::new_from_seed(seed).generate(*width, *height);
-                    render_grid(&grid, style);
+                    render_grid(&grid, &options);
                 }
                 "wilson" => {
                     let grid =
                         <Wilson4 as MazeGenerator2D>::new_from_seed(seed).generate(*width, *height);
-                    render_grid(&grid, style);
+                    render_grid(&grid, &options);
                 }
                 "hunt-and-kill" => {
                     let grid = <HuntAndKill4 as MazeGenerator2D>::new_from_seed(seed)
                         .generate(*width, *height);
-                    render_grid(&grid, style);
+                    render_grid(&grid, &options);
                 }
                 "sidewinder" => {
                     let grid = <Sidewinder4 as MazeGenerator2D>::new_from_seed(seed)
                         .generate(*width, *height);
-                    render_grid(&grid, style);
+                    render_grid(&grid, &options);
                 }
                 "binary-tree" => {
                     let grid = <BinaryTree4 as MazeGenerator2D>::new_from_seed(seed)
                         .generate(*width, *height);
-                    render_grid(&grid, style);
+                    render_grid(&grid, &options);
                 }
                 "prim" => {
                     let grid =
                         <Prim4 as MazeGenerator2D>::new_from_seed(seed).generate(*width, *height);
-                    render_grid(&grid, style);
+                    render_grid(&grid, &options);
+                }
+                "recursive-division" => {
+                    let grid = RecursiveDivision4::new_from_seed(seed).generate(*width, *height);
+                    render_grid(&grid, &options);
+                }
+                "aldous-broder" => {
+                    let grid = AldousBroder4::new_from_seed(seed).generate(*width, *height);
+                    render_grid(&grid, &options);
                 }
                 "hex-recursive-backtracker" => {
                     let grid = RecursiveBacktracker6::new_from_seed(seed).generate(*width, *height);
-                    render_hex_grid(&grid);
+                    render_hex_grid(&grid, &options);
                 }
                 "hex-growing-tree" => {
                     let grid = <GrowingTree6 as MazeGenerator6D>::new_from_seed(seed)
                         .generate(*width, *height);
-                    render_hex_grid(&grid);
+                    render_hex_grid(&grid, &options);
                 }
                 "hex-aldous-broder" => {
                     let grid = AldousBroder6::new_from_seed(seed).generate(*width, *height);
-                    render_hex_grid(&grid);
+                    render_hex_grid(&grid, &options);
+                }
+                "hex-wilson" => {
+                    let grid = Wilson6::new_from_seed(seed).generate(*width, *height);
+                    render_hex_grid(&grid, &options);
+                }
+                "hex-kruskal" => {
+                    let grid = Kruskal6::new_from_seed(seed).generate(*width, *height);
+                    render_hex_grid(&grid, &options);
+                }
+                "hex-prim" => {
+                    let grid = Prim6::new_from_seed(seed).generate(*width, *height);
+                    render_hex_grid(&grid, &options);
                 }
                 _ => unreachable!(),
             }
@@ -371,7 +546,48 @@ fn main() {
                 )
                 .generate(width, height, floor_count);
 
-            println!("{}", render_dungeon(&dungeon));
+            if dungeon_matches.get_flag("heatmap") {
+                let mut renderer = AnsiHeatmapRenderer::new();
+                renderer.set_gradient(
+                    dungeon_matches
+                        .get_one::<Gradient>("gradient")
+                        .expect("defaulted")
+                        .clone(),
+                );
+                let first_floor = (0..dungeon.height())
+                    .flat_map(|y| (0..dungeon.width()).map(move |x| GridCoord2D::new(x, y)))
+                    .find(|&coord| dungeon.is_floor(coord))
+                    .unwrap_or_default();
+                let distances = DistanceMap::from_diameter_end(&dungeon, first_floor);
+                print!("{}", renderer.render_dungeon(&dungeon, &distances));
+            } else {
+                println!("{}", render_dungeon(&dungeon));
+            }
+        }
+        Some(("identify", identify_matches)) => {
+            let file = identify_matches
+                .get_one::<PathBuf>("file")
+                .expect("required");
+            let top = *identify_matches.get_one::<usize>("top").expect("defaulted");
+
+            let maze = load_wall4_grid(file, MazeFormat::Auto).unwrap_or_else(|e| {
+                eprintln!("Failed to load {}: {e}", file.display());
+                std::process::exit(1);
+            });
+
+            println!(
+                "{:<4} {:<22} {:>11} {:>9}",
+                "rank", "generator", "probability", "distance"
+            );
+            for (rank, candidate) in identify(&maze).iter().take(top).enumerate() {
+                println!(
+                    "{:<4} {:<22} {:>10.1}% {:>9.2}",
+                    rank + 1,
+                    candidate.generator,
+                    candidate.probability * 100.0,
+                    candidate.distance
+                );
+            }
         }
         _ => unreachable!(), // If all subcommands are defined above, anything else is unreachable
     }
@@ -405,33 +621,205 @@ fn render_dungeon(dungeon: &DungeonGrid) -> String {
     output
 }
 
-#[cfg(feature = "generators-hex")]
-fn render_grid(grid: &Wall4Grid, style: &RenderStyle) {
-    match style {
+/// How the `gen` command renders, writes and saves a maze.
+struct RenderOptions<'a> {
+    style: &'a RenderStyle,
+    /// The gradient of the distance heatmap, if one is drawn instead of the maze.
+    heatmap: Option<&'a Gradient>,
+    /// The name of the solver whose solution is drawn over the maze.
+    solver: Option<&'a str>,
+    /// Whether the solution is colored with ANSI escape codes.
+    colored: bool,
+    /// The orientation of hexagons drawn as text.
+    #[cfg(feature = "generators-hex")]
+    hex_layout: HexLayout,
+    output: Option<&'a PathBuf>,
+    save: Option<&'a PathBuf>,
+}
+
+/// Returns the solver selected by `--solve`.
+fn solver<G: MazeGrid>(name: &str) -> &'static dyn MazeSolver<G>
+where
+    BfsSolver: MazeSolver<G>,
+    DfsSolver: MazeSolver<G>,
+    AStarSolver: MazeSolver<G>,
+    DeadEndFillingSolver: MazeSolver<G>,
+{
+    match name {
+        "bfs" => &BfsSolver,
+        "dfs" => &DfsSolver,
+        "astar" => &AStarSolver,
+        "dead-end" => &DeadEndFillingSolver,
+        _ => unreachable!(),
+    }
+}
+
+/// Solves the maze from its top-left to its bottom-right cell, returning the path and
+/// the cells the solver explored or filled on the way.
+fn solve_corners(grid: &Wall4Grid, name: &str) -> (Option<Path>, Vec<GridCoord2D>) {
+    let start = GridCoord2D::new(0, 0);
+    let end = GridCoord2D::new(
+        grid.width().saturating_sub(1),
+        grid.height().saturating_sub(1),
+    );
+    let mut visited = Vec::new();
+    let mut solution = None;
+    for step in solver(name).solve_steps(grid, start, end) {
+        match step {
+            SolveStep::Explore { cell } | SolveStep::FillDeadEnd { cell } => visited.push(cell),
+            SolveStep::PathFound { path } => solution = Some(path),
+            _ => {}
+        }
+    }
+    (solution, visited)
+}
+
+/// Renders a square maze, its solution, or its distance heatmap.
+fn render_grid(grid: &Wall4Grid, options: &RenderOptions) {
+    let RenderOptions {
+        style,
+        heatmap,
+        output,
+        save,
+        ..
+    } = *options;
+
+    if let Some(name) = options.solver {
+        let (solution, visited) = solve_corners(grid, name);
+        let markers = solution
+            .as_ref()
+            .and_then(|path| path.start().zip(path.end()));
+        let rendered = match style {
+            RenderStyle::Unicode(style) => {
+                let mut renderer = UnicodeRenderer::new(*style, true);
+                renderer.set_colored(options.colored);
+                text_output(renderer.render_with(grid, solution.as_ref(), markers, &visited))
+            }
+            RenderStyle::Png => PngRenderer::new().render_with(grid, solution.as_ref(), markers),
+            _ => unreachable!("rejected when parsing the arguments"),
+        };
+        write_output(&rendered, output);
+        save_maze(grid, save);
+        return;
+    }
+
+    if let Some(gradient) = heatmap {
+        let distances = DistanceMap::from_diameter_end(grid, GridCoord2D::new(0, 0));
+        let rendered = if *style == RenderStyle::Png {
+            let mut renderer = PngRenderer::new();
+            renderer.style_mut().set_gradient(gradient.clone());
+            renderer.render_heatmap(grid, &distances)
+        } else {
+            let mut renderer = AnsiHeatmapRenderer::new();
+            renderer.set_gradient(gradient.clone());
+            renderer.render(grid, &distances).into_bytes()
+        };
+        write_output(&rendered, output);
+        save_maze(grid, save);
+        return;
+    }
+
+    let rendered = match style {
         RenderStyle::Unicode(style) => {
             let renderer = UnicodeRenderer::new(*style, true);
-            println!("{}", renderer.render(grid).trim_end());
+            text_output(renderer.render(grid))
         }
         RenderStyle::Image(style) => {
             let renderer = ImageRenderer::new(*style);
-            println!("{}", renderer.render(grid).trim_end());
+            text_output(renderer.render(grid))
         }
-    }
+        RenderStyle::Png => PngRenderer::new().render(grid),
+        RenderStyle::Ascii => text_output(AsciiRenderer::new().render(grid)),
+        RenderStyle::Braille => text_output(BrailleRenderer::new().render(grid)),
+    };
+    write_output(&rendered, output);
+    save_maze(grid, save);
 }
 
+/// Renders a hex maze, its solution, or its distance heatmap.
 #[cfg(feature = "generators-hex")]
-fn render_hex_grid(grid: &Wall6Grid) {
-    let mut output = String::new();
-    for r in 0..grid.height() {
-        if r % 2 == 1 {
-            output.push(' ');
+fn render_hex_grid(grid: &Wall6Grid, options: &RenderOptions) {
+    let RenderOptions {
+        style,
+        heatmap,
+        output,
+        save,
+        ..
+    } = *options;
+
+    if let Some(name) = options.solver {
+        let start = HexCoord::new(0, 0);
+        let end = HexCoord::new(grid.width() as isize - 1, grid.height() as isize - 1);
+        let solution = solver(name).solve(grid, start, end);
+        let png = PngRenderer::new().render_with(grid, solution.as_ref(), Some((start, end)));
+        write_output(&png, output);
+        save_maze(grid, save);
+        return;
+    }
+
+    if let Some(gradient) = heatmap {
+        let distances = DistanceMap::from_diameter_end(grid, HexCoord::new(0, 0));
+        let rendered = if *style == RenderStyle::Png {
+            let mut renderer = PngRenderer::new();
+            renderer.style_mut().set_gradient(gradient.clone());
+            renderer.render_heatmap(grid, &distances)
+        } else {
+            let mut renderer = AnsiHeatmapRenderer::new();
+            renderer.set_gradient(gradient.clone());
+            renderer.render_hex(grid, &distances).into_bytes()
+        };
+        write_output(&rendered, output);
+        save_maze(grid, save);
+        return;
+    }
+
+    let rendered = match style {
+        RenderStyle::Unicode(UnicodeRenderStyle::Hexadecimal) => {
+            text_output(HexAsciiRenderer::new().render_wall_bits(grid))
         }
-        for q in 0..grid.width() {
-            let coord = HexCoord::new(q as isize, r as isize);
-            let walls = grid.get(coord).expect("coord in bounds");
-            output.push_str(&format!("{:02X} ", { **walls }));
+        RenderStyle::Unicode(_) => {
+            let mut renderer = HexUnicodeRenderer::new();
+            renderer.set_layout(options.hex_layout);
+            text_output(renderer.render(grid))
         }
-        output.push('\n');
+        RenderStyle::Ascii => {
+            let mut renderer = HexAsciiRenderer::new();
+            renderer.set_layout(options.hex_layout);
+            text_output(renderer.render(grid))
+        }
+        RenderStyle::Image(format) => text_output(ImageRenderer::new(*format).render_hex(grid)),
+        RenderStyle::Png => PngRenderer::new().render(grid),
+        RenderStyle::Braille => unreachable!("rejected when parsing the arguments"),
+    };
+    write_output(&rendered, output);
+    save_maze(grid, save);
+}
+
+/// Trims trailing whitespace from rendered text and ends it with a single newline.
+fn text_output(text: String) -> Vec<u8> {
+    format!("{}\n", text.trim_end()).into_bytes()
+}
+
+/// Writes the rendered maze to the file given by `--output`, or to stdout.
+fn write_output(rendered: &[u8], output: Option<&PathBuf>) {
+    let result = match output {
+        Some(path) => std::fs::write(path, rendered),
+        None => std::io::stdout().write_all(rendered),
+    };
+    if let Err(e) = result {
+        let target = output.map_or("stdout".to_string(), |path| path.display().to_string());
+        eprintln!("Failed to write {target}: {e}");
+        std::process::exit(1);
+    }
+}
+
+/// Writes the maze to the file given by `--save`, picking the format from its extension.
+fn save_maze<G: StorableGrid>(grid: &G, path: Option<&PathBuf>) {
+    let Some(path) = path else {
+        return;
+    };
+    if let Err(e) = save_grid(path, grid, MazeFormat::Auto) {
+        eprintln!("Failed to save {}: {e}", path.display());
+        std::process::exit(1);
     }
-    println!("{}", output.trim_end());
 }
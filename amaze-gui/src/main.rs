@@ -1,15 +1,20 @@
 use amaze::dungeon::{DungeonGrid, DungeonType, DungeonWalkGenerator, TileType, solve_bfs};
-#[cfg(feature = "generators-hex")]
 use amaze::generators::{
-    AldousBroder6, GrowingTree6, HexGenerationStep, MazeGenerator6D, RecursiveBacktracker6,
+    AldousBroder4, BinaryTree4, Braid4, Eller4, GenerationStep, GenerationSteps, GrowingTree4,
+    HuntAndKill4, Kruskal4, MazeGenerator2D, Prim4, RecursiveBacktracker4, RecursiveDivision4,
+    Sidewinder4, Wilson4,
 };
+#[cfg(feature = "generators-hex")]
 use amaze::generators::{
-    BinaryTree4, Eller4, GenerationStep, GrowingTree4, HuntAndKill4, Kruskal4, MazeGenerator2D,
-    Prim4, RecursiveBacktracker4, Sidewinder4, Wilson4,
+    AldousBroder6, Braid6, GrowingTree6, HexGenerationStep, HexGenerationSteps, Kruskal6,
+    MazeGenerator6D, Prim6, RecursiveBacktracker6, Wilson6,
 };
 use amaze::preamble::*;
+use amaze::renderers::Gradient;
+use amaze::solvers::SolveStep;
 use eframe::{App, Frame, NativeOptions, egui, epaint::Color32};
 use rand::RngExt;
+use std::hash::Hash;
 use std::sync::Mutex;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -29,12 +34,20 @@ enum AlgorithmChoice {
     Sidewinder,
     BinaryTree,
     Prim,
+    RecursiveDivision,
+    AldousBroder,
     #[cfg(feature = "generators-hex")]
     RecursiveBacktracker6,
     #[cfg(feature = "generators-hex")]
     GrowingTree6,
     #[cfg(feature = "generators-hex")]
     AldousBroder6,
+    #[cfg(feature = "generators-hex")]
+    Wilson6,
+    #[cfg(feature = "generators-hex")]
+    Kruskal6,
+    #[cfg(feature = "generators-hex")]
+    Prim6,
 }
 
 impl AlgorithmChoice {
@@ -49,22 +62,207 @@ impl AlgorithmChoice {
             Self::Sidewinder => "Sidewinder",
             Self::BinaryTree => "Binary Tree",
             Self::Prim => "Prim",
+            Self::RecursiveDivision => "Recursive Division",
+            Self::AldousBroder => "Aldous-Broder",
             #[cfg(feature = "generators-hex")]
             Self::RecursiveBacktracker6 => "Hex Recursive Backtracker",
             #[cfg(feature = "generators-hex")]
             Self::GrowingTree6 => "Hex Growing Tree",
             #[cfg(feature = "generators-hex")]
             Self::AldousBroder6 => "Hex Aldous-Broder",
+            #[cfg(feature = "generators-hex")]
+            Self::Wilson6 => "Hex Wilson",
+            #[cfg(feature = "generators-hex")]
+            Self::Kruskal6 => "Hex Kruskal",
+            #[cfg(feature = "generators-hex")]
+            Self::Prim6 => "Hex Prim",
         }
     }
 
     fn is_hex(self) -> bool {
         match self {
             #[cfg(feature = "generators-hex")]
-            Self::RecursiveBacktracker6 | Self::GrowingTree6 | Self::AldousBroder6 => true,
+            Self::RecursiveBacktracker6
+            | Self::GrowingTree6
+            | Self::AldousBroder6
+            | Self::Wilson6
+            | Self::Kruskal6
+            | Self::Prim6 => true,
             _ => false,
         }
     }
+
+    /// Whether the generator adds walls to an open grid instead of carving passages.
+    fn is_wall_adding(self) -> bool {
+        matches!(self, Self::RecursiveDivision)
+    }
+
+    /// Returns the grid an animation of this generator starts from.
+    fn initial_grid(self, width: usize, height: usize) -> Wall4Grid {
+        if self.is_wall_adding() {
+            Wall4Grid::new_open(width, height)
+        } else {
+            Wall4Grid::new(width, height)
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum SolverChoice {
+    Bfs,
+    Dfs,
+    AStar,
+    DeadEndFilling,
+}
+
+impl SolverChoice {
+    const ALL: [Self; 4] = [Self::Bfs, Self::Dfs, Self::AStar, Self::DeadEndFilling];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Bfs => "Breadth-First Search",
+            Self::Dfs => "Depth-First Search",
+            Self::AStar => "A*",
+            Self::DeadEndFilling => "Dead-End Filling",
+        }
+    }
+
+    fn solver(self) -> &'static dyn MazeSolver {
+        match self {
+            Self::Bfs => &BfsSolver,
+            Self::Dfs => &DfsSolver,
+            Self::AStar => &AStarSolver,
+            Self::DeadEndFilling => &DeadEndFillingSolver,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum GradientChoice {
+    Viridis,
+    Heat,
+    Grayscale,
+}
+
+impl GradientChoice {
+    const ALL: [Self; 3] = [Self::Viridis, Self::Heat, Self::Grayscale];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Viridis => "Viridis",
+            Self::Heat => "Heat",
+            Self::Grayscale => "Grayscale",
+        }
+    }
+
+    fn gradient(self) -> Gradient {
+        match self {
+            Self::Viridis => Gradient::viridis(),
+            Self::Heat => Gradient::heat(),
+            Self::Grayscale => Gradient::grayscale(),
+        }
+    }
+}
+
+/// Cell colors by distance from the start cell or, without one, from an end of the
+/// longest path through the grid.
+///
+/// Heatmaps are kept across frames until the grid changes, or until they no longer
+/// match the start cell and gradient they were made for.
+struct Heatmap<C> {
+    distances: DistanceMap<C>,
+    max_distance: usize,
+    start: Option<C>,
+    choice: GradientChoice,
+    gradient: Gradient,
+}
+
+impl<C: Copy + Eq + Hash> Heatmap<C> {
+    fn new<G: WalkableGrid<Coord = C>>(
+        grid: &G,
+        start: Option<C>,
+        first_cell: C,
+        gradient: GradientChoice,
+    ) -> Self {
+        let distances = match start.filter(|&start| grid.is_walkable(start)) {
+            Some(start) => DistanceMap::new(grid, start),
+            None => DistanceMap::from_diameter_end(grid, first_cell),
+        };
+        Self {
+            max_distance: distances.max_distance().unwrap_or(0),
+            distances,
+            start,
+            choice: gradient,
+            gradient: gradient.gradient(),
+        }
+    }
+
+    /// Tests whether the heatmap was made for this start cell and gradient.
+    fn is_current(&self, start: Option<C>, gradient: GradientChoice) -> bool {
+        self.start == start && self.choice == gradient
+    }
+
+    fn fill_color(&self, cell: C) -> Option<Color32> {
+        let distance = self.distances.distance(cell)?;
+        let (r, g, b) = self.gradient.sample_distance(distance, self.max_distance);
+        Some(Color32::from_rgb(r, g, b))
+    }
+}
+
+/// A recorded search being replayed onto the maze, a few steps per frame.
+struct SolveAnimation {
+    steps: std::vec::IntoIter<SolveStep>,
+    width: usize,
+    explored: Vec<bool>,
+    frontier: Vec<bool>,
+    filled: Vec<bool>,
+    path: Option<Path>,
+}
+
+impl SolveAnimation {
+    fn new(steps: Vec<SolveStep>, width: usize, height: usize) -> Self {
+        Self {
+            steps: steps.into_iter(),
+            width,
+            explored: vec![false; width * height],
+            frontier: vec![false; width * height],
+            filled: vec![false; width * height],
+            path: None,
+        }
+    }
+
+    fn apply(&mut self, step: SolveStep) {
+        match step {
+            SolveStep::Explore { cell } => {
+                let idx = cell.y * self.width + cell.x;
+                self.frontier[idx] = false;
+                self.explored[idx] = true;
+            }
+            SolveStep::Enqueue { cell, .. } => self.frontier[cell.y * self.width + cell.x] = true,
+            SolveStep::FillDeadEnd { cell } => self.filled[cell.y * self.width + cell.x] = true,
+            SolveStep::PathFound { path } => self.path = Some(path),
+            SolveStep::Backtrack { .. } => {}
+        }
+    }
+
+    fn fill_color(&self, coord: GridCoord2D) -> Option<Color32> {
+        let idx = coord.y * self.width + coord.x;
+        if self
+            .path
+            .as_ref()
+            .is_some_and(|path| path.cells().contains(&coord))
+        {
+            Some(Color32::from_rgb(180, 230, 180))
+        } else if self.filled[idx] {
+            Some(Color32::from_rgb(150, 150, 150))
+        } else if self.frontier[idx] {
+            Some(Color32::from_rgb(255, 210, 150))
+        } else if self.explored[idx] {
+            Some(Color32::from_rgb(190, 215, 255))
+        } else {
+            None
+        }
+    }
 }
 
 struct MyApp {
@@ -78,6 +276,7 @@ struct MyApp {
     dungeon_width: usize,
     dungeon_height: usize,
     algorithm: AlgorithmChoice,
+    braid_ratio: f64,
     maze: Mutex<Wall4Grid>,
     #[cfg(feature = "generators-hex")]
     hex_maze: Mutex<Option<Wall6Grid>>,
@@ -98,11 +297,23 @@ struct MyApp {
     hex_start_cell: Option<HexCoord>,
     #[cfg(feature = "generators-hex")]
     hex_end_cell: Option<HexCoord>,
-    animation_steps: Vec<GenerationStep>,
+    /// The generation being animated, pulled a few steps per frame.
+    animation: Option<GenerationSteps>,
+    #[cfg(feature = "generators-hex")]
+    hex_animation: Option<HexGenerationSteps>,
+    /// Whether braiding still follows the animated generation.
+    animation_braid_pending: bool,
+    animation_paused: bool,
+    solver: SolverChoice,
+    solve_animation: Option<SolveAnimation>,
+    /// Whether cells are colored by their distance instead of a checkerboard.
+    heatmap: bool,
+    gradient: GradientChoice,
+    /// The heatmaps drawn last, dropped whenever their grid changes.
+    maze_heatmap: Option<Heatmap<GridCoord2D>>,
+    dungeon_heatmap: Option<Heatmap<GridCoord2D>>,
     #[cfg(feature = "generators-hex")]
-    hex_animation_steps: Vec<HexGenerationStep>,
-    animation_index: usize,
-    is_animating: bool,
+    hex_heatmap: Option<Heatmap<HexCoord>>,
     auto_fit_pending: bool,
 }
 
@@ -133,6 +344,7 @@ impl Default for MyApp {
             dungeon_width,
             dungeon_height,
             algorithm,
+            braid_ratio: 0.0,
             maze: Mutex::new(maze),
             #[cfg(feature = "generators-hex")]
             hex_maze: Mutex::new(None),
@@ -153,11 +365,19 @@ impl Default for MyApp {
             hex_start_cell: None,
             #[cfg(feature = "generators-hex")]
             hex_end_cell: None,
-            animation_steps: Vec::new(),
+            animation: None,
             #[cfg(feature = "generators-hex")]
-            hex_animation_steps: Vec::new(),
-            animation_index: 0,
-            is_animating: false,
+            hex_animation: None,
+            animation_braid_pending: false,
+            animation_paused: false,
+            solver: SolverChoice::Bfs,
+            solve_animation: None,
+            heatmap: false,
+            gradient: GradientChoice::Viridis,
+            maze_heatmap: None,
+            dungeon_heatmap: None,
+            #[cfg(feature = "generators-hex")]
+            hex_heatmap: None,
             auto_fit_pending: true,
         }
     }
@@ -169,6 +389,7 @@ impl App for MyApp {
 
         handle_panning_zooming(&ctx, self);
         tick_animation(self);
+        tick_solve_animation(self);
 
         egui::Panel::left("controls_panel").show_inside(ui, |ui| {
             ui.heading("Controls");
@@ -211,6 +432,7 @@ impl App for MyApp {
                 self.auto_fit_pending = true;
                 self.start_cell = None;
                 self.end_cell = None;
+                self.solve_animation = None;
                 #[cfg(feature = "generators-hex")]
                 {
                     self.hex_start_cell = None;
@@ -276,6 +498,16 @@ impl App for MyApp {
                             AlgorithmChoice::Prim,
                             AlgorithmChoice::Prim.as_str(),
                         );
+                        ui.selectable_value(
+                            &mut self.algorithm,
+                            AlgorithmChoice::RecursiveDivision,
+                            AlgorithmChoice::RecursiveDivision.as_str(),
+                        );
+                        ui.selectable_value(
+                            &mut self.algorithm,
+                            AlgorithmChoice::AldousBroder,
+                            AlgorithmChoice::AldousBroder.as_str(),
+                        );
                         #[cfg(feature = "generators-hex")]
                         ui.selectable_value(
                             &mut self.algorithm,
@@ -294,11 +526,37 @@ impl App for MyApp {
                             AlgorithmChoice::AldousBroder6,
                             AlgorithmChoice::AldousBroder6.as_str(),
                         );
+                        #[cfg(feature = "generators-hex")]
+                        ui.selectable_value(
+                            &mut self.algorithm,
+                            AlgorithmChoice::Wilson6,
+                            AlgorithmChoice::Wilson6.as_str(),
+                        );
+                        #[cfg(feature = "generators-hex")]
+                        ui.selectable_value(
+                            &mut self.algorithm,
+                            AlgorithmChoice::Kruskal6,
+                            AlgorithmChoice::Kruskal6.as_str(),
+                        );
+                        #[cfg(feature = "generators-hex")]
+                        ui.selectable_value(
+                            &mut self.algorithm,
+                            AlgorithmChoice::Prim6,
+                            AlgorithmChoice::Prim6.as_str(),
+                        );
                     });
 
                 if previous_algorithm != self.algorithm {
                     regenerate_maze(self);
                 }
+
+                ui.label("Braid Ratio:");
+                if ui
+                    .add(egui::Slider::new(&mut self.braid_ratio, 0.0..=1.0))
+                    .changed()
+                {
+                    regenerate_maze(self);
+                }
             } else {
                 ui.label("Dungeon Type:");
                 let previous_dungeon_type = self.dungeon_type;
@@ -468,40 +726,63 @@ impl App for MyApp {
             }
 
             ui.separator();
-            if self.mode == Mode::Maze && ui.button("Animate Generation").clicked() {
-                #[cfg(feature = "generators-hex")]
-                if self.algorithm.is_hex() {
-                    self.hex_animation_steps =
-                        generate_hex_steps(self.algorithm, self.seed, self.width, self.height);
-                    self.animation_steps.clear();
-                    self.animation_index = 0;
-                    self.is_animating = true;
-                    let mut lock = self.hex_maze.lock().unwrap();
-                    *lock = Some(Wall6Grid::new(self.width, self.height));
-                } else {
-                    self.animation_steps =
-                        generate_steps(self.algorithm, self.seed, self.width, self.height);
-                    self.animation_index = 0;
-                    self.is_animating = true;
-                    let mut lock = self.maze.lock().unwrap();
-                    *lock = Wall4Grid::new(self.width, self.height);
-                }
-                #[cfg(not(feature = "generators-hex"))]
+            if self.mode == Mode::Maze {
+                ui.horizontal(|ui| {
+                    if ui.button("Animate Generation").clicked() {
+                        start_animation(self);
+                    }
+                    if is_animating(self) {
+                        let label = if self.animation_paused {
+                            "Resume"
+                        } else {
+                            "Pause"
+                        };
+                        if ui.button(label).clicked() {
+                            self.animation_paused = !self.animation_paused;
+                        }
+                    }
+                });
+            }
+
+            if self.mode == Mode::Maze && !self.algorithm.is_hex() {
+                ui.label("Solver:");
+                egui::ComboBox::from_id_salt("solver")
+                    .selected_text(self.solver.as_str())
+                    .show_ui(ui, |ui| {
+                        for solver in SolverChoice::ALL {
+                            ui.selectable_value(&mut self.solver, solver, solver.as_str());
+                        }
+                    });
+                let can_solve = self.start_cell.is_some() && self.end_cell.is_some();
+                if ui
+                    .add_enabled(can_solve, egui::Button::new("Animate Solve"))
+                    .on_disabled_hover_text("Click a start and an end cell first")
+                    .clicked()
                 {
-                    self.animation_steps =
-                        generate_steps(self.algorithm, self.seed, self.width, self.height);
-                    self.animation_index = 0;
-                    self.is_animating = true;
-                    let mut lock = self.maze.lock().unwrap();
-                    *lock = Wall4Grid::new(self.width, self.height);
+                    start_solve_animation(self);
                 }
             }
 
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut self.heatmap, "Distance Heatmap")
+                    .on_hover_text("Colors cells by distance from the start cell, or from an end of the longest path");
+                ui.add_enabled_ui(self.heatmap, |ui| {
+                    egui::ComboBox::from_id_salt("gradient")
+                        .selected_text(self.gradient.as_str())
+                        .show_ui(ui, |ui| {
+                            for gradient in GradientChoice::ALL {
+                                ui.selectable_value(&mut self.gradient, gradient, gradient.as_str());
+                            }
+                        });
+                });
+            });
+
             if ui.button("Reset View").clicked() {
                 self.auto_fit_pending = true;
                 self.pan = egui::Vec2::new(0.0, 0.0);
                 self.start_cell = None;
                 self.end_cell = None;
+                self.solve_animation = None;
                 #[cfg(feature = "generators-hex")]
                 {
                     self.hex_start_cell = None;
@@ -512,7 +793,7 @@ impl App for MyApp {
             if let (Some(start), Some(end)) = (self.start_cell, self.end_cell) {
                 if self.mode == Mode::Maze {
                     let maze = self.maze.lock().unwrap();
-                    let has_path = BfsSolver.solve(&maze, start, end).is_some();
+                    let has_path = BfsSolver.solve(&*maze, start, end).is_some();
                     ui.label(format!("Path ready: {has_path}"));
                 } else {
                     let dungeon = self.dungeon.lock().unwrap();
@@ -582,10 +863,17 @@ fn generate_maze(algorithm: AlgorithmChoice, seed: u64, width: usize, height: us
         AlgorithmChoice::Prim => {
             <Prim4 as MazeGenerator2D>::new_from_seed(seed).generate(width, height)
         }
+        AlgorithmChoice::RecursiveDivision => {
+            RecursiveDivision4::new_from_seed(seed).generate(width, height)
+        }
+        AlgorithmChoice::AldousBroder => AldousBroder4::new_from_seed(seed).generate(width, height),
         #[cfg(feature = "generators-hex")]
         AlgorithmChoice::RecursiveBacktracker6
         | AlgorithmChoice::GrowingTree6
-        | AlgorithmChoice::AldousBroder6 => Wall4Grid::new(width, height),
+        | AlgorithmChoice::AldousBroder6
+        | AlgorithmChoice::Wilson6
+        | AlgorithmChoice::Kruskal6
+        | AlgorithmChoice::Prim6 => Wall4Grid::new(width, height),
     }
 }
 
@@ -594,40 +882,49 @@ fn generate_steps(
     seed: u64,
     width: usize,
     height: usize,
-) -> Vec<GenerationStep> {
+) -> GenerationSteps {
     match algorithm {
-        AlgorithmChoice::RecursiveBacktracker => RecursiveBacktracker4::new_from_seed(seed)
-            .generate_steps(width, height)
-            .collect(),
-        AlgorithmChoice::GrowingTree => <GrowingTree4 as MazeGenerator2D>::new_from_seed(seed)
-            .generate_steps(width, height)
-            .collect(),
-        AlgorithmChoice::Kruskal => <Kruskal4 as MazeGenerator2D>::new_from_seed(seed)
-            .generate_steps(width, height)
-            .collect(),
-        AlgorithmChoice::Eller => <Eller4 as MazeGenerator2D>::new_from_seed(seed)
-            .generate_steps(width, height)
-            .collect(),
-        AlgorithmChoice::Wilson => <Wilson4 as MazeGenerator2D>::new_from_seed(seed)
-            .generate_steps(width, height)
-            .collect(),
-        AlgorithmChoice::HuntAndKill => <HuntAndKill4 as MazeGenerator2D>::new_from_seed(seed)
-            .generate_steps(width, height)
-            .collect(),
-        AlgorithmChoice::Sidewinder => <Sidewinder4 as MazeGenerator2D>::new_from_seed(seed)
-            .generate_steps(width, height)
-            .collect(),
-        AlgorithmChoice::BinaryTree => <BinaryTree4 as MazeGenerator2D>::new_from_seed(seed)
-            .generate_steps(width, height)
-            .collect(),
-        AlgorithmChoice::Prim => <Prim4 as MazeGenerator2D>::new_from_seed(seed)
-            .generate_steps(width, height)
-            .collect(),
+        AlgorithmChoice::RecursiveBacktracker => {
+            RecursiveBacktracker4::new_from_seed(seed).generate_steps(width, height)
+        }
+        AlgorithmChoice::GrowingTree => {
+            <GrowingTree4 as MazeGenerator2D>::new_from_seed(seed).generate_steps(width, height)
+        }
+        AlgorithmChoice::Kruskal => {
+            <Kruskal4 as MazeGenerator2D>::new_from_seed(seed).generate_steps(width, height)
+        }
+        AlgorithmChoice::Eller => {
+            <Eller4 as MazeGenerator2D>::new_from_seed(seed).generate_steps(width, height)
+        }
+        AlgorithmChoice::Wilson => {
+            <Wilson4 as MazeGenerator2D>::new_from_seed(seed).generate_steps(width, height)
+        }
+        AlgorithmChoice::HuntAndKill => {
+            <HuntAndKill4 as MazeGenerator2D>::new_from_seed(seed).generate_steps(width, height)
+        }
+        AlgorithmChoice::Sidewinder => {
+            <Sidewinder4 as MazeGenerator2D>::new_from_seed(seed).generate_steps(width, height)
+        }
+        AlgorithmChoice::BinaryTree => {
+            <BinaryTree4 as MazeGenerator2D>::new_from_seed(seed).generate_steps(width, height)
+        }
+        AlgorithmChoice::Prim => {
+            <Prim4 as MazeGenerator2D>::new_from_seed(seed).generate_steps(width, height)
+        }
+        AlgorithmChoice::RecursiveDivision => {
+            RecursiveDivision4::new_from_seed(seed).generate_steps(width, height)
+        }
+        AlgorithmChoice::AldousBroder => {
+            AldousBroder4::new_from_seed(seed).generate_steps(width, height)
+        }
         #[cfg(feature = "generators-hex")]
         AlgorithmChoice::RecursiveBacktracker6
         | AlgorithmChoice::GrowingTree6
-        | AlgorithmChoice::AldousBroder6 => {
-            vec![GenerationStep::Complete]
+        | AlgorithmChoice::AldousBroder6
+        | AlgorithmChoice::Wilson6
+        | AlgorithmChoice::Kruskal6
+        | AlgorithmChoice::Prim6 => {
+            GenerationSteps::finished(Wall4Grid::new(width, height), GenerationStep::Complete)
         }
     }
 }
@@ -638,29 +935,31 @@ fn generate_hex_steps(
     seed: u64,
     width: usize,
     height: usize,
-) -> Vec<HexGenerationStep> {
+) -> HexGenerationSteps {
     match algorithm {
-        AlgorithmChoice::RecursiveBacktracker6 => RecursiveBacktracker6::new_from_seed(seed)
-            .generate_steps(width, height)
-            .collect(),
-        AlgorithmChoice::GrowingTree6 => <GrowingTree6 as MazeGenerator6D>::new_from_seed(seed)
-            .generate_steps(width, height)
-            .collect(),
-        AlgorithmChoice::AldousBroder6 => AldousBroder6::new_from_seed(seed)
-            .generate_steps(width, height)
-            .collect(),
-        _ => vec![HexGenerationStep::Complete],
+        AlgorithmChoice::RecursiveBacktracker6 => {
+            RecursiveBacktracker6::new_from_seed(seed).generate_steps(width, height)
+        }
+        AlgorithmChoice::GrowingTree6 => {
+            <GrowingTree6 as MazeGenerator6D>::new_from_seed(seed).generate_steps(width, height)
+        }
+        AlgorithmChoice::AldousBroder6 => {
+            AldousBroder6::new_from_seed(seed).generate_steps(width, height)
+        }
+        AlgorithmChoice::Wilson6 => Wilson6::new_from_seed(seed).generate_steps(width, height),
+        AlgorithmChoice::Kruskal6 => Kruskal6::new_from_seed(seed).generate_steps(width, height),
+        AlgorithmChoice::Prim6 => Prim6::new_from_seed(seed).generate_steps(width, height),
+        _ => {
+            HexGenerationSteps::finished(Wall6Grid::new(width, height), HexGenerationStep::Complete)
+        }
     }
 }
 
 fn regenerate_maze(app: &mut MyApp) {
-    app.is_animating = false;
-    app.animation_steps.clear();
-    #[cfg(feature = "generators-hex")]
-    app.hex_animation_steps.clear();
-    app.animation_index = 0;
+    stop_animation(app);
     app.start_cell = None;
     app.end_cell = None;
+    app.solve_animation = None;
     #[cfg(feature = "generators-hex")]
     {
         app.hex_start_cell = None;
@@ -670,14 +969,91 @@ fn regenerate_maze(app: &mut MyApp) {
 
     #[cfg(feature = "generators-hex")]
     if app.algorithm.is_hex() {
-        let grid = generate_hex_maze(app.algorithm, app.seed, app.width, app.height);
+        let mut grid = generate_hex_maze(app.algorithm, app.seed, app.width, app.height);
+        if app.braid_ratio > 0.0 {
+            Braid6::new_from_seed(app.seed)
+                .with_ratio(app.braid_ratio)
+                .braid(&mut grid);
+        }
+        app.hex_heatmap = None;
         let mut lock = app.hex_maze.lock().unwrap();
         *lock = Some(grid);
         return;
     }
 
+    let mut grid = generate_maze(app.algorithm, app.seed, app.width, app.height);
+    if app.braid_ratio > 0.0 {
+        Braid4::new_from_seed(app.seed)
+            .with_ratio(app.braid_ratio)
+            .braid(&mut grid);
+    }
+    app.maze_heatmap = None;
     let mut lock = app.maze.lock().unwrap();
-    *lock = generate_maze(app.algorithm, app.seed, app.width, app.height);
+    *lock = grid;
+}
+
+/// Starts animating the generation of the selected algorithm from its initial grid.
+fn start_animation(app: &mut MyApp) {
+    stop_animation(app);
+    app.solve_animation = None;
+    app.animation_braid_pending = app.braid_ratio > 0.0;
+
+    #[cfg(feature = "generators-hex")]
+    if app.algorithm.is_hex() {
+        app.hex_animation = Some(generate_hex_steps(
+            app.algorithm,
+            app.seed,
+            app.width,
+            app.height,
+        ));
+        app.hex_heatmap = None;
+        let mut lock = app.hex_maze.lock().unwrap();
+        *lock = Some(Wall6Grid::new(app.width, app.height));
+        return;
+    }
+
+    app.animation = Some(generate_steps(
+        app.algorithm,
+        app.seed,
+        app.width,
+        app.height,
+    ));
+    app.maze_heatmap = None;
+    let mut lock = app.maze.lock().unwrap();
+    *lock = app.algorithm.initial_grid(app.width, app.height);
+}
+
+/// Records the selected solver's search between the start and end cells for replay.
+fn start_solve_animation(app: &mut MyApp) {
+    let (Some(start), Some(end)) = (app.start_cell, app.end_cell) else {
+        return;
+    };
+
+    let maze = app.maze.lock().unwrap();
+    let mut steps = Vec::new();
+    app.solver
+        .solver()
+        .solve_steps(&*maze, start, end)
+        .visit(&mut steps);
+    app.solve_animation = Some(SolveAnimation::new(steps, maze.width(), maze.height()));
+}
+
+fn stop_animation(app: &mut MyApp) {
+    app.animation = None;
+    #[cfg(feature = "generators-hex")]
+    {
+        app.hex_animation = None;
+    }
+    app.animation_braid_pending = false;
+    app.animation_paused = false;
+}
+
+fn is_animating(app: &MyApp) -> bool {
+    #[cfg(feature = "generators-hex")]
+    if app.hex_animation.is_some() {
+        return true;
+    }
+    app.animation.is_some()
 }
 
 #[cfg(feature = "generators-hex")]
@@ -697,6 +1073,9 @@ fn generate_hex_maze(
         AlgorithmChoice::AldousBroder6 => {
             AldousBroder6::new_from_seed(seed).generate(width, height)
         }
+        AlgorithmChoice::Wilson6 => Wilson6::new_from_seed(seed).generate(width, height),
+        AlgorithmChoice::Kruskal6 => Kruskal6::new_from_seed(seed).generate(width, height),
+        AlgorithmChoice::Prim6 => Prim6::new_from_seed(seed).generate(width, height),
         _ => Wall6Grid::new(width, height),
     }
 }
@@ -705,6 +1084,7 @@ fn regenerate_dungeon(app: &mut MyApp) {
     app.start_cell = None;
     app.end_cell = None;
     app.auto_fit_pending = true;
+    app.dungeon_heatmap = None;
     let mut lock = app.dungeon.lock().unwrap();
     *lock = DungeonWalkGenerator::new_from_seed(app.dungeon_type, app.seed)
         .with_winding_probability(app.winding_probability)
@@ -770,13 +1150,30 @@ fn render_maze(ui: &mut egui::Ui, app: &mut MyApp, ctx: &egui::Context) {
         } else {
             app.end_cell = Some(clicked);
         }
+        app.solve_animation = None;
     }
 
-    let solution = if let (Some(start), Some(end)) = (app.start_cell, app.end_cell) {
-        BfsSolver.solve(&maze, start, end)
-    } else {
-        None
+    // A replayed search shows its own progress instead of the finished solution.
+    let solution = match (app.start_cell, app.end_cell) {
+        (Some(start), Some(end)) if app.solve_animation.is_none() => {
+            BfsSolver.solve(&*maze, start, end)
+        }
+        _ => None,
     };
+    if app.heatmap
+        && !app
+            .maze_heatmap
+            .as_ref()
+            .is_some_and(|heatmap| heatmap.is_current(app.start_cell, app.gradient))
+    {
+        app.maze_heatmap = Some(Heatmap::new(
+            &*maze,
+            app.start_cell,
+            GridCoord2D::new(0, 0),
+            app.gradient,
+        ));
+    }
+    let heatmap = app.maze_heatmap.as_ref().filter(|_| app.heatmap);
 
     for y in 0..maze.height() {
         for x in 0..maze.width() {
@@ -810,8 +1207,16 @@ fn render_maze(ui: &mut egui::Ui, app: &mut MyApp, ctx: &egui::Context) {
                 Color32::from_rgb(200, 200, 255)
             } else if is_in_solution {
                 Color32::from_rgb(180, 230, 180)
+            } else if let Some(color) = app
+                .solve_animation
+                .as_ref()
+                .and_then(|animation| animation.fill_color(coord))
+            {
+                color
             } else if hovered_coord.is_some_and(|c| c == coord) {
                 Color32::from_rgb(255, 255, 200)
+            } else if let Some(color) = heatmap.as_ref().and_then(|h| h.fill_color(coord)) {
+                color
             } else if (x + y) % 2 == 0 {
                 Color32::from_rgb(240, 240, 240)
             } else {
@@ -921,6 +1326,24 @@ fn render_dungeon(ui: &mut egui::Ui, app: &mut MyApp, ctx: &egui::Context) {
     } else {
         None
     };
+    if app.heatmap
+        && !app
+            .dungeon_heatmap
+            .as_ref()
+            .is_some_and(|heatmap| heatmap.is_current(app.start_cell, app.gradient))
+    {
+        let first_floor = (0..dungeon.height())
+            .flat_map(|y| (0..dungeon.width()).map(move |x| GridCoord2D::new(x, y)))
+            .find(|&coord| dungeon.is_floor(coord))
+            .unwrap_or_default();
+        app.dungeon_heatmap = Some(Heatmap::new(
+            &*dungeon,
+            app.start_cell,
+            first_floor,
+            app.gradient,
+        ));
+    }
+    let heatmap = app.dungeon_heatmap.as_ref().filter(|_| app.heatmap);
 
     for y in 0..dungeon.height() {
         for x in 0..dungeon.width() {
@@ -954,6 +1377,8 @@ fn render_dungeon(ui: &mut egui::Ui, app: &mut MyApp, ctx: &egui::Context) {
                         Color32::from_rgb(180, 230, 180)
                     } else if hovered_coord.is_some_and(|c| c == coord) {
                         Color32::from_rgb(255, 255, 200)
+                    } else if let Some(color) = heatmap.as_ref().and_then(|h| h.fill_color(coord)) {
+                        color
                     } else {
                         Color32::from_rgb(220, 220, 200) // Light tan for floor
                     }
@@ -984,44 +1409,6 @@ fn fit_zoom_to_available(maze: &Wall4Grid, available_size: egui::Vec2) -> f32 {
     fit_w.min(fit_h).clamp(0.1, 5.0)
 }
 
-#[cfg(feature = "generators-hex")]
-fn bfs_hex_solve(
-    maze: &Wall6Grid,
-    start: HexCoord,
-    end: HexCoord,
-) -> Option<std::collections::HashSet<HexCoord>> {
-    use std::collections::{HashMap, HashSet, VecDeque};
-    if maze.get(start).is_none() || maze.get(end).is_none() {
-        return None;
-    }
-    let mut queue = VecDeque::new();
-    let mut parent: HashMap<HexCoord, HexCoord> = HashMap::new();
-    let mut seen: HashSet<HexCoord> = HashSet::new();
-    seen.insert(start);
-    queue.push_back(start);
-    while let Some(cell) = queue.pop_front() {
-        if cell == end {
-            let mut path: HashSet<HexCoord> = HashSet::new();
-            let mut cur = end;
-            path.insert(cur);
-            while cur != start {
-                let p = *parent.get(&cur)?;
-                path.insert(p);
-                cur = p;
-            }
-            return Some(path);
-        }
-        for next in maze.open_neighbors(cell) {
-            if !seen.contains(&next) {
-                seen.insert(next);
-                parent.insert(next, cell);
-                queue.push_back(next);
-            }
-        }
-    }
-    None
-}
-
 #[cfg(feature = "generators-hex")]
 fn render_hex_maze(ui: &mut egui::Ui, app: &mut MyApp, ctx: &egui::Context) {
     let hex_maze = app.hex_maze.lock().unwrap();
@@ -1124,12 +1511,25 @@ fn render_hex_maze(ui: &mut egui::Ui, app: &mut MyApp, ctx: &egui::Context) {
         }
     }
 
-    let solution: Option<std::collections::HashSet<HexCoord>> =
-        if let (Some(s), Some(e)) = (app.hex_start_cell, app.hex_end_cell) {
-            bfs_hex_solve(maze, s, e)
-        } else {
-            None
-        };
+    let solution = if let (Some(start), Some(end)) = (app.hex_start_cell, app.hex_end_cell) {
+        BfsSolver.solve(maze, start, end)
+    } else {
+        None
+    };
+    if app.heatmap
+        && !app
+            .hex_heatmap
+            .as_ref()
+            .is_some_and(|heatmap| heatmap.is_current(app.hex_start_cell, app.gradient))
+    {
+        app.hex_heatmap = Some(Heatmap::new(
+            maze,
+            app.hex_start_cell,
+            HexCoord::new(0, 0),
+            app.gradient,
+        ));
+    }
+    let heatmap = app.hex_heatmap.as_ref().filter(|_| app.heatmap);
 
     let stroke = egui::Stroke::new(2.0, Color32::BLACK);
 
@@ -1197,7 +1597,9 @@ fn render_hex_maze(ui: &mut egui::Ui, app: &mut MyApp, ctx: &egui::Context) {
 
             let shade_idx = (q as isize - r as isize).rem_euclid(3) as usize;
             let coord_here = HexCoord::new(q as isize, r as isize);
-            let in_solution = solution.as_ref().is_some_and(|p| p.contains(&coord_here));
+            let in_solution = solution
+                .as_ref()
+                .is_some_and(|path| path.cells().contains(&coord_here));
             let fill_color = if Some(coord_here) == app.hex_start_cell {
                 Color32::from_rgb(255, 200, 200)
             } else if Some(coord_here) == app.hex_end_cell {
@@ -1206,6 +1608,8 @@ fn render_hex_maze(ui: &mut egui::Ui, app: &mut MyApp, ctx: &egui::Context) {
                 Color32::from_rgb(180, 230, 180)
             } else if hovered_coord == Some(coord_here) {
                 Color32::from_rgb(255, 255, 200)
+            } else if let Some(color) = heatmap.as_ref().and_then(|h| h.fill_color(coord_here)) {
+                color
             } else {
                 fill_shades[shade_idx]
             };
@@ -1294,55 +1698,80 @@ fn fit_zoom_to_dungeon(dungeon: &DungeonGrid, available_size: egui::Vec2) -> f32
     fit_w.min(fit_h).clamp(0.1, 5.0)
 }
 
+/// The number of animation steps applied per frame.
+const ANIMATION_STEPS_PER_FRAME: usize = 8;
+
+/// The number of search steps replayed per frame, slow enough to follow the search.
+const SOLVE_STEPS_PER_FRAME: usize = 2;
+
 fn tick_animation(app: &mut MyApp) {
-    if !app.is_animating {
+    if app.animation_paused {
         return;
     }
 
     #[cfg(feature = "generators-hex")]
-    if !app.hex_animation_steps.is_empty() {
+    if let Some(steps) = app.hex_animation.as_mut() {
+        app.hex_heatmap = None;
         let mut lock = app.hex_maze.lock().unwrap();
         let Some(maze) = lock.as_mut() else {
-            app.is_animating = false;
+            app.hex_animation = None;
             return;
         };
-        let mut processed = 0usize;
-        while app.animation_index < app.hex_animation_steps.len() && processed < 8 {
-            match app.hex_animation_steps[app.animation_index] {
-                HexGenerationStep::Carve { from, to } => maze.remove_wall_between(from, to),
-                HexGenerationStep::Complete => {
-                    app.is_animating = false;
-                    break;
-                }
-                _ => {}
+        for step in steps.by_ref().take(ANIMATION_STEPS_PER_FRAME) {
+            if let HexGenerationStep::Carve { from, to } = step {
+                maze.remove_wall_between(from, to);
             }
-            app.animation_index += 1;
-            processed += 1;
         }
 
-        if app.animation_index >= app.hex_animation_steps.len() {
-            app.is_animating = false;
+        if steps.is_complete() {
+            let steps = app.hex_animation.take().expect("animation in progress");
+            if std::mem::take(&mut app.animation_braid_pending) {
+                // Braid the finished maze, animating the removed dead ends.
+                app.hex_animation = Some(
+                    Braid6::new_from_seed(app.seed)
+                        .with_ratio(app.braid_ratio)
+                        .braid_steps(steps.finish()),
+                );
+            }
         }
         return;
     }
 
+    let Some(steps) = app.animation.as_mut() else {
+        return;
+    };
+    app.maze_heatmap = None;
     let mut maze = app.maze.lock().unwrap();
-    let mut processed = 0usize;
-    while app.animation_index < app.animation_steps.len() && processed < 8 {
-        match app.animation_steps[app.animation_index] {
+    for step in steps.by_ref().take(ANIMATION_STEPS_PER_FRAME) {
+        match step {
             GenerationStep::Carve { from, to } => maze.remove_wall_between(from, to),
-            GenerationStep::Complete => {
-                app.is_animating = false;
-                break;
-            }
+            GenerationStep::AddWall { from, to } => maze.add_wall_between(from, to),
             _ => {}
         }
-        app.animation_index += 1;
-        processed += 1;
     }
 
-    if app.animation_index >= app.animation_steps.len() {
-        app.is_animating = false;
+    if steps.is_complete() {
+        let steps = app.animation.take().expect("animation in progress");
+        if std::mem::take(&mut app.animation_braid_pending) {
+            // Braid the finished maze, animating the removed dead ends.
+            app.animation = Some(
+                Braid4::new_from_seed(app.seed)
+                    .with_ratio(app.braid_ratio)
+                    .braid_steps(steps.finish()),
+            );
+        }
+    }
+}
+
+fn tick_solve_animation(app: &mut MyApp) {
+    let Some(animation) = app.solve_animation.as_mut() else {
+        return;
+    };
+    for _ in 0..SOLVE_STEPS_PER_FRAME {
+        let Some(step) = animation.steps.next() else {
+            break;
+        };
+        animation.apply(step);
     }
 }
 
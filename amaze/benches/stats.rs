@@ -3,7 +3,12 @@ use amaze::stats::MazeStats;
 use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
 
 fn bench_stats(c: &mut Criterion) {
-    let sizes = [(16, "16x16"), (64, "64x64"), (128, "128x128")];
+    let sizes = [
+        (16, "16x16"),
+        (64, "64x64"),
+        (128, "128x128"),
+        (512, "512x512"),
+    ];
 
     let generator = RecursiveBacktracker4::new_from_seed(1337);
 
@@ -0,0 +1,286 @@
+use std::fmt::{Debug, Formatter};
+use std::ops::{Add, AddAssign, Deref, Not, Sub, SubAssign};
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Direction3(u8);
+
+/// A 3-connected (triangular) neighborhood direction.
+///
+/// Upward-pointing triangles have their base at the bottom, downward-pointing
+/// triangles at the top, so [`BASE`](Self::BASE) leads south or north
+/// depending on the orientation of the cell.
+impl Direction3 {
+    /// The east direction.
+    pub const EAST: Self = Self(0b001);
+    /// The west direction.
+    pub const WEST: Self = Self(0b010);
+    /// The direction across the horizontal edge of the triangle.
+    pub const BASE: Self = Self(0b100);
+    /// All directions.
+    pub const ALL: Self = Self(0b111);
+    /// No direction.
+    pub const NONE: Self = Self(0b000);
+    /// A bit mask used for obtaining valid values from arbitrary inputs.
+    const MASK: u8 = Self::ALL.0;
+
+    #[cfg(any(feature = "binary-format", feature = "json-format"))]
+    #[inline]
+    pub(crate) fn from_bits(byte: u8) -> Self {
+        Self(byte & Self::MASK)
+    }
+
+    /// Tests whether this direction value "contains" a specified set of directions.
+    #[inline]
+    pub fn contains(&self, other: Direction3) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Tests whether this direction encodes "all" directions.
+    #[inline]
+    pub fn is_all(&self) -> bool {
+        self.0 == Self::ALL.0
+    }
+
+    /// Tests whether this direction encodes "no" directions.
+    #[inline]
+    pub fn is_none(&self) -> bool {
+        self.0 == Self::NONE.0
+    }
+
+    /// Tests whether this direction encodes a trivial (single) direction.
+    pub fn is_trivial(&self) -> bool {
+        matches!(*self, Self::EAST | Self::WEST | Self::BASE)
+    }
+
+    /// Returns the direction leading back; the base is shared by both cells.
+    #[inline]
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::EAST => Self::WEST,
+            Self::WEST => Self::EAST,
+            Self::BASE => Self::BASE,
+            _ => Self::NONE,
+        }
+    }
+
+    pub const CARDINALS: [Self; 3] = [Self::EAST, Self::WEST, Self::BASE];
+
+    /// Adds a new direction to this value.
+    #[inline]
+    pub fn include(&mut self, rhs: Self) -> &mut Self {
+        *self += rhs;
+        self
+    }
+
+    /// Removes a direction from this value.
+    #[inline]
+    pub fn remove(&mut self, rhs: Self) -> &mut Self {
+        *self -= rhs;
+        self
+    }
+
+    /// Joins two direction values
+    #[inline]
+    pub const fn join(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl Default for Direction3 {
+    #[inline]
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+impl Add for Direction3 {
+    type Output = Self;
+
+    /// Joins two direction values.
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl AddAssign for Direction3 {
+    /// Joins two direction values while assigning.
+    #[inline]
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl Sub for Direction3 {
+    type Output = Self;
+
+    /// Removes a direction value from a direction.
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self((self.0 & !rhs.0) & Direction3::MASK)
+    }
+}
+
+impl SubAssign for Direction3 {
+    /// Removes a direction value from a direction while assigning.
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 = (self.0 & !rhs.0) & Direction3::MASK;
+    }
+}
+
+impl Not for Direction3 {
+    type Output = Direction3;
+
+    /// Negates a direction value.
+    fn not(self) -> Self::Output {
+        Self((!self.0) & Self::MASK)
+    }
+}
+
+impl Deref for Direction3 {
+    type Target = u8;
+
+    /// Gets the byte representation of a direction.
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct Direction3Iterator {
+    direction: Direction3,
+    step: u8,
+}
+
+impl Iterator for Direction3Iterator {
+    type Item = Direction3;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.step < 3 {
+            let current = Direction3(1 << self.step);
+            self.step += 1;
+            if self.direction.contains(current) {
+                return Some(current);
+            }
+        }
+
+        None
+    }
+}
+
+impl IntoIterator for Direction3 {
+    type Item = Direction3;
+    type IntoIter = Direction3Iterator;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Direction3Iterator {
+            direction: self,
+            step: 0,
+        }
+    }
+}
+
+impl Debug for Direction3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "0b{0:03b} ", self.0)?;
+
+        match *self {
+            Self::NONE => {
+                write!(f, "(none)")?;
+            }
+            dir => {
+                write!(f, "(")?;
+                if dir.contains(Self::EAST) {
+                    write!(f, "E")?;
+                }
+                if dir.contains(Self::WEST) {
+                    write!(f, "W")?;
+                }
+                if dir.contains(Self::BASE) {
+                    write!(f, "B")?;
+                }
+                write!(f, ")")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_none() {
+        assert_eq!(Direction3::default(), Direction3::NONE);
+        assert!(Direction3::default().is_none());
+        assert_eq!(*Direction3::default(), 0);
+    }
+
+    #[test]
+    fn all_contains_all_directions() {
+        assert!(Direction3::ALL.contains(Direction3::EAST));
+        assert!(Direction3::ALL.contains(Direction3::WEST));
+        assert!(Direction3::ALL.contains(Direction3::BASE));
+        assert!(Direction3::ALL.contains(Direction3::NONE));
+        assert!(Direction3::ALL.is_all());
+        assert_eq!(*Direction3::ALL, 0b111);
+    }
+
+    #[test]
+    fn add_and_sub_combine_directions() {
+        let direction = Direction3::EAST + Direction3::BASE;
+        assert!(direction.contains(Direction3::EAST));
+        assert!(!direction.contains(Direction3::WEST));
+        assert_eq!(Direction3::ALL - Direction3::WEST, direction);
+
+        let mut direction = Direction3::ALL;
+        direction.remove(Direction3::EAST).remove(Direction3::BASE);
+        assert_eq!(direction, Direction3::WEST);
+        direction += Direction3::BASE;
+        direction -= Direction3::WEST;
+        assert_eq!(direction, Direction3::BASE);
+    }
+
+    #[test]
+    fn not_inverts_selection() {
+        assert_eq!(!Direction3::ALL, Direction3::NONE);
+        assert_eq!(!Direction3::BASE, Direction3::EAST + Direction3::WEST);
+    }
+
+    #[test]
+    fn into_iter_enumerates_contained() {
+        let dirs: Vec<_> = (Direction3::EAST + Direction3::BASE).into_iter().collect();
+        assert_eq!(dirs, vec![Direction3::EAST, Direction3::BASE]);
+        assert_eq!(Direction3::ALL.into_iter().count(), 3);
+    }
+
+    #[test]
+    fn debug_lists_directions() {
+        assert_eq!(format!("{:?}", Direction3::NONE), "0b000 (none)");
+        assert_eq!(format!("{:?}", Direction3::ALL), "0b111 (EWB)");
+        assert_eq!(format!("{:?}", Direction3::BASE), "0b100 (B)");
+    }
+
+    #[test]
+    fn is_trivial_works() {
+        assert!(Direction3::EAST.is_trivial());
+        assert!(Direction3::WEST.is_trivial());
+        assert!(Direction3::BASE.is_trivial());
+        assert!(!Direction3::NONE.is_trivial());
+        assert!(!Direction3::ALL.is_trivial());
+    }
+
+    #[test]
+    fn opposite_works() {
+        assert_eq!(Direction3::EAST.opposite(), Direction3::WEST);
+        assert_eq!(Direction3::WEST.opposite(), Direction3::EAST);
+        assert_eq!(Direction3::BASE.opposite(), Direction3::BASE);
+        assert_eq!(Direction3::ALL.opposite(), Direction3::NONE);
+    }
+}
@@ -0,0 +1,292 @@
+use std::fmt::{Debug, Formatter};
+use std::ops::{Add, AddAssign, Deref, Not, Sub, SubAssign};
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Direction3D(u8);
+
+/// A 6-connected (cubic) neighborhood direction.
+///
+/// The four horizontal directions use the same bits as [`Direction4`](crate::preamble::Direction4);
+/// [`UP`](Self::UP) and [`DOWN`](Self::DOWN) lead to the adjacent layers.
+impl Direction3D {
+    /// The north direction.
+    pub const NORTH: Self = Self(0b000001);
+    /// The south direction.
+    pub const SOUTH: Self = Self(0b000010);
+    /// The east direction.
+    pub const EAST: Self = Self(0b000100);
+    /// The west direction.
+    pub const WEST: Self = Self(0b001000);
+    /// The direction to the layer above.
+    pub const UP: Self = Self(0b010000);
+    /// The direction to the layer below.
+    pub const DOWN: Self = Self(0b100000);
+    /// All directions.
+    pub const ALL: Self = Self(0b111111);
+    /// No direction.
+    pub const NONE: Self = Self(0b000000);
+    /// A bit mask used for obtaining valid values from arbitrary inputs.
+    const MASK: u8 = Self::ALL.0;
+
+    #[cfg(any(feature = "binary-format", feature = "json-format"))]
+    #[inline]
+    pub(crate) fn from_bits(byte: u8) -> Self {
+        Self(byte & Self::MASK)
+    }
+
+    /// Tests whether this direction value "contains" a specified set of directions.
+    #[inline]
+    pub fn contains(&self, other: Direction3D) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Tests whether this direction encodes "all" directions.
+    #[inline]
+    pub fn is_all(&self) -> bool {
+        self.0 == Self::ALL.0
+    }
+
+    /// Tests whether this direction encodes "no" directions.
+    #[inline]
+    pub fn is_none(&self) -> bool {
+        self.0 == Self::NONE.0
+    }
+
+    /// Tests whether this direction encodes a trivial (single) direction.
+    pub fn is_trivial(&self) -> bool {
+        matches!(
+            *self,
+            Self::NORTH | Self::SOUTH | Self::EAST | Self::WEST | Self::UP | Self::DOWN
+        )
+    }
+
+    #[inline]
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::NORTH => Self::SOUTH,
+            Self::SOUTH => Self::NORTH,
+            Self::EAST => Self::WEST,
+            Self::WEST => Self::EAST,
+            Self::UP => Self::DOWN,
+            Self::DOWN => Self::UP,
+            _ => Self::NONE,
+        }
+    }
+
+    /// The horizontal directions in clockwise order, followed by up and down.
+    pub const CARDINALS: [Self; 6] = [
+        Self::NORTH,
+        Self::EAST,
+        Self::SOUTH,
+        Self::WEST,
+        Self::UP,
+        Self::DOWN,
+    ];
+
+    /// Adds a new direction to this value.
+    #[inline]
+    pub fn include(&mut self, rhs: Self) -> &mut Self {
+        *self += rhs;
+        self
+    }
+
+    /// Removes a direction from this value.
+    #[inline]
+    pub fn remove(&mut self, rhs: Self) -> &mut Self {
+        *self -= rhs;
+        self
+    }
+
+    /// Joins two direction values
+    #[inline]
+    pub const fn join(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl Default for Direction3D {
+    #[inline]
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+impl Add for Direction3D {
+    type Output = Self;
+
+    /// Joins two direction values.
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl AddAssign for Direction3D {
+    /// Joins two direction values while assigning.
+    #[inline]
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl Sub for Direction3D {
+    type Output = Self;
+
+    /// Removes a direction value from a direction.
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self((self.0 & !rhs.0) & Direction3D::MASK)
+    }
+}
+
+impl SubAssign for Direction3D {
+    /// Removes a direction value from a direction while assigning.
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 = (self.0 & !rhs.0) & Direction3D::MASK;
+    }
+}
+
+impl Not for Direction3D {
+    type Output = Direction3D;
+
+    /// Negates a direction value.
+    fn not(self) -> Self::Output {
+        Self((!self.0) & Self::MASK)
+    }
+}
+
+impl Deref for Direction3D {
+    type Target = u8;
+
+    /// Gets the byte representation of a direction.
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct Direction3DIterator {
+    direction: Direction3D,
+    step: u8,
+}
+
+impl Iterator for Direction3DIterator {
+    type Item = Direction3D;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.step < 6 {
+            let current = Direction3D(1 << self.step);
+            self.step += 1;
+            if self.direction.contains(current) {
+                return Some(current);
+            }
+        }
+
+        None
+    }
+}
+
+impl IntoIterator for Direction3D {
+    type Item = Direction3D;
+    type IntoIter = Direction3DIterator;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Direction3DIterator {
+            direction: self,
+            step: 0,
+        }
+    }
+}
+
+impl Debug for Direction3D {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "0b{0:06b} ", self.0)?;
+
+        match *self {
+            Self::NONE => {
+                write!(f, "(none)")?;
+            }
+            dir => {
+                write!(f, "(")?;
+                for (direction, name) in [
+                    (Self::NORTH, "N"),
+                    (Self::SOUTH, "S"),
+                    (Self::EAST, "E"),
+                    (Self::WEST, "W"),
+                    (Self::UP, "U"),
+                    (Self::DOWN, "D"),
+                ] {
+                    if dir.contains(direction) {
+                        write!(f, "{name}")?;
+                    }
+                }
+                write!(f, ")")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_none() {
+        assert_eq!(Direction3D::default(), Direction3D::NONE);
+        assert_eq!(*Direction3D::default(), 0);
+    }
+
+    #[test]
+    fn all_contains_all_directions() {
+        for dir in Direction3D::CARDINALS {
+            assert!(Direction3D::ALL.contains(dir));
+            assert!(dir.is_trivial());
+        }
+        assert!(Direction3D::ALL.is_all());
+        assert_eq!(*Direction3D::ALL, 0b111111);
+    }
+
+    #[test]
+    fn add_and_sub_combine_directions() {
+        let direction = Direction3D::UP + Direction3D::EAST;
+        assert!(direction.contains(Direction3D::UP));
+        assert!(!direction.contains(Direction3D::DOWN));
+
+        let mut direction = Direction3D::ALL;
+        direction -= Direction3D::UP;
+        direction.remove(Direction3D::NORTH);
+        assert_eq!(!direction, Direction3D::UP + Direction3D::NORTH);
+    }
+
+    #[test]
+    fn into_iter_enumerates_contained() {
+        let dirs: Vec<_> = (Direction3D::DOWN + Direction3D::SOUTH)
+            .into_iter()
+            .collect();
+        assert_eq!(dirs, vec![Direction3D::SOUTH, Direction3D::DOWN]);
+        assert_eq!(Direction3D::ALL.into_iter().count(), 6);
+    }
+
+    #[test]
+    fn opposite_works() {
+        for dir in Direction3D::CARDINALS {
+            assert_eq!(dir.opposite().opposite(), dir);
+            assert_ne!(dir.opposite(), dir);
+        }
+        assert_eq!(Direction3D::UP.opposite(), Direction3D::DOWN);
+        assert_eq!(Direction3D::ALL.opposite(), Direction3D::NONE);
+    }
+
+    #[test]
+    fn debug_lists_directions() {
+        assert_eq!(format!("{:?}", Direction3D::NONE), "0b000000 (none)");
+        assert_eq!(format!("{:?}", Direction3D::ALL), "0b111111 (NSEWUD)");
+        assert_eq!(format!("{:?}", Direction3D::UP), "0b010000 (U)");
+    }
+}
@@ -94,6 +94,38 @@ impl Direction4 {
         }
     }
 
+    /// Returns the direction a quarter turn counterclockwise from this one.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::direction4::Direction4;
+    /// assert_eq!(Direction4::NORTH.turn_left(), Direction4::WEST);
+    /// assert_eq!(Direction4::WEST.turn_left(), Direction4::SOUTH);
+    /// ```
+    #[inline]
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::NORTH => Self::WEST,
+            Self::WEST => Self::SOUTH,
+            Self::SOUTH => Self::EAST,
+            Self::EAST => Self::NORTH,
+            _ => Self::NONE,
+        }
+    }
+
+    /// Returns the direction a quarter turn clockwise from this one.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::direction4::Direction4;
+    /// assert_eq!(Direction4::NORTH.turn_right(), Direction4::EAST);
+    /// assert_eq!(Direction4::WEST.turn_right(), Direction4::NORTH);
+    /// ```
+    #[inline]
+    pub fn turn_right(self) -> Self {
+        self.turn_left().opposite()
+    }
+
     #[inline]
     pub fn from_delta(dx: isize, dy: isize) -> Option<Self> {
         match (dx, dy) {
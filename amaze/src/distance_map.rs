@@ -0,0 +1,287 @@
+//! Multi-source distance maps and flow fields.
+//!
+//! A [`DistanceMap`] stores the number of steps from every reachable cell to the
+//! nearest of one or more source cells. Besides plain distances it answers which
+//! neighbor leads one step closer to a source, which turns a single breadth-first
+//! search into a flow field that any number of agents can follow.
+//!
+//! Distance maps are built over any [`WalkableGrid`]: every [`MazeGrid`], as well as
+//! [`DungeonGrid`] and [`PassabilityGrid`](crate::preamble::PassabilityGrid), where
+//! passable tiles are 4-connected.
+//!
+//! ## Example
+//! ```
+//! use amaze::distance_map::DistanceMap;
+//! use amaze::generators::RecursiveBacktracker4;
+//! use amaze::preamble::GridCoord2D;
+//!
+//! let maze = RecursiveBacktracker4::new_from_seed(7).generate(8, 8);
+//! let exits = [GridCoord2D::new(0, 0), GridCoord2D::new(7, 7)];
+//! let field = DistanceMap::from_sources(&maze, exits);
+//!
+//! // Every monster walks towards its closest exit.
+//! let monster = GridCoord2D::new(3, 4);
+//! let next = field.next_step(monster).unwrap();
+//! assert_eq!(field.distance(next), Some(field.distance(monster).unwrap() - 1));
+//! ```
+
+use crate::dungeon::DungeonGrid;
+use crate::grid_coord_2d::GridCoord2D;
+use crate::maze_grid::MazeGrid;
+use crate::path::Path;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+/// A grid that agents can walk between adjacent cells of.
+///
+/// Every [`MazeGrid`] is walkable through its open passages; tile grids are walkable
+/// between 4-connected passable tiles.
+pub trait WalkableGrid {
+    /// The coordinate type addressing a single cell.
+    type Coord: Copy + Eq + Hash;
+
+    /// Tests whether the cell lies inside the grid and can be stood on.
+    fn is_walkable(&self, cell: Self::Coord) -> bool;
+
+    /// Returns the walkable cells that can be entered from `cell` in a single step.
+    fn walkable_neighbors(&self, cell: Self::Coord) -> impl Iterator<Item = Self::Coord> + '_;
+}
+
+impl<G: MazeGrid> WalkableGrid for G {
+    type Coord = G::Coord;
+
+    #[inline]
+    fn is_walkable(&self, cell: G::Coord) -> bool {
+        self.contains(cell)
+    }
+
+    fn walkable_neighbors(&self, cell: G::Coord) -> impl Iterator<Item = G::Coord> + '_ {
+        self.open_neighbors(cell)
+    }
+}
+
+impl WalkableGrid for DungeonGrid {
+    type Coord = GridCoord2D;
+
+    #[inline]
+    fn is_walkable(&self, cell: GridCoord2D) -> bool {
+        self.is_floor(cell)
+    }
+
+    fn walkable_neighbors(&self, cell: GridCoord2D) -> impl Iterator<Item = GridCoord2D> + '_ {
+        orthogonal_neighbors(cell).filter(|&n| self.is_floor(n))
+    }
+}
+
+#[cfg(feature = "representations")]
+impl WalkableGrid for crate::representations::PassabilityGrid {
+    type Coord = GridCoord2D;
+
+    #[inline]
+    fn is_walkable(&self, cell: GridCoord2D) -> bool {
+        self.is_passable(cell.x, cell.y)
+    }
+
+    fn walkable_neighbors(&self, cell: GridCoord2D) -> impl Iterator<Item = GridCoord2D> + '_ {
+        orthogonal_neighbors(cell).filter(|&n| self.is_passable(n.x, n.y))
+    }
+}
+
+/// Returns the up to four cells sharing an edge with `cell`, without bounds checks
+/// beyond the grid origin.
+fn orthogonal_neighbors(cell: GridCoord2D) -> impl Iterator<Item = GridCoord2D> {
+    let GridCoord2D { x, y } = cell;
+    [
+        y.checked_sub(1).map(|y| GridCoord2D::new(x, y)),
+        Some(GridCoord2D::new(x + 1, y)),
+        Some(GridCoord2D::new(x, y + 1)),
+        x.checked_sub(1).map(|x| GridCoord2D::new(x, y)),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// The step distance from every reachable cell to its nearest source.
+///
+/// The map owns its data and does not borrow the grid, so it can be kept around and
+/// shared as long as the grid does not change.
+#[derive(Debug, Clone)]
+pub struct DistanceMap<C = GridCoord2D> {
+    /// The distance of each reached cell and the neighbor one step closer to a source.
+    reached: HashMap<C, (usize, Option<C>)>,
+    /// The reached cells in order of increasing distance.
+    order: Vec<C>,
+    /// The index into `order` at which each distance begins, plus a final end marker.
+    band_starts: Vec<usize>,
+}
+
+impl<C: Copy + Eq + Hash> DistanceMap<C> {
+    /// Computes the distances from a single source cell.
+    pub fn new<G>(grid: &G, source: C) -> Self
+    where
+        G: WalkableGrid<Coord = C> + ?Sized,
+    {
+        Self::from_sources(grid, [source])
+    }
+
+    /// Computes the distance from every cell to the closest of the source cells.
+    ///
+    /// Sources that are not walkable are ignored; without any walkable source the map
+    /// is empty.
+    pub fn from_sources<G, I>(grid: &G, sources: I) -> Self
+    where
+        G: WalkableGrid<Coord = C> + ?Sized,
+        I: IntoIterator<Item = C>,
+    {
+        let mut reached = HashMap::new();
+        let mut order = Vec::new();
+        let mut band_starts = Vec::new();
+        let mut queue = VecDeque::new();
+
+        for source in sources {
+            if !grid.is_walkable(source) {
+                continue;
+            }
+            if let Entry::Vacant(entry) = reached.entry(source) {
+                entry.insert((0, None));
+                queue.push_back((source, 0));
+            }
+        }
+
+        while let Some((cell, distance)) = queue.pop_front() {
+            if distance == band_starts.len() {
+                band_starts.push(order.len());
+            }
+            order.push(cell);
+
+            for next in grid.walkable_neighbors(cell) {
+                if let Entry::Vacant(entry) = reached.entry(next) {
+                    entry.insert((distance + 1, Some(cell)));
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+        band_starts.push(order.len());
+
+        Self {
+            reached,
+            order,
+            band_starts,
+        }
+    }
+
+    /// Computes the distances from one end of the longest shortest path through the
+    /// cells reachable from `cell`.
+    ///
+    /// This is the two-sweep search behind [`MazeStats::longest_path`]: the cell
+    /// farthest from `cell` becomes the only source. In perfect mazes it ends a
+    /// diameter of the maze, so the map's [`farthest`](Self::farthest) cell is the
+    /// other end; with loops it is a close approximation. If `cell` is not walkable
+    /// the map is empty.
+    ///
+    /// [`MazeStats::longest_path`]: crate::stats::MazeStats::longest_path
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::distance_map::DistanceMap;
+    /// use amaze::generators::RecursiveBacktracker4;
+    /// use amaze::preamble::{GridCoord2D, MazeStats};
+    ///
+    /// let maze = RecursiveBacktracker4::new_from_seed(7).generate(8, 8);
+    /// let map = DistanceMap::from_diameter_end(&maze, GridCoord2D::new(0, 0));
+    /// assert_eq!(map.max_distance(), Some(MazeStats::from_grid(&maze).longest_path));
+    /// ```
+    pub fn from_diameter_end<G>(grid: &G, cell: C) -> Self
+    where
+        G: WalkableGrid<Coord = C> + ?Sized,
+    {
+        let first = Self::new(grid, cell);
+        match first.farthest() {
+            Some((end, _)) => Self::new(grid, end),
+            None => first,
+        }
+    }
+
+    /// Returns the source cells that were walkable, in the order they were given.
+    pub fn sources(&self) -> &[C] {
+        self.band(0)
+    }
+
+    /// Returns the number of steps from `cell` to the nearest source, or `None` if it
+    /// cannot be reached.
+    pub fn distance(&self, cell: C) -> Option<usize> {
+        self.reached.get(&cell).map(|&(distance, _)| distance)
+    }
+
+    /// Returns the number of reachable cells, including the sources.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Returns `true` if no source was walkable.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Returns the largest distance of any reachable cell.
+    pub fn max_distance(&self) -> Option<usize> {
+        self.band_starts.len().checked_sub(2)
+    }
+
+    /// Returns a cell farthest from all sources together with its distance.
+    ///
+    /// For a single source in a perfect maze, this is one end of the longest path
+    /// starting at the source.
+    pub fn farthest(&self) -> Option<(C, usize)> {
+        let cell = *self.order.last()?;
+        self.distance(cell).map(|distance| (cell, distance))
+    }
+
+    /// Returns the neighbor of `cell` that lies one step closer to the nearest source.
+    ///
+    /// Following the steps from any reachable cell leads to a source. Returns `None`
+    /// for the sources themselves and for unreachable cells.
+    pub fn next_step(&self, cell: C) -> Option<C> {
+        self.reached.get(&cell).and_then(|&(_, next)| next)
+    }
+
+    /// Returns the path from `cell` down the flow field to its nearest source.
+    pub fn path_to_source(&self, cell: C) -> Option<Path<C>> {
+        let mut cells = vec![cell];
+        let mut current = self.reached.get(&cell)?.1;
+        while let Some(next) = current {
+            cells.push(next);
+            current = self.reached[&next].1;
+        }
+        Some(Path::new(cells))
+    }
+
+    /// Returns every reachable cell with its distance, in order of increasing distance.
+    pub fn iter(&self) -> impl Iterator<Item = (C, usize)> + '_ {
+        self.order.iter().map(|&cell| (cell, self.reached[&cell].0))
+    }
+
+    /// Returns the cells exactly `distance` steps away from the nearest source.
+    pub fn band(&self, distance: usize) -> &[C] {
+        match self.band_starts.get(distance..distance + 2) {
+            Some(&[start, end]) => &self.order[start..end],
+            _ => &[],
+        }
+    }
+
+    /// Splits the reachable cells into iso-distance bands `width` steps wide.
+    ///
+    /// The `k`-th band holds the cells whose distance lies in `k * width..(k + 1) * width`.
+    ///
+    /// ## Panics
+    /// Panics if `width` is zero.
+    pub fn bands(&self, width: usize) -> impl Iterator<Item = &[C]> + '_ {
+        assert!(width > 0, "band width must be positive");
+        let distances = self.band_starts.len().saturating_sub(1);
+        (0..distances).step_by(width).map(move |first| {
+            let last = (first + width).min(distances);
+            &self.order[self.band_starts[first]..self.band_starts[last]]
+        })
+    }
+}
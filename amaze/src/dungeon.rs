@@ -16,8 +16,7 @@ pub use dungeon_grid::DungeonGrid;
 pub use dungeon_type::DungeonType;
 pub use dyn_dungeon_grid::DynDungeonGrid;
 pub use generators::{
-    DungeonGenerationStep, DungeonGenerationSteps, DungeonGenerationVisitor, DungeonGenerator,
-    DungeonWalkGenerator, VecDungeonGenerationVisitor,
+    DungeonGenerationStep, DungeonGenerationSteps, DungeonGenerator, DungeonWalkGenerator,
 };
 #[cfg(all(feature = "representations", feature = "solvers"))]
 pub use solvers::{solve_astar, solve_bfs};
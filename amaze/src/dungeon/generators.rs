@@ -1,8 +1,10 @@
 use crate::dungeon::{DungeonGrid, DungeonType, DynDungeonGrid, TileType};
+use crate::generators::{GenerationProcess, StepStream};
 use crate::grid_coord_2d::{GetCoordinateBounds2D, GridCoord2D};
 use rand::prelude::IndexedRandom;
 use rand::rngs::StdRng;
 use rand::{RngExt, SeedableRng};
+use std::collections::VecDeque;
 
 /// Generation step for dungeon creation (for animation support).
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -24,59 +26,8 @@ pub enum DungeonGenerationStep {
     Complete,
 }
 
-/// Visitor for dungeon generation steps (for animation).
-pub trait DungeonGenerationVisitor {
-    fn on_step(&mut self, step: &DungeonGenerationStep);
-}
-
-/// Default visitor that collects steps into a vector.
-#[derive(Default)]
-pub struct VecDungeonGenerationVisitor {
-    steps: Vec<DungeonGenerationStep>,
-}
-
-impl VecDungeonGenerationVisitor {
-    pub fn into_steps(self) -> Vec<DungeonGenerationStep> {
-        self.steps
-    }
-}
-
-impl DungeonGenerationVisitor for VecDungeonGenerationVisitor {
-    fn on_step(&mut self, step: &DungeonGenerationStep) {
-        self.steps.push(step.clone());
-    }
-}
-
-/// No-op visitor for non-instrumented generation.
-struct NoOpVisitor;
-
-impl DungeonGenerationVisitor for NoOpVisitor {
-    #[inline]
-    fn on_step(&mut self, _step: &DungeonGenerationStep) {
-        // No-op: don't record steps
-    }
-}
-
-/// Iterator over dungeon generation steps.
-pub struct DungeonGenerationSteps {
-    inner: std::vec::IntoIter<DungeonGenerationStep>,
-}
-
-impl DungeonGenerationSteps {
-    pub fn new(steps: Vec<DungeonGenerationStep>) -> Self {
-        Self {
-            inner: steps.into_iter(),
-        }
-    }
-}
-
-impl Iterator for DungeonGenerationSteps {
-    type Item = DungeonGenerationStep;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.inner.next()
-    }
-}
+/// Lazily generated steps of a [`DungeonGrid`].
+pub type DungeonGenerationSteps = StepStream<DungeonGrid, DungeonGenerationStep>;
 
 /// Trait for dungeon generators.
 pub trait DungeonGenerator {
@@ -100,8 +51,10 @@ pub trait DungeonGenerator {
         height: usize,
         floor_count: usize,
     ) -> DungeonGenerationSteps {
-        let _ = self.generate(width, height, floor_count);
-        DungeonGenerationSteps::new(vec![DungeonGenerationStep::Complete])
+        DungeonGenerationSteps::finished(
+            self.generate(width, height, floor_count),
+            DungeonGenerationStep::Complete,
+        )
     }
 
     /// Get the dungeon type this generator produces.
@@ -124,6 +77,7 @@ pub trait DungeonGenerator {
 /// - Caverns: Unconstrained random walk
 /// - Rooms: Long corridors with stamped rectangular rooms
 /// - Winding: Like Rooms, but with probabilistic room suppression
+#[derive(Debug, Clone)]
 pub struct DungeonWalkGenerator {
     rng_seed: u64,
     dungeon_type: DungeonType,
@@ -145,20 +99,20 @@ pub struct DungeonWalkGenerator {
 }
 
 /// Helper struct to reduce parameter count in internal methods
-struct WalkContext<'a, V> {
+struct WalkContext<'a> {
     rng: &'a mut StdRng,
     grid: &'a mut DungeonGrid,
-    visitor: &'a mut V,
+    steps: &'a mut VecDeque<DungeonGenerationStep>,
     width: usize,
     height: usize,
     last_floor: GridCoord2D,
 }
 
 /// Helper struct for dynamic grid generation
-struct DynWalkContext<'a, V> {
+struct DynWalkContext<'a> {
     rng: &'a mut StdRng,
     dyn_grid: &'a mut DynDungeonGrid,
-    visitor: &'a mut V,
+    steps: &'a mut VecDeque<DungeonGenerationStep>,
     last_floor_world: (isize, isize),
     initial_size: usize,
 }
@@ -240,273 +194,92 @@ impl DungeonWalkGenerator {
 
     /// Generate a dungeon with the configured parameters.
     pub fn generate(&self, width: usize, height: usize, floor_count: usize) -> DungeonGrid {
-        self.generate_internal(width, height, floor_count, &mut NoOpVisitor, false)
+        DungeonGenerationSteps::new(self.process(width, height, floor_count, false)).finish()
     }
 
     /// Generate with animation steps.
+    ///
+    /// The walker only advances as far as needed to produce the requested steps, and the
+    /// final dungeon is returned by [`DungeonGenerationSteps::finish`].
     pub fn generate_steps(
         &self,
         width: usize,
         height: usize,
         floor_count: usize,
     ) -> DungeonGenerationSteps {
-        let mut visitor = VecDungeonGenerationVisitor::default();
-        let _ = self.generate_internal(width, height, floor_count, &mut visitor, true);
-        DungeonGenerationSteps::new(visitor.into_steps())
+        DungeonGenerationSteps::new(self.process(width, height, floor_count, true))
     }
 
-    fn generate_internal<V: DungeonGenerationVisitor>(
+    fn process(
         &self,
         width: usize,
         height: usize,
         floor_count: usize,
-        visitor: &mut V,
         emit_wall_steps: bool,
-    ) -> DungeonGrid {
-        let mut rng = StdRng::seed_from_u64(self.rng_seed);
-
-        if width == 0 || height == 0 || floor_count == 0 {
-            let grid = DungeonGrid::new(width, height);
-            visitor.on_step(&DungeonGenerationStep::Complete);
-            return grid;
-        }
-
-        if self.dynamic_resize {
-            self.generate_dynamic(floor_count, visitor, emit_wall_steps, &mut rng)
+    ) -> DungeonWalkProcess {
+        let rng = StdRng::seed_from_u64(self.rng_seed);
+
+        let (phase, progress) = if width == 0 || height == 0 || floor_count == 0 {
+            let scan = WallScan::new(DungeonGrid::new(width, height), false, None);
+            (WalkPhase::Walls(scan), WalkProgress::new(0, 0))
+        } else if self.dynamic_resize {
+            let initial_size = self.initial_grid_size;
+
+            // Cap floor_count based on a reasonable maximum
+            let max_possible_floor =
+                initial_size.saturating_mul(initial_size).saturating_mul(9) / 10;
+            let target_floor_count = floor_count.min(max_possible_floor.max(10000));
+
+            // Stall limit - use a larger default for dynamic mode
+            let max_stalled_iterations = target_floor_count.max(5000);
+
+            let walk = DynamicWalk {
+                dyn_grid: DynDungeonGrid::new(initial_size, initial_size),
+                initial_size,
+                // Start at world position (0, 0)
+                walker_world: (0, 0),
+                last_floor_world: (0, 0),
+                started: false,
+            };
+            (
+                WalkPhase::Dynamic(walk),
+                WalkProgress::new(target_floor_count, max_stalled_iterations),
+            )
         } else {
-            self.generate_fixed(
+            // Cap floor_count to max possible tiles (leaving some margin for walls)
+            let max_possible_floor = width.saturating_mul(height).saturating_mul(9) / 10; // 90% max
+            let target_floor_count = floor_count.min(max_possible_floor);
+
+            // Track iterations to prevent infinite loops
+            let max_stalled_iterations = target_floor_count
+                .max(width.saturating_mul(height) / 10)
+                .max(1000);
+
+            // Start at center
+            let start = GridCoord2D::new(width / 2, height / 2);
+            let walk = FixedWalk {
+                grid: DungeonGrid::new(width, height),
                 width,
                 height,
-                floor_count,
-                visitor,
-                emit_wall_steps,
-                &mut rng,
+                walker_pos: start,
+                last_floor_pos: start,
+                started: false,
+            };
+            (
+                WalkPhase::Fixed(walk),
+                WalkProgress::new(target_floor_count, max_stalled_iterations),
             )
+        };
+
+        DungeonWalkProcess {
+            generator: self.clone(),
+            rng,
+            emit_wall_steps,
+            progress,
+            phase,
         }
     }
 
-    fn generate_fixed<V: DungeonGenerationVisitor>(
-        &self,
-        width: usize,
-        height: usize,
-        floor_count: usize,
-        visitor: &mut V,
-        emit_wall_steps: bool,
-        rng: &mut StdRng,
-    ) -> DungeonGrid {
-        let mut grid = DungeonGrid::new(width, height);
-
-        // Cap floor_count to max possible tiles (leaving some margin for walls)
-        let max_possible_floor = width.saturating_mul(height).saturating_mul(9) / 10; // 90% max
-        let target_floor_count = floor_count.min(max_possible_floor);
-
-        // Start at center
-        let mut walker_pos = GridCoord2D::new(width / 2, height / 2);
-        let mut last_floor_pos = walker_pos;
-
-        // Place initial floor
-        grid.set(walker_pos, TileType::Floor);
-        visitor.on_step(&DungeonGenerationStep::PlaceFloor { coord: walker_pos });
-
-        // Track iterations to prevent infinite loops
-        let mut iterations_since_progress = 0;
-        let max_stalled_iterations = target_floor_count
-            .max(width.saturating_mul(height) / 10)
-            .max(1000);
-
-        // Generate floor tiles
-        while grid.floor_count() < target_floor_count {
-            let floor_count_before = grid.floor_count();
-            iterations_since_progress += 1;
-
-            if iterations_since_progress > max_stalled_iterations {
-                break;
-            }
-            match self.dungeon_type {
-                DungeonType::Caverns => {
-                    walker_pos = self.take_step(rng, walker_pos, width, height);
-                    if !grid.is_floor(walker_pos) {
-                        grid.set(walker_pos, TileType::Floor);
-                        visitor.on_step(&DungeonGenerationStep::PlaceFloor { coord: walker_pos });
-                        last_floor_pos = walker_pos;
-                    }
-                }
-                DungeonType::Rooms | DungeonType::Winding => {
-                    let mut ctx = WalkContext {
-                        rng,
-                        grid: &mut grid,
-                        visitor,
-                        width,
-                        height,
-                        last_floor: last_floor_pos,
-                    };
-
-                    walker_pos = self.take_long_walk(&mut ctx, walker_pos, target_floor_count);
-
-                    let should_stamp_room = if self.dungeon_type == DungeonType::Rooms {
-                        true
-                    } else {
-                        let roll = ctx.rng.random_range(0..100);
-                        roll > self.winding_hall_probability
-                    };
-
-                    if should_stamp_room && ctx.grid.floor_count() < target_floor_count {
-                        self.stamp_room(&mut ctx, walker_pos, target_floor_count);
-                    }
-
-                    last_floor_pos = ctx.last_floor;
-                }
-            }
-
-            if grid.floor_count() > floor_count_before {
-                iterations_since_progress = 0;
-            }
-        }
-
-        // Set exit before trimming
-        grid.set_exit(last_floor_pos);
-
-        // Trim to content bounds (also calls place_walls and compute_edge_masks)
-        grid = grid.trim(self.trim_padding);
-
-        // Emit wall steps from trimmed grid
-        if emit_wall_steps {
-            for y in 0..grid.height() {
-                for x in 0..grid.width() {
-                    let coord = GridCoord2D::new(x, y);
-                    if grid.get(coord).unwrap().is_wall() {
-                        visitor.on_step(&DungeonGenerationStep::PlaceWall { coord });
-                    }
-                }
-            }
-        }
-
-        let trimmed_exit = grid.exit().unwrap_or(last_floor_pos);
-        visitor.on_step(&DungeonGenerationStep::SetExit {
-            coord: trimmed_exit,
-        });
-
-        visitor.on_step(&DungeonGenerationStep::Complete);
-        grid
-    }
-
-    fn generate_dynamic<V: DungeonGenerationVisitor>(
-        &self,
-        floor_count: usize,
-        visitor: &mut V,
-        emit_wall_steps: bool,
-        rng: &mut StdRng,
-    ) -> DungeonGrid {
-        let initial_size = self.initial_grid_size;
-        let mut dyn_grid = DynDungeonGrid::new(initial_size, initial_size);
-
-        // Cap floor_count based on a reasonable maximum
-        let max_possible_floor = initial_size.saturating_mul(initial_size).saturating_mul(9) / 10;
-        let target_floor_count = floor_count.min(max_possible_floor.max(10000));
-
-        // Start at world position (0, 0)
-        let mut walker_world = (0isize, 0isize);
-        let mut last_floor_world = (0isize, 0isize);
-
-        // Place initial floor
-        dyn_grid.ensure_bounds(0, 0, 0, 0);
-        dyn_grid.set_world(0, 0, TileType::Floor);
-        dyn_grid.update_floor_bounds(0, 0);
-        visitor.on_step(&DungeonGenerationStep::PlaceFloor {
-            coord: GridCoord2D::new(initial_size / 2, initial_size / 2),
-        });
-
-        // Stall limit - use a larger default for dynamic mode
-        let mut iterations_since_progress = 0;
-        let max_stalled_iterations = target_floor_count.max(5000);
-
-        // Track floor count directly from dyn_grid
-        while dyn_grid.inner().floor_count() < target_floor_count {
-            let floor_count_before = dyn_grid.inner().floor_count();
-            iterations_since_progress += 1;
-
-            if iterations_since_progress > max_stalled_iterations {
-                break;
-            }
-
-            match self.dungeon_type {
-                DungeonType::Caverns => {
-                    walker_world = self.take_step_world(rng, walker_world);
-                    // Ensure bounds before setting
-                    dyn_grid.ensure_bounds(
-                        walker_world.0,
-                        walker_world.1,
-                        walker_world.0,
-                        walker_world.1,
-                    );
-                    if !dyn_grid.is_floor_world(walker_world.0, walker_world.1) {
-                        dyn_grid.set_world(walker_world.0, walker_world.1, TileType::Floor);
-                        dyn_grid.update_floor_bounds(walker_world.0, walker_world.1);
-                        // Emit event with a synthetic grid coord (center-based)
-                        visitor.on_step(&DungeonGenerationStep::PlaceFloor {
-                            coord: GridCoord2D::new(initial_size / 2, initial_size / 2),
-                        });
-                        last_floor_world = walker_world;
-                    }
-                }
-                DungeonType::Rooms | DungeonType::Winding => {
-                    let mut ctx = DynWalkContext {
-                        rng,
-                        dyn_grid: &mut dyn_grid,
-                        visitor,
-                        last_floor_world,
-                        initial_size,
-                    };
-
-                    walker_world =
-                        self.take_long_walk_world(&mut ctx, walker_world, target_floor_count);
-
-                    let should_stamp_room = if self.dungeon_type == DungeonType::Rooms {
-                        true
-                    } else {
-                        let roll = ctx.rng.random_range(0..100);
-                        roll > self.winding_hall_probability
-                    };
-
-                    if should_stamp_room && ctx.dyn_grid.inner().floor_count() < target_floor_count
-                    {
-                        self.stamp_room_world(&mut ctx, walker_world, target_floor_count);
-                    }
-
-                    last_floor_world = ctx.last_floor_world;
-                }
-            }
-
-            if dyn_grid.inner().floor_count() > floor_count_before {
-                iterations_since_progress = 0;
-            }
-        }
-
-        // Set exit world position
-        dyn_grid.set_exit_world(last_floor_world.0, last_floor_world.1);
-        visitor.on_step(&DungeonGenerationStep::SetExit {
-            coord: GridCoord2D::new(initial_size / 2, initial_size / 2),
-        });
-
-        // Finalize: trim to content bounds with padding
-        let final_grid = dyn_grid.finalize(self.trim_padding);
-
-        // Emit wall steps if needed (from final grid)
-        if emit_wall_steps {
-            for y in 0..final_grid.height() {
-                for x in 0..final_grid.width() {
-                    let coord = GridCoord2D::new(x, y);
-                    if final_grid.get(coord).unwrap().is_wall() {
-                        visitor.on_step(&DungeonGenerationStep::PlaceWall { coord });
-                    }
-                }
-            }
-        }
-
-        visitor.on_step(&DungeonGenerationStep::Complete);
-        final_grid
-    }
-
     /// Take a single random step in world coordinates (no clamping).
     fn take_step_world(&self, rng: &mut StdRng, pos: (isize, isize)) -> (isize, isize) {
         let directions: [(isize, isize); 4] = [
@@ -545,9 +318,9 @@ impl DungeonWalkGenerator {
     /// Take a long walk (Unity: Random.Range(9, 18) => 9..=17 steps).
     /// Unity picks one direction and walks straight in that direction.
     /// Stops early if target floor count is reached.
-    fn take_long_walk<V: DungeonGenerationVisitor>(
+    fn take_long_walk(
         &self,
-        ctx: &mut WalkContext<V>,
+        ctx: &mut WalkContext,
         start: GridCoord2D,
         target_floor_count: usize,
     ) -> GridCoord2D {
@@ -577,8 +350,8 @@ impl DungeonWalkGenerator {
 
             if !ctx.grid.is_floor(pos) {
                 ctx.grid.set(pos, TileType::Floor);
-                ctx.visitor
-                    .on_step(&DungeonGenerationStep::PlaceFloor { coord: pos });
+                ctx.steps
+                    .push_back(DungeonGenerationStep::PlaceFloor { coord: pos });
                 ctx.last_floor = pos;
             }
         }
@@ -589,16 +362,11 @@ impl DungeonWalkGenerator {
     /// Stamp a rectangular room centered at pos.
     /// Unity: half-sizes are Random.Range(1, 5) => 1..=4, so room size is (2*hw+1) x (2*hh+1).
     /// Stops stamping if floor count reaches target to respect cap.
-    fn stamp_room<V: DungeonGenerationVisitor>(
-        &self,
-        ctx: &mut WalkContext<V>,
-        center: GridCoord2D,
-        target_floor_count: usize,
-    ) {
+    fn stamp_room(&self, ctx: &mut WalkContext, center: GridCoord2D, target_floor_count: usize) {
         let half_width = ctx.rng.random_range(1..5); // Unity: Random.Range(1,5) = 1..=4
         let half_height = ctx.rng.random_range(1..5);
 
-        ctx.visitor.on_step(&DungeonGenerationStep::StampRoom {
+        ctx.steps.push_back(DungeonGenerationStep::StampRoom {
             center,
             half_width,
             half_height,
@@ -619,8 +387,8 @@ impl DungeonWalkGenerator {
                 let coord = GridCoord2D::new(x, y);
                 if !ctx.grid.is_floor(coord) {
                     ctx.grid.set(coord, TileType::Floor);
-                    ctx.visitor
-                        .on_step(&DungeonGenerationStep::PlaceFloor { coord });
+                    ctx.steps
+                        .push_back(DungeonGenerationStep::PlaceFloor { coord });
                     ctx.last_floor = coord;
                 }
             }
@@ -628,9 +396,9 @@ impl DungeonWalkGenerator {
     }
 
     /// Take a long walk in world coordinates (no clamping, expands grid as needed).
-    fn take_long_walk_world<V: DungeonGenerationVisitor>(
+    fn take_long_walk_world(
         &self,
-        ctx: &mut DynWalkContext<V>,
+        ctx: &mut DynWalkContext,
         start: (isize, isize),
         target_floor_count: usize,
     ) -> (isize, isize) {
@@ -659,7 +427,7 @@ impl DungeonWalkGenerator {
             if !ctx.dyn_grid.is_floor_world(pos.0, pos.1) {
                 ctx.dyn_grid.set_world(pos.0, pos.1, TileType::Floor);
                 ctx.dyn_grid.update_floor_bounds(pos.0, pos.1);
-                ctx.visitor.on_step(&DungeonGenerationStep::PlaceFloor {
+                ctx.steps.push_back(DungeonGenerationStep::PlaceFloor {
                     coord: GridCoord2D::new(ctx.initial_size / 2, ctx.initial_size / 2),
                 });
                 ctx.last_floor_world = pos;
@@ -670,16 +438,16 @@ impl DungeonWalkGenerator {
     }
 
     /// Stamp a rectangular room in world coordinates.
-    fn stamp_room_world<V: DungeonGenerationVisitor>(
+    fn stamp_room_world(
         &self,
-        ctx: &mut DynWalkContext<V>,
+        ctx: &mut DynWalkContext,
         center: (isize, isize),
         target_floor_count: usize,
     ) {
         let half_width = ctx.rng.random_range(1..5);
         let half_height = ctx.rng.random_range(1..5);
 
-        ctx.visitor.on_step(&DungeonGenerationStep::StampRoom {
+        ctx.steps.push_back(DungeonGenerationStep::StampRoom {
             center: GridCoord2D::new(ctx.initial_size / 2, ctx.initial_size / 2),
             half_width,
             half_height,
@@ -702,7 +470,7 @@ impl DungeonWalkGenerator {
                 if !ctx.dyn_grid.is_floor_world(x, y) {
                     ctx.dyn_grid.set_world(x, y, TileType::Floor);
                     ctx.dyn_grid.update_floor_bounds(x, y);
-                    ctx.visitor.on_step(&DungeonGenerationStep::PlaceFloor {
+                    ctx.steps.push_back(DungeonGenerationStep::PlaceFloor {
                         coord: GridCoord2D::new(ctx.initial_size / 2, ctx.initial_size / 2),
                     });
                     ctx.last_floor_world = (x, y);
@@ -712,6 +480,339 @@ impl DungeonWalkGenerator {
     }
 }
 
+/// A [`DungeonWalkGenerator`] run, paused between two walker iterations.
+struct DungeonWalkProcess {
+    generator: DungeonWalkGenerator,
+    rng: StdRng,
+    emit_wall_steps: bool,
+    progress: WalkProgress,
+    phase: WalkPhase,
+}
+
+/// The stages of a dungeon walk.
+enum WalkPhase {
+    /// Carving floors into a grid of fixed size.
+    Fixed(FixedWalk),
+    /// Carving floors into a grid that grows with the walker.
+    Dynamic(DynamicWalk),
+    /// Reporting the walls of the finished dungeon.
+    Walls(WallScan),
+}
+
+/// Tracks the floor target and detects a stalled walker.
+struct WalkProgress {
+    target_floor_count: usize,
+    iterations_since_progress: usize,
+    max_stalled_iterations: usize,
+}
+
+impl WalkProgress {
+    fn new(target_floor_count: usize, max_stalled_iterations: usize) -> Self {
+        Self {
+            target_floor_count,
+            iterations_since_progress: 0,
+            max_stalled_iterations,
+        }
+    }
+
+    /// Returns `true` if the walker should run another iteration.
+    fn next_iteration(&mut self, floor_count: usize) -> bool {
+        if floor_count >= self.target_floor_count {
+            return false;
+        }
+
+        self.iterations_since_progress += 1;
+        self.iterations_since_progress <= self.max_stalled_iterations
+    }
+
+    fn record(&mut self, floor_count_before: usize, floor_count_after: usize) {
+        if floor_count_after > floor_count_before {
+            self.iterations_since_progress = 0;
+        }
+    }
+}
+
+/// The walker state of a fixed-size dungeon.
+struct FixedWalk {
+    grid: DungeonGrid,
+    width: usize,
+    height: usize,
+    walker_pos: GridCoord2D,
+    last_floor_pos: GridCoord2D,
+    started: bool,
+}
+
+impl FixedWalk {
+    /// Places the initial floor, or runs one walker iteration.
+    ///
+    /// Returns `false` once the floor target is reached or the walker stalls.
+    fn advance(
+        &mut self,
+        generator: &DungeonWalkGenerator,
+        rng: &mut StdRng,
+        progress: &mut WalkProgress,
+        steps: &mut VecDeque<DungeonGenerationStep>,
+    ) -> bool {
+        if !self.started {
+            self.started = true;
+            self.grid.set(self.walker_pos, TileType::Floor);
+            steps.push_back(DungeonGenerationStep::PlaceFloor {
+                coord: self.walker_pos,
+            });
+            return true;
+        }
+
+        let floor_count_before = self.grid.floor_count();
+        if !progress.next_iteration(floor_count_before) {
+            return false;
+        }
+
+        match generator.dungeon_type {
+            DungeonType::Caverns => {
+                self.walker_pos =
+                    generator.take_step(rng, self.walker_pos, self.width, self.height);
+                if !self.grid.is_floor(self.walker_pos) {
+                    self.grid.set(self.walker_pos, TileType::Floor);
+                    steps.push_back(DungeonGenerationStep::PlaceFloor {
+                        coord: self.walker_pos,
+                    });
+                    self.last_floor_pos = self.walker_pos;
+                }
+            }
+            DungeonType::Rooms | DungeonType::Winding => {
+                let target_floor_count = progress.target_floor_count;
+                let mut ctx = WalkContext {
+                    rng,
+                    grid: &mut self.grid,
+                    steps,
+                    width: self.width,
+                    height: self.height,
+                    last_floor: self.last_floor_pos,
+                };
+
+                self.walker_pos =
+                    generator.take_long_walk(&mut ctx, self.walker_pos, target_floor_count);
+
+                let should_stamp_room = if generator.dungeon_type == DungeonType::Rooms {
+                    true
+                } else {
+                    let roll = ctx.rng.random_range(0..100);
+                    roll > generator.winding_hall_probability
+                };
+
+                if should_stamp_room && ctx.grid.floor_count() < target_floor_count {
+                    generator.stamp_room(&mut ctx, self.walker_pos, target_floor_count);
+                }
+
+                self.last_floor_pos = ctx.last_floor;
+            }
+        }
+
+        progress.record(floor_count_before, self.grid.floor_count());
+        true
+    }
+
+    /// Trims the dungeon to its content and returns the remaining steps to report.
+    fn into_walls(mut self, trim_padding: usize, emit_wall_steps: bool) -> WallScan {
+        // Set exit before trimming
+        self.grid.set_exit(self.last_floor_pos);
+
+        // Trim to content bounds (also calls place_walls and compute_edge_masks)
+        let grid = self.grid.trim(trim_padding);
+
+        let trimmed_exit = grid.exit().unwrap_or(self.last_floor_pos);
+        WallScan::new(grid, emit_wall_steps, Some(trimmed_exit))
+    }
+}
+
+/// The walker state of a dungeon that grows as needed.
+struct DynamicWalk {
+    dyn_grid: DynDungeonGrid,
+    initial_size: usize,
+    walker_world: (isize, isize),
+    last_floor_world: (isize, isize),
+    started: bool,
+}
+
+impl DynamicWalk {
+    /// Places the initial floor, or runs one walker iteration.
+    ///
+    /// Returns `false` once the floor target is reached or the walker stalls.
+    fn advance(
+        &mut self,
+        generator: &DungeonWalkGenerator,
+        rng: &mut StdRng,
+        progress: &mut WalkProgress,
+        steps: &mut VecDeque<DungeonGenerationStep>,
+    ) -> bool {
+        let center = GridCoord2D::new(self.initial_size / 2, self.initial_size / 2);
+        if !self.started {
+            self.started = true;
+            self.dyn_grid.ensure_bounds(0, 0, 0, 0);
+            self.dyn_grid.set_world(0, 0, TileType::Floor);
+            self.dyn_grid.update_floor_bounds(0, 0);
+            steps.push_back(DungeonGenerationStep::PlaceFloor { coord: center });
+            return true;
+        }
+
+        // Track floor count directly from dyn_grid
+        let floor_count_before = self.dyn_grid.inner().floor_count();
+        if !progress.next_iteration(floor_count_before) {
+            return false;
+        }
+
+        match generator.dungeon_type {
+            DungeonType::Caverns => {
+                let (x, y) = generator.take_step_world(rng, self.walker_world);
+                self.walker_world = (x, y);
+                // Ensure bounds before setting
+                self.dyn_grid.ensure_bounds(x, y, x, y);
+                if !self.dyn_grid.is_floor_world(x, y) {
+                    self.dyn_grid.set_world(x, y, TileType::Floor);
+                    self.dyn_grid.update_floor_bounds(x, y);
+                    // Emit event with a synthetic grid coord (center-based)
+                    steps.push_back(DungeonGenerationStep::PlaceFloor { coord: center });
+                    self.last_floor_world = (x, y);
+                }
+            }
+            DungeonType::Rooms | DungeonType::Winding => {
+                let target_floor_count = progress.target_floor_count;
+                let mut ctx = DynWalkContext {
+                    rng,
+                    dyn_grid: &mut self.dyn_grid,
+                    steps,
+                    last_floor_world: self.last_floor_world,
+                    initial_size: self.initial_size,
+                };
+
+                self.walker_world =
+                    generator.take_long_walk_world(&mut ctx, self.walker_world, target_floor_count);
+
+                let should_stamp_room = if generator.dungeon_type == DungeonType::Rooms {
+                    true
+                } else {
+                    let roll = ctx.rng.random_range(0..100);
+                    roll > generator.winding_hall_probability
+                };
+
+                if should_stamp_room && ctx.dyn_grid.inner().floor_count() < target_floor_count {
+                    generator.stamp_room_world(&mut ctx, self.walker_world, target_floor_count);
+                }
+
+                self.last_floor_world = ctx.last_floor_world;
+            }
+        }
+
+        progress.record(floor_count_before, self.dyn_grid.inner().floor_count());
+        true
+    }
+
+    /// Sets the exit, trims the dungeon to its content and returns the remaining steps to report.
+    fn into_walls(
+        mut self,
+        trim_padding: usize,
+        emit_wall_steps: bool,
+        steps: &mut VecDeque<DungeonGenerationStep>,
+    ) -> WallScan {
+        // Set exit world position
+        let (x, y) = self.last_floor_world;
+        self.dyn_grid.set_exit_world(x, y);
+        steps.push_back(DungeonGenerationStep::SetExit {
+            coord: GridCoord2D::new(self.initial_size / 2, self.initial_size / 2),
+        });
+
+        // Finalize: trim to content bounds with padding
+        WallScan::new(self.dyn_grid.finalize(trim_padding), emit_wall_steps, None)
+    }
+}
+
+/// The finished dungeon, paused between two reported walls.
+struct WallScan {
+    grid: DungeonGrid,
+    next_index: usize,
+    /// The exit reported after the walls, if not reported already.
+    exit: Option<GridCoord2D>,
+}
+
+impl WallScan {
+    fn new(grid: DungeonGrid, emit_wall_steps: bool, exit: Option<GridCoord2D>) -> Self {
+        let next_index = if emit_wall_steps {
+            0
+        } else {
+            grid.width() * grid.height()
+        };
+        Self {
+            grid,
+            next_index,
+            exit,
+        }
+    }
+
+    /// Reports the next wall, or the exit and completion once all walls are reported.
+    fn advance(&mut self, steps: &mut VecDeque<DungeonGenerationStep>) -> bool {
+        let width = self.grid.width();
+        while self.next_index < width * self.grid.height() {
+            let coord = GridCoord2D::new(self.next_index % width, self.next_index / width);
+            self.next_index += 1;
+            if self.grid.get(coord).unwrap().is_wall() {
+                steps.push_back(DungeonGenerationStep::PlaceWall { coord });
+                return true;
+            }
+        }
+
+        if let Some(coord) = self.exit.take() {
+            steps.push_back(DungeonGenerationStep::SetExit { coord });
+        }
+        steps.push_back(DungeonGenerationStep::Complete);
+        false
+    }
+}
+
+impl GenerationProcess for DungeonWalkProcess {
+    type Grid = DungeonGrid;
+    type Step = DungeonGenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<DungeonGenerationStep>) -> bool {
+        let generator = &self.generator;
+        let rng = &mut self.rng;
+        let progress = &mut self.progress;
+        let walking = match &mut self.phase {
+            WalkPhase::Fixed(walk) => walk.advance(generator, rng, progress, steps),
+            WalkPhase::Dynamic(walk) => walk.advance(generator, rng, progress, steps),
+            WalkPhase::Walls(scan) => return scan.advance(steps),
+        };
+        if walking {
+            return true;
+        }
+
+        let padding = generator.trim_padding;
+        let placeholder = WalkPhase::Walls(WallScan::new(DungeonGrid::new(0, 0), false, None));
+        let scan = match std::mem::replace(&mut self.phase, placeholder) {
+            WalkPhase::Fixed(walk) => walk.into_walls(padding, self.emit_wall_steps),
+            WalkPhase::Dynamic(walk) => walk.into_walls(padding, self.emit_wall_steps, steps),
+            WalkPhase::Walls(scan) => scan,
+        };
+        self.phase = WalkPhase::Walls(scan);
+        true
+    }
+
+    fn grid(&self) -> &DungeonGrid {
+        match &self.phase {
+            WalkPhase::Fixed(walk) => &walk.grid,
+            WalkPhase::Dynamic(walk) => walk.dyn_grid.inner(),
+            WalkPhase::Walls(scan) => &scan.grid,
+        }
+    }
+
+    fn into_grid(self: Box<Self>) -> DungeonGrid {
+        match self.phase {
+            WalkPhase::Fixed(walk) => walk.grid,
+            WalkPhase::Dynamic(walk) => walk.dyn_grid.finalize(self.generator.trim_padding),
+            WalkPhase::Walls(scan) => scan.grid,
+        }
+    }
+}
+
 impl DungeonGenerator for DungeonWalkGenerator {
     fn new_random() -> Self {
         Self::new_random(DungeonType::Caverns)
@@ -0,0 +1,407 @@
+//! Guessing which algorithm generated a maze from its texture.
+//!
+//! Every generator leaves characteristic traces: binary tree mazes open all dead ends
+//! towards one diagonal and carve two unbroken boundary corridors, sidewinder carves
+//! one, the recursive backtracker produces long rivers with few dead ends, and the
+//! uniform spanning trees of Wilson's and Aldous-Broder's algorithms sit in between.
+//! A [`Fingerprint`] condenses these traces into a few size-independent features, and
+//! [`identify`] ranks the built-in [`REFERENCE_PROFILES`] by how well they match.
+//!
+//! The reference profiles are measured on 32×32 mazes; fingerprints of mazes between
+//! roughly 16 and 64 cells per side compare well against them. Comparing a profile
+//! measured from the current generators with the shipped one detects texture drift
+//! between releases.
+//!
+//! ## Example
+//! ```
+//! use amaze::fingerprint::identify;
+//! use amaze::generators::{BinaryTree4, MazeGenerator2D};
+//!
+//! let maze = BinaryTree4::new_from_seed(3).generate(32, 32);
+//! let ranking = identify(&maze);
+//!
+//! assert_eq!(ranking[0].generator, "binary-tree");
+//! ```
+
+use crate::direction4::Direction4;
+use crate::grid_coord_2d::GridCoord2D;
+use crate::metrics::MazeMetrics;
+use crate::wall4_grid::Wall4Grid;
+
+/// The number of features in a [`Fingerprint`].
+pub const FEATURE_COUNT: usize = 8;
+
+/// The names of the [`Fingerprint`] features, in the order of [`Fingerprint::features`].
+pub const FEATURE_NAMES: [&str; FEATURE_COUNT] = [
+    "dead_end_ratio",
+    "junction_ratio",
+    "river_factor",
+    "straight_corridor_ratio",
+    "horizontal_bias",
+    "dead_end_diagonal_bias",
+    "boundary_row_open",
+    "boundary_column_open",
+];
+
+/// Texture features of a maze that are largely independent of its size.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fingerprint {
+    /// Fraction of cells with at most one passage.
+    pub dead_end_ratio: f64,
+    /// Fraction of cells with three or more passages.
+    pub junction_ratio: f64,
+    /// The [`MazeMetrics::river_factor`].
+    pub river_factor: f64,
+    /// Fraction of corridor cells whose two passages lie opposite each other.
+    pub straight_corridor_ratio: f64,
+    /// The [`MazeMetrics::horizontal_bias`].
+    pub horizontal_bias: f64,
+    /// The largest fraction of dead ends that open towards the same diagonal, such as
+    /// north or east.
+    pub dead_end_diagonal_bias: f64,
+    /// The larger fraction of open walls inside the top or the bottom row.
+    pub boundary_row_open: f64,
+    /// The larger fraction of open walls inside the leftmost or the rightmost column.
+    pub boundary_column_open: f64,
+}
+
+impl Fingerprint {
+    /// Measures the texture of a maze.
+    pub fn of(maze: &Wall4Grid) -> Self {
+        let (width, height) = (maze.width(), maze.height());
+        let corner = GridCoord2D::new(width.saturating_sub(1), height.saturating_sub(1));
+        let metrics = MazeMetrics::from_grid(maze, GridCoord2D::new(0, 0), corner);
+        let cells = metrics.cells.max(1) as f64;
+
+        let mut junctions = 0usize;
+        let mut corridors = 0usize;
+        let mut straight = 0usize;
+        let mut diagonal_openings = [0usize; 4];
+        for cell in maze.coords() {
+            let open = !maze[cell];
+            let degree = Direction4::CARDINALS
+                .into_iter()
+                .filter(|&dir| open.contains(dir))
+                .count();
+            match degree {
+                0 => {}
+                1 => {
+                    for (i, diagonal) in DIAGONALS.iter().enumerate() {
+                        if diagonal.contains(open) {
+                            diagonal_openings[i] += 1;
+                        }
+                    }
+                }
+                2 => {
+                    corridors += 1;
+                    if open == Direction4::NORTH + Direction4::SOUTH
+                        || open == Direction4::EAST + Direction4::WEST
+                    {
+                        straight += 1;
+                    }
+                }
+                _ => junctions += 1,
+            }
+        }
+
+        let dead_ends_with_opening = diagonal_openings.iter().sum::<usize>() / 2;
+
+        Self {
+            dead_end_ratio: metrics.dead_ends as f64 / cells,
+            junction_ratio: junctions as f64 / cells,
+            river_factor: metrics.river_factor,
+            straight_corridor_ratio: ratio(straight, corridors),
+            horizontal_bias: metrics.horizontal_bias(),
+            dead_end_diagonal_bias: ratio(
+                diagonal_openings.into_iter().max().unwrap_or(0),
+                dead_ends_with_opening,
+            ),
+            boundary_row_open: open_fraction(maze, 0, Direction4::EAST).max(open_fraction(
+                maze,
+                height.saturating_sub(1),
+                Direction4::EAST,
+            )),
+            boundary_column_open: open_fraction(maze, 0, Direction4::SOUTH).max(open_fraction(
+                maze,
+                width.saturating_sub(1),
+                Direction4::SOUTH,
+            )),
+        }
+    }
+
+    /// Returns the features in the order of [`FEATURE_NAMES`].
+    pub fn features(&self) -> [f64; FEATURE_COUNT] {
+        [
+            self.dead_end_ratio,
+            self.junction_ratio,
+            self.river_factor,
+            self.straight_corridor_ratio,
+            self.horizontal_bias,
+            self.dead_end_diagonal_bias,
+            self.boundary_row_open,
+            self.boundary_column_open,
+        ]
+    }
+
+    /// Builds a fingerprint from features in the order of [`FEATURE_NAMES`].
+    pub const fn from_features(features: [f64; FEATURE_COUNT]) -> Self {
+        Self {
+            dead_end_ratio: features[0],
+            junction_ratio: features[1],
+            river_factor: features[2],
+            straight_corridor_ratio: features[3],
+            horizontal_bias: features[4],
+            dead_end_diagonal_bias: features[5],
+            boundary_row_open: features[6],
+            boundary_column_open: features[7],
+        }
+    }
+}
+
+/// The four pairs of directions a dead end can open towards.
+const DIAGONALS: [Direction4; 4] = [
+    Direction4::NORTH.join(Direction4::EAST),
+    Direction4::NORTH.join(Direction4::WEST),
+    Direction4::SOUTH.join(Direction4::EAST),
+    Direction4::SOUTH.join(Direction4::WEST),
+];
+
+fn ratio(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 / total as f64
+    }
+}
+
+/// Returns the fraction of open walls between consecutive cells of a row (walking
+/// east) or a column (walking south).
+fn open_fraction(maze: &Wall4Grid, line: usize, dir: Direction4) -> f64 {
+    let len = if dir == Direction4::EAST {
+        maze.width()
+    } else {
+        maze.height()
+    };
+    let open = (0..len.saturating_sub(1))
+        .map(|i| {
+            if dir == Direction4::EAST {
+                GridCoord2D::new(i, line)
+            } else {
+                GridCoord2D::new(line, i)
+            }
+        })
+        .filter(|&cell| !maze[cell].contains(dir))
+        .count();
+    ratio(open, len.saturating_sub(1))
+}
+
+/// The typical fingerprint of a generator: the mean and standard deviation of each
+/// feature over a sample of its mazes.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GeneratorProfile {
+    /// The generator's name, as used by `amaze-cli gen --algorithm`.
+    pub generator: &'static str,
+    pub mean: Fingerprint,
+    pub std_dev: Fingerprint,
+}
+
+impl GeneratorProfile {
+    /// Measures a profile from mazes produced by one generator.
+    pub fn from_samples(generator: &'static str, mazes: &[Wall4Grid]) -> Self {
+        let samples: Vec<_> = mazes
+            .iter()
+            .map(|m| Fingerprint::of(m).features())
+            .collect();
+        let n = samples.len().max(1) as f64;
+
+        let mut mean = [0.0; FEATURE_COUNT];
+        for sample in &samples {
+            for (m, x) in mean.iter_mut().zip(sample) {
+                *m += x / n;
+            }
+        }
+
+        let mut variance = [0.0; FEATURE_COUNT];
+        for sample in &samples {
+            for ((v, x), m) in variance.iter_mut().zip(sample).zip(&mean) {
+                *v += (x - m).powi(2) / n;
+            }
+        }
+
+        Self {
+            generator,
+            mean: Fingerprint::from_features(mean),
+            std_dev: Fingerprint::from_features(variance.map(f64::sqrt)),
+        }
+    }
+
+    /// Returns the squared distance of a fingerprint from this profile, measured in
+    /// standard deviations per feature.
+    pub fn distance(&self, fingerprint: &Fingerprint) -> f64 {
+        let x = fingerprint.features();
+        let mean = self.mean.features();
+        let std_dev = self.std_dev.features();
+        (0..FEATURE_COUNT)
+            .map(|i| ((x[i] - mean[i]) / std_dev[i].max(MIN_STD_DEV)).powi(2))
+            .sum()
+    }
+}
+
+/// The smallest standard deviation assumed for a feature, so that features that never
+/// vary for a generator, such as sidewinder's open top row, do not dominate.
+const MIN_STD_DEV: f64 = 0.02;
+
+/// A generator that may have produced a maze.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Candidate {
+    pub generator: &'static str,
+    /// The squared distance from the generator's profile; see
+    /// [`GeneratorProfile::distance`].
+    pub distance: f64,
+    /// The relative likelihood of this generator among all candidates, summing to one.
+    pub probability: f64,
+}
+
+/// Ranks the [`REFERENCE_PROFILES`] by how well they match the maze, best first.
+pub fn identify(maze: &Wall4Grid) -> Vec<Candidate> {
+    identify_with(maze, REFERENCE_PROFILES)
+}
+
+/// Ranks the given profiles by how well they match the maze, best first.
+pub fn identify_with(maze: &Wall4Grid, profiles: &[GeneratorProfile]) -> Vec<Candidate> {
+    let fingerprint = Fingerprint::of(maze);
+    let mut candidates: Vec<_> = profiles
+        .iter()
+        .map(|profile| Candidate {
+            generator: profile.generator,
+            distance: profile.distance(&fingerprint),
+            probability: 0.0,
+        })
+        .collect();
+    candidates.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+
+    // Likelihoods relative to the best match, which avoids underflow for poor fits.
+    let best = candidates.first().map_or(0.0, |c| c.distance);
+    let likelihoods: Vec<_> = candidates
+        .iter()
+        .map(|c| (-(c.distance - best) / 2.0).exp())
+        .collect();
+    let total: f64 = likelihoods.iter().sum();
+    for (candidate, likelihood) in candidates.iter_mut().zip(likelihoods) {
+        candidate.probability = likelihood / total;
+    }
+
+    candidates
+}
+
+/// The number of mazes each reference profile was measured from.
+pub const REFERENCE_SAMPLES: u64 = 20;
+
+/// The side length of the square mazes the reference profiles were measured from.
+pub const REFERENCE_SIZE: usize = 32;
+
+/// Profiles of the built-in square generators, measured from mazes generated with
+/// seeds `1..=REFERENCE_SAMPLES` at `REFERENCE_SIZE`×`REFERENCE_SIZE` cells.
+pub const REFERENCE_PROFILES: &[GeneratorProfile] = &[
+    GeneratorProfile {
+        generator: "recursive-backtracker",
+        mean: Fingerprint::from_features([
+            0.1046, 0.1010, 4.8888, 0.3940, 0.4986, 0.5609, 0.7952, 0.8000,
+        ]),
+        std_dev: Fingerprint::from_features([
+            0.0042, 0.0043, 0.1977, 0.0173, 0.0169, 0.0305, 0.0357, 0.0362,
+        ]),
+    },
+    GeneratorProfile {
+        generator: "growing-tree",
+        mean: Fingerprint::from_features([
+            0.1042, 0.1008, 4.9138, 0.3907, 0.4968, 0.5584, 0.7984, 0.8161,
+        ]),
+        std_dev: Fingerprint::from_features([
+            0.0058, 0.0057, 0.2766, 0.0142, 0.0163, 0.0288, 0.0352, 0.0383,
+        ]),
+    },
+    GeneratorProfile {
+        generator: "kruskal",
+        mean: Fingerprint::from_features([
+            0.3025, 0.2615, 1.7759, 0.3842, 0.4971, 0.5254, 0.6387, 0.6500,
+        ]),
+        std_dev: Fingerprint::from_features([
+            0.0082, 0.0081, 0.0508, 0.0232, 0.0117, 0.0119, 0.0666, 0.0471,
+        ]),
+    },
+    GeneratorProfile {
+        generator: "eller",
+        mean: Fingerprint::from_features([
+            0.2864, 0.2522, 1.8594, 0.4490, 0.4178, 0.5645, 0.7661, 0.7597,
+        ]),
+        std_dev: Fingerprint::from_features([
+            0.0086, 0.0070, 0.0517, 0.0265, 0.0119, 0.0265, 0.0815, 0.0462,
+        ]),
+    },
+    GeneratorProfile {
+        generator: "wilson",
+        mean: Fingerprint::from_features([
+            0.2908, 0.2555, 1.8336, 0.3877, 0.4964, 0.5251, 0.6952, 0.6774,
+        ]),
+        std_dev: Fingerprint::from_features([
+            0.0084, 0.0083, 0.0536, 0.0193, 0.0107, 0.0140, 0.0581, 0.0699,
+        ]),
+    },
+    GeneratorProfile {
+        generator: "hunt-and-kill",
+        mean: Fingerprint::from_features([
+            0.0984, 0.0947, 5.2161, 0.3666, 0.4955, 0.5621, 0.8145, 0.7710,
+        ]),
+        std_dev: Fingerprint::from_features([
+            0.0049, 0.0053, 0.2894, 0.0188, 0.0137, 0.0294, 0.0498, 0.0585,
+        ]),
+    },
+    GeneratorProfile {
+        generator: "sidewinder",
+        mean: Fingerprint::from_features([
+            0.2760, 0.2479, 1.9113, 0.4892, 0.4925, 0.7544, 1.0000, 0.7597,
+        ]),
+        std_dev: Fingerprint::from_features([
+            0.0071, 0.0051, 0.0429, 0.0232, 0.0159, 0.0228, 0.0000, 0.0616,
+        ]),
+    },
+    GeneratorProfile {
+        generator: "binary-tree",
+        mean: Fingerprint::from_features([
+            0.2515, 0.2496, 2.0014, 0.5243, 0.5027, 1.0000, 1.0000, 1.0000,
+        ]),
+        std_dev: Fingerprint::from_features([
+            0.0108, 0.0108, 0.0875, 0.0197, 0.0144, 0.0000, 0.0000, 0.0000,
+        ]),
+    },
+    GeneratorProfile {
+        generator: "prim",
+        mean: Fingerprint::from_features([
+            0.3244, 0.2745, 1.6716, 0.4494, 0.5006, 0.5928, 0.6129, 0.5710,
+        ]),
+        std_dev: Fingerprint::from_features([
+            0.0082, 0.0055, 0.0358, 0.0215, 0.0143, 0.0473, 0.1005, 0.0654,
+        ]),
+    },
+    GeneratorProfile {
+        generator: "recursive-division",
+        mean: Fingerprint::from_features([
+            0.1691, 0.1561, 3.1243, 0.8560, 0.4856, 0.5371, 0.8210, 0.7952,
+        ]),
+        std_dev: Fingerprint::from_features([
+            0.0193, 0.0166, 0.3946, 0.0387, 0.1650, 0.0203, 0.1678, 0.1944,
+        ]),
+    },
+    GeneratorProfile {
+        generator: "aldous-broder",
+        mean: Fingerprint::from_features([
+            0.2906, 0.2556, 1.8341, 0.3833, 0.4978, 0.5265, 0.6984, 0.6952,
+        ]),
+        std_dev: Fingerprint::from_features([
+            0.0100, 0.0075, 0.0569, 0.0225, 0.0121, 0.0176, 0.0709, 0.0599,
+        ]),
+    },
+];
@@ -2,8 +2,28 @@
 //!
 //! Algorithms are organized around the [`MazeGenerator2D`] trait, which supports
 //! full generation and step-by-step event streams for animation.
+//!
+//! Step streams are lazy: [`GenerationSteps`] and its counterparts for the other
+//! topologies are [`StepStream`]s that advance the underlying [`GenerationProcess`]
+//! only when the next step is requested, so animations of huge grids start at once
+//! and never buffer more than a single iteration of the algorithm.
+//!
+//! Generators implementing [`MaskedMazeGenerator2D`] can additionally carve inside an
+//! arbitrary [`GridMask`]; masked cells stay fully walled and are never visited.
+//!
+//! All generators carve passages into a fully walled grid, except for the wall-adding
+//! `RecursiveDivision4`, which starts from an open grid and emits
+//! [`GenerationStep::AddWall`] events instead.
+//!
+//! With their default settings, all generators produce perfect mazes (spanning trees).
+//! [`Braid4`] and `Braid6` post-process a finished maze by removing dead ends,
+//! introducing loops.
 
+#[cfg(feature = "generator-aldous-broder")]
+mod aldous_broder4;
 mod binary_tree4;
+mod braid4;
+mod braid_strategy;
 mod cell_selector;
 mod eller4;
 mod growing_tree4;
@@ -12,26 +32,106 @@ mod hunt_and_kill4;
 mod kruskal4;
 mod prim4;
 mod recursive_backtracker4;
+#[cfg(feature = "generator-recursive-division")]
+mod recursive_division4;
 mod sidewinder4;
+mod step_stream;
+#[cfg(feature = "generator-weave-kruskal")]
+mod weave_kruskal4;
 mod wilson4;
 
 #[cfg(feature = "generator-hex-aldous-broder")]
 mod aldous_broder6;
+#[cfg(any(
+    feature = "generator-hex-recursive-backtracker",
+    feature = "generator-hex-growing-tree",
+    feature = "generator-hex-aldous-broder",
+    feature = "generator-hex-wilson",
+    feature = "generator-hex-kruskal",
+    feature = "generator-hex-prim",
+))]
+mod braid6;
 #[cfg(feature = "generator-hex-growing-tree")]
 mod growing_tree6;
+#[cfg(feature = "generator-hex-kruskal")]
+mod kruskal6;
+#[cfg(feature = "generator-hex-prim")]
+mod prim6;
 #[cfg(feature = "generator-hex-recursive-backtracker")]
 mod recursive_backtracker6;
+#[cfg(feature = "generator-hex-wilson")]
+mod wilson6;
+
+#[cfg(feature = "generator-3d-growing-tree")]
+mod growing_tree3d;
+#[cfg(feature = "generator-3d-kruskal")]
+mod kruskal3d;
+#[cfg(feature = "generator-3d-recursive-backtracker")]
+mod recursive_backtracker3d;
+
+#[cfg(feature = "generator-tri-kruskal")]
+mod kruskal3;
+#[cfg(feature = "generator-tri-recursive-backtracker")]
+mod recursive_backtracker3;
+
+#[cfg(feature = "generator-polar-growing-tree")]
+mod growing_tree_polar;
+#[cfg(feature = "generator-polar-recursive-backtracker")]
+mod recursive_backtracker_polar;
+#[cfg(feature = "generator-polar-wilson")]
+mod wilson_polar;
 
 use crate::grid_coord_2d::GridCoord2D;
+use crate::grid_mask::GridMask;
 use crate::wall4_grid::Wall4Grid;
+use crate::wrap_mode::WrapMode;
 #[cfg(feature = "generator-hex-aldous-broder")]
 pub use aldous_broder6::AldousBroder6;
+#[cfg(any(
+    feature = "generator-hex-recursive-backtracker",
+    feature = "generator-hex-growing-tree",
+    feature = "generator-hex-aldous-broder",
+    feature = "generator-hex-wilson",
+    feature = "generator-hex-kruskal",
+    feature = "generator-hex-prim",
+))]
+pub use braid6::Braid6;
 #[cfg(feature = "generator-hex-growing-tree")]
 pub use growing_tree6::GrowingTree6;
+#[cfg(feature = "generator-hex-kruskal")]
+pub use kruskal6::Kruskal6;
+#[cfg(feature = "generator-hex-prim")]
+pub use prim6::Prim6;
 #[cfg(feature = "generator-hex-recursive-backtracker")]
 pub use recursive_backtracker6::RecursiveBacktracker6;
+#[cfg(feature = "generator-hex-wilson")]
+pub use wilson6::Wilson6;
+
+#[cfg(feature = "generator-3d-growing-tree")]
+pub use growing_tree3d::GrowingTree3D;
+#[cfg(feature = "generator-3d-kruskal")]
+pub use kruskal3d::Kruskal3D;
+#[cfg(feature = "generator-3d-recursive-backtracker")]
+pub use recursive_backtracker3d::RecursiveBacktracker3D;
+
+#[cfg(feature = "generator-tri-kruskal")]
+pub use kruskal3::Kruskal3;
+#[cfg(feature = "generator-tri-recursive-backtracker")]
+pub use recursive_backtracker3::RecursiveBacktracker3;
+
+#[cfg(feature = "generator-polar-growing-tree")]
+pub use growing_tree_polar::GrowingTreePolar;
+#[cfg(feature = "generator-polar-recursive-backtracker")]
+pub use recursive_backtracker_polar::RecursiveBacktrackerPolar;
+#[cfg(feature = "generator-polar-wilson")]
+pub use wilson_polar::WilsonPolar;
+
+#[cfg(feature = "generator-aldous-broder")]
+pub use aldous_broder4::AldousBroder4;
 
 pub use binary_tree4::BinaryTree4;
+pub use braid_strategy::BraidStrategy;
+pub use braid4::Braid4;
 pub use cell_selector::{CellSelector, MixedCell, NewestCell, OldestCell, RandomCell};
 pub use eller4::Eller4;
 pub use growing_tree4::GrowingTree4;
@@ -39,85 +139,190 @@ pub use hunt_and_kill4::HuntAndKill4;
 pub use kruskal4::Kruskal4;
 pub use prim4::Prim4;
 pub use recursive_backtracker4::RecursiveBacktracker4;
+#[cfg(feature = "generator-recursive-division")]
+pub use recursive_division4::RecursiveDivision4;
 pub use sidewinder4::Sidewinder4;
+pub use step_stream::{GenerationProcess, StepStream};
+#[cfg(feature = "generator-weave-kruskal")]
+pub use weave_kruskal4::WeaveKruskal4;
 pub use wilson4::Wilson4;
 
 #[cfg(any(
     feature = "generator-hex-recursive-backtracker",
     feature = "generator-hex-growing-tree",
     feature = "generator-hex-aldous-broder",
+    feature = "generator-hex-wilson",
+    feature = "generator-hex-kruskal",
+    feature = "generator-hex-prim",
 ))]
 use crate::hex_coord::HexCoord;
 #[cfg(any(
     feature = "generator-hex-recursive-backtracker",
     feature = "generator-hex-growing-tree",
     feature = "generator-hex-aldous-broder",
+    feature = "generator-hex-wilson",
+    feature = "generator-hex-kruskal",
+    feature = "generator-hex-prim",
 ))]
 use crate::wall6_grid::Wall6Grid;
 
+#[cfg(any(
+    feature = "generator-3d-recursive-backtracker",
+    feature = "generator-3d-growing-tree",
+    feature = "generator-3d-kruskal",
+))]
+use crate::grid_coord_3d::GridCoord3D;
+#[cfg(any(
+    feature = "generator-3d-recursive-backtracker",
+    feature = "generator-3d-growing-tree",
+    feature = "generator-3d-kruskal",
+))]
+use crate::wall3d_grid::Wall3DGrid;
+
+#[cfg(any(
+    feature = "generator-tri-recursive-backtracker",
+    feature = "generator-tri-kruskal",
+))]
+use crate::tri_coord::TriCoord;
+#[cfg(any(
+    feature = "generator-tri-recursive-backtracker",
+    feature = "generator-tri-kruskal",
+))]
+use crate::wall3_grid::Wall3Grid;
+
+#[cfg(any(
+    feature = "generator-polar-recursive-backtracker",
+    feature = "generator-polar-growing-tree",
+    feature = "generator-polar-wilson",
+))]
+use crate::polar_grid::{PolarCoord, PolarGrid};
+
+#[cfg(feature = "generator-weave-kruskal")]
+use crate::weave_grid::WeaveGrid;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GenerationStep {
-    Visit { cell: GridCoord2D },
-    Carve { from: GridCoord2D, to: GridCoord2D },
-    Backtrack { to: GridCoord2D },
-    AddToFrontier { cell: GridCoord2D },
+    Visit {
+        cell: GridCoord2D,
+    },
+    Carve {
+        from: GridCoord2D,
+        to: GridCoord2D,
+    },
+    /// A wall was added between two adjacent cells, emitted by wall-adding
+    /// generators such as `RecursiveDivision4` that start from an open grid.
+    AddWall {
+        from: GridCoord2D,
+        to: GridCoord2D,
+    },
+    Backtrack {
+        to: GridCoord2D,
+    },
+    AddToFrontier {
+        cell: GridCoord2D,
+    },
+    /// A tunnel was dug from `from` to `to`, passing beneath the crossing `under`;
+    /// emitted by weave generators such as `WeaveKruskal4`.
+    Tunnel {
+        from: GridCoord2D,
+        under: GridCoord2D,
+        to: GridCoord2D,
+    },
     Complete,
 }
 
-pub trait GenerationVisitor {
-    fn on_step(&mut self, step: &GenerationStep);
-}
+/// Lazily generated steps of a maze on a [`Wall4Grid`] or another square-cell grid `G`.
+pub type GenerationSteps<G = Wall4Grid> = StepStream<G, GenerationStep>;
 
-#[derive(Default)]
-pub struct VecGenerationVisitor {
-    steps: Vec<GenerationStep>,
-}
+pub trait MazeGenerator2D {
+    fn new_random() -> Self
+    where
+        Self: Sized;
+    fn new_from_seed(rng_seed: u64) -> Self
+    where
+        Self: Sized;
+    fn generate(&self, width: usize, height: usize) -> Wall4Grid;
 
-impl VecGenerationVisitor {
-    pub fn into_steps(self) -> Vec<GenerationStep> {
-        self.steps
+    fn generate_steps(&self, width: usize, height: usize) -> GenerationSteps {
+        GenerationSteps::finished(self.generate(width, height), GenerationStep::Complete)
     }
-}
 
-impl GenerationVisitor for VecGenerationVisitor {
-    fn on_step(&mut self, step: &GenerationStep) {
-        self.steps.push(step.clone());
+    fn name(&self) -> &'static str {
+        "unknown"
     }
-}
 
-pub struct GenerationSteps {
-    inner: std::vec::IntoIter<GenerationStep>,
+    fn description(&self) -> &'static str {
+        "maze generator"
+    }
 }
 
-impl GenerationSteps {
-    pub fn new(steps: Vec<GenerationStep>) -> Self {
-        Self {
-            inner: steps.into_iter(),
-        }
-    }
+/// A [`MazeGenerator2D`] that can generate mazes restricted to the enabled cells of a [`GridMask`].
+///
+/// The resulting grid has the mask's dimensions. Masked cells keep all four walls,
+/// and every 4-connected region of enabled cells becomes its own perfect maze.
+///
+/// ## Example
+/// ```
+/// use amaze::generators::{MaskedMazeGenerator2D, MazeGenerator2D, Kruskal4};
+/// use amaze::preamble::{GridCoord2D, GridMask};
+///
+/// let mask = GridMask::from_ascii("#####\n#...#\n#####");
+/// let grid = Kruskal4::new_from_seed(7).generate_masked(&mask);
+/// assert_eq!(grid.open_neighbors(GridCoord2D::new(2, 1)).count(), 0);
+/// ```
+pub trait MaskedMazeGenerator2D: MazeGenerator2D {
+    fn generate_masked(&self, mask: &GridMask) -> Wall4Grid;
+
+    fn generate_masked_steps(&self, mask: &GridMask) -> GenerationSteps;
 }
 
-impl Iterator for GenerationSteps {
-    type Item = GenerationStep;
+/// A [`MazeGenerator2D`] that can carve passages across the edges joined by a [`WrapMode`].
+///
+/// With [`WrapMode::None`], the result is identical to [`MazeGenerator2D::generate`].
+///
+/// ## Example
+/// ```
+/// use amaze::generators::{Kruskal4, MazeGenerator2D, WrappingMazeGenerator2D};
+/// use amaze::preamble::{GridCoord2D, WrapMode};
+///
+/// let grid = Kruskal4::new_from_seed(7).generate_wrapped(8, 6, WrapMode::Torus);
+/// assert_eq!(grid.wrap_mode(), WrapMode::Torus);
+/// assert_eq!(grid.neighbors(GridCoord2D::new(0, 0)).count(), 4);
+/// ```
+pub trait WrappingMazeGenerator2D: MazeGenerator2D {
+    fn generate_wrapped(&self, width: usize, height: usize, wrap: WrapMode) -> Wall4Grid;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.inner.next()
-    }
+    fn generate_wrapped_steps(
+        &self,
+        width: usize,
+        height: usize,
+        wrap: WrapMode,
+    ) -> GenerationSteps;
 }
 
-pub trait MazeGenerator2D {
+/// A generator for [`WeaveGrid`] mazes, in which passages may tunnel under each other.
+///
+/// ## Example
+/// ```
+/// use amaze::generators::{MazeGeneratorWeave, WeaveKruskal4};
+///
+/// let grid = WeaveKruskal4::new_from_seed(7).with_density(1.0).generate(10, 10);
+/// assert!(grid.crossings().count() > 0);
+/// assert_eq!(grid.stats().loops, 0);
+/// ```
+#[cfg(feature = "generator-weave-kruskal")]
+pub trait MazeGeneratorWeave {
     fn new_random() -> Self
     where
         Self: Sized;
     fn new_from_seed(rng_seed: u64) -> Self
     where
         Self: Sized;
-    fn generate(&self, width: usize, height: usize) -> Wall4Grid;
+    fn generate(&self, width: usize, height: usize) -> WeaveGrid;
 
-    fn generate_steps(&self, width: usize, height: usize) -> GenerationSteps {
-        let _ = self.generate(width, height);
-        GenerationSteps::new(vec![GenerationStep::Complete])
+    fn generate_steps(&self, width: usize, height: usize) -> GenerationSteps<WeaveGrid> {
+        GenerationSteps::finished(self.generate(width, height), GenerationStep::Complete)
     }
 
     fn name(&self) -> &'static str {
@@ -125,7 +330,7 @@ pub trait MazeGenerator2D {
     }
 
     fn description(&self) -> &'static str {
-        "maze generator"
+        "weave maze generator"
     }
 }
 
@@ -133,6 +338,9 @@ pub trait MazeGenerator2D {
     feature = "generator-hex-recursive-backtracker",
     feature = "generator-hex-growing-tree",
     feature = "generator-hex-aldous-broder",
+    feature = "generator-hex-wilson",
+    feature = "generator-hex-kruskal",
+    feature = "generator-hex-prim",
 ))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -144,99 +352,209 @@ pub enum HexGenerationStep {
     Complete,
 }
 
+/// Lazily generated steps of a [`Wall6Grid`] maze.
 #[cfg(any(
     feature = "generator-hex-recursive-backtracker",
     feature = "generator-hex-growing-tree",
     feature = "generator-hex-aldous-broder",
+    feature = "generator-hex-wilson",
+    feature = "generator-hex-kruskal",
+    feature = "generator-hex-prim",
 ))]
-pub trait HexGenerationVisitor {
-    fn on_step(&mut self, step: &HexGenerationStep);
-}
+pub type HexGenerationSteps = StepStream<Wall6Grid, HexGenerationStep>;
 
 #[cfg(any(
     feature = "generator-hex-recursive-backtracker",
     feature = "generator-hex-growing-tree",
     feature = "generator-hex-aldous-broder",
+    feature = "generator-hex-wilson",
+    feature = "generator-hex-kruskal",
+    feature = "generator-hex-prim",
 ))]
-#[derive(Default)]
-pub struct VecHexGenerationVisitor {
-    steps: Vec<HexGenerationStep>,
+pub trait MazeGenerator6D {
+    fn new_random() -> Self
+    where
+        Self: Sized;
+    fn new_from_seed(rng_seed: u64) -> Self
+    where
+        Self: Sized;
+    fn generate(&self, width: usize, height: usize) -> Wall6Grid;
+
+    fn generate_steps(&self, width: usize, height: usize) -> HexGenerationSteps {
+        HexGenerationSteps::finished(self.generate(width, height), HexGenerationStep::Complete)
+    }
+
+    fn name(&self) -> &'static str {
+        "unknown"
+    }
+
+    fn description(&self) -> &'static str {
+        "hex maze generator"
+    }
 }
 
+/// A [`MazeGenerator6D`] that can carve passages across the edges joined by a [`WrapMode`].
 #[cfg(any(
     feature = "generator-hex-recursive-backtracker",
     feature = "generator-hex-growing-tree",
     feature = "generator-hex-aldous-broder",
+    feature = "generator-hex-wilson",
+    feature = "generator-hex-kruskal",
+    feature = "generator-hex-prim",
 ))]
-impl VecHexGenerationVisitor {
-    pub fn into_steps(self) -> Vec<HexGenerationStep> {
-        self.steps
-    }
+pub trait WrappingMazeGenerator6D: MazeGenerator6D {
+    fn generate_wrapped(&self, width: usize, height: usize, wrap: WrapMode) -> Wall6Grid;
+
+    fn generate_wrapped_steps(
+        &self,
+        width: usize,
+        height: usize,
+        wrap: WrapMode,
+    ) -> HexGenerationSteps;
 }
 
 #[cfg(any(
-    feature = "generator-hex-recursive-backtracker",
-    feature = "generator-hex-growing-tree",
-    feature = "generator-hex-aldous-broder",
+    feature = "generator-polar-recursive-backtracker",
+    feature = "generator-polar-growing-tree",
+    feature = "generator-polar-wilson",
 ))]
-impl HexGenerationVisitor for VecHexGenerationVisitor {
-    fn on_step(&mut self, step: &HexGenerationStep) {
-        self.steps.push(step.clone());
-    }
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PolarGenerationStep {
+    Visit { cell: PolarCoord },
+    Carve { from: PolarCoord, to: PolarCoord },
+    Backtrack { to: PolarCoord },
+    AddToFrontier { cell: PolarCoord },
+    Complete,
 }
 
+/// Lazily generated steps of a [`PolarGrid`] maze.
 #[cfg(any(
-    feature = "generator-hex-recursive-backtracker",
-    feature = "generator-hex-growing-tree",
-    feature = "generator-hex-aldous-broder",
+    feature = "generator-polar-recursive-backtracker",
+    feature = "generator-polar-growing-tree",
+    feature = "generator-polar-wilson",
 ))]
-pub struct HexGenerationSteps {
-    inner: std::vec::IntoIter<HexGenerationStep>,
-}
+pub type PolarGenerationSteps = StepStream<PolarGrid, PolarGenerationStep>;
 
+/// A generator for circular [`PolarGrid`] mazes with the given number of rings.
 #[cfg(any(
-    feature = "generator-hex-recursive-backtracker",
-    feature = "generator-hex-growing-tree",
-    feature = "generator-hex-aldous-broder",
+    feature = "generator-polar-recursive-backtracker",
+    feature = "generator-polar-growing-tree",
+    feature = "generator-polar-wilson",
 ))]
-impl HexGenerationSteps {
-    pub fn new(steps: Vec<HexGenerationStep>) -> Self {
-        Self {
-            inner: steps.into_iter(),
-        }
+pub trait MazeGeneratorPolar {
+    fn new_random() -> Self
+    where
+        Self: Sized;
+    fn new_from_seed(rng_seed: u64) -> Self
+    where
+        Self: Sized;
+    fn generate(&self, rings: usize) -> PolarGrid;
+
+    fn generate_steps(&self, rings: usize) -> PolarGenerationSteps {
+        PolarGenerationSteps::finished(self.generate(rings), PolarGenerationStep::Complete)
+    }
+
+    fn name(&self) -> &'static str {
+        "unknown"
+    }
+
+    fn description(&self) -> &'static str {
+        "polar maze generator"
     }
 }
 
 #[cfg(any(
-    feature = "generator-hex-recursive-backtracker",
-    feature = "generator-hex-growing-tree",
-    feature = "generator-hex-aldous-broder",
+    feature = "generator-tri-recursive-backtracker",
+    feature = "generator-tri-kruskal",
+))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TriGenerationStep {
+    Visit { cell: TriCoord },
+    Carve { from: TriCoord, to: TriCoord },
+    Backtrack { to: TriCoord },
+    AddToFrontier { cell: TriCoord },
+    Complete,
+}
+
+/// Lazily generated steps of a [`Wall3Grid`] maze.
+#[cfg(any(
+    feature = "generator-tri-recursive-backtracker",
+    feature = "generator-tri-kruskal",
 ))]
-impl Iterator for HexGenerationSteps {
-    type Item = HexGenerationStep;
+pub type TriGenerationSteps = StepStream<Wall3Grid, TriGenerationStep>;
+
+/// A generator for triangular [`Wall3Grid`] mazes.
+#[cfg(any(
+    feature = "generator-tri-recursive-backtracker",
+    feature = "generator-tri-kruskal",
+))]
+pub trait MazeGeneratorTri {
+    fn new_random() -> Self
+    where
+        Self: Sized;
+    fn new_from_seed(rng_seed: u64) -> Self
+    where
+        Self: Sized;
+    fn generate(&self, width: usize, height: usize) -> Wall3Grid;
+
+    fn generate_steps(&self, width: usize, height: usize) -> TriGenerationSteps {
+        TriGenerationSteps::finished(self.generate(width, height), TriGenerationStep::Complete)
+    }
+
+    fn name(&self) -> &'static str {
+        "unknown"
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.inner.next()
+    fn description(&self) -> &'static str {
+        "triangle maze generator"
     }
 }
 
 #[cfg(any(
-    feature = "generator-hex-recursive-backtracker",
-    feature = "generator-hex-growing-tree",
-    feature = "generator-hex-aldous-broder",
+    feature = "generator-3d-recursive-backtracker",
+    feature = "generator-3d-growing-tree",
+    feature = "generator-3d-kruskal",
 ))]
-pub trait MazeGenerator6D {
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GenerationStep3D {
+    Visit { cell: GridCoord3D },
+    Carve { from: GridCoord3D, to: GridCoord3D },
+    Backtrack { to: GridCoord3D },
+    AddToFrontier { cell: GridCoord3D },
+    Complete,
+}
+
+/// Lazily generated steps of a [`Wall3DGrid`] maze.
+#[cfg(any(
+    feature = "generator-3d-recursive-backtracker",
+    feature = "generator-3d-growing-tree",
+    feature = "generator-3d-kruskal",
+))]
+pub type GenerationSteps3D = StepStream<Wall3DGrid, GenerationStep3D>;
+
+/// A generator for layered [`Wall3DGrid`] mazes.
+#[cfg(any(
+    feature = "generator-3d-recursive-backtracker",
+    feature = "generator-3d-growing-tree",
+    feature = "generator-3d-kruskal",
+))]
+pub trait MazeGenerator3D {
     fn new_random() -> Self
     where
         Self: Sized;
     fn new_from_seed(rng_seed: u64) -> Self
     where
         Self: Sized;
-    fn generate(&self, width: usize, height: usize) -> Wall6Grid;
+    fn generate(&self, width: usize, height: usize, depth: usize) -> Wall3DGrid;
 
-    fn generate_steps(&self, width: usize, height: usize) -> HexGenerationSteps {
-        let _ = self.generate(width, height);
-        HexGenerationSteps::new(vec![HexGenerationStep::Complete])
+    fn generate_steps(&self, width: usize, height: usize, depth: usize) -> GenerationSteps3D {
+        GenerationSteps3D::finished(
+            self.generate(width, height, depth),
+            GenerationStep3D::Complete,
+        )
     }
 
     fn name(&self) -> &'static str {
@@ -244,6 +562,6 @@ pub trait MazeGenerator6D {
     }
 
     fn description(&self) -> &'static str {
-        "hex maze generator"
+        "3D maze generator"
     }
 }
@@ -0,0 +1,165 @@
+use crate::generators::{
+    GenerationProcess, GenerationStep, GenerationSteps, MazeGenerator2D, WrappingMazeGenerator2D,
+};
+use crate::grid_coord_2d::GridCoord2D;
+use crate::visit_map_2d::VisitMap2D;
+use crate::wall4_grid::Wall4Grid;
+use crate::wrap_mode::WrapMode;
+use rand::RngExt;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::collections::VecDeque;
+
+/// A maze generator that implements the Aldous-Broder algorithm.
+///
+/// Performs a random walk until every cell has been visited, carving a passage
+/// whenever an unvisited cell is entered. Like [`Wilson4`](crate::generators::Wilson4)
+/// it produces uniform spanning trees, but converges considerably slower.
+pub struct AldousBroder4 {
+    rng_seed: u64,
+}
+
+impl Default for AldousBroder4 {
+    fn default() -> Self {
+        Self::new_random()
+    }
+}
+
+impl AldousBroder4 {
+    pub fn new_random() -> Self {
+        Self {
+            rng_seed: rand::random(),
+        }
+    }
+
+    pub fn new_from_seed(rng_seed: u64) -> Self {
+        if rng_seed == 0 {
+            Self::new_random()
+        } else {
+            Self { rng_seed }
+        }
+    }
+
+    pub fn generate(&self, width: usize, height: usize) -> Wall4Grid {
+        self.generate_steps(width, height).finish()
+    }
+
+    pub fn generate_steps(&self, width: usize, height: usize) -> GenerationSteps {
+        self.steps(width, height, WrapMode::None)
+    }
+
+    fn steps(&self, width: usize, height: usize, wrap: WrapMode) -> GenerationSteps {
+        let grid = Wall4Grid::new_wrapped(width, height, wrap);
+        let visited = VisitMap2D::new_like(&grid);
+        let mut rng = StdRng::seed_from_u64(self.rng_seed);
+        let current = if width == 0 || height == 0 {
+            GridCoord2D::default()
+        } else {
+            GridCoord2D::new(rng.random_range(0..width), rng.random_range(0..height))
+        };
+
+        GenerationSteps::new(AldousBroder4Process {
+            rng,
+            grid,
+            visited,
+            current,
+            visited_count: 0,
+            total_cells: width * height,
+        })
+    }
+}
+
+/// The random walk of an [`AldousBroder4`], paused between two moves.
+struct AldousBroder4Process {
+    rng: StdRng,
+    grid: Wall4Grid,
+    visited: VisitMap2D,
+    current: GridCoord2D,
+    visited_count: usize,
+    total_cells: usize,
+}
+
+impl GenerationProcess for AldousBroder4Process {
+    type Grid = Wall4Grid;
+    type Step = GenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<GenerationStep>) -> bool {
+        if self.visited_count == self.total_cells {
+            steps.push_back(GenerationStep::Complete);
+            return false;
+        }
+
+        if self.visited_count == 0 {
+            self.visited[self.current] = true;
+            self.visited_count = 1;
+            steps.push_back(GenerationStep::Visit { cell: self.current });
+            return true;
+        }
+
+        let neighbors: Vec<_> = self.grid.neighbors(self.current).collect();
+        let next = neighbors[self.rng.random_range(0..neighbors.len())];
+
+        if !self.visited[next] {
+            self.visited[next] = true;
+            self.visited_count += 1;
+            self.grid.remove_wall_between(self.current, next);
+            steps.push_back(GenerationStep::Carve {
+                from: self.current,
+                to: next,
+            });
+            steps.push_back(GenerationStep::Visit { cell: next });
+        }
+
+        self.current = next;
+        true
+    }
+
+    fn grid(&self) -> &Wall4Grid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall4Grid {
+        self.grid
+    }
+}
+
+impl MazeGenerator2D for AldousBroder4 {
+    fn new_random() -> Self {
+        AldousBroder4::new_random()
+    }
+
+    fn new_from_seed(rng_seed: u64) -> Self {
+        AldousBroder4::new_from_seed(rng_seed)
+    }
+
+    fn generate(&self, width: usize, height: usize) -> Wall4Grid {
+        self.generate(width, height)
+    }
+
+    fn generate_steps(&self, width: usize, height: usize) -> GenerationSteps {
+        self.generate_steps(width, height)
+    }
+
+    fn name(&self) -> &'static str {
+        "aldous-broder"
+    }
+
+    fn description(&self) -> &'static str {
+        "Aldous-Broder random walk producing uniform spanning trees"
+    }
+}
+
+impl WrappingMazeGenerator2D for AldousBroder4 {
+    fn generate_wrapped(&self, width: usize, height: usize, wrap: WrapMode) -> Wall4Grid {
+        self.generate_wrapped_steps(width, height, wrap).finish()
+    }
+
+    fn generate_wrapped_steps(
+        &self,
+        width: usize,
+        height: usize,
+        wrap: WrapMode,
+    ) -> GenerationSteps {
+        self.steps(width, height, wrap)
+    }
+}
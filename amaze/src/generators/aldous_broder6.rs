@@ -1,13 +1,14 @@
-use crate::direction6::Direction6;
 use crate::generators::{
-    HexGenerationStep, HexGenerationSteps, HexGenerationVisitor, MazeGenerator6D,
-    VecHexGenerationVisitor,
+    GenerationProcess, HexGenerationStep, HexGenerationSteps, MazeGenerator6D,
+    WrappingMazeGenerator6D,
 };
 use crate::hex_coord::HexCoord;
 use crate::wall6_grid::Wall6Grid;
+use crate::wrap_mode::WrapMode;
 use rand::RngExt;
 use rand::SeedableRng;
 use rand::rngs::StdRng;
+use std::collections::VecDeque;
 
 pub struct AldousBroder6 {
     rng_seed: u64,
@@ -35,74 +36,97 @@ impl AldousBroder6 {
     }
 
     pub fn generate(&self, width: usize, height: usize) -> Wall6Grid {
-        self.generate_with_steps(width, height).0
+        self.generate_steps(width, height).finish()
     }
 
     pub fn generate_steps(&self, width: usize, height: usize) -> HexGenerationSteps {
-        HexGenerationSteps::new(self.generate_with_steps(width, height).1)
+        self.steps(width, height, WrapMode::None)
     }
 
-    fn generate_with_steps(
-        &self,
-        width: usize,
-        height: usize,
-    ) -> (Wall6Grid, Vec<HexGenerationStep>) {
-        let mut grid = Wall6Grid::new(width, height);
-        let mut visitor = VecHexGenerationVisitor::default();
+    fn steps(&self, width: usize, height: usize, wrap: WrapMode) -> HexGenerationSteps {
+        let mut rng = StdRng::seed_from_u64(self.rng_seed);
+        let current = (width > 0 && height > 0).then(|| {
+            HexCoord::new(
+                rng.random_range(0..width) as isize,
+                rng.random_range(0..height) as isize,
+            )
+        });
+
+        HexGenerationSteps::new(AldousBroder6Process {
+            rng,
+            grid: Wall6Grid::new_wrapped(width, height, wrap),
+            visited: vec![false; width * height],
+            visited_count: 0,
+            current,
+        })
+    }
+}
 
-        if width == 0 || height == 0 {
-            visitor.on_step(&HexGenerationStep::Complete);
-            return (grid, visitor.into_steps());
-        }
+/// The random walk of an [`AldousBroder6`], paused between two moves.
+struct AldousBroder6Process {
+    rng: StdRng,
+    grid: Wall6Grid,
+    visited: Vec<bool>,
+    /// The number of visited cells; zero until the walk visits its start.
+    visited_count: usize,
+    current: Option<HexCoord>,
+}
 
-        let mut visited = vec![false; width * height];
-        let mut visited_count = 0usize;
-        let total_cells = width * height;
+impl AldousBroder6Process {
+    fn index(&self, cell: HexCoord) -> usize {
+        (cell.r as usize) * self.grid.width() + cell.q as usize
+    }
+}
 
-        let mut rng = StdRng::seed_from_u64(self.rng_seed);
-        let mut current = HexCoord::new(
-            rng.random_range(0..width) as isize,
-            rng.random_range(0..height) as isize,
-        );
-
-        let idx = (current.r as usize) * width + current.q as usize;
-        if !visited[idx] {
-            visited[idx] = true;
-            visited_count += 1;
-            visitor.on_step(&HexGenerationStep::Visit { cell: current });
+impl GenerationProcess for AldousBroder6Process {
+    type Grid = Wall6Grid;
+    type Step = HexGenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<HexGenerationStep>) -> bool {
+        let Some(current) = self.current else {
+            steps.push_back(HexGenerationStep::Complete);
+            return false;
+        };
+
+        if self.visited_count == 0 {
+            let idx = self.index(current);
+            self.visited[idx] = true;
+            self.visited_count += 1;
+            steps.push_back(HexGenerationStep::Visit { cell: current });
+            return true;
+        }
+
+        if self.visited_count == self.visited.len() {
+            self.current = None;
+            steps.push_back(HexGenerationStep::Complete);
+            return false;
         }
 
-        while visited_count < total_cells {
-            let neighbors = Self::neighbors(current, width, height);
-            let next = neighbors[rng.random_range(0..neighbors.len())];
-            let next_idx = (next.r as usize) * width + next.q as usize;
-
-            if !visited[next_idx] {
-                visited[next_idx] = true;
-                visited_count += 1;
-                grid.remove_wall_between(current, next);
-                visitor.on_step(&HexGenerationStep::Carve {
-                    from: current,
-                    to: next,
-                });
-                visitor.on_step(&HexGenerationStep::Visit { cell: next });
-            }
-
-            current = next;
+        let neighbors: Vec<_> = self.grid.neighbors(current).collect();
+        let next = neighbors[self.rng.random_range(0..neighbors.len())];
+        let next_idx = self.index(next);
+
+        if !self.visited[next_idx] {
+            self.visited[next_idx] = true;
+            self.visited_count += 1;
+            self.grid.remove_wall_between(current, next);
+            steps.push_back(HexGenerationStep::Carve {
+                from: current,
+                to: next,
+            });
+            steps.push_back(HexGenerationStep::Visit { cell: next });
         }
 
-        visitor.on_step(&HexGenerationStep::Complete);
-        (grid, visitor.into_steps())
+        self.current = Some(next);
+        true
     }
 
-    fn neighbors(cell: HexCoord, width: usize, height: usize) -> Vec<HexCoord> {
-        let mut result = Vec::with_capacity(6);
-        for &dir in &Direction6::CARDINALS {
-            if let Some(n) = cell.try_neighbor(dir, width, height) {
-                result.push(n);
-            }
-        }
-        result
+    fn grid(&self) -> &Wall6Grid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall6Grid {
+        self.grid
     }
 }
 
@@ -131,3 +155,18 @@ impl MazeGenerator6D for AldousBroder6 {
         "Aldous-Broder algorithm for hexagonal grids producing uniform spanning trees"
     }
 }
+
+impl WrappingMazeGenerator6D for AldousBroder6 {
+    fn generate_wrapped(&self, width: usize, height: usize, wrap: WrapMode) -> Wall6Grid {
+        self.generate_wrapped_steps(width, height, wrap).finish()
+    }
+
+    fn generate_wrapped_steps(
+        &self,
+        width: usize,
+        height: usize,
+        wrap: WrapMode,
+    ) -> HexGenerationSteps {
+        self.steps(width, height, wrap)
+    }
+}
@@ -1,10 +1,9 @@
-use crate::generators::{
-    GenerationStep, GenerationSteps, GenerationVisitor, MazeGenerator2D, VecGenerationVisitor,
-};
+use crate::generators::{GenerationProcess, GenerationStep, GenerationSteps, MazeGenerator2D};
 use crate::grid_coord_2d::GridCoord2D;
 use crate::wall4_grid::Wall4Grid;
 use rand::rngs::StdRng;
 use rand::{RngExt, SeedableRng};
+use std::collections::VecDeque;
 
 pub struct BinaryTree4 {
     rng_seed: u64,
@@ -17,47 +16,70 @@ impl Default for BinaryTree4 {
 }
 
 impl BinaryTree4 {
-    fn generate_with_steps(&self, width: usize, height: usize) -> (Wall4Grid, Vec<GenerationStep>) {
-        let mut grid = Wall4Grid::new(width, height);
-        let mut visitor = VecGenerationVisitor::default();
-        if width == 0 || height == 0 {
-            visitor.on_step(&GenerationStep::Complete);
-            return (grid, visitor.into_steps());
+    fn steps(&self, width: usize, height: usize) -> GenerationSteps {
+        GenerationSteps::new(BinaryTree4Process {
+            rng: StdRng::seed_from_u64(self.rng_seed),
+            grid: Wall4Grid::new(width, height),
+            next_index: 0,
+        })
+    }
+}
+
+/// A [`BinaryTree4`] maze, paused between two cells in row-major order.
+struct BinaryTree4Process {
+    rng: StdRng,
+    grid: Wall4Grid,
+    next_index: usize,
+}
+
+impl GenerationProcess for BinaryTree4Process {
+    type Grid = Wall4Grid;
+    type Step = GenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<GenerationStep>) -> bool {
+        let (width, height) = (self.grid.width(), self.grid.height());
+        if self.next_index == width * height {
+            steps.push_back(GenerationStep::Complete);
+            return false;
         }
 
-        let mut rng = StdRng::seed_from_u64(self.rng_seed);
-        for y in 0..height {
-            for x in 0..width {
-                let cell = GridCoord2D::new(x, y);
-                visitor.on_step(&GenerationStep::Visit { cell });
-                let can_north = y > 0;
-                let can_east = x + 1 < width;
-
-                let target = match (can_north, can_east) {
-                    (true, true) => {
-                        if rng.random_bool(0.5) {
-                            Some(GridCoord2D::new(x, y - 1))
-                        } else {
-                            Some(GridCoord2D::new(x + 1, y))
-                        }
-                    }
-                    (true, false) => Some(GridCoord2D::new(x, y - 1)),
-                    (false, true) => Some(GridCoord2D::new(x + 1, y)),
-                    (false, false) => None,
-                };
-
-                if let Some(next) = target {
-                    grid.remove_wall_between(cell, next);
-                    visitor.on_step(&GenerationStep::Carve {
-                        from: cell,
-                        to: next,
-                    });
+        let (x, y) = (self.next_index % width, self.next_index / width);
+        self.next_index += 1;
+
+        let cell = GridCoord2D::new(x, y);
+        steps.push_back(GenerationStep::Visit { cell });
+        let can_north = y > 0;
+        let can_east = x + 1 < width;
+
+        let target = match (can_north, can_east) {
+            (true, true) => {
+                if self.rng.random_bool(0.5) {
+                    Some(GridCoord2D::new(x, y - 1))
+                } else {
+                    Some(GridCoord2D::new(x + 1, y))
                 }
             }
+            (true, false) => Some(GridCoord2D::new(x, y - 1)),
+            (false, true) => Some(GridCoord2D::new(x + 1, y)),
+            (false, false) => None,
+        };
+
+        if let Some(next) = target {
+            self.grid.remove_wall_between(cell, next);
+            steps.push_back(GenerationStep::Carve {
+                from: cell,
+                to: next,
+            });
         }
+        true
+    }
+
+    fn grid(&self) -> &Wall4Grid {
+        &self.grid
+    }
 
-        visitor.on_step(&GenerationStep::Complete);
-        (grid, visitor.into_steps())
+    fn into_grid(self: Box<Self>) -> Wall4Grid {
+        self.grid
     }
 }
 
@@ -77,11 +99,11 @@ impl MazeGenerator2D for BinaryTree4 {
     }
 
     fn generate(&self, width: usize, height: usize) -> Wall4Grid {
-        self.generate_with_steps(width, height).0
+        self.generate_steps(width, height).finish()
     }
 
     fn generate_steps(&self, width: usize, height: usize) -> GenerationSteps {
-        GenerationSteps::new(self.generate_with_steps(width, height).1)
+        self.steps(width, height)
     }
 
     fn name(&self) -> &'static str {
@@ -0,0 +1,239 @@
+use crate::generators::{BraidStrategy, GenerationProcess, GenerationStep, GenerationSteps};
+use crate::grid_coord_2d::{GridCoord2D, LinearizeCoords2D};
+use crate::maze_grid::MazeGrid;
+use crate::wall4_grid::Wall4Grid;
+use rand::SeedableRng;
+use rand::prelude::SliceRandom;
+use rand::rngs::StdRng;
+use std::collections::VecDeque;
+
+/// Post-processor that turns a perfect maze into a braided (imperfect) one
+/// by removing dead ends.
+///
+/// A `ratio` of `0.0` leaves the maze untouched, while `1.0` removes every dead end.
+/// Each removed dead end introduces a loop into the maze.
+///
+/// ## Example
+/// ```
+/// use amaze::generators::{Braid4, BraidStrategy, RecursiveBacktracker4};
+///
+/// let mut grid = RecursiveBacktracker4::new_from_seed(42).generate(10, 10);
+/// Braid4::new_from_seed(42)
+///     .with_ratio(1.0)
+///     .with_strategy(BraidStrategy::PreferDeadEnd)
+///     .braid(&mut grid);
+///
+/// assert_eq!(grid.stats().dead_ends, 0);
+/// assert!(grid.stats().loops > 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Braid4 {
+    rng_seed: u64,
+    ratio: f64,
+    strategy: BraidStrategy,
+}
+
+impl Default for Braid4 {
+    fn default() -> Self {
+        Self::new_random()
+    }
+}
+
+impl Braid4 {
+    pub fn new_random() -> Self {
+        Self {
+            rng_seed: rand::random(),
+            ratio: 1.0,
+            strategy: BraidStrategy::default(),
+        }
+    }
+
+    pub fn new_from_seed(rng_seed: u64) -> Self {
+        if rng_seed == 0 {
+            Self::new_random()
+        } else {
+            Self {
+                rng_seed,
+                ratio: 1.0,
+                strategy: BraidStrategy::default(),
+            }
+        }
+    }
+
+    /// Sets the fraction of dead ends to remove, clamped to `0.0..=1.0`.
+    pub fn with_ratio(mut self, ratio: f64) -> Self {
+        self.ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the strategy used to select the wall to remove.
+    pub fn with_strategy(mut self, strategy: BraidStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Removes dead ends from the grid in place.
+    pub fn braid(&self, grid: &mut Wall4Grid) {
+        let taken = std::mem::replace(grid, Wall4Grid::new(0, 0));
+        *grid = self.braid_steps(taken).finish();
+    }
+
+    /// Lazily removes dead ends from the grid, yielding the carving steps.
+    ///
+    /// The braided grid is returned by [`GenerationSteps::finish`].
+    pub fn braid_steps(&self, grid: Wall4Grid) -> GenerationSteps {
+        let mut dead_ends: Vec<_> = grid
+            .coords()
+            .filter(|&c| Self::is_dead_end(&grid, c))
+            .collect();
+
+        let mut rng = StdRng::seed_from_u64(self.rng_seed);
+        dead_ends.shuffle(&mut rng);
+        let count = (dead_ends.len() as f64 * self.ratio).round() as usize;
+        dead_ends.truncate(count);
+
+        GenerationSteps::new(Braid4Process {
+            rng,
+            strategy: self.strategy,
+            grid,
+            dead_ends: dead_ends.into_iter(),
+        })
+    }
+
+    fn is_dead_end(grid: &Wall4Grid, cell: GridCoord2D) -> bool {
+        grid.open_neighbors(cell).count() == 1
+    }
+}
+
+/// A [`Braid4`] pass, paused between two dead ends.
+struct Braid4Process {
+    rng: StdRng,
+    strategy: BraidStrategy,
+    grid: Wall4Grid,
+    dead_ends: std::vec::IntoIter<GridCoord2D>,
+}
+
+impl GenerationProcess for Braid4Process {
+    type Grid = Wall4Grid;
+    type Step = GenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<GenerationStep>) -> bool {
+        let Some(cell) = self.dead_ends.next() else {
+            steps.push_back(GenerationStep::Complete);
+            return false;
+        };
+
+        let grid = &mut self.grid;
+        // An earlier removal may already have connected this cell.
+        if !Braid4::is_dead_end(grid, cell) {
+            return true;
+        }
+
+        // Cells without any passage lie outside the maze, e.g. masked cells, and
+        // must stay fully walled.
+        let candidates: Vec<_> = grid
+            .neighbors(cell)
+            .filter(|n| !grid.open_neighbors(cell).any(|o| o == *n))
+            .filter(|&n| grid.open_neighbors(n).next().is_some())
+            .collect();
+        if candidates.is_empty() {
+            return true;
+        }
+
+        let index = self.strategy.select(
+            &mut self.rng,
+            candidates.len(),
+            |i| Braid4::is_dead_end(grid, candidates[i]),
+            || {
+                let dist = grid.bfs_distances(cell);
+                candidates
+                    .iter()
+                    .map(|&n| dist[grid.linearize_coords(n)])
+                    .collect()
+            },
+        );
+
+        let next = candidates[index];
+        steps.push_back(GenerationStep::Visit { cell });
+        grid.remove_wall_between(cell, next);
+        steps.push_back(GenerationStep::Carve {
+            from: cell,
+            to: next,
+        });
+        true
+    }
+
+    fn grid(&self) -> &Wall4Grid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall4Grid {
+        self.grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::RecursiveBacktracker4;
+
+    #[test]
+    fn zero_ratio_keeps_perfect_maze() {
+        let mut grid = RecursiveBacktracker4::new_from_seed(7).generate(8, 8);
+        let before = grid.stats();
+        Braid4::new_from_seed(7).with_ratio(0.0).braid(&mut grid);
+        let after = grid.stats();
+
+        assert_eq!(before.dead_ends, after.dead_ends);
+        assert_eq!(after.loops, 0);
+    }
+
+    #[test]
+    fn full_ratio_removes_all_dead_ends() {
+        for strategy in [
+            BraidStrategy::Random,
+            BraidStrategy::PreferDeadEnd,
+            BraidStrategy::LongestLoop,
+        ] {
+            let mut grid = RecursiveBacktracker4::new_from_seed(11).generate(12, 12);
+            Braid4::new_from_seed(11)
+                .with_ratio(1.0)
+                .with_strategy(strategy)
+                .braid(&mut grid);
+
+            let stats = grid.stats();
+            assert_eq!(stats.dead_ends, 0, "{strategy:?} left dead ends");
+            assert!(stats.loops > 0);
+        }
+    }
+
+    #[test]
+    fn steps_match_carved_walls() {
+        let grid = RecursiveBacktracker4::new_from_seed(3).generate(10, 10);
+        let mut replay = grid.stats();
+        let mut stream = Braid4::new_from_seed(3).with_ratio(0.5).braid_steps(grid);
+        let steps: Vec<_> = stream.by_ref().collect();
+        let grid = stream.finish();
+
+        let carves = steps
+            .iter()
+            .filter(|s| matches!(s, GenerationStep::Carve { .. }))
+            .count();
+        assert_eq!(steps.last(), Some(&GenerationStep::Complete));
+
+        replay.loops += carves;
+        assert_eq!(grid.stats().loops, replay.loops);
+    }
+
+    #[test]
+    fn braiding_is_deterministic() {
+        let mut a = RecursiveBacktracker4::new_from_seed(5).generate(9, 9);
+        let mut b = RecursiveBacktracker4::new_from_seed(5).generate(9, 9);
+        Braid4::new_from_seed(99).with_ratio(0.6).braid(&mut a);
+        Braid4::new_from_seed(99).with_ratio(0.6).braid(&mut b);
+
+        for cell in a.coords() {
+            assert_eq!(a[cell], b[cell]);
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use crate::generators::{BraidStrategy, GenerationProcess, HexGenerationStep, HexGenerationSteps};
+use crate::hex_coord::HexCoord;
+use crate::maze_grid::MazeGrid;
+use crate::wall6_grid::Wall6Grid;
+use rand::SeedableRng;
+use rand::prelude::SliceRandom;
+use rand::rngs::StdRng;
+use std::collections::VecDeque;
+
+/// Post-processor that removes dead ends from hexagonal mazes.
+///
+/// See [`Braid4`](crate::generators::Braid4) for the rectangular counterpart.
+#[derive(Debug, Clone)]
+pub struct Braid6 {
+    rng_seed: u64,
+    ratio: f64,
+    strategy: BraidStrategy,
+}
+
+impl Default for Braid6 {
+    fn default() -> Self {
+        Self::new_random()
+    }
+}
+
+impl Braid6 {
+    pub fn new_random() -> Self {
+        Self {
+            rng_seed: rand::random(),
+            ratio: 1.0,
+            strategy: BraidStrategy::default(),
+        }
+    }
+
+    pub fn new_from_seed(rng_seed: u64) -> Self {
+        if rng_seed == 0 {
+            Self::new_random()
+        } else {
+            Self {
+                rng_seed,
+                ratio: 1.0,
+                strategy: BraidStrategy::default(),
+            }
+        }
+    }
+
+    /// Sets the fraction of dead ends to remove, clamped to `0.0..=1.0`.
+    pub fn with_ratio(mut self, ratio: f64) -> Self {
+        self.ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the strategy used to select the wall to remove.
+    pub fn with_strategy(mut self, strategy: BraidStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Removes dead ends from the grid in place.
+    pub fn braid(&self, grid: &mut Wall6Grid) {
+        let taken = std::mem::replace(grid, Wall6Grid::new(0, 0));
+        *grid = self.braid_steps(taken).finish();
+    }
+
+    /// Lazily removes dead ends from the grid, yielding the carving steps.
+    ///
+    /// The braided grid is returned by [`HexGenerationSteps::finish`].
+    pub fn braid_steps(&self, grid: Wall6Grid) -> HexGenerationSteps {
+        let mut dead_ends: Vec<_> = grid
+            .coords()
+            .filter(|&c| Self::is_dead_end(&grid, c))
+            .collect();
+
+        let mut rng = StdRng::seed_from_u64(self.rng_seed);
+        dead_ends.shuffle(&mut rng);
+        let count = (dead_ends.len() as f64 * self.ratio).round() as usize;
+        dead_ends.truncate(count);
+
+        HexGenerationSteps::new(Braid6Process {
+            rng,
+            strategy: self.strategy,
+            grid,
+            dead_ends: dead_ends.into_iter(),
+        })
+    }
+
+    fn is_dead_end(grid: &Wall6Grid, cell: HexCoord) -> bool {
+        grid.open_neighbors(cell).count() == 1
+    }
+}
+
+/// A [`Braid6`] pass, paused between two dead ends.
+struct Braid6Process {
+    rng: StdRng,
+    strategy: BraidStrategy,
+    grid: Wall6Grid,
+    dead_ends: std::vec::IntoIter<HexCoord>,
+}
+
+impl GenerationProcess for Braid6Process {
+    type Grid = Wall6Grid;
+    type Step = HexGenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<HexGenerationStep>) -> bool {
+        let Some(cell) = self.dead_ends.next() else {
+            steps.push_back(HexGenerationStep::Complete);
+            return false;
+        };
+
+        let grid = &mut self.grid;
+        // An earlier removal may already have connected this cell.
+        if !Braid6::is_dead_end(grid, cell) {
+            return true;
+        }
+
+        // Cells without any passage lie outside the maze, e.g. masked cells, and
+        // must stay fully walled.
+        let candidates: Vec<_> = grid
+            .neighbors(cell)
+            .filter(|n| !grid.open_neighbors(cell).any(|o| o == *n))
+            .filter(|&n| grid.open_neighbors(n).next().is_some())
+            .collect();
+        if candidates.is_empty() {
+            return true;
+        }
+
+        let index = self.strategy.select(
+            &mut self.rng,
+            candidates.len(),
+            |i| Braid6::is_dead_end(grid, candidates[i]),
+            || {
+                let dist = grid.bfs_distances(cell);
+                candidates
+                    .iter()
+                    .map(|&n| dist[grid.linearize(n)])
+                    .collect()
+            },
+        );
+
+        let next = candidates[index];
+        steps.push_back(HexGenerationStep::Visit { cell });
+        grid.remove_wall_between(cell, next);
+        steps.push_back(HexGenerationStep::Carve {
+            from: cell,
+            to: next,
+        });
+        true
+    }
+
+    fn grid(&self) -> &Wall6Grid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall6Grid {
+        self.grid
+    }
+}
+
+#[cfg(all(test, feature = "generator-hex-recursive-backtracker"))]
+mod tests {
+    use super::*;
+    use crate::generators::RecursiveBacktracker6;
+
+    #[test]
+    fn full_ratio_removes_all_dead_ends() {
+        for strategy in [
+            BraidStrategy::Random,
+            BraidStrategy::PreferDeadEnd,
+            BraidStrategy::LongestLoop,
+        ] {
+            let mut grid = RecursiveBacktracker6::new_from_seed(11).generate(8, 8);
+            Braid6::new_from_seed(11)
+                .with_ratio(1.0)
+                .with_strategy(strategy)
+                .braid(&mut grid);
+
+            let stats = grid.stats();
+            assert_eq!(stats.dead_ends, 0, "{strategy:?} left dead ends");
+            assert!(stats.loops > 0);
+        }
+    }
+
+    #[test]
+    fn partial_ratio_keeps_some_dead_ends() {
+        let grid = RecursiveBacktracker6::new_from_seed(4).generate(10, 10);
+        let before = grid.stats().dead_ends;
+        let mut stream = Braid6::new_from_seed(4).with_ratio(0.25).braid_steps(grid);
+        let steps: Vec<_> = stream.by_ref().collect();
+        let grid = stream.finish();
+
+        let after = grid.stats().dead_ends;
+        assert!(after < before);
+        assert!(after > 0);
+        assert_eq!(steps.last(), Some(&HexGenerationStep::Complete));
+    }
+}
@@ -0,0 +1,60 @@
+/// Selects which wall to knock out when removing a dead end during braiding.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BraidStrategy {
+    /// Opens a uniformly random wall of the dead end.
+    #[default]
+    Random,
+    /// Prefers opening into a neighboring dead end, removing two dead ends at once.
+    /// Falls back to a random wall if no neighbor is a dead end.
+    PreferDeadEnd,
+    /// Opens the wall whose neighbor is farthest away along existing passages,
+    /// producing the longest possible loop.
+    ///
+    /// This runs a breadth-first search per dead end and is therefore noticeably
+    /// slower than the other strategies on large mazes.
+    LongestLoop,
+}
+
+impl BraidStrategy {
+    /// Picks the index of the candidate wall to remove.
+    ///
+    /// `is_dead_end` reports whether the candidate neighbor is a dead end and
+    /// `loop_length` returns the passage distance between the dead end and the
+    /// candidate (if they are connected at all).
+    pub(crate) fn select<R, D, L>(
+        &self,
+        rng: &mut R,
+        candidates: usize,
+        is_dead_end: D,
+        loop_length: L,
+    ) -> usize
+    where
+        R: rand::Rng,
+        D: Fn(usize) -> bool,
+        L: FnOnce() -> Vec<Option<usize>>,
+    {
+        use rand::RngExt;
+        debug_assert!(candidates > 0);
+
+        match self {
+            BraidStrategy::Random => rng.random_range(0..candidates),
+            BraidStrategy::PreferDeadEnd => {
+                let dead_ends: Vec<_> = (0..candidates).filter(|&i| is_dead_end(i)).collect();
+                if dead_ends.is_empty() {
+                    rng.random_range(0..candidates)
+                } else {
+                    dead_ends[rng.random_range(0..dead_ends.len())]
+                }
+            }
+            BraidStrategy::LongestLoop => {
+                let lengths = loop_length();
+                let longest = lengths.iter().map(|d| d.unwrap_or(usize::MAX)).max();
+                let best: Vec<_> = (0..candidates)
+                    .filter(|&i| Some(lengths[i].unwrap_or(usize::MAX)) == longest)
+                    .collect();
+                best[rng.random_range(0..best.len())]
+            }
+        }
+    }
+}
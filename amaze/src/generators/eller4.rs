@@ -1,11 +1,9 @@
-use crate::generators::{
-    GenerationStep, GenerationSteps, GenerationVisitor, MazeGenerator2D, VecGenerationVisitor,
-};
+use crate::generators::{GenerationProcess, GenerationStep, GenerationSteps, MazeGenerator2D};
 use crate::grid_coord_2d::GridCoord2D;
 use crate::wall4_grid::Wall4Grid;
 use rand::rngs::StdRng;
 use rand::{RngExt, SeedableRng};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, VecDeque};
 
 pub struct Eller4 {
     rng_seed: u64,
@@ -18,79 +16,112 @@ impl Default for Eller4 {
 }
 
 impl Eller4 {
-    fn generate_with_steps(&self, width: usize, height: usize) -> (Wall4Grid, Vec<GenerationStep>) {
-        let mut grid = Wall4Grid::new(width, height);
-        let mut visitor = VecGenerationVisitor::default();
-        if width == 0 || height == 0 {
-            visitor.on_step(&GenerationStep::Complete);
-            return (grid, visitor.into_steps());
+    fn steps(&self, width: usize, height: usize) -> GenerationSteps {
+        GenerationSteps::new(Eller4Process {
+            rng: StdRng::seed_from_u64(self.rng_seed),
+            grid: Wall4Grid::new(width, height),
+            next_visit: 0,
+            row: 0,
+            set_ids: (0..width).collect(),
+            next_set_id: width,
+        })
+    }
+}
+
+/// An [`Eller4`] maze, paused between two rows.
+struct Eller4Process {
+    rng: StdRng,
+    grid: Wall4Grid,
+    next_visit: usize,
+    row: usize,
+    set_ids: Vec<usize>,
+    next_set_id: usize,
+}
+
+impl GenerationProcess for Eller4Process {
+    type Grid = Wall4Grid;
+    type Step = GenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<GenerationStep>) -> bool {
+        let (width, height) = (self.grid.width(), self.grid.height());
+        if self.next_visit < width * height {
+            let cell = GridCoord2D::new(self.next_visit % width, self.next_visit / width);
+            self.next_visit += 1;
+            steps.push_back(GenerationStep::Visit { cell });
+            return true;
         }
 
-        for cell in grid.coords() {
-            visitor.on_step(&GenerationStep::Visit { cell });
+        if width == 0 || self.row == height {
+            steps.push_back(GenerationStep::Complete);
+            return false;
         }
 
-        let mut rng = StdRng::seed_from_u64(self.rng_seed);
-        let mut set_ids: Vec<usize> = (0..width).collect();
-        let mut next_set_id = width;
-
-        for y in 0..height {
-            for x in 0..width.saturating_sub(1) {
-                let join = y + 1 == height || rng.random_bool(0.5);
-                if join && set_ids[x] != set_ids[x + 1] {
-                    let a = GridCoord2D::new(x, y);
-                    let b = GridCoord2D::new(x + 1, y);
-                    grid.remove_wall_between(a, b);
-                    visitor.on_step(&GenerationStep::Carve { from: a, to: b });
-                    let from = set_ids[x + 1];
-                    let to = set_ids[x];
-                    for id in &mut set_ids {
-                        if *id == from {
-                            *id = to;
-                        }
+        let y = self.row;
+        self.row += 1;
+        let rng = &mut self.rng;
+        let set_ids = &mut self.set_ids;
+
+        for x in 0..width.saturating_sub(1) {
+            let join = y + 1 == height || rng.random_bool(0.5);
+            if join && set_ids[x] != set_ids[x + 1] {
+                let a = GridCoord2D::new(x, y);
+                let b = GridCoord2D::new(x + 1, y);
+                self.grid.remove_wall_between(a, b);
+                steps.push_back(GenerationStep::Carve { from: a, to: b });
+                let from = set_ids[x + 1];
+                let to = set_ids[x];
+                for id in set_ids.iter_mut() {
+                    if *id == from {
+                        *id = to;
                     }
                 }
             }
+        }
 
-            if y + 1 == height {
-                continue;
-            }
+        if y + 1 == height {
+            return true;
+        }
 
-            let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
-            for (x, set_id) in set_ids.iter().copied().enumerate() {
-                groups.entry(set_id).or_default().push(x);
-            }
+        let mut groups: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for (x, set_id) in set_ids.iter().copied().enumerate() {
+            groups.entry(set_id).or_default().push(x);
+        }
 
-            let mut carry_down = vec![false; width];
-            for indices in groups.values() {
-                let forced = indices[rng.random_range(0..indices.len())];
-                carry_down[forced] = true;
-                for &x in indices {
-                    if rng.random_bool(0.35) {
-                        carry_down[x] = true;
-                    }
+        let mut carry_down = vec![false; width];
+        for indices in groups.values() {
+            let forced = indices[rng.random_range(0..indices.len())];
+            carry_down[forced] = true;
+            for &x in indices {
+                if rng.random_bool(0.35) {
+                    carry_down[x] = true;
                 }
             }
+        }
 
-            let mut next_row = vec![0usize; width];
-            for x in 0..width {
-                if carry_down[x] {
-                    let a = GridCoord2D::new(x, y);
-                    let b = GridCoord2D::new(x, y + 1);
-                    grid.remove_wall_between(a, b);
-                    visitor.on_step(&GenerationStep::Carve { from: a, to: b });
-                    next_row[x] = set_ids[x];
-                } else {
-                    next_row[x] = next_set_id;
-                    next_set_id += 1;
-                }
+        let mut next_row = vec![0usize; width];
+        for x in 0..width {
+            if carry_down[x] {
+                let a = GridCoord2D::new(x, y);
+                let b = GridCoord2D::new(x, y + 1);
+                self.grid.remove_wall_between(a, b);
+                steps.push_back(GenerationStep::Carve { from: a, to: b });
+                next_row[x] = set_ids[x];
+            } else {
+                next_row[x] = self.next_set_id;
+                self.next_set_id += 1;
             }
-
-            set_ids = next_row;
         }
 
-        visitor.on_step(&GenerationStep::Complete);
-        (grid, visitor.into_steps())
+        *set_ids = next_row;
+        true
+    }
+
+    fn grid(&self) -> &Wall4Grid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall4Grid {
+        self.grid
     }
 }
 
@@ -110,11 +141,11 @@ impl MazeGenerator2D for Eller4 {
     }
 
     fn generate(&self, width: usize, height: usize) -> Wall4Grid {
-        self.generate_with_steps(width, height).0
+        self.generate_steps(width, height).finish()
     }
 
     fn generate_steps(&self, width: usize, height: usize) -> GenerationSteps {
-        GenerationSteps::new(self.generate_with_steps(width, height).1)
+        self.steps(width, height)
     }
 
     fn name(&self) -> &'static str {
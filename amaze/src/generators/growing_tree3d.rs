@@ -0,0 +1,177 @@
+use crate::generators::{
+    CellSelector, GenerationProcess, GenerationStep3D, GenerationSteps3D, MazeGenerator3D,
+    NewestCell,
+};
+use crate::grid_coord_3d::GridCoord3D;
+use crate::maze_grid::MazeGrid;
+use crate::wall3d_grid::Wall3DGrid;
+use rand::prelude::SliceRandom;
+use rand::rngs::StdRng;
+use rand::{RngExt, SeedableRng};
+use std::collections::VecDeque;
+
+/// Growing tree algorithm for layered 3D grids.
+///
+/// See [`GrowingTree4`](crate::generators::GrowingTree4) for the single-layer counterpart.
+///
+/// ## Example
+/// ```
+/// use amaze::generators::{GrowingTree3D, MazeGenerator3D, RandomCell};
+///
+/// let grid = GrowingTree3D::new_from_seed_with_selector(42, RandomCell).generate(5, 5, 3);
+/// assert_eq!(grid.stats().loops, 0);
+/// ```
+pub struct GrowingTree3D<S: CellSelector = NewestCell> {
+    rng_seed: u64,
+    selector: S,
+}
+
+impl<S> GrowingTree3D<S>
+where
+    S: CellSelector,
+{
+    pub fn with_selector(selector: S) -> Self {
+        Self {
+            rng_seed: rand::random(),
+            selector,
+        }
+    }
+
+    pub fn new_from_seed_with_selector(rng_seed: u64, selector: S) -> Self {
+        let rng_seed = if rng_seed == 0 {
+            rand::random()
+        } else {
+            rng_seed
+        };
+
+        Self { rng_seed, selector }
+    }
+}
+
+impl<S> GrowingTree3D<S>
+where
+    S: CellSelector + Clone + 'static,
+{
+    pub fn generate(&self, width: usize, height: usize, depth: usize) -> Wall3DGrid {
+        self.generate_steps(width, height, depth).finish()
+    }
+
+    pub fn generate_steps(&self, width: usize, height: usize, depth: usize) -> GenerationSteps3D {
+        let grid = Wall3DGrid::new(width, height, depth);
+        let mut rng = StdRng::seed_from_u64(self.rng_seed);
+        let start = (grid.cell_count() > 0)
+            .then(|| grid.delinearize(rng.random_range(0..grid.cell_count())));
+
+        GenerationSteps3D::new(GrowingTree3DProcess {
+            rng,
+            selector: self.selector.clone(),
+            visited: vec![false; grid.cell_count()],
+            grid,
+            frontier: Vec::new(),
+            start,
+        })
+    }
+}
+
+/// The frontier of a [`GrowingTree3D`], paused between two cell selections.
+struct GrowingTree3DProcess<S> {
+    rng: StdRng,
+    selector: S,
+    grid: Wall3DGrid,
+    visited: Vec<bool>,
+    frontier: Vec<GridCoord3D>,
+    start: Option<GridCoord3D>,
+}
+
+impl<S: CellSelector> GenerationProcess for GrowingTree3DProcess<S> {
+    type Grid = Wall3DGrid;
+    type Step = GenerationStep3D;
+
+    fn advance(&mut self, steps: &mut VecDeque<GenerationStep3D>) -> bool {
+        if let Some(start) = self.start.take() {
+            self.visited[self.grid.linearize(start)] = true;
+            steps.push_back(GenerationStep3D::Visit { cell: start });
+            self.frontier.push(start);
+            steps.push_back(GenerationStep3D::AddToFrontier { cell: start });
+            return true;
+        }
+
+        if self.frontier.is_empty() {
+            steps.push_back(GenerationStep3D::Complete);
+            return false;
+        }
+
+        let idx = self.selector.select(&mut self.rng, self.frontier.len());
+        let cell = self.frontier[idx];
+        let mut candidates: Vec<_> = self
+            .grid
+            .neighbors(cell)
+            .filter(|&n| !self.visited[self.grid.linearize(n)])
+            .collect();
+
+        if candidates.is_empty() {
+            self.frontier.swap_remove(idx);
+            return true;
+        }
+
+        candidates.shuffle(&mut self.rng);
+        let next = candidates[0];
+        self.grid.remove_wall_between(cell, next);
+        steps.push_back(GenerationStep3D::Carve {
+            from: cell,
+            to: next,
+        });
+        self.visited[self.grid.linearize(next)] = true;
+        steps.push_back(GenerationStep3D::Visit { cell: next });
+        self.frontier.push(next);
+        steps.push_back(GenerationStep3D::AddToFrontier { cell: next });
+        true
+    }
+
+    fn grid(&self) -> &Wall3DGrid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall3DGrid {
+        self.grid
+    }
+}
+
+impl<S> MazeGenerator3D for GrowingTree3D<S>
+where
+    S: CellSelector + Default + Clone + 'static,
+{
+    fn new_random() -> Self {
+        Self {
+            rng_seed: rand::random(),
+            selector: S::default(),
+        }
+    }
+
+    fn new_from_seed(rng_seed: u64) -> Self {
+        if rng_seed == 0 {
+            Self::new_random()
+        } else {
+            Self {
+                rng_seed,
+                selector: S::default(),
+            }
+        }
+    }
+
+    fn generate(&self, width: usize, height: usize, depth: usize) -> Wall3DGrid {
+        self.generate(width, height, depth)
+    }
+
+    fn generate_steps(&self, width: usize, height: usize, depth: usize) -> GenerationSteps3D {
+        self.generate_steps(width, height, depth)
+    }
+
+    fn name(&self) -> &'static str {
+        "3d-growing-tree"
+    }
+
+    fn description(&self) -> &'static str {
+        "Growing tree maze generator for layered 3D grids with configurable frontier selection"
+    }
+}
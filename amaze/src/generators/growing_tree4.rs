@@ -1,13 +1,17 @@
+use crate::generators::helpers::mask_start::{RegionStarts, random_start};
 use crate::generators::{
-    CellSelector, GenerationStep, GenerationSteps, GenerationVisitor, MazeGenerator2D, NewestCell,
-    VecGenerationVisitor,
+    CellSelector, GenerationProcess, GenerationStep, GenerationSteps, MaskedMazeGenerator2D,
+    MazeGenerator2D, NewestCell, WrappingMazeGenerator2D,
 };
 use crate::grid_coord_2d::GridCoord2D;
+use crate::grid_mask::GridMask;
 use crate::visit_map_2d::VisitMap2D;
 use crate::wall4_grid::Wall4Grid;
+use crate::wrap_mode::WrapMode;
+use rand::SeedableRng;
 use rand::prelude::SliceRandom;
 use rand::rngs::StdRng;
-use rand::{RngExt, SeedableRng};
+use std::collections::VecDeque;
 
 pub struct GrowingTree4<S: CellSelector = NewestCell> {
     rng_seed: u64,
@@ -34,58 +38,101 @@ where
 
         Self { rng_seed, selector }
     }
+}
 
-    fn generate_with_steps(&self, width: usize, height: usize) -> (Wall4Grid, Vec<GenerationStep>) {
-        let mut grid = Wall4Grid::new(width, height);
-        let mut visited = VisitMap2D::new_like(&grid);
-        let mut frontier = Vec::new();
-        let mut visitor = VecGenerationVisitor::default();
+impl<S> GrowingTree4<S>
+where
+    S: CellSelector + Clone + 'static,
+{
+    fn steps(&self, mask: &GridMask, wrap: WrapMode) -> GenerationSteps {
+        let mut rng = StdRng::seed_from_u64(self.rng_seed);
+        let next_start = random_start(&mut rng, mask);
+
+        GenerationSteps::new(GrowingTree4Process {
+            rng,
+            selector: self.selector.clone(),
+            grid: Wall4Grid::new_wrapped(mask.width(), mask.height(), wrap),
+            visited: VisitMap2D::new_masked(mask),
+            frontier: Vec::new(),
+            regions: RegionStarts::new(),
+            next_start,
+        })
+    }
+}
 
-        if width == 0 || height == 0 {
-            visitor.on_step(&GenerationStep::Complete);
-            return (grid, visitor.into_steps());
-        }
+/// The frontier of a [`GrowingTree4`], paused between two cell selections.
+struct GrowingTree4Process<S> {
+    rng: StdRng,
+    selector: S,
+    grid: Wall4Grid,
+    visited: VisitMap2D,
+    frontier: Vec<GridCoord2D>,
+    regions: RegionStarts,
+    next_start: Option<GridCoord2D>,
+}
 
-        let mut rng = StdRng::seed_from_u64(self.rng_seed);
-        let start = GridCoord2D::new(rng.random_range(0..width), rng.random_range(0..height));
-
-        visited[start] = true;
-        frontier.push(start);
-        visitor.on_step(&GenerationStep::Visit { cell: start });
-        visitor.on_step(&GenerationStep::AddToFrontier { cell: start });
-
-        while !frontier.is_empty() {
-            let idx = self.selector.select(&mut rng, frontier.len());
-            let cell = frontier[idx];
-            let mut candidates = visited.unvisited_neighbors(cell);
-            if candidates.is_empty() {
-                frontier.swap_remove(idx);
-                visitor.on_step(&GenerationStep::Backtrack { to: cell });
-                continue;
-            }
+impl<S: CellSelector> GenerationProcess for GrowingTree4Process<S> {
+    type Grid = Wall4Grid;
+    type Step = GenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<GenerationStep>) -> bool {
+        if self.frontier.is_empty() {
+            let Some(start) = self.next_start.take() else {
+                steps.push_back(GenerationStep::Complete);
+                return false;
+            };
+
+            self.visited[start] = true;
+            self.frontier.push(start);
+            steps.push_back(GenerationStep::Visit { cell: start });
+            steps.push_back(GenerationStep::AddToFrontier { cell: start });
+            return true;
+        }
 
-            candidates.shuffle(&mut rng);
+        let idx = self.selector.select(&mut self.rng, self.frontier.len());
+        let cell = self.frontier[idx];
+        let mut candidates: Vec<_> = self
+            .grid
+            .neighbors(cell)
+            .filter(|&n| !self.visited[n])
+            .collect();
+        if candidates.is_empty() {
+            self.frontier.swap_remove(idx);
+            steps.push_back(GenerationStep::Backtrack { to: cell });
+        } else {
+            candidates.shuffle(&mut self.rng);
             let next = candidates[0];
-            grid.remove_wall_between(cell, next);
-            visited[next] = true;
-            frontier.push(next);
+            self.grid.remove_wall_between(cell, next);
+            self.visited[next] = true;
+            self.frontier.push(next);
 
-            visitor.on_step(&GenerationStep::Carve {
+            steps.push_back(GenerationStep::Carve {
                 from: cell,
                 to: next,
             });
-            visitor.on_step(&GenerationStep::Visit { cell: next });
-            visitor.on_step(&GenerationStep::AddToFrontier { cell: next });
+            steps.push_back(GenerationStep::Visit { cell: next });
+            steps.push_back(GenerationStep::AddToFrontier { cell: next });
+        }
+
+        // Regions of the mask not reachable from the start are grown from their first cell.
+        if self.frontier.is_empty() {
+            self.next_start = self.regions.next_unvisited(&self.visited);
         }
+        true
+    }
 
-        visitor.on_step(&GenerationStep::Complete);
-        (grid, visitor.into_steps())
+    fn grid(&self) -> &Wall4Grid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall4Grid {
+        self.grid
     }
 }
 
 impl<S> MazeGenerator2D for GrowingTree4<S>
 where
-    S: CellSelector + Default,
+    S: CellSelector + Default + Clone + 'static,
 {
     fn new_random() -> Self {
         Self {
@@ -106,11 +153,11 @@ where
     }
 
     fn generate(&self, width: usize, height: usize) -> Wall4Grid {
-        self.generate_with_steps(width, height).0
+        self.generate_steps(width, height).finish()
     }
 
     fn generate_steps(&self, width: usize, height: usize) -> GenerationSteps {
-        GenerationSteps::new(self.generate_with_steps(width, height).1)
+        self.steps(&GridMask::new(width, height), WrapMode::None)
     }
 
     fn name(&self) -> &'static str {
@@ -121,3 +168,34 @@ where
         "Growing tree maze generator with configurable frontier selection"
     }
 }
+
+impl<S> MaskedMazeGenerator2D for GrowingTree4<S>
+where
+    S: CellSelector + Default + Clone + 'static,
+{
+    fn generate_masked(&self, mask: &GridMask) -> Wall4Grid {
+        self.generate_masked_steps(mask).finish()
+    }
+
+    fn generate_masked_steps(&self, mask: &GridMask) -> GenerationSteps {
+        self.steps(mask, WrapMode::None)
+    }
+}
+
+impl<S> WrappingMazeGenerator2D for GrowingTree4<S>
+where
+    S: CellSelector + Default + Clone + 'static,
+{
+    fn generate_wrapped(&self, width: usize, height: usize, wrap: WrapMode) -> Wall4Grid {
+        self.generate_wrapped_steps(width, height, wrap).finish()
+    }
+
+    fn generate_wrapped_steps(
+        &self,
+        width: usize,
+        height: usize,
+        wrap: WrapMode,
+    ) -> GenerationSteps {
+        self.steps(&GridMask::new(width, height), wrap)
+    }
+}
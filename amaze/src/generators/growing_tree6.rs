@@ -1,13 +1,14 @@
-use crate::direction6::Direction6;
 use crate::generators::{
-    CellSelector, HexGenerationStep, HexGenerationSteps, HexGenerationVisitor, MazeGenerator6D,
-    NewestCell, VecHexGenerationVisitor,
+    CellSelector, GenerationProcess, HexGenerationStep, HexGenerationSteps, MazeGenerator6D,
+    NewestCell, WrappingMazeGenerator6D,
 };
 use crate::hex_coord::HexCoord;
 use crate::wall6_grid::Wall6Grid;
+use crate::wrap_mode::WrapMode;
 use rand::prelude::SliceRandom;
 use rand::rngs::StdRng;
 use rand::{RngExt, SeedableRng};
+use std::collections::VecDeque;
 
 pub struct GrowingTree6<S: CellSelector = NewestCell> {
     rng_seed: u64,
@@ -34,93 +35,115 @@ where
 
         Self { rng_seed, selector }
     }
+}
 
+impl<S> GrowingTree6<S>
+where
+    S: CellSelector + Clone + 'static,
+{
     pub fn generate(&self, width: usize, height: usize) -> Wall6Grid {
-        self.generate_with_steps(width, height).0
+        self.generate_steps(width, height).finish()
     }
 
     pub fn generate_steps(&self, width: usize, height: usize) -> HexGenerationSteps {
-        HexGenerationSteps::new(self.generate_with_steps(width, height).1)
+        self.steps(width, height, WrapMode::None)
     }
 
-    fn generate_with_steps(
-        &self,
-        width: usize,
-        height: usize,
-    ) -> (Wall6Grid, Vec<HexGenerationStep>) {
-        let mut grid = Wall6Grid::new(width, height);
-        let mut visitor = VecHexGenerationVisitor::default();
+    fn steps(&self, width: usize, height: usize, wrap: WrapMode) -> HexGenerationSteps {
+        let mut rng = StdRng::seed_from_u64(self.rng_seed);
+        let start = (width > 0 && height > 0).then(|| {
+            HexCoord::new(
+                rng.random_range(0..width) as isize,
+                rng.random_range(0..height) as isize,
+            )
+        });
+
+        HexGenerationSteps::new(GrowingTree6Process {
+            rng,
+            selector: self.selector.clone(),
+            grid: Wall6Grid::new_wrapped(width, height, wrap),
+            visited: vec![false; width * height],
+            frontier: Vec::new(),
+            start,
+        })
+    }
+}
 
-        if width == 0 || height == 0 {
-            visitor.on_step(&HexGenerationStep::Complete);
-            return (grid, visitor.into_steps());
-        }
+/// The frontier of a [`GrowingTree6`], paused between two cell selections.
+struct GrowingTree6Process<S> {
+    rng: StdRng,
+    selector: S,
+    grid: Wall6Grid,
+    visited: Vec<bool>,
+    frontier: Vec<HexCoord>,
+    start: Option<HexCoord>,
+}
 
-        let mut visited = vec![false; width * height];
-        let mut frontier: Vec<HexCoord> = Vec::new();
-        let mut rng = StdRng::seed_from_u64(self.rng_seed);
+impl<S> GrowingTree6Process<S> {
+    fn index(&self, cell: HexCoord) -> usize {
+        (cell.r as usize) * self.grid.width() + cell.q as usize
+    }
+}
 
-        let start = HexCoord::new(
-            rng.random_range(0..width) as isize,
-            rng.random_range(0..height) as isize,
-        );
-
-        let start_idx = (start.r as usize) * width + start.q as usize;
-        visited[start_idx] = true;
-        visitor.on_step(&HexGenerationStep::Visit { cell: start });
-        frontier.push(start);
-        visitor.on_step(&HexGenerationStep::AddToFrontier { cell: start });
-
-        while !frontier.is_empty() {
-            let idx = self.selector.select(&mut rng, frontier.len());
-            let cell = frontier[idx];
-            let mut candidates = Self::unvisited_neighbors(&visited, cell, width, height);
-
-            if candidates.is_empty() {
-                frontier.swap_remove(idx);
-                continue;
-            }
+impl<S: CellSelector> GenerationProcess for GrowingTree6Process<S> {
+    type Grid = Wall6Grid;
+    type Step = HexGenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<HexGenerationStep>) -> bool {
+        if let Some(start) = self.start.take() {
+            let start_idx = self.index(start);
+            self.visited[start_idx] = true;
+            steps.push_back(HexGenerationStep::Visit { cell: start });
+            self.frontier.push(start);
+            steps.push_back(HexGenerationStep::AddToFrontier { cell: start });
+            return true;
+        }
 
-            candidates.shuffle(&mut rng);
-            let next = candidates[0];
-            grid.remove_wall_between(cell, next);
-            visitor.on_step(&HexGenerationStep::Carve {
-                from: cell,
-                to: next,
-            });
-            let next_idx = (next.r as usize) * width + next.q as usize;
-            visited[next_idx] = true;
-            visitor.on_step(&HexGenerationStep::Visit { cell: next });
-            frontier.push(next);
-            visitor.on_step(&HexGenerationStep::AddToFrontier { cell: next });
+        if self.frontier.is_empty() {
+            steps.push_back(HexGenerationStep::Complete);
+            return false;
         }
 
-        visitor.on_step(&HexGenerationStep::Complete);
-        (grid, visitor.into_steps())
+        let idx = self.selector.select(&mut self.rng, self.frontier.len());
+        let cell = self.frontier[idx];
+        let mut candidates: Vec<_> = self
+            .grid
+            .neighbors(cell)
+            .filter(|&n| !self.visited[self.index(n)])
+            .collect();
+
+        if candidates.is_empty() {
+            self.frontier.swap_remove(idx);
+            return true;
+        }
+
+        candidates.shuffle(&mut self.rng);
+        let next = candidates[0];
+        self.grid.remove_wall_between(cell, next);
+        steps.push_back(HexGenerationStep::Carve {
+            from: cell,
+            to: next,
+        });
+        let next_idx = self.index(next);
+        self.visited[next_idx] = true;
+        steps.push_back(HexGenerationStep::Visit { cell: next });
+        self.frontier.push(next);
+        steps.push_back(HexGenerationStep::AddToFrontier { cell: next });
+        true
     }
 
-    fn unvisited_neighbors(
-        visited: &[bool],
-        cell: HexCoord,
-        width: usize,
-        height: usize,
-    ) -> Vec<HexCoord> {
-        let mut result = Vec::with_capacity(6);
-        for &dir in &Direction6::CARDINALS {
-            if let Some(n) = cell.try_neighbor(dir, width, height) {
-                let idx = (n.r as usize) * width + n.q as usize;
-                if !visited[idx] {
-                    result.push(n);
-                }
-            }
-        }
-        result
+    fn grid(&self) -> &Wall6Grid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall6Grid {
+        self.grid
     }
 }
 
 impl<S> MazeGenerator6D for GrowingTree6<S>
 where
-    S: CellSelector + Default,
+    S: CellSelector + Default + Clone + 'static,
 {
     fn new_random() -> Self {
         Self {
@@ -156,3 +179,21 @@ where
         "Growing tree maze generator for hexagonal grids with configurable frontier selection"
     }
 }
+
+impl<S> WrappingMazeGenerator6D for GrowingTree6<S>
+where
+    S: CellSelector + Default + Clone + 'static,
+{
+    fn generate_wrapped(&self, width: usize, height: usize, wrap: WrapMode) -> Wall6Grid {
+        self.generate_wrapped_steps(width, height, wrap).finish()
+    }
+
+    fn generate_wrapped_steps(
+        &self,
+        width: usize,
+        height: usize,
+        wrap: WrapMode,
+    ) -> HexGenerationSteps {
+        self.steps(width, height, wrap)
+    }
+}
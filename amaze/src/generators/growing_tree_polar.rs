@@ -0,0 +1,167 @@
+use crate::generators::{
+    CellSelector, GenerationProcess, MazeGeneratorPolar, NewestCell, PolarGenerationStep,
+    PolarGenerationSteps,
+};
+use crate::maze_grid::MazeGrid;
+use crate::polar_grid::{PolarCoord, PolarGrid};
+use rand::prelude::SliceRandom;
+use rand::rngs::StdRng;
+use rand::{RngExt, SeedableRng};
+use std::collections::VecDeque;
+
+/// Growing tree algorithm for circular mazes.
+///
+/// See [`GrowingTree4`](crate::generators::GrowingTree4) for the rectangular counterpart.
+pub struct GrowingTreePolar<S: CellSelector = NewestCell> {
+    rng_seed: u64,
+    selector: S,
+}
+
+impl<S> GrowingTreePolar<S>
+where
+    S: CellSelector,
+{
+    pub fn with_selector(selector: S) -> Self {
+        Self {
+            rng_seed: rand::random(),
+            selector,
+        }
+    }
+
+    pub fn new_from_seed_with_selector(rng_seed: u64, selector: S) -> Self {
+        let rng_seed = if rng_seed == 0 {
+            rand::random()
+        } else {
+            rng_seed
+        };
+
+        Self { rng_seed, selector }
+    }
+}
+
+impl<S> GrowingTreePolar<S>
+where
+    S: CellSelector + Clone + 'static,
+{
+    pub fn generate(&self, rings: usize) -> PolarGrid {
+        self.generate_steps(rings).finish()
+    }
+
+    pub fn generate_steps(&self, rings: usize) -> PolarGenerationSteps {
+        let grid = PolarGrid::new(rings);
+        let mut rng = StdRng::seed_from_u64(self.rng_seed);
+        let start = (rings > 0).then(|| grid.delinearize(rng.random_range(0..grid.cell_count())));
+
+        PolarGenerationSteps::new(GrowingTreePolarProcess {
+            rng,
+            selector: self.selector.clone(),
+            visited: vec![false; grid.cell_count()],
+            grid,
+            frontier: Vec::new(),
+            start,
+        })
+    }
+}
+
+/// The frontier of a [`GrowingTreePolar`], paused between two cell selections.
+struct GrowingTreePolarProcess<S> {
+    rng: StdRng,
+    selector: S,
+    grid: PolarGrid,
+    visited: Vec<bool>,
+    frontier: Vec<PolarCoord>,
+    start: Option<PolarCoord>,
+}
+
+impl<S: CellSelector> GenerationProcess for GrowingTreePolarProcess<S> {
+    type Grid = PolarGrid;
+    type Step = PolarGenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<PolarGenerationStep>) -> bool {
+        if let Some(start) = self.start.take() {
+            self.visited[self.grid.linearize(start)] = true;
+            steps.push_back(PolarGenerationStep::Visit { cell: start });
+            self.frontier.push(start);
+            steps.push_back(PolarGenerationStep::AddToFrontier { cell: start });
+            return true;
+        }
+
+        if self.frontier.is_empty() {
+            steps.push_back(PolarGenerationStep::Complete);
+            return false;
+        }
+
+        let idx = self.selector.select(&mut self.rng, self.frontier.len());
+        let cell = self.frontier[idx];
+        let mut candidates: Vec<_> = self
+            .grid
+            .neighbors(cell)
+            .filter(|&n| !self.visited[self.grid.linearize(n)])
+            .collect();
+
+        if candidates.is_empty() {
+            self.frontier.swap_remove(idx);
+            return true;
+        }
+
+        candidates.shuffle(&mut self.rng);
+        let next = candidates[0];
+        self.grid.remove_wall_between(cell, next);
+        steps.push_back(PolarGenerationStep::Carve {
+            from: cell,
+            to: next,
+        });
+        self.visited[self.grid.linearize(next)] = true;
+        steps.push_back(PolarGenerationStep::Visit { cell: next });
+        self.frontier.push(next);
+        steps.push_back(PolarGenerationStep::AddToFrontier { cell: next });
+        true
+    }
+
+    fn grid(&self) -> &PolarGrid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> PolarGrid {
+        self.grid
+    }
+}
+
+impl<S> MazeGeneratorPolar for GrowingTreePolar<S>
+where
+    S: CellSelector + Default + Clone + 'static,
+{
+    fn new_random() -> Self {
+        Self {
+            rng_seed: rand::random(),
+            selector: S::default(),
+        }
+    }
+
+    fn new_from_seed(rng_seed: u64) -> Self {
+        if rng_seed == 0 {
+            Self::new_random()
+        } else {
+            Self {
+                rng_seed,
+                selector: S::default(),
+            }
+        }
+    }
+
+    fn generate(&self, rings: usize) -> PolarGrid {
+        self.generate(rings)
+    }
+
+    fn generate_steps(&self, rings: usize) -> PolarGenerationSteps {
+        self.generate_steps(rings)
+    }
+
+    fn name(&self) -> &'static str {
+        "polar-growing-tree"
+    }
+
+    fn description(&self) -> &'static str {
+        "Growing tree maze generator for circular mazes with configurable frontier selection"
+    }
+}
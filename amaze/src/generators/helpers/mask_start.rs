@@ -0,0 +1,60 @@
+use crate::grid_coord_2d::{GetCoordinateBounds2D, GridCoord2D};
+use crate::grid_mask::GridMask;
+use crate::visit_map_2d::VisitMap2D;
+use rand::{Rng, RngExt};
+
+/// Picks a random enabled cell of the mask to start growing a maze from.
+///
+/// The first draw is a uniformly random grid coordinate, so a full mask consumes
+/// the random number generator exactly like an unmasked generator would.
+/// If that cell is masked, a random enabled cell is chosen instead.
+pub fn random_start<R: Rng>(rng: &mut R, mask: &GridMask) -> Option<GridCoord2D> {
+    if mask.width() == 0 || mask.height() == 0 {
+        return None;
+    }
+
+    let start = GridCoord2D::new(
+        rng.random_range(0..mask.width()),
+        rng.random_range(0..mask.height()),
+    );
+    if mask.is_enabled(start) {
+        return Some(start);
+    }
+
+    let enabled: Vec<_> = mask.enabled_cells().collect();
+    if enabled.is_empty() {
+        None
+    } else {
+        Some(enabled[rng.random_range(0..enabled.len())])
+    }
+}
+
+/// Finds where to grow the next region of a mask once the previous ones are done.
+///
+/// The cells are scanned in row-major order, resuming where the last scan stopped,
+/// so an unmasked grid is not partitioned into regions up front. Since regions are
+/// grown whole, the first unvisited cell is the first cell of its region, just like
+/// the starts taken from [`GridMask::regions`].
+#[derive(Debug, Default)]
+pub struct RegionStarts {
+    next: usize,
+}
+
+impl RegionStarts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the first cell that is neither masked nor visited yet.
+    pub fn next_unvisited(&mut self, visited: &VisitMap2D) -> Option<GridCoord2D> {
+        let width = visited.width();
+        while self.next < width * visited.height() {
+            let cell = GridCoord2D::new(self.next % width, self.next / width);
+            if !visited[cell] {
+                return Some(cell);
+            }
+            self.next += 1;
+        }
+        None
+    }
+}
@@ -1 +1,2 @@
+pub mod mask_start;
 pub mod union_find;
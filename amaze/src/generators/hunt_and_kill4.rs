@@ -1,12 +1,11 @@
-use crate::generators::{
-    GenerationStep, GenerationSteps, GenerationVisitor, MazeGenerator2D, VecGenerationVisitor,
-};
+use crate::generators::{GenerationProcess, GenerationStep, GenerationSteps, MazeGenerator2D};
 use crate::grid_coord_2d::{GetCoordinateBounds2D, GridCoord2D};
 use crate::visit_map_2d::VisitMap2D;
 use crate::wall4_grid::Wall4Grid;
 use rand::prelude::SliceRandom;
 use rand::rngs::StdRng;
 use rand::{RngExt, SeedableRng};
+use std::collections::VecDeque;
 
 pub struct HuntAndKill4 {
     rng_seed: u64,
@@ -32,65 +31,92 @@ impl HuntAndKill4 {
         all
     }
 
-    fn generate_with_steps(&self, width: usize, height: usize) -> (Wall4Grid, Vec<GenerationStep>) {
-        let mut grid = Wall4Grid::new(width, height);
-        let mut visited = VisitMap2D::new_like(&grid);
-        let mut visitor = VecGenerationVisitor::default();
-        if width == 0 || height == 0 {
-            visitor.on_step(&GenerationStep::Complete);
-            return (grid, visitor.into_steps());
-        }
-
+    fn steps(&self, width: usize, height: usize) -> GenerationSteps {
         let mut rng = StdRng::seed_from_u64(self.rng_seed);
-        let mut current = Some(GridCoord2D::new(
-            rng.random_range(0..width),
-            rng.random_range(0..height),
-        ));
-
-        while let Some(cell) = current {
-            visited[cell] = true;
-            visitor.on_step(&GenerationStep::Visit { cell });
-
-            let mut unvisited = visited.unvisited_neighbors(cell);
-            if !unvisited.is_empty() {
-                unvisited.shuffle(&mut rng);
-                let next = unvisited[0];
-                grid.remove_wall_between(cell, next);
-                visitor.on_step(&GenerationStep::Carve {
-                    from: cell,
+        let current = (width > 0 && height > 0)
+            .then(|| GridCoord2D::new(rng.random_range(0..width), rng.random_range(0..height)));
+
+        GenerationSteps::new(HuntAndKill4Process {
+            rng,
+            grid: Wall4Grid::new(width, height),
+            visited: VisitMap2D::new(width, height),
+            current,
+        })
+    }
+}
+
+/// A [`HuntAndKill4`] maze, paused between two walks or hunts.
+struct HuntAndKill4Process {
+    rng: StdRng,
+    grid: Wall4Grid,
+    visited: VisitMap2D,
+    current: Option<GridCoord2D>,
+}
+
+impl HuntAndKill4Process {
+    /// Scans the grid for an unvisited cell next to the visited area and connects it.
+    fn hunt(&mut self, steps: &mut VecDeque<GenerationStep>) -> Option<GridCoord2D> {
+        for y in 0..self.grid.height() {
+            for x in 0..self.grid.width() {
+                let hunt = GridCoord2D::new(x, y);
+                if self.visited[hunt] {
+                    continue;
+                }
+                let mut neighbors = HuntAndKill4::visited_neighbors(&self.visited, hunt);
+                if neighbors.is_empty() {
+                    continue;
+                }
+                neighbors.shuffle(&mut self.rng);
+                let next = neighbors[0];
+                self.grid.remove_wall_between(hunt, next);
+                steps.push_back(GenerationStep::Carve {
+                    from: hunt,
                     to: next,
                 });
-                current = Some(next);
-                continue;
+                steps.push_back(GenerationStep::AddToFrontier { cell: hunt });
+                return Some(hunt);
             }
+        }
+        None
+    }
+}
 
-            current = None;
-            'hunt: for y in 0..height {
-                for x in 0..width {
-                    let hunt = GridCoord2D::new(x, y);
-                    if visited[hunt] {
-                        continue;
-                    }
-                    let mut neighbors = Self::visited_neighbors(&visited, hunt);
-                    if neighbors.is_empty() {
-                        continue;
-                    }
-                    neighbors.shuffle(&mut rng);
-                    let next = neighbors[0];
-                    grid.remove_wall_between(hunt, next);
-                    visitor.on_step(&GenerationStep::Carve {
-                        from: hunt,
-                        to: next,
-                    });
-                    current = Some(hunt);
-                    visitor.on_step(&GenerationStep::AddToFrontier { cell: hunt });
-                    break 'hunt;
-                }
-            }
+impl GenerationProcess for HuntAndKill4Process {
+    type Grid = Wall4Grid;
+    type Step = GenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<GenerationStep>) -> bool {
+        let Some(cell) = self.current else {
+            steps.push_back(GenerationStep::Complete);
+            return false;
+        };
+
+        self.visited[cell] = true;
+        steps.push_back(GenerationStep::Visit { cell });
+
+        let mut unvisited = self.visited.unvisited_neighbors(cell);
+        if unvisited.is_empty() {
+            self.current = self.hunt(steps);
+            return true;
         }
 
-        visitor.on_step(&GenerationStep::Complete);
-        (grid, visitor.into_steps())
+        unvisited.shuffle(&mut self.rng);
+        let next = unvisited[0];
+        self.grid.remove_wall_between(cell, next);
+        steps.push_back(GenerationStep::Carve {
+            from: cell,
+            to: next,
+        });
+        self.current = Some(next);
+        true
+    }
+
+    fn grid(&self) -> &Wall4Grid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall4Grid {
+        self.grid
     }
 }
 
@@ -110,11 +136,11 @@ impl MazeGenerator2D for HuntAndKill4 {
     }
 
     fn generate(&self, width: usize, height: usize) -> Wall4Grid {
-        self.generate_with_steps(width, height).0
+        self.generate_steps(width, height).finish()
     }
 
     fn generate_steps(&self, width: usize, height: usize) -> GenerationSteps {
-        GenerationSteps::new(self.generate_with_steps(width, height).1)
+        self.steps(width, height)
     }
 
     fn name(&self) -> &'static str {
@@ -0,0 +1,135 @@
+use crate::generators::helpers::union_find::UnionFind;
+use crate::generators::{
+    GenerationProcess, MazeGeneratorTri, TriGenerationStep, TriGenerationSteps,
+};
+use crate::tri_coord::TriCoord;
+use crate::wall3_grid::Wall3Grid;
+use rand::SeedableRng;
+use rand::prelude::SliceRandom;
+use rand::rngs::StdRng;
+use std::collections::VecDeque;
+
+/// Randomized Kruskal algorithm for triangular grids.
+///
+/// See [`Kruskal4`](crate::generators::Kruskal4) for the rectangular counterpart.
+pub struct Kruskal3 {
+    rng_seed: u64,
+}
+
+impl Default for Kruskal3 {
+    fn default() -> Self {
+        Self::new_random()
+    }
+}
+
+impl Kruskal3 {
+    pub fn new_random() -> Self {
+        Self {
+            rng_seed: rand::random(),
+        }
+    }
+
+    pub fn new_from_seed(rng_seed: u64) -> Self {
+        if rng_seed == 0 {
+            Self::new_random()
+        } else {
+            Self { rng_seed }
+        }
+    }
+
+    pub fn generate(&self, width: usize, height: usize) -> Wall3Grid {
+        self.generate_steps(width, height).finish()
+    }
+
+    pub fn generate_steps(&self, width: usize, height: usize) -> TriGenerationSteps {
+        let grid = Wall3Grid::new(width, height);
+        let index = |c: TriCoord| c.y * width + c.x;
+
+        // Each edge is added once, from the cell with the lower index.
+        let mut edges = Vec::new();
+        for cell in grid.coords() {
+            for neighbor in grid.neighbors(cell) {
+                if index(neighbor) > index(cell) {
+                    edges.push((cell, neighbor));
+                }
+            }
+        }
+
+        let mut rng = StdRng::seed_from_u64(self.rng_seed);
+        edges.shuffle(&mut rng);
+
+        TriGenerationSteps::new(Kruskal3Process {
+            unvisited: grid.coords().collect::<Vec<_>>().into_iter(),
+            grid,
+            edges: edges.into_iter(),
+            uf: UnionFind::new(width * height),
+        })
+    }
+}
+
+/// The shuffled edges of a [`Kruskal3`] maze, paused between two carved passages.
+struct Kruskal3Process {
+    grid: Wall3Grid,
+    unvisited: std::vec::IntoIter<TriCoord>,
+    edges: std::vec::IntoIter<(TriCoord, TriCoord)>,
+    uf: UnionFind,
+}
+
+impl GenerationProcess for Kruskal3Process {
+    type Grid = Wall3Grid;
+    type Step = TriGenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<TriGenerationStep>) -> bool {
+        if let Some(cell) = self.unvisited.next() {
+            steps.push_back(TriGenerationStep::Visit { cell });
+            return true;
+        }
+
+        let width = self.grid.width();
+        let index = |c: TriCoord| c.y * width + c.x;
+        for (a, b) in self.edges.by_ref() {
+            if self.uf.union(index(a), index(b)) {
+                self.grid.remove_wall_between(a, b);
+                steps.push_back(TriGenerationStep::Carve { from: a, to: b });
+                return true;
+            }
+        }
+
+        steps.push_back(TriGenerationStep::Complete);
+        false
+    }
+
+    fn grid(&self) -> &Wall3Grid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall3Grid {
+        self.grid
+    }
+}
+
+impl MazeGeneratorTri for Kruskal3 {
+    fn new_random() -> Self {
+        Kruskal3::new_random()
+    }
+
+    fn new_from_seed(rng_seed: u64) -> Self {
+        Kruskal3::new_from_seed(rng_seed)
+    }
+
+    fn generate(&self, width: usize, height: usize) -> Wall3Grid {
+        self.generate(width, height)
+    }
+
+    fn generate_steps(&self, width: usize, height: usize) -> TriGenerationSteps {
+        self.generate_steps(width, height)
+    }
+
+    fn name(&self) -> &'static str {
+        "tri-kruskal"
+    }
+
+    fn description(&self) -> &'static str {
+        "Randomized Kruskal algorithm for triangular grids using union-find"
+    }
+}
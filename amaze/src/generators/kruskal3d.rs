@@ -0,0 +1,142 @@
+use crate::generators::helpers::union_find::UnionFind;
+use crate::generators::{GenerationProcess, GenerationStep3D, GenerationSteps3D, MazeGenerator3D};
+use crate::grid_coord_3d::GridCoord3D;
+use crate::maze_grid::MazeGrid;
+use crate::wall3d_grid::Wall3DGrid;
+use rand::SeedableRng;
+use rand::prelude::SliceRandom;
+use rand::rngs::StdRng;
+use std::collections::VecDeque;
+
+/// Randomized Kruskal algorithm for layered 3D grids.
+///
+/// See [`Kruskal4`](crate::generators::Kruskal4) for the single-layer counterpart.
+///
+/// ## Example
+/// ```
+/// use amaze::generators::Kruskal3D;
+///
+/// let grid = Kruskal3D::new_from_seed(42).generate(5, 5, 4);
+/// assert_eq!(grid.stats().loops, 0);
+/// ```
+pub struct Kruskal3D {
+    rng_seed: u64,
+}
+
+impl Default for Kruskal3D {
+    fn default() -> Self {
+        Self::new_random()
+    }
+}
+
+impl Kruskal3D {
+    pub fn new_random() -> Self {
+        Self {
+            rng_seed: rand::random(),
+        }
+    }
+
+    pub fn new_from_seed(rng_seed: u64) -> Self {
+        if rng_seed == 0 {
+            Self::new_random()
+        } else {
+            Self { rng_seed }
+        }
+    }
+
+    pub fn generate(&self, width: usize, height: usize, depth: usize) -> Wall3DGrid {
+        self.generate_steps(width, height, depth).finish()
+    }
+
+    pub fn generate_steps(&self, width: usize, height: usize, depth: usize) -> GenerationSteps3D {
+        let grid = Wall3DGrid::new(width, height, depth);
+
+        // Each edge is added once, from the cell with the lower index.
+        let mut edges = Vec::new();
+        for cell in grid.coords() {
+            for neighbor in grid.neighbors(cell) {
+                if grid.linearize(neighbor) > grid.linearize(cell) {
+                    edges.push((cell, neighbor));
+                }
+            }
+        }
+
+        let mut rng = StdRng::seed_from_u64(self.rng_seed);
+        edges.shuffle(&mut rng);
+
+        GenerationSteps3D::new(Kruskal3DProcess {
+            unvisited: grid.coords().collect::<Vec<_>>().into_iter(),
+            edges: edges.into_iter(),
+            uf: UnionFind::new(grid.cell_count()),
+            grid,
+        })
+    }
+}
+
+/// The shuffled edges of a [`Kruskal3D`] maze, paused between two carved passages.
+struct Kruskal3DProcess {
+    grid: Wall3DGrid,
+    unvisited: std::vec::IntoIter<GridCoord3D>,
+    edges: std::vec::IntoIter<(GridCoord3D, GridCoord3D)>,
+    uf: UnionFind,
+}
+
+impl GenerationProcess for Kruskal3DProcess {
+    type Grid = Wall3DGrid;
+    type Step = GenerationStep3D;
+
+    fn advance(&mut self, steps: &mut VecDeque<GenerationStep3D>) -> bool {
+        if let Some(cell) = self.unvisited.next() {
+            steps.push_back(GenerationStep3D::Visit { cell });
+            return true;
+        }
+
+        for (a, b) in self.edges.by_ref() {
+            if self
+                .uf
+                .union(self.grid.linearize(a), self.grid.linearize(b))
+            {
+                self.grid.remove_wall_between(a, b);
+                steps.push_back(GenerationStep3D::Carve { from: a, to: b });
+                return true;
+            }
+        }
+
+        steps.push_back(GenerationStep3D::Complete);
+        false
+    }
+
+    fn grid(&self) -> &Wall3DGrid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall3DGrid {
+        self.grid
+    }
+}
+
+impl MazeGenerator3D for Kruskal3D {
+    fn new_random() -> Self {
+        Kruskal3D::new_random()
+    }
+
+    fn new_from_seed(rng_seed: u64) -> Self {
+        Kruskal3D::new_from_seed(rng_seed)
+    }
+
+    fn generate(&self, width: usize, height: usize, depth: usize) -> Wall3DGrid {
+        self.generate(width, height, depth)
+    }
+
+    fn generate_steps(&self, width: usize, height: usize, depth: usize) -> GenerationSteps3D {
+        self.generate_steps(width, height, depth)
+    }
+
+    fn name(&self) -> &'static str {
+        "3d-kruskal"
+    }
+
+    fn description(&self) -> &'static str {
+        "Randomized Kruskal algorithm for layered 3D grids using union-find"
+    }
+}
@@ -1,12 +1,17 @@
+use crate::direction4::Direction4;
 use crate::generators::helpers::union_find::UnionFind;
 use crate::generators::{
-    GenerationStep, GenerationSteps, GenerationVisitor, MazeGenerator2D, VecGenerationVisitor,
+    GenerationProcess, GenerationStep, GenerationSteps, MaskedMazeGenerator2D, MazeGenerator2D,
+    WrappingMazeGenerator2D,
 };
 use crate::grid_coord_2d::{GridCoord2D, LinearizeCoords2D};
+use crate::grid_mask::GridMask;
 use crate::wall4_grid::Wall4Grid;
+use crate::wrap_mode::WrapMode;
 use rand::SeedableRng;
 use rand::prelude::SliceRandom;
 use rand::rngs::StdRng;
+use std::collections::VecDeque;
 
 pub struct Kruskal4 {
     rng_seed: u64,
@@ -19,27 +24,16 @@ impl Default for Kruskal4 {
 }
 
 impl Kruskal4 {
-    fn generate_with_steps(&self, width: usize, height: usize) -> (Wall4Grid, Vec<GenerationStep>) {
-        let mut grid = Wall4Grid::new(width, height);
-        let mut visitor = VecGenerationVisitor::default();
-        if width == 0 || height == 0 {
-            visitor.on_step(&GenerationStep::Complete);
-            return (grid, visitor.into_steps());
-        }
-
-        for cell in grid.coords() {
-            visitor.on_step(&GenerationStep::Visit { cell });
-        }
+    fn steps(&self, mask: &GridMask, wrap: WrapMode) -> GenerationSteps {
+        let (width, height) = (mask.width(), mask.height());
+        let grid = Wall4Grid::new_wrapped(width, height, wrap);
 
+        // Only edges between two enabled cells are candidates for carving.
         let mut edges = Vec::new();
-        for y in 0..height {
-            for x in 0..width {
-                let cell = GridCoord2D::new(x, y);
-                if x + 1 < width {
-                    edges.push((cell, GridCoord2D::new(x + 1, y)));
-                }
-                if y + 1 < height {
-                    edges.push((cell, GridCoord2D::new(x, y + 1)));
+        for cell in mask.enabled_cells() {
+            for dir in [Direction4::EAST, Direction4::SOUTH] {
+                if let Some(other) = grid.neighbor(cell, dir).filter(|&n| mask.is_enabled(n)) {
+                    edges.push((cell, other));
                 }
             }
         }
@@ -47,18 +41,53 @@ impl Kruskal4 {
         let mut rng = StdRng::seed_from_u64(self.rng_seed);
         edges.shuffle(&mut rng);
 
-        let mut uf = UnionFind::new(width * height);
-        for (a, b) in edges {
-            let ia = grid.linearize_coords(a);
-            let ib = grid.linearize_coords(b);
-            if uf.union(ia, ib) {
-                grid.remove_wall_between(a, b);
-                visitor.on_step(&GenerationStep::Carve { from: a, to: b });
+        GenerationSteps::new(Kruskal4Process {
+            grid,
+            unvisited: mask.enabled_cells().collect::<Vec<_>>().into_iter(),
+            edges: edges.into_iter(),
+            uf: UnionFind::new(width * height),
+        })
+    }
+}
+
+/// The shuffled edges of a [`Kruskal4`] maze, paused between two carved passages.
+struct Kruskal4Process {
+    grid: Wall4Grid,
+    unvisited: std::vec::IntoIter<GridCoord2D>,
+    edges: std::vec::IntoIter<(GridCoord2D, GridCoord2D)>,
+    uf: UnionFind,
+}
+
+impl GenerationProcess for Kruskal4Process {
+    type Grid = Wall4Grid;
+    type Step = GenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<GenerationStep>) -> bool {
+        if let Some(cell) = self.unvisited.next() {
+            steps.push_back(GenerationStep::Visit { cell });
+            return true;
+        }
+
+        for (a, b) in self.edges.by_ref() {
+            let ia = self.grid.linearize_coords(a);
+            let ib = self.grid.linearize_coords(b);
+            if self.uf.union(ia, ib) {
+                self.grid.remove_wall_between(a, b);
+                steps.push_back(GenerationStep::Carve { from: a, to: b });
+                return true;
             }
         }
 
-        visitor.on_step(&GenerationStep::Complete);
-        (grid, visitor.into_steps())
+        steps.push_back(GenerationStep::Complete);
+        false
+    }
+
+    fn grid(&self) -> &Wall4Grid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall4Grid {
+        self.grid
     }
 }
 
@@ -78,11 +107,11 @@ impl MazeGenerator2D for Kruskal4 {
     }
 
     fn generate(&self, width: usize, height: usize) -> Wall4Grid {
-        self.generate_with_steps(width, height).0
+        self.generate_steps(width, height).finish()
     }
 
     fn generate_steps(&self, width: usize, height: usize) -> GenerationSteps {
-        GenerationSteps::new(self.generate_with_steps(width, height).1)
+        self.steps(&GridMask::new(width, height), WrapMode::None)
     }
 
     fn name(&self) -> &'static str {
@@ -93,3 +122,28 @@ impl MazeGenerator2D for Kruskal4 {
         "Randomized Kruskal algorithm using union-find"
     }
 }
+
+impl MaskedMazeGenerator2D for Kruskal4 {
+    fn generate_masked(&self, mask: &GridMask) -> Wall4Grid {
+        self.generate_masked_steps(mask).finish()
+    }
+
+    fn generate_masked_steps(&self, mask: &GridMask) -> GenerationSteps {
+        self.steps(mask, WrapMode::None)
+    }
+}
+
+impl WrappingMazeGenerator2D for Kruskal4 {
+    fn generate_wrapped(&self, width: usize, height: usize, wrap: WrapMode) -> Wall4Grid {
+        self.generate_wrapped_steps(width, height, wrap).finish()
+    }
+
+    fn generate_wrapped_steps(
+        &self,
+        width: usize,
+        height: usize,
+        wrap: WrapMode,
+    ) -> GenerationSteps {
+        self.steps(&GridMask::new(width, height), wrap)
+    }
+}
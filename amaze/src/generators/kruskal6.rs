@@ -0,0 +1,156 @@
+use crate::generators::helpers::union_find::UnionFind;
+use crate::generators::{
+    GenerationProcess, HexGenerationStep, HexGenerationSteps, MazeGenerator6D,
+    WrappingMazeGenerator6D,
+};
+use crate::hex_coord::HexCoord;
+use crate::wall6_grid::Wall6Grid;
+use crate::wrap_mode::WrapMode;
+use rand::SeedableRng;
+use rand::prelude::SliceRandom;
+use rand::rngs::StdRng;
+use std::collections::VecDeque;
+
+/// Randomized Kruskal algorithm for hexagonal grids.
+///
+/// See [`Kruskal4`](crate::generators::Kruskal4) for the rectangular counterpart.
+pub struct Kruskal6 {
+    rng_seed: u64,
+}
+
+impl Default for Kruskal6 {
+    fn default() -> Self {
+        Self::new_random()
+    }
+}
+
+impl Kruskal6 {
+    pub fn new_random() -> Self {
+        Self {
+            rng_seed: rand::random(),
+        }
+    }
+
+    pub fn new_from_seed(rng_seed: u64) -> Self {
+        if rng_seed == 0 {
+            Self::new_random()
+        } else {
+            Self { rng_seed }
+        }
+    }
+
+    pub fn generate(&self, width: usize, height: usize) -> Wall6Grid {
+        self.generate_steps(width, height).finish()
+    }
+
+    pub fn generate_steps(&self, width: usize, height: usize) -> HexGenerationSteps {
+        self.steps(width, height, WrapMode::None)
+    }
+
+    fn steps(&self, width: usize, height: usize, wrap: WrapMode) -> HexGenerationSteps {
+        let grid = Wall6Grid::new_wrapped(width, height, wrap);
+        let index = |c: HexCoord| (c.r as usize) * width + c.q as usize;
+
+        // Each edge is added once, from the cell with the lower index.
+        let mut edges = Vec::new();
+        for cell in grid.coords() {
+            for neighbor in grid.neighbors(cell) {
+                if index(neighbor) > index(cell) {
+                    edges.push((cell, neighbor));
+                }
+            }
+        }
+
+        let mut rng = StdRng::seed_from_u64(self.rng_seed);
+        edges.shuffle(&mut rng);
+
+        HexGenerationSteps::new(Kruskal6Process {
+            unvisited: grid.coords().collect::<Vec<_>>().into_iter(),
+            grid,
+            edges: edges.into_iter(),
+            uf: UnionFind::new(width * height),
+        })
+    }
+}
+
+/// The shuffled edges of a [`Kruskal6`] maze, paused between two carved passages.
+struct Kruskal6Process {
+    grid: Wall6Grid,
+    unvisited: std::vec::IntoIter<HexCoord>,
+    edges: std::vec::IntoIter<(HexCoord, HexCoord)>,
+    uf: UnionFind,
+}
+
+impl GenerationProcess for Kruskal6Process {
+    type Grid = Wall6Grid;
+    type Step = HexGenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<HexGenerationStep>) -> bool {
+        if let Some(cell) = self.unvisited.next() {
+            steps.push_back(HexGenerationStep::Visit { cell });
+            return true;
+        }
+
+        let width = self.grid.width();
+        let index = |c: HexCoord| (c.r as usize) * width + c.q as usize;
+        for (a, b) in self.edges.by_ref() {
+            if self.uf.union(index(a), index(b)) {
+                self.grid.remove_wall_between(a, b);
+                steps.push_back(HexGenerationStep::Carve { from: a, to: b });
+                return true;
+            }
+        }
+
+        steps.push_back(HexGenerationStep::Complete);
+        false
+    }
+
+    fn grid(&self) -> &Wall6Grid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall6Grid {
+        self.grid
+    }
+}
+
+impl MazeGenerator6D for Kruskal6 {
+    fn new_random() -> Self {
+        Kruskal6::new_random()
+    }
+
+    fn new_from_seed(rng_seed: u64) -> Self {
+        Kruskal6::new_from_seed(rng_seed)
+    }
+
+    fn generate(&self, width: usize, height: usize) -> Wall6Grid {
+        self.generate(width, height)
+    }
+
+    fn generate_steps(&self, width: usize, height: usize) -> HexGenerationSteps {
+        self.generate_steps(width, height)
+    }
+
+    fn name(&self) -> &'static str {
+        "hex-kruskal"
+    }
+
+    fn description(&self) -> &'static str {
+        "Randomized Kruskal algorithm for hexagonal grids using union-find"
+    }
+}
+
+impl WrappingMazeGenerator6D for Kruskal6 {
+    fn generate_wrapped(&self, width: usize, height: usize, wrap: WrapMode) -> Wall6Grid {
+        self.generate_wrapped_steps(width, height, wrap).finish()
+    }
+
+    fn generate_wrapped_steps(
+        &self,
+        width: usize,
+        height: usize,
+        wrap: WrapMode,
+    ) -> HexGenerationSteps {
+        self.steps(width, height, wrap)
+    }
+}
@@ -1,12 +1,17 @@
+use crate::generators::helpers::mask_start::{RegionStarts, random_start};
 use crate::generators::{
-    GenerationStep, GenerationSteps, GenerationVisitor, MazeGenerator2D, VecGenerationVisitor,
+    GenerationProcess, GenerationStep, GenerationSteps, MaskedMazeGenerator2D, MazeGenerator2D,
+    WrappingMazeGenerator2D,
 };
 use crate::grid_coord_2d::GridCoord2D;
+use crate::grid_mask::GridMask;
 use crate::visit_map_2d::VisitMap2D;
 use crate::wall4_grid::Wall4Grid;
+use crate::wrap_mode::WrapMode;
 use rand::RngExt;
 use rand::SeedableRng;
 use rand::rngs::StdRng;
+use std::collections::VecDeque;
 
 pub struct Prim4 {
     rng_seed: u64,
@@ -34,85 +39,103 @@ impl Prim4 {
     }
 
     pub fn generate(&self, width: usize, height: usize) -> Wall4Grid {
-        self.generate_with_steps(width, height).0
+        self.generate_steps(width, height).finish()
     }
 
     pub fn generate_steps(&self, width: usize, height: usize) -> GenerationSteps {
-        GenerationSteps::new(self.generate_with_steps(width, height).1)
+        self.steps(&GridMask::new(width, height), WrapMode::None)
     }
 
-    fn generate_with_steps(&self, width: usize, height: usize) -> (Wall4Grid, Vec<GenerationStep>) {
-        let mut cells = Wall4Grid::new(width, height);
-        let mut visit_map = VisitMap2D::new_like(&cells);
-        let mut visitor = VecGenerationVisitor::default();
+    /// Generates a maze restricted to the enabled cells of the mask.
+    pub fn generate_masked(&self, mask: &GridMask) -> Wall4Grid {
+        self.generate_masked_steps(mask).finish()
+    }
 
-        if width == 0 || height == 0 {
-            visitor.on_step(&GenerationStep::Complete);
-            return (cells, visitor.into_steps());
-        }
+    pub fn generate_masked_steps(&self, mask: &GridMask) -> GenerationSteps {
+        self.steps(mask, WrapMode::None)
+    }
 
+    fn steps(&self, mask: &GridMask, wrap: WrapMode) -> GenerationSteps {
         let mut rng = StdRng::seed_from_u64(self.rng_seed);
-        let start = GridCoord2D::new(rng.random_range(0..width), rng.random_range(0..height));
-
-        visit_map[start] = true;
-        visitor.on_step(&GenerationStep::Visit { cell: start });
-
-        let mut frontier: Vec<(GridCoord2D, GridCoord2D)> = Vec::new();
-        Self::add_frontier_walls(
-            &mut frontier,
-            &visit_map,
-            start,
-            width,
-            height,
-            &mut visitor,
-        );
-
-        while !frontier.is_empty() {
-            let idx = rng.random_range(0..frontier.len());
-            let (from_cell, to_cell) = frontier.swap_remove(idx);
-
-            if visit_map[to_cell] {
-                continue;
-            }
+        let next_start = random_start(&mut rng, mask);
+
+        GenerationSteps::new(Prim4Process {
+            rng,
+            cells: Wall4Grid::new_wrapped(mask.width(), mask.height(), wrap),
+            visit_map: VisitMap2D::new_masked(mask),
+            frontier: Vec::new(),
+            regions: RegionStarts::new(),
+            next_start,
+        })
+    }
+}
 
-            visit_map[to_cell] = true;
-            cells.remove_wall_between(from_cell, to_cell);
-
-            visitor.on_step(&GenerationStep::Visit { cell: to_cell });
-            visitor.on_step(&GenerationStep::Carve {
-                from: from_cell,
-                to: to_cell,
-            });
-
-            Self::add_frontier_walls(
-                &mut frontier,
-                &visit_map,
-                to_cell,
-                width,
-                height,
-                &mut visitor,
-            );
-        }
+/// The frontier walls of a [`Prim4`] maze, paused between two removals.
+struct Prim4Process {
+    rng: StdRng,
+    cells: Wall4Grid,
+    visit_map: VisitMap2D,
+    frontier: Vec<(GridCoord2D, GridCoord2D)>,
+    regions: RegionStarts,
+    next_start: Option<GridCoord2D>,
+}
 
-        visitor.on_step(&GenerationStep::Complete);
-        (cells, visitor.into_steps())
+impl Prim4Process {
+    fn add_frontier_walls(&mut self, cell: GridCoord2D, steps: &mut VecDeque<GenerationStep>) {
+        for neighbor in self.cells.neighbors(cell) {
+            if !self.visit_map[neighbor] {
+                self.frontier.push((cell, neighbor));
+                steps.push_back(GenerationStep::AddToFrontier { cell: neighbor });
+            }
+        }
     }
+}
 
-    fn add_frontier_walls<V: GenerationVisitor>(
-        frontier: &mut Vec<(GridCoord2D, GridCoord2D)>,
-        visit_map: &VisitMap2D,
-        cell: GridCoord2D,
-        width: usize,
-        height: usize,
-        visitor: &mut V,
-    ) {
-        let neighbors = [cell.up(), cell.right(), cell.down(), cell.left()];
-        for neighbor in neighbors.into_iter().flatten() {
-            if neighbor.x < width && neighbor.y < height && !visit_map[neighbor] {
-                frontier.push((cell, neighbor));
-                visitor.on_step(&GenerationStep::AddToFrontier { cell: neighbor });
+impl GenerationProcess for Prim4Process {
+    type Grid = Wall4Grid;
+    type Step = GenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<GenerationStep>) -> bool {
+        if self.frontier.is_empty() {
+            let Some(start) = self.next_start.take() else {
+                steps.push_back(GenerationStep::Complete);
+                return false;
+            };
+
+            self.visit_map[start] = true;
+            steps.push_back(GenerationStep::Visit { cell: start });
+            self.add_frontier_walls(start, steps);
+        } else {
+            let idx = self.rng.random_range(0..self.frontier.len());
+            let (from_cell, to_cell) = self.frontier.swap_remove(idx);
+
+            if !self.visit_map[to_cell] {
+                self.visit_map[to_cell] = true;
+                self.cells.remove_wall_between(from_cell, to_cell);
+
+                steps.push_back(GenerationStep::Visit { cell: to_cell });
+                steps.push_back(GenerationStep::Carve {
+                    from: from_cell,
+                    to: to_cell,
+                });
+
+                self.add_frontier_walls(to_cell, steps);
             }
         }
+
+        // Regions of the mask not reachable from the start are grown from their first cell.
+        if self.frontier.is_empty() {
+            self.next_start = self.regions.next_unvisited(&self.visit_map);
+        }
+        true
+    }
+
+    fn grid(&self) -> &Wall4Grid {
+        &self.cells
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall4Grid {
+        self.cells
     }
 }
 
@@ -141,3 +164,28 @@ impl MazeGenerator2D for Prim4 {
         "Randomized Prim's algorithm producing mazes with many short corridors and branches"
     }
 }
+
+impl MaskedMazeGenerator2D for Prim4 {
+    fn generate_masked(&self, mask: &GridMask) -> Wall4Grid {
+        self.generate_masked(mask)
+    }
+
+    fn generate_masked_steps(&self, mask: &GridMask) -> GenerationSteps {
+        self.generate_masked_steps(mask)
+    }
+}
+
+impl WrappingMazeGenerator2D for Prim4 {
+    fn generate_wrapped(&self, width: usize, height: usize, wrap: WrapMode) -> Wall4Grid {
+        self.generate_wrapped_steps(width, height, wrap).finish()
+    }
+
+    fn generate_wrapped_steps(
+        &self,
+        width: usize,
+        height: usize,
+        wrap: WrapMode,
+    ) -> GenerationSteps {
+        self.steps(&GridMask::new(width, height), wrap)
+    }
+}
@@ -0,0 +1,180 @@
+use crate::generators::{
+    GenerationProcess, HexGenerationStep, HexGenerationSteps, MazeGenerator6D,
+    WrappingMazeGenerator6D,
+};
+use crate::hex_coord::HexCoord;
+use crate::wall6_grid::Wall6Grid;
+use crate::wrap_mode::WrapMode;
+use rand::RngExt;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::collections::VecDeque;
+
+/// Randomized Prim's algorithm for hexagonal grids.
+///
+/// See [`Prim4`](crate::generators::Prim4) for the rectangular counterpart.
+pub struct Prim6 {
+    rng_seed: u64,
+}
+
+impl Default for Prim6 {
+    fn default() -> Self {
+        Self::new_random()
+    }
+}
+
+impl Prim6 {
+    pub fn new_random() -> Self {
+        Self {
+            rng_seed: rand::random(),
+        }
+    }
+
+    pub fn new_from_seed(rng_seed: u64) -> Self {
+        if rng_seed == 0 {
+            Self::new_random()
+        } else {
+            Self { rng_seed }
+        }
+    }
+
+    pub fn generate(&self, width: usize, height: usize) -> Wall6Grid {
+        self.generate_steps(width, height).finish()
+    }
+
+    pub fn generate_steps(&self, width: usize, height: usize) -> HexGenerationSteps {
+        self.steps(width, height, WrapMode::None)
+    }
+
+    fn steps(&self, width: usize, height: usize, wrap: WrapMode) -> HexGenerationSteps {
+        let mut rng = StdRng::seed_from_u64(self.rng_seed);
+        let start = (width > 0 && height > 0).then(|| {
+            HexCoord::new(
+                rng.random_range(0..width) as isize,
+                rng.random_range(0..height) as isize,
+            )
+        });
+
+        HexGenerationSteps::new(Prim6Process {
+            rng,
+            cells: Wall6Grid::new_wrapped(width, height, wrap),
+            visit_map: vec![false; width * height],
+            frontier: Vec::new(),
+            start,
+        })
+    }
+}
+
+/// The frontier walls of a [`Prim6`] maze, paused between two carved passages.
+struct Prim6Process {
+    rng: StdRng,
+    cells: Wall6Grid,
+    visit_map: Vec<bool>,
+    frontier: Vec<(HexCoord, HexCoord)>,
+    start: Option<HexCoord>,
+}
+
+impl Prim6Process {
+    fn add_frontier_walls(&mut self, cell: HexCoord, steps: &mut VecDeque<HexGenerationStep>) {
+        for neighbor in self.cells.neighbors(cell) {
+            if !self.visit_map[self.index(neighbor)] {
+                self.frontier.push((cell, neighbor));
+                steps.push_back(HexGenerationStep::AddToFrontier { cell: neighbor });
+            }
+        }
+    }
+
+    #[inline]
+    fn index(&self, cell: HexCoord) -> usize {
+        (cell.r as usize) * self.cells.width() + cell.q as usize
+    }
+}
+
+impl GenerationProcess for Prim6Process {
+    type Grid = Wall6Grid;
+    type Step = HexGenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<HexGenerationStep>) -> bool {
+        if let Some(start) = self.start.take() {
+            let idx = self.index(start);
+            self.visit_map[idx] = true;
+            steps.push_back(HexGenerationStep::Visit { cell: start });
+            self.add_frontier_walls(start, steps);
+            return true;
+        }
+
+        if self.frontier.is_empty() {
+            steps.push_back(HexGenerationStep::Complete);
+            return false;
+        }
+
+        let idx = self.rng.random_range(0..self.frontier.len());
+        let (from_cell, to_cell) = self.frontier.swap_remove(idx);
+
+        let to_idx = self.index(to_cell);
+        if self.visit_map[to_idx] {
+            return true;
+        }
+
+        self.visit_map[to_idx] = true;
+        self.cells.remove_wall_between(from_cell, to_cell);
+
+        steps.push_back(HexGenerationStep::Visit { cell: to_cell });
+        steps.push_back(HexGenerationStep::Carve {
+            from: from_cell,
+            to: to_cell,
+        });
+
+        self.add_frontier_walls(to_cell, steps);
+        true
+    }
+
+    fn grid(&self) -> &Wall6Grid {
+        &self.cells
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall6Grid {
+        self.cells
+    }
+}
+
+impl MazeGenerator6D for Prim6 {
+    fn new_random() -> Self {
+        Prim6::new_random()
+    }
+
+    fn new_from_seed(rng_seed: u64) -> Self {
+        Prim6::new_from_seed(rng_seed)
+    }
+
+    fn generate(&self, width: usize, height: usize) -> Wall6Grid {
+        self.generate(width, height)
+    }
+
+    fn generate_steps(&self, width: usize, height: usize) -> HexGenerationSteps {
+        self.generate_steps(width, height)
+    }
+
+    fn name(&self) -> &'static str {
+        "hex-prim"
+    }
+
+    fn description(&self) -> &'static str {
+        "Randomized Prim's algorithm for hexagonal grids"
+    }
+}
+
+impl WrappingMazeGenerator6D for Prim6 {
+    fn generate_wrapped(&self, width: usize, height: usize, wrap: WrapMode) -> Wall6Grid {
+        self.generate_wrapped_steps(width, height, wrap).finish()
+    }
+
+    fn generate_wrapped_steps(
+        &self,
+        width: usize,
+        height: usize,
+        wrap: WrapMode,
+    ) -> HexGenerationSteps {
+        self.steps(width, height, wrap)
+    }
+}
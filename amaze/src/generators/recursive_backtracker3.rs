@@ -0,0 +1,147 @@
+use crate::generators::{
+    GenerationProcess, MazeGeneratorTri, TriGenerationStep, TriGenerationSteps,
+};
+use crate::tri_coord::TriCoord;
+use crate::wall3_grid::Wall3Grid;
+use rand::SeedableRng;
+use rand::prelude::IndexedRandom;
+use rand::rngs::StdRng;
+use std::collections::VecDeque;
+
+/// Recursive backtracker (randomized depth-first search) for triangular grids.
+///
+/// See [`RecursiveBacktracker4`](crate::generators::RecursiveBacktracker4) for the
+/// rectangular counterpart.
+///
+/// ## Example
+/// ```
+/// use amaze::generators::RecursiveBacktracker3;
+///
+/// let grid = RecursiveBacktracker3::new_from_seed(42).generate(12, 6);
+/// assert_eq!(grid.stats().loops, 0);
+/// ```
+pub struct RecursiveBacktracker3 {
+    rng_seed: u64,
+}
+
+impl Default for RecursiveBacktracker3 {
+    fn default() -> Self {
+        Self::new_random()
+    }
+}
+
+impl RecursiveBacktracker3 {
+    pub fn new_random() -> Self {
+        Self {
+            rng_seed: rand::random(),
+        }
+    }
+
+    pub fn new_from_seed(rng_seed: u64) -> Self {
+        if rng_seed == 0 {
+            Self::new_random()
+        } else {
+            Self { rng_seed }
+        }
+    }
+
+    pub fn generate(&self, width: usize, height: usize) -> Wall3Grid {
+        self.generate_steps(width, height).finish()
+    }
+
+    pub fn generate_steps(&self, width: usize, height: usize) -> TriGenerationSteps {
+        TriGenerationSteps::new(RecursiveBacktracker3Process {
+            rng: StdRng::seed_from_u64(self.rng_seed),
+            cells: Wall3Grid::new(width, height),
+            visit_map: vec![false; width * height],
+            backtrace: Vec::new(),
+            current: (width > 0 && height > 0).then(|| TriCoord::new(0, 0)),
+        })
+    }
+}
+
+/// The walker of a [`RecursiveBacktracker3`], paused between two moves.
+struct RecursiveBacktracker3Process {
+    rng: StdRng,
+    cells: Wall3Grid,
+    visit_map: Vec<bool>,
+    backtrace: Vec<TriCoord>,
+    current: Option<TriCoord>,
+}
+
+impl GenerationProcess for RecursiveBacktracker3Process {
+    type Grid = Wall3Grid;
+    type Step = TriGenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<TriGenerationStep>) -> bool {
+        let Some(current) = self.current else {
+            steps.push_back(TriGenerationStep::Complete);
+            return false;
+        };
+
+        let width = self.cells.width();
+        let idx = current.y * width + current.x;
+        if !self.visit_map[idx] {
+            steps.push_back(TriGenerationStep::Visit { cell: current });
+        }
+        self.visit_map[idx] = true;
+
+        let candidates: Vec<_> = self
+            .cells
+            .neighbors(current)
+            .filter(|n| !self.visit_map[n.y * width + n.x])
+            .collect();
+
+        if let Some(&next) = candidates.choose(&mut self.rng) {
+            self.backtrace.push(current);
+            steps.push_back(TriGenerationStep::AddToFrontier { cell: current });
+            self.cells.remove_wall_between(current, next);
+            steps.push_back(TriGenerationStep::Carve {
+                from: current,
+                to: next,
+            });
+            self.current = Some(next);
+            return true;
+        }
+
+        self.current = self.backtrace.pop();
+        if let Some(cell) = self.current {
+            steps.push_back(TriGenerationStep::Backtrack { to: cell });
+        }
+        true
+    }
+
+    fn grid(&self) -> &Wall3Grid {
+        &self.cells
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall3Grid {
+        self.cells
+    }
+}
+
+impl MazeGeneratorTri for RecursiveBacktracker3 {
+    fn new_random() -> Self {
+        RecursiveBacktracker3::new_random()
+    }
+
+    fn new_from_seed(rng_seed: u64) -> Self {
+        RecursiveBacktracker3::new_from_seed(rng_seed)
+    }
+
+    fn generate(&self, width: usize, height: usize) -> Wall3Grid {
+        self.generate(width, height)
+    }
+
+    fn generate_steps(&self, width: usize, height: usize) -> TriGenerationSteps {
+        self.generate_steps(width, height)
+    }
+
+    fn name(&self) -> &'static str {
+        "tri-recursive-backtracker"
+    }
+
+    fn description(&self) -> &'static str {
+        "Depth-first search generator for triangular grids with long, winding corridors"
+    }
+}
@@ -0,0 +1,146 @@
+use crate::generators::{GenerationProcess, GenerationStep3D, GenerationSteps3D, MazeGenerator3D};
+use crate::grid_coord_3d::GridCoord3D;
+use crate::maze_grid::MazeGrid;
+use crate::wall3d_grid::Wall3DGrid;
+use rand::SeedableRng;
+use rand::prelude::IndexedRandom;
+use rand::rngs::StdRng;
+use std::collections::VecDeque;
+
+/// Recursive backtracker (randomized depth-first search) for layered 3D grids.
+///
+/// See [`RecursiveBacktracker4`](crate::generators::RecursiveBacktracker4) for the
+/// single-layer counterpart.
+///
+/// ## Example
+/// ```
+/// use amaze::generators::RecursiveBacktracker3D;
+///
+/// let grid = RecursiveBacktracker3D::new_from_seed(42).generate(6, 6, 3);
+/// assert_eq!(grid.stats().loops, 0);
+/// ```
+pub struct RecursiveBacktracker3D {
+    rng_seed: u64,
+}
+
+impl Default for RecursiveBacktracker3D {
+    fn default() -> Self {
+        Self::new_random()
+    }
+}
+
+impl RecursiveBacktracker3D {
+    pub fn new_random() -> Self {
+        Self {
+            rng_seed: rand::random(),
+        }
+    }
+
+    pub fn new_from_seed(rng_seed: u64) -> Self {
+        if rng_seed == 0 {
+            Self::new_random()
+        } else {
+            Self { rng_seed }
+        }
+    }
+
+    pub fn generate(&self, width: usize, height: usize, depth: usize) -> Wall3DGrid {
+        self.generate_steps(width, height, depth).finish()
+    }
+
+    pub fn generate_steps(&self, width: usize, height: usize, depth: usize) -> GenerationSteps3D {
+        let cells = Wall3DGrid::new(width, height, depth);
+        GenerationSteps3D::new(RecursiveBacktracker3DProcess {
+            rng: StdRng::seed_from_u64(self.rng_seed),
+            visit_map: vec![false; cells.cell_count()],
+            cells,
+            backtrace: Vec::new(),
+            current: (width > 0 && height > 0 && depth > 0).then(|| GridCoord3D::new(0, 0, 0)),
+        })
+    }
+}
+
+/// The walker of a [`RecursiveBacktracker3D`], paused between two moves.
+struct RecursiveBacktracker3DProcess {
+    rng: StdRng,
+    cells: Wall3DGrid,
+    visit_map: Vec<bool>,
+    backtrace: Vec<GridCoord3D>,
+    current: Option<GridCoord3D>,
+}
+
+impl GenerationProcess for RecursiveBacktracker3DProcess {
+    type Grid = Wall3DGrid;
+    type Step = GenerationStep3D;
+
+    fn advance(&mut self, steps: &mut VecDeque<GenerationStep3D>) -> bool {
+        let Some(current) = self.current else {
+            steps.push_back(GenerationStep3D::Complete);
+            return false;
+        };
+
+        let idx = self.cells.linearize(current);
+        if !self.visit_map[idx] {
+            steps.push_back(GenerationStep3D::Visit { cell: current });
+        }
+        self.visit_map[idx] = true;
+
+        let candidates: Vec<_> = self
+            .cells
+            .neighbors(current)
+            .filter(|&n| !self.visit_map[self.cells.linearize(n)])
+            .collect();
+
+        if let Some(&next) = candidates.choose(&mut self.rng) {
+            self.backtrace.push(current);
+            steps.push_back(GenerationStep3D::AddToFrontier { cell: current });
+            self.cells.remove_wall_between(current, next);
+            steps.push_back(GenerationStep3D::Carve {
+                from: current,
+                to: next,
+            });
+            self.current = Some(next);
+            return true;
+        }
+
+        self.current = self.backtrace.pop();
+        if let Some(cell) = self.current {
+            steps.push_back(GenerationStep3D::Backtrack { to: cell });
+        }
+        true
+    }
+
+    fn grid(&self) -> &Wall3DGrid {
+        &self.cells
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall3DGrid {
+        self.cells
+    }
+}
+
+impl MazeGenerator3D for RecursiveBacktracker3D {
+    fn new_random() -> Self {
+        RecursiveBacktracker3D::new_random()
+    }
+
+    fn new_from_seed(rng_seed: u64) -> Self {
+        RecursiveBacktracker3D::new_from_seed(rng_seed)
+    }
+
+    fn generate(&self, width: usize, height: usize, depth: usize) -> Wall3DGrid {
+        self.generate(width, height, depth)
+    }
+
+    fn generate_steps(&self, width: usize, height: usize, depth: usize) -> GenerationSteps3D {
+        self.generate_steps(width, height, depth)
+    }
+
+    fn name(&self) -> &'static str {
+        "3d-recursive-backtracker"
+    }
+
+    fn description(&self) -> &'static str {
+        "Depth-first search generator for layered 3D grids with long, winding corridors"
+    }
+}
@@ -1,12 +1,17 @@
+use crate::generators::helpers::mask_start::RegionStarts;
 use crate::generators::{
-    GenerationStep, GenerationSteps, GenerationVisitor, MazeGenerator2D, VecGenerationVisitor,
+    GenerationProcess, GenerationStep, GenerationSteps, MaskedMazeGenerator2D, MazeGenerator2D,
+    WrappingMazeGenerator2D,
 };
 use crate::grid_coord_2d::{GetCoordinateBounds2D, GridCoord2D};
+use crate::grid_mask::GridMask;
 use crate::visit_map_2d::VisitMap2D;
 use crate::wall4_grid::Wall4Grid;
+use crate::wrap_mode::WrapMode;
 use rand::SeedableRng;
 use rand::prelude::IndexedRandom;
 use rand::rngs::StdRng;
+use std::collections::VecDeque;
 
 /// A maze generator that implements the Recursive Backtracking algorithm.
 pub struct RecursiveBacktracker4 {
@@ -35,93 +40,109 @@ impl RecursiveBacktracker4 {
     }
 
     pub fn generate(&self, width: usize, height: usize) -> Wall4Grid {
-        self.generate_with_steps(width, height).0
+        self.generate_steps(width, height).finish()
     }
 
     pub fn generate_steps(&self, width: usize, height: usize) -> GenerationSteps {
-        GenerationSteps::new(self.generate_with_steps(width, height).1)
+        self.steps(&GridMask::new(width, height), WrapMode::None)
     }
 
-    fn generate_with_steps(&self, width: usize, height: usize) -> (Wall4Grid, Vec<GenerationStep>) {
-        let mut cells = Wall4Grid::new(width, height);
-        let mut visit_map = VisitMap2D::new_like(&cells);
-        let start_coordinate = GridCoord2D::default();
-        let mut backtrace = Vec::new();
-        let mut visitor = VecGenerationVisitor::default();
+    /// Generates a maze restricted to the enabled cells of the mask.
+    pub fn generate_masked(&self, mask: &GridMask) -> Wall4Grid {
+        self.generate_masked_steps(mask).finish()
+    }
 
-        if width == 0 || height == 0 {
-            visitor.on_step(&GenerationStep::Complete);
-            return (cells, visitor.into_steps());
-        }
+    pub fn generate_masked_steps(&self, mask: &GridMask) -> GenerationSteps {
+        self.steps(mask, WrapMode::None)
+    }
 
-        let rng = StdRng::seed_from_u64(self.rng_seed);
-        Self::backtrack(
-            rng,
-            &mut cells,
-            &mut visit_map,
-            start_coordinate,
-            &mut backtrace,
-            &mut visitor,
-        );
-        visitor.on_step(&GenerationStep::Complete);
-
-        (cells, visitor.into_steps())
-    }
-
-    fn backtrack<V: GenerationVisitor>(
-        mut rng: StdRng,
-        cells: &mut Wall4Grid,
-        visit_map: &mut VisitMap2D,
-        mut current_cell: GridCoord2D,
-        backtrace: &mut Vec<GridCoord2D>,
-        visitor: &mut V,
-    ) {
-        debug_assert_eq!(cells.width(), visit_map.width());
-        debug_assert_eq!(cells.height(), visit_map.height());
-
-        loop {
-            if !visit_map[current_cell] {
-                visitor.on_step(&GenerationStep::Visit { cell: current_cell });
-            }
-            visit_map[current_cell] = true;
-
-            if let Some(selected_cell) =
-                Self::select_random_unvisited_neighbor(&mut rng, visit_map, current_cell)
-            {
-                backtrace.push(current_cell);
-                visitor.on_step(&GenerationStep::AddToFrontier { cell: current_cell });
-                cells.remove_wall_between(current_cell, selected_cell);
-                visitor.on_step(&GenerationStep::Carve {
-                    from: current_cell,
-                    to: selected_cell,
-                });
-                current_cell = selected_cell;
-                continue;
-            }
-
-            if let Some(cell) = backtrace.pop() {
-                current_cell = cell;
-                visitor.on_step(&GenerationStep::Backtrack { to: cell });
-            } else {
-                break;
-            }
-        }
+    fn steps(&self, mask: &GridMask, wrap: WrapMode) -> GenerationSteps {
+        GenerationSteps::new(RecursiveBacktracker4Process {
+            rng: StdRng::seed_from_u64(self.rng_seed),
+            cells: Wall4Grid::new_wrapped(mask.width(), mask.height(), wrap),
+            visit_map: VisitMap2D::new_masked(mask),
+            backtrace: Vec::new(),
+            // Each disconnected region of the mask becomes its own maze.
+            starts: RegionStarts::new(),
+            current_cell: None,
+        })
     }
+}
+
+/// The walker of a [`RecursiveBacktracker4`], paused between two moves.
+struct RecursiveBacktracker4Process {
+    rng: StdRng,
+    cells: Wall4Grid,
+    visit_map: VisitMap2D,
+    backtrace: Vec<GridCoord2D>,
+    starts: RegionStarts,
+    current_cell: Option<GridCoord2D>,
+}
 
+impl RecursiveBacktracker4Process {
     fn select_random_unvisited_neighbor(
-        rng: &mut StdRng,
-        visit_map: &VisitMap2D,
+        &mut self,
         current_cell: GridCoord2D,
     ) -> Option<GridCoord2D> {
-        debug_assert!(current_cell.x < visit_map.width());
-        debug_assert!(current_cell.y < visit_map.height());
-
-        let list = visit_map.unvisited_neighbors(current_cell);
+        debug_assert!(current_cell.x < self.visit_map.width());
+        debug_assert!(current_cell.y < self.visit_map.height());
+
+        let list: Vec<_> = self
+            .cells
+            .neighbors(current_cell)
+            .filter(|&n| !self.visit_map[n])
+            .collect();
         if list.is_empty() {
             return None;
         }
 
-        list.choose(rng).copied()
+        list.choose(&mut self.rng).copied()
+    }
+}
+
+impl GenerationProcess for RecursiveBacktracker4Process {
+    type Grid = Wall4Grid;
+    type Step = GenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<GenerationStep>) -> bool {
+        let Some(current_cell) = self
+            .current_cell
+            .or_else(|| self.starts.next_unvisited(&self.visit_map))
+        else {
+            steps.push_back(GenerationStep::Complete);
+            return false;
+        };
+
+        if !self.visit_map[current_cell] {
+            steps.push_back(GenerationStep::Visit { cell: current_cell });
+        }
+        self.visit_map[current_cell] = true;
+
+        if let Some(selected_cell) = self.select_random_unvisited_neighbor(current_cell) {
+            self.backtrace.push(current_cell);
+            steps.push_back(GenerationStep::AddToFrontier { cell: current_cell });
+            self.cells.remove_wall_between(current_cell, selected_cell);
+            steps.push_back(GenerationStep::Carve {
+                from: current_cell,
+                to: selected_cell,
+            });
+            self.current_cell = Some(selected_cell);
+            return true;
+        }
+
+        self.current_cell = self.backtrace.pop();
+        if let Some(cell) = self.current_cell {
+            steps.push_back(GenerationStep::Backtrack { to: cell });
+        }
+        true
+    }
+
+    fn grid(&self) -> &Wall4Grid {
+        &self.cells
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall4Grid {
+        self.cells
     }
 }
 
@@ -150,3 +171,28 @@ impl MazeGenerator2D for RecursiveBacktracker4 {
         "Depth-first backtracking generator that creates long corridors"
     }
 }
+
+impl MaskedMazeGenerator2D for RecursiveBacktracker4 {
+    fn generate_masked(&self, mask: &GridMask) -> Wall4Grid {
+        self.generate_masked(mask)
+    }
+
+    fn generate_masked_steps(&self, mask: &GridMask) -> GenerationSteps {
+        self.generate_masked_steps(mask)
+    }
+}
+
+impl WrappingMazeGenerator2D for RecursiveBacktracker4 {
+    fn generate_wrapped(&self, width: usize, height: usize, wrap: WrapMode) -> Wall4Grid {
+        self.generate_wrapped_steps(width, height, wrap).finish()
+    }
+
+    fn generate_wrapped_steps(
+        &self,
+        width: usize,
+        height: usize,
+        wrap: WrapMode,
+    ) -> GenerationSteps {
+        self.steps(&GridMask::new(width, height), wrap)
+    }
+}
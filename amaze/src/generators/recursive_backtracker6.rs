@@ -1,13 +1,14 @@
-use crate::direction6::Direction6;
 use crate::generators::{
-    HexGenerationStep, HexGenerationSteps, HexGenerationVisitor, MazeGenerator6D,
-    VecHexGenerationVisitor,
+    GenerationProcess, HexGenerationStep, HexGenerationSteps, MazeGenerator6D,
+    WrappingMazeGenerator6D,
 };
 use crate::hex_coord::HexCoord;
 use crate::wall6_grid::Wall6Grid;
+use crate::wrap_mode::WrapMode;
 use rand::SeedableRng;
 use rand::prelude::IndexedRandom;
 use rand::rngs::StdRng;
+use std::collections::VecDeque;
 
 pub struct RecursiveBacktracker6 {
     rng_seed: u64,
@@ -35,87 +36,93 @@ impl RecursiveBacktracker6 {
     }
 
     pub fn generate(&self, width: usize, height: usize) -> Wall6Grid {
-        self.generate_with_steps(width, height).0
+        self.generate_steps(width, height).finish()
     }
 
     pub fn generate_steps(&self, width: usize, height: usize) -> HexGenerationSteps {
-        HexGenerationSteps::new(self.generate_with_steps(width, height).1)
+        self.steps(width, height, WrapMode::None)
     }
 
-    fn generate_with_steps(
-        &self,
-        width: usize,
-        height: usize,
-    ) -> (Wall6Grid, Vec<HexGenerationStep>) {
-        let mut cells = Wall6Grid::new(width, height);
-        let mut visitor = VecHexGenerationVisitor::default();
+    fn steps(&self, width: usize, height: usize, wrap: WrapMode) -> HexGenerationSteps {
+        let current = (width > 0 && height > 0).then(|| HexCoord::new(0, 0));
 
-        if width == 0 || height == 0 {
-            visitor.on_step(&HexGenerationStep::Complete);
-            return (cells, visitor.into_steps());
-        }
+        HexGenerationSteps::new(RecursiveBacktracker6Process {
+            rng: StdRng::seed_from_u64(self.rng_seed),
+            cells: Wall6Grid::new_wrapped(width, height, wrap),
+            visit_map: vec![false; width * height],
+            backtrace: Vec::new(),
+            current,
+        })
+    }
+}
+
+/// The walker of a [`RecursiveBacktracker6`], paused between two moves.
+struct RecursiveBacktracker6Process {
+    rng: StdRng,
+    cells: Wall6Grid,
+    visit_map: Vec<bool>,
+    backtrace: Vec<HexCoord>,
+    current: Option<HexCoord>,
+}
 
-        let mut visit_map = vec![false; width * height];
-        let start = HexCoord::new(0, 0);
-        let mut backtrace: Vec<HexCoord> = Vec::new();
-        let mut rng = StdRng::seed_from_u64(self.rng_seed);
-
-        let mut current = start;
-        loop {
-            let idx = (current.r as usize) * width + current.q as usize;
-            if !visit_map[idx] {
-                visitor.on_step(&HexGenerationStep::Visit { cell: current });
-            }
-            visit_map[idx] = true;
-
-            if let Some(next) =
-                Self::select_random_unvisited_neighbor(&mut rng, &visit_map, current, width, height)
-            {
-                backtrace.push(current);
-                visitor.on_step(&HexGenerationStep::AddToFrontier { cell: current });
-                cells.remove_wall_between(current, next);
-                visitor.on_step(&HexGenerationStep::Carve {
-                    from: current,
-                    to: next,
-                });
-                current = next;
-                continue;
-            }
-
-            if let Some(cell) = backtrace.pop() {
-                current = cell;
-                visitor.on_step(&HexGenerationStep::Backtrack { to: cell });
-            } else {
-                break;
-            }
+impl RecursiveBacktracker6Process {
+    fn select_random_unvisited_neighbor(&mut self, current: HexCoord) -> Option<HexCoord> {
+        let width = self.cells.width();
+        let candidates: Vec<_> = self
+            .cells
+            .neighbors(current)
+            .filter(|n| !self.visit_map[(n.r as usize) * width + n.q as usize])
+            .collect();
+
+        if candidates.is_empty() {
+            return None;
         }
 
-        visitor.on_step(&HexGenerationStep::Complete);
-        (cells, visitor.into_steps())
+        candidates.choose(&mut self.rng).copied()
     }
+}
 
-    fn select_random_unvisited_neighbor(
-        rng: &mut StdRng,
-        visit_map: &[bool],
-        current: HexCoord,
-        width: usize,
-        height: usize,
-    ) -> Option<HexCoord> {
-        let mut candidates = Vec::with_capacity(6);
-        for &dir in &Direction6::CARDINALS {
-            if let Some(n) = current.try_neighbor(dir, width, height) {
-                let idx = (n.r as usize) * width + n.q as usize;
-                if !visit_map[idx] {
-                    candidates.push(n);
-                }
-            }
+impl GenerationProcess for RecursiveBacktracker6Process {
+    type Grid = Wall6Grid;
+    type Step = HexGenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<HexGenerationStep>) -> bool {
+        let Some(current) = self.current else {
+            steps.push_back(HexGenerationStep::Complete);
+            return false;
+        };
+
+        let idx = (current.r as usize) * self.cells.width() + current.q as usize;
+        if !self.visit_map[idx] {
+            steps.push_back(HexGenerationStep::Visit { cell: current });
+        }
+        self.visit_map[idx] = true;
+
+        if let Some(next) = self.select_random_unvisited_neighbor(current) {
+            self.backtrace.push(current);
+            steps.push_back(HexGenerationStep::AddToFrontier { cell: current });
+            self.cells.remove_wall_between(current, next);
+            steps.push_back(HexGenerationStep::Carve {
+                from: current,
+                to: next,
+            });
+            self.current = Some(next);
+            return true;
         }
 
-        if candidates.is_empty() {
-            return None;
+        self.current = self.backtrace.pop();
+        if let Some(cell) = self.current {
+            steps.push_back(HexGenerationStep::Backtrack { to: cell });
         }
+        true
+    }
+
+    fn grid(&self) -> &Wall6Grid {
+        &self.cells
+    }
 
-        candidates.choose(rng).copied()
+    fn into_grid(self: Box<Self>) -> Wall6Grid {
+        self.cells
     }
 }
 
@@ -144,3 +151,18 @@ impl MazeGenerator6D for RecursiveBacktracker6 {
         "Depth-first backtracking generator for hexagonal grids creating long corridors"
     }
 }
+
+impl WrappingMazeGenerator6D for RecursiveBacktracker6 {
+    fn generate_wrapped(&self, width: usize, height: usize, wrap: WrapMode) -> Wall6Grid {
+        self.generate_wrapped_steps(width, height, wrap).finish()
+    }
+
+    fn generate_wrapped_steps(
+        &self,
+        width: usize,
+        height: usize,
+        wrap: WrapMode,
+    ) -> HexGenerationSteps {
+        self.steps(width, height, wrap)
+    }
+}
@@ -0,0 +1,147 @@
+use crate::generators::{
+    GenerationProcess, MazeGeneratorPolar, PolarGenerationStep, PolarGenerationSteps,
+};
+use crate::maze_grid::MazeGrid;
+use crate::polar_grid::{PolarCoord, PolarGrid};
+use rand::SeedableRng;
+use rand::prelude::IndexedRandom;
+use rand::rngs::StdRng;
+use std::collections::VecDeque;
+
+/// Recursive backtracker (randomized depth-first search) for circular mazes.
+///
+/// See [`RecursiveBacktracker4`](crate::generators::RecursiveBacktracker4) for the
+/// rectangular counterpart. Generation starts in the center cell.
+///
+/// ## Example
+/// ```
+/// use amaze::generators::RecursiveBacktrackerPolar;
+///
+/// let grid = RecursiveBacktrackerPolar::new_from_seed(42).generate(6);
+/// assert_eq!(grid.stats().loops, 0);
+/// ```
+pub struct RecursiveBacktrackerPolar {
+    rng_seed: u64,
+}
+
+impl Default for RecursiveBacktrackerPolar {
+    fn default() -> Self {
+        Self::new_random()
+    }
+}
+
+impl RecursiveBacktrackerPolar {
+    pub fn new_random() -> Self {
+        Self {
+            rng_seed: rand::random(),
+        }
+    }
+
+    pub fn new_from_seed(rng_seed: u64) -> Self {
+        if rng_seed == 0 {
+            Self::new_random()
+        } else {
+            Self { rng_seed }
+        }
+    }
+
+    pub fn generate(&self, rings: usize) -> PolarGrid {
+        self.generate_steps(rings).finish()
+    }
+
+    pub fn generate_steps(&self, rings: usize) -> PolarGenerationSteps {
+        let grid = PolarGrid::new(rings);
+        PolarGenerationSteps::new(RecursiveBacktrackerPolarProcess {
+            rng: StdRng::seed_from_u64(self.rng_seed),
+            visited: vec![false; grid.cell_count()],
+            grid,
+            backtrace: Vec::new(),
+            current: (rings > 0).then(|| PolarCoord::new(0, 0)),
+        })
+    }
+}
+
+/// The walker of a [`RecursiveBacktrackerPolar`], paused between two moves.
+struct RecursiveBacktrackerPolarProcess {
+    rng: StdRng,
+    grid: PolarGrid,
+    visited: Vec<bool>,
+    backtrace: Vec<PolarCoord>,
+    current: Option<PolarCoord>,
+}
+
+impl GenerationProcess for RecursiveBacktrackerPolarProcess {
+    type Grid = PolarGrid;
+    type Step = PolarGenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<PolarGenerationStep>) -> bool {
+        let Some(current) = self.current else {
+            steps.push_back(PolarGenerationStep::Complete);
+            return false;
+        };
+
+        let idx = self.grid.linearize(current);
+        if !self.visited[idx] {
+            steps.push_back(PolarGenerationStep::Visit { cell: current });
+        }
+        self.visited[idx] = true;
+
+        let candidates: Vec<_> = self
+            .grid
+            .neighbors(current)
+            .filter(|&n| !self.visited[self.grid.linearize(n)])
+            .collect();
+
+        if let Some(&next) = candidates.choose(&mut self.rng) {
+            self.backtrace.push(current);
+            steps.push_back(PolarGenerationStep::AddToFrontier { cell: current });
+            self.grid.remove_wall_between(current, next);
+            steps.push_back(PolarGenerationStep::Carve {
+                from: current,
+                to: next,
+            });
+            self.current = Some(next);
+            return true;
+        }
+
+        self.current = self.backtrace.pop();
+        if let Some(cell) = self.current {
+            steps.push_back(PolarGenerationStep::Backtrack { to: cell });
+        }
+        true
+    }
+
+    fn grid(&self) -> &PolarGrid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> PolarGrid {
+        self.grid
+    }
+}
+
+impl MazeGeneratorPolar for RecursiveBacktrackerPolar {
+    fn new_random() -> Self {
+        RecursiveBacktrackerPolar::new_random()
+    }
+
+    fn new_from_seed(rng_seed: u64) -> Self {
+        RecursiveBacktrackerPolar::new_from_seed(rng_seed)
+    }
+
+    fn generate(&self, rings: usize) -> PolarGrid {
+        self.generate(rings)
+    }
+
+    fn generate_steps(&self, rings: usize) -> PolarGenerationSteps {
+        self.generate_steps(rings)
+    }
+
+    fn name(&self) -> &'static str {
+        "polar-recursive-backtracker"
+    }
+
+    fn description(&self) -> &'static str {
+        "Depth-first search generator for circular mazes with long, winding corridors"
+    }
+}
@@ -0,0 +1,293 @@
+use crate::generators::{GenerationProcess, GenerationStep, GenerationSteps, MazeGenerator2D};
+use crate::grid_coord_2d::GridCoord2D;
+use crate::wall4_grid::Wall4Grid;
+use rand::rngs::StdRng;
+use rand::{RngExt, SeedableRng};
+use std::collections::VecDeque;
+
+/// A wall-adding maze generator that implements the Recursive Division algorithm.
+///
+/// Unlike the passage-carving generators, this generator starts from an open grid
+/// (see [`Wall4Grid::new_open`]) and recursively bisects chambers with a wall that
+/// has a single passage in it. Its generation steps are therefore
+/// [`GenerationStep::AddWall`] events rather than carves.
+///
+/// ## Example
+/// ```
+/// use amaze::generators::RecursiveDivision4;
+///
+/// let grid = RecursiveDivision4::new_from_seed(42)
+///     .with_horizontal_bias(0.7)
+///     .generate(12, 8);
+/// assert_eq!(grid.stats().loops, 0);
+/// ```
+pub struct RecursiveDivision4 {
+    rng_seed: u64,
+    horizontal_bias: f64,
+    room_size: usize,
+}
+
+impl Default for RecursiveDivision4 {
+    fn default() -> Self {
+        Self::new_random()
+    }
+}
+
+/// A rectangular, wall-free region of the grid awaiting division.
+struct Chamber {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl RecursiveDivision4 {
+    pub fn new_random() -> Self {
+        Self {
+            rng_seed: rand::random(),
+            horizontal_bias: 0.5,
+            room_size: 1,
+        }
+    }
+
+    pub fn new_from_seed(rng_seed: u64) -> Self {
+        if rng_seed == 0 {
+            Self::new_random()
+        } else {
+            Self {
+                rng_seed,
+                horizontal_bias: 0.5,
+                room_size: 1,
+            }
+        }
+    }
+
+    /// Sets the probability of dividing a chamber with a horizontal wall, clamped to `0.0..=1.0`.
+    ///
+    /// Values above `0.5` produce mazes with long horizontal corridors,
+    /// values below `0.5` favor vertical ones.
+    pub fn with_horizontal_bias(mut self, bias: f64) -> Self {
+        self.horizontal_bias = bias.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the room size below which chambers are no longer divided.
+    ///
+    /// Chambers that fit within `size` × `size` cells are left as open rooms,
+    /// so any value above `1` produces an imperfect maze.
+    pub fn with_room_size(mut self, size: usize) -> Self {
+        self.room_size = size.max(1);
+        self
+    }
+
+    pub fn generate(&self, width: usize, height: usize) -> Wall4Grid {
+        self.generate_steps(width, height).finish()
+    }
+
+    pub fn generate_steps(&self, width: usize, height: usize) -> GenerationSteps {
+        let chambers = if width == 0 || height == 0 {
+            Vec::new()
+        } else {
+            vec![Chamber {
+                x: 0,
+                y: 0,
+                width,
+                height,
+            }]
+        };
+
+        GenerationSteps::new(RecursiveDivision4Process {
+            rng: StdRng::seed_from_u64(self.rng_seed),
+            grid: Wall4Grid::new_open(width, height),
+            horizontal_bias: self.horizontal_bias,
+            room_size: self.room_size,
+            chambers,
+        })
+    }
+    /// Adds a wall between two rows of the chamber, leaving a single passage.
+    fn divide_horizontally(
+        rng: &mut StdRng,
+        grid: &mut Wall4Grid,
+        chamber: &Chamber,
+        steps: &mut VecDeque<GenerationStep>,
+    ) -> (Chamber, Chamber) {
+        let wall_y = chamber.y + rng.random_range(0..chamber.height - 1);
+        let passage_x = chamber.x + rng.random_range(0..chamber.width);
+
+        for x in (chamber.x..chamber.x + chamber.width).filter(|&x| x != passage_x) {
+            let from = GridCoord2D::new(x, wall_y);
+            let to = GridCoord2D::new(x, wall_y + 1);
+            grid.add_wall_between(from, to);
+            steps.push_back(GenerationStep::AddWall { from, to });
+        }
+
+        let top_height = wall_y - chamber.y + 1;
+        (
+            Chamber {
+                height: top_height,
+                ..*chamber
+            },
+            Chamber {
+                y: wall_y + 1,
+                height: chamber.height - top_height,
+                ..*chamber
+            },
+        )
+    }
+
+    /// Adds a wall between two columns of the chamber, leaving a single passage.
+    fn divide_vertically(
+        rng: &mut StdRng,
+        grid: &mut Wall4Grid,
+        chamber: &Chamber,
+        steps: &mut VecDeque<GenerationStep>,
+    ) -> (Chamber, Chamber) {
+        let wall_x = chamber.x + rng.random_range(0..chamber.width - 1);
+        let passage_y = chamber.y + rng.random_range(0..chamber.height);
+
+        for y in (chamber.y..chamber.y + chamber.height).filter(|&y| y != passage_y) {
+            let from = GridCoord2D::new(wall_x, y);
+            let to = GridCoord2D::new(wall_x + 1, y);
+            grid.add_wall_between(from, to);
+            steps.push_back(GenerationStep::AddWall { from, to });
+        }
+
+        let left_width = wall_x - chamber.x + 1;
+        (
+            Chamber {
+                width: left_width,
+                ..*chamber
+            },
+            Chamber {
+                x: wall_x + 1,
+                width: chamber.width - left_width,
+                ..*chamber
+            },
+        )
+    }
+}
+
+/// The chambers of a [`RecursiveDivision4`] maze still awaiting division.
+struct RecursiveDivision4Process {
+    rng: StdRng,
+    grid: Wall4Grid,
+    horizontal_bias: f64,
+    room_size: usize,
+    chambers: Vec<Chamber>,
+}
+
+impl RecursiveDivision4Process {
+    fn should_divide(&self, chamber: &Chamber) -> bool {
+        let is_room = chamber.width <= self.room_size && chamber.height <= self.room_size;
+        chamber.width >= 2 && chamber.height >= 2 && !is_room
+    }
+}
+
+impl GenerationProcess for RecursiveDivision4Process {
+    type Grid = Wall4Grid;
+    type Step = GenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<GenerationStep>) -> bool {
+        let Some(chamber) = self.chambers.pop() else {
+            steps.push_back(GenerationStep::Complete);
+            return false;
+        };
+        if !self.should_divide(&chamber) {
+            return true;
+        }
+
+        let (first, second) = if self.rng.random_bool(self.horizontal_bias) {
+            RecursiveDivision4::divide_horizontally(&mut self.rng, &mut self.grid, &chamber, steps)
+        } else {
+            RecursiveDivision4::divide_vertically(&mut self.rng, &mut self.grid, &chamber, steps)
+        };
+
+        // Push the second half first so the first half is divided next.
+        self.chambers.push(second);
+        self.chambers.push(first);
+        true
+    }
+
+    fn grid(&self) -> &Wall4Grid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall4Grid {
+        self.grid
+    }
+}
+
+impl MazeGenerator2D for RecursiveDivision4 {
+    fn new_random() -> Self {
+        RecursiveDivision4::new_random()
+    }
+
+    fn new_from_seed(rng_seed: u64) -> Self {
+        RecursiveDivision4::new_from_seed(rng_seed)
+    }
+
+    fn generate(&self, width: usize, height: usize) -> Wall4Grid {
+        self.generate(width, height)
+    }
+
+    fn generate_steps(&self, width: usize, height: usize) -> GenerationSteps {
+        self.generate_steps(width, height)
+    }
+
+    fn name(&self) -> &'static str {
+        "recursive-division"
+    }
+
+    fn description(&self) -> &'static str {
+        "Wall-adding generator that recursively bisects open chambers"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps_replay_onto_open_grid() {
+        let generator = RecursiveDivision4::new_from_seed(13);
+        let grid = generator.generate(10, 7);
+
+        let mut replay = Wall4Grid::new_open(10, 7);
+        for step in generator.generate_steps(10, 7) {
+            match step {
+                GenerationStep::AddWall { from, to } => replay.add_wall_between(from, to),
+                GenerationStep::Complete => break,
+                other => panic!("unexpected step {other:?}"),
+            }
+        }
+
+        for cell in grid.coords() {
+            assert_eq!(grid[cell], replay[cell]);
+        }
+    }
+
+    #[test]
+    fn room_size_leaves_open_rooms() {
+        let perfect = RecursiveDivision4::new_from_seed(21).generate(16, 16);
+        let rooms = RecursiveDivision4::new_from_seed(21)
+            .with_room_size(4)
+            .generate(16, 16);
+
+        assert_eq!(perfect.stats().loops, 0);
+        assert!(rooms.stats().loops > 0);
+    }
+
+    #[test]
+    fn bias_controls_orientation() {
+        let count_walls = |bias: f64| {
+            let grid = RecursiveDivision4::new_from_seed(8)
+                .with_horizontal_bias(bias)
+                .generate(12, 12);
+            grid.coords()
+                .filter(|&c| c.y + 1 < 12 && grid[c].contains(crate::room4::Wall4::SOUTH))
+                .count()
+        };
+
+        assert!(count_walls(1.0) > count_walls(0.0));
+    }
+}
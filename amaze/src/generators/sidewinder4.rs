@@ -1,10 +1,9 @@
-use crate::generators::{
-    GenerationStep, GenerationSteps, GenerationVisitor, MazeGenerator2D, VecGenerationVisitor,
-};
+use crate::generators::{GenerationProcess, GenerationStep, GenerationSteps, MazeGenerator2D};
 use crate::grid_coord_2d::GridCoord2D;
 use crate::wall4_grid::Wall4Grid;
 use rand::rngs::StdRng;
 use rand::{RngExt, SeedableRng};
+use std::collections::VecDeque;
 
 pub struct Sidewinder4 {
     rng_seed: u64,
@@ -17,44 +16,71 @@ impl Default for Sidewinder4 {
 }
 
 impl Sidewinder4 {
-    fn generate_with_steps(&self, width: usize, height: usize) -> (Wall4Grid, Vec<GenerationStep>) {
-        let mut grid = Wall4Grid::new(width, height);
-        let mut visitor = VecGenerationVisitor::default();
-        if width == 0 || height == 0 {
-            visitor.on_step(&GenerationStep::Complete);
-            return (grid, visitor.into_steps());
+    fn steps(&self, width: usize, height: usize) -> GenerationSteps {
+        GenerationSteps::new(Sidewinder4Process {
+            rng: StdRng::seed_from_u64(self.rng_seed),
+            grid: Wall4Grid::new(width, height),
+            next_index: 0,
+            run_start: 0,
+        })
+    }
+}
+
+/// A [`Sidewinder4`] maze, paused between two cells in row-major order.
+struct Sidewinder4Process {
+    rng: StdRng,
+    grid: Wall4Grid,
+    next_index: usize,
+    run_start: usize,
+}
+
+impl GenerationProcess for Sidewinder4Process {
+    type Grid = Wall4Grid;
+    type Step = GenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<GenerationStep>) -> bool {
+        let (width, height) = (self.grid.width(), self.grid.height());
+        if self.next_index == width * height {
+            steps.push_back(GenerationStep::Complete);
+            return false;
         }
 
-        let mut rng = StdRng::seed_from_u64(self.rng_seed);
-        for y in 0..height {
-            let mut run_start = 0usize;
-            for x in 0..width {
-                let cell = GridCoord2D::new(x, y);
-                visitor.on_step(&GenerationStep::Visit { cell });
-                let at_east_boundary = x + 1 == width;
-                let at_north_boundary = y == 0;
-                let carve_east = !at_east_boundary && (at_north_boundary || rng.random_bool(0.5));
-
-                if carve_east {
-                    let east = GridCoord2D::new(x + 1, y);
-                    grid.remove_wall_between(cell, east);
-                    visitor.on_step(&GenerationStep::Carve {
-                        from: cell,
-                        to: east,
-                    });
-                } else if !at_north_boundary {
-                    let carve_x = rng.random_range(run_start..=x);
-                    let from = GridCoord2D::new(carve_x, y);
-                    let to = GridCoord2D::new(carve_x, y - 1);
-                    grid.remove_wall_between(from, to);
-                    visitor.on_step(&GenerationStep::Carve { from, to });
-                    run_start = x + 1;
-                }
-            }
+        let (x, y) = (self.next_index % width, self.next_index / width);
+        self.next_index += 1;
+        if x == 0 {
+            self.run_start = 0;
         }
 
-        visitor.on_step(&GenerationStep::Complete);
-        (grid, visitor.into_steps())
+        let cell = GridCoord2D::new(x, y);
+        steps.push_back(GenerationStep::Visit { cell });
+        let at_east_boundary = x + 1 == width;
+        let at_north_boundary = y == 0;
+        let carve_east = !at_east_boundary && (at_north_boundary || self.rng.random_bool(0.5));
+
+        if carve_east {
+            let east = GridCoord2D::new(x + 1, y);
+            self.grid.remove_wall_between(cell, east);
+            steps.push_back(GenerationStep::Carve {
+                from: cell,
+                to: east,
+            });
+        } else if !at_north_boundary {
+            let carve_x = self.rng.random_range(self.run_start..=x);
+            let from = GridCoord2D::new(carve_x, y);
+            let to = GridCoord2D::new(carve_x, y - 1);
+            self.grid.remove_wall_between(from, to);
+            steps.push_back(GenerationStep::Carve { from, to });
+            self.run_start = x + 1;
+        }
+        true
+    }
+
+    fn grid(&self) -> &Wall4Grid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall4Grid {
+        self.grid
     }
 }
 
@@ -74,11 +100,11 @@ impl MazeGenerator2D for Sidewinder4 {
     }
 
     fn generate(&self, width: usize, height: usize) -> Wall4Grid {
-        self.generate_with_steps(width, height).0
+        self.generate_steps(width, height).finish()
     }
 
     fn generate_steps(&self, width: usize, height: usize) -> GenerationSteps {
-        GenerationSteps::new(self.generate_with_steps(width, height).1)
+        self.steps(width, height)
     }
 
     fn name(&self) -> &'static str {
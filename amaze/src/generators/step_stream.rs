@@ -0,0 +1,188 @@
+use std::collections::VecDeque;
+
+/// A generation algorithm suspended between two iterations.
+///
+/// Generators hand out their algorithms as processes wrapped in a [`StepStream`], which
+/// advances them only as far as needed to produce the next step. A process owns its
+/// grid and all of its bookkeeping, so it can be paused indefinitely between calls.
+pub trait GenerationProcess {
+    /// The grid being generated.
+    type Grid;
+    /// The events reported while generating.
+    type Step;
+
+    /// Performs the next iteration of the algorithm, appending the steps it took to `steps`.
+    ///
+    /// Returns `false` once the algorithm has finished; the last step it appends is the
+    /// `Complete` event of its step type.
+    fn advance(&mut self, steps: &mut VecDeque<Self::Step>) -> bool;
+
+    /// Returns the grid as generated so far.
+    fn grid(&self) -> &Self::Grid;
+
+    /// Consumes the process and returns its grid.
+    fn into_grid(self: Box<Self>) -> Self::Grid;
+}
+
+/// A lazy iterator over the steps of a [`GenerationProcess`].
+///
+/// Steps are produced on demand, one iteration of the algorithm at a time, so even huge
+/// grids start yielding steps immediately and only a handful of steps are ever buffered.
+/// The grid can be inspected at any point with [`grid`](Self::grid); it already reflects
+/// the remaining steps of the current iteration.
+///
+/// ## Example
+/// ```
+/// use amaze::generators::{GenerationStep, RecursiveBacktracker4};
+///
+/// let mut steps = RecursiveBacktracker4::new_from_seed(7).generate_steps(2000, 2000);
+/// assert!(matches!(steps.next(), Some(GenerationStep::Visit { .. })));
+/// assert!(!steps.is_complete());
+///
+/// let grid = RecursiveBacktracker4::new_from_seed(7)
+///     .generate_steps(8, 8)
+///     .finish();
+/// assert_eq!(grid.stats().loops, 0);
+/// ```
+pub struct StepStream<G, S> {
+    process: Box<dyn GenerationProcess<Grid = G, Step = S>>,
+    pending: VecDeque<S>,
+    running: bool,
+}
+
+impl<G: 'static, S: 'static> StepStream<G, S> {
+    pub fn new<P>(process: P) -> Self
+    where
+        P: GenerationProcess<Grid = G, Step = S> + 'static,
+    {
+        Self {
+            process: Box::new(process),
+            pending: VecDeque::new(),
+            running: true,
+        }
+    }
+
+    /// Returns a stream over an already generated grid that only yields `complete`.
+    pub fn finished(grid: G, complete: S) -> Self {
+        Self::new(Finished {
+            grid,
+            complete: Some(complete),
+        })
+    }
+}
+
+impl<G, S> StepStream<G, S> {
+    /// Returns the grid as generated so far.
+    pub fn grid(&self) -> &G {
+        self.process.grid()
+    }
+
+    /// Returns `true` once all steps have been yielded.
+    pub fn is_complete(&self) -> bool {
+        !self.running && self.pending.is_empty()
+    }
+
+    /// Runs the remaining iterations without collecting their steps and returns the grid.
+    pub fn finish(mut self) -> G {
+        while self.running {
+            self.pending.clear();
+            self.running = self.process.advance(&mut self.pending);
+        }
+        self.process.into_grid()
+    }
+}
+
+impl<G, S> Iterator for StepStream<G, S> {
+    type Item = S;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending.is_empty() && self.running {
+            self.running = self.process.advance(&mut self.pending);
+        }
+        self.pending.pop_front()
+    }
+}
+
+/// A process whose grid was generated without reporting steps.
+struct Finished<G, S> {
+    grid: G,
+    complete: Option<S>,
+}
+
+impl<G, S> GenerationProcess for Finished<G, S> {
+    type Grid = G;
+    type Step = S;
+
+    fn advance(&mut self, steps: &mut VecDeque<S>) -> bool {
+        steps.extend(self.complete.take());
+        false
+    }
+
+    fn grid(&self) -> &G {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> G {
+        self.grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts down from `n`, emitting the remaining count at each iteration.
+    struct Countdown {
+        n: usize,
+        advanced: usize,
+    }
+
+    impl GenerationProcess for Countdown {
+        type Grid = usize;
+        type Step = usize;
+
+        fn advance(&mut self, steps: &mut VecDeque<usize>) -> bool {
+            self.advanced += 1;
+            steps.push_back(self.n);
+            if self.n == 0 {
+                return false;
+            }
+            self.n -= 1;
+            true
+        }
+
+        fn grid(&self) -> &usize {
+            &self.advanced
+        }
+
+        fn into_grid(self: Box<Self>) -> usize {
+            self.advanced
+        }
+    }
+
+    #[test]
+    fn advances_only_on_demand() {
+        let mut stream = StepStream::new(Countdown { n: 3, advanced: 0 });
+        assert_eq!(*stream.grid(), 0);
+        assert_eq!(stream.next(), Some(3));
+        assert_eq!(*stream.grid(), 1);
+        assert_eq!(stream.by_ref().collect::<Vec<_>>(), vec![2, 1, 0]);
+        assert!(stream.is_complete());
+        assert_eq!(stream.next(), None);
+        assert_eq!(*stream.grid(), 4);
+    }
+
+    #[test]
+    fn finish_runs_remaining_iterations() {
+        let mut stream = StepStream::new(Countdown { n: 5, advanced: 0 });
+        stream.next();
+        assert_eq!(stream.finish(), 6);
+    }
+
+    #[test]
+    fn finished_stream_yields_complete_once() {
+        let stream = StepStream::finished(7usize, "complete");
+        assert_eq!(*stream.grid(), 7);
+        assert_eq!(stream.collect::<Vec<_>>(), vec!["complete"]);
+    }
+}
@@ -0,0 +1,240 @@
+use crate::direction4::Direction4;
+use crate::generators::helpers::union_find::UnionFind;
+use crate::generators::{GenerationProcess, GenerationStep, GenerationSteps, MazeGeneratorWeave};
+use crate::grid_coord_2d::{GridCoord2D, LinearizeCoords2D};
+use crate::weave_grid::WeaveGrid;
+use rand::rngs::StdRng;
+use rand::{RngExt, SeedableRng, prelude::SliceRandom};
+use std::collections::VecDeque;
+
+/// A weave maze generator based on Kruskal's algorithm.
+///
+/// Crossings are placed first: each eligible cell becomes a crossing with probability
+/// `density`, carrying a straight passage over a tunnel that runs perpendicular to it.
+/// Both passages join otherwise unconnected cells, so the remaining walls can be carved
+/// with plain Kruskal without ever closing a loop.
+///
+/// ## Example
+/// ```
+/// use amaze::generators::{MazeGeneratorWeave, WeaveKruskal4};
+///
+/// let grid = WeaveKruskal4::new_from_seed(42).with_density(0.0).generate(8, 8);
+/// assert_eq!(grid.crossings().count(), 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct WeaveKruskal4 {
+    rng_seed: u64,
+    density: f64,
+}
+
+impl Default for WeaveKruskal4 {
+    fn default() -> Self {
+        Self::new_random()
+    }
+}
+
+impl WeaveKruskal4 {
+    const DEFAULT_DENSITY: f64 = 0.3;
+
+    /// Sets the probability of placing a crossing at each eligible cell, clamped to `0.0..=1.0`.
+    pub fn with_density(mut self, density: f64) -> Self {
+        self.density = density.clamp(0.0, 1.0);
+        self
+    }
+
+    fn steps(&self, width: usize, height: usize) -> GenerationSteps<WeaveGrid> {
+        let grid = WeaveGrid::new(width, height);
+        let cells: Vec<_> = grid.coords().collect();
+        let mut candidates = cells.clone();
+
+        let mut rng = StdRng::seed_from_u64(self.rng_seed);
+        candidates.shuffle(&mut rng);
+
+        GenerationSteps::new(WeaveKruskal4Process {
+            rng,
+            density: self.density,
+            grid,
+            uf: UnionFind::new(width * height),
+            unvisited: cells.into_iter(),
+            candidates: candidates.into_iter(),
+            edges: None,
+        })
+    }
+}
+
+/// A [`WeaveKruskal4`] maze, paused between two crossings or carved passages.
+struct WeaveKruskal4Process {
+    rng: StdRng,
+    density: f64,
+    grid: WeaveGrid,
+    uf: UnionFind,
+    unvisited: std::vec::IntoIter<GridCoord2D>,
+    candidates: std::vec::IntoIter<GridCoord2D>,
+    /// The shuffled walls between non-crossing cells, collected once all crossings are placed.
+    edges: Option<std::vec::IntoIter<(GridCoord2D, GridCoord2D)>>,
+}
+
+impl WeaveKruskal4Process {
+    /// Turns `cell` into a crossing with a random orientation.
+    fn place_crossing(&mut self, cell: GridCoord2D, steps: &mut VecDeque<GenerationStep>) {
+        let grid = &mut self.grid;
+        let (over, under) = if self.rng.random_bool(0.5) {
+            (Direction4::NORTH, Direction4::EAST)
+        } else {
+            (Direction4::EAST, Direction4::NORTH)
+        };
+        for dir in [over, over.opposite()] {
+            let to = grid
+                .grid()
+                .neighbor(cell, dir)
+                .expect("checked by can_cross");
+            grid.remove_wall_between(cell, to);
+            self.uf.union(
+                grid.grid().linearize_coords(cell),
+                grid.grid().linearize_coords(to),
+            );
+            steps.push_back(GenerationStep::Carve { from: cell, to });
+        }
+
+        let from = grid
+            .grid()
+            .neighbor(cell, under.opposite())
+            .expect("checked by can_cross");
+        let to = grid.add_tunnel(from, under);
+        self.uf.union(
+            grid.grid().linearize_coords(from),
+            grid.grid().linearize_coords(to),
+        );
+        steps.push_back(GenerationStep::Tunnel {
+            from,
+            under: cell,
+            to,
+        });
+    }
+
+    /// Collects and shuffles the walls left to carve.
+    fn shuffled_edges(&mut self) -> std::vec::IntoIter<(GridCoord2D, GridCoord2D)> {
+        let grid = &self.grid;
+
+        // Crossings are fully enclosed by their two passages.
+        let mut edges = Vec::new();
+        for cell in grid.coords().filter(|&c| !grid.is_crossing(c)) {
+            for dir in [Direction4::EAST, Direction4::SOUTH] {
+                if let Some(other) = grid
+                    .grid()
+                    .neighbor(cell, dir)
+                    .filter(|&n| !grid.is_crossing(n))
+                {
+                    edges.push((cell, other));
+                }
+            }
+        }
+        edges.shuffle(&mut self.rng);
+        edges.into_iter()
+    }
+}
+
+impl GenerationProcess for WeaveKruskal4Process {
+    type Grid = WeaveGrid;
+    type Step = GenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<GenerationStep>) -> bool {
+        if let Some(cell) = self.unvisited.next() {
+            steps.push_back(GenerationStep::Visit { cell });
+            return true;
+        }
+
+        if let Some(cell) = self.candidates.next() {
+            if self.rng.random_bool(self.density) && can_cross(&self.grid, &mut self.uf, cell) {
+                self.place_crossing(cell, steps);
+            }
+            return true;
+        }
+
+        if self.edges.is_none() {
+            self.edges = Some(self.shuffled_edges());
+        }
+        let edges = self.edges.as_mut().expect("collected above");
+        for (a, b) in edges {
+            let ia = self.grid.grid().linearize_coords(a);
+            let ib = self.grid.grid().linearize_coords(b);
+            if self.uf.union(ia, ib) {
+                self.grid.remove_wall_between(a, b);
+                steps.push_back(GenerationStep::Carve { from: a, to: b });
+                return true;
+            }
+        }
+
+        steps.push_back(GenerationStep::Complete);
+        false
+    }
+
+    fn grid(&self) -> &WeaveGrid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> WeaveGrid {
+        self.grid
+    }
+}
+
+/// Tests whether `cell` can become a crossing: it and its four neighbors must exist,
+/// be untouched by other crossings and still belong to five separate trees.
+fn can_cross(grid: &WeaveGrid, uf: &mut UnionFind, cell: GridCoord2D) -> bool {
+    if grid.is_crossing(cell) || !grid[cell].is_all() {
+        return false;
+    }
+
+    let mut sets = vec![uf.find(grid.grid().linearize_coords(cell))];
+
+    for dir in Direction4::CARDINALS {
+        let Some(neighbor) = grid.grid().neighbor(cell, dir) else {
+            return false;
+        };
+        if grid.is_crossing(neighbor) {
+            return false;
+        }
+        let set = uf.find(grid.grid().linearize_coords(neighbor));
+        if sets.contains(&set) {
+            return false;
+        }
+        sets.push(set);
+    }
+    true
+}
+
+impl MazeGeneratorWeave for WeaveKruskal4 {
+    fn new_random() -> Self {
+        Self {
+            rng_seed: rand::random(),
+            density: Self::DEFAULT_DENSITY,
+        }
+    }
+
+    fn new_from_seed(rng_seed: u64) -> Self {
+        if rng_seed == 0 {
+            Self::new_random()
+        } else {
+            Self {
+                rng_seed,
+                density: Self::DEFAULT_DENSITY,
+            }
+        }
+    }
+
+    fn generate(&self, width: usize, height: usize) -> WeaveGrid {
+        self.generate_steps(width, height).finish()
+    }
+
+    fn generate_steps(&self, width: usize, height: usize) -> GenerationSteps<WeaveGrid> {
+        self.steps(width, height)
+    }
+
+    fn name(&self) -> &'static str {
+        "weave-kruskal"
+    }
+
+    fn description(&self) -> &'static str {
+        "Kruskal's algorithm with random under-crossings (weave maze)"
+    }
+}
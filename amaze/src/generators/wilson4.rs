@@ -1,12 +1,15 @@
 use crate::generators::{
-    GenerationStep, GenerationSteps, GenerationVisitor, MazeGenerator2D, VecGenerationVisitor,
+    GenerationProcess, GenerationStep, GenerationSteps, MaskedMazeGenerator2D, MazeGenerator2D,
+    WrappingMazeGenerator2D,
 };
 use crate::grid_coord_2d::GridCoord2D;
+use crate::grid_mask::GridMask;
 use crate::visit_map_2d::VisitMap2D;
 use crate::wall4_grid::Wall4Grid;
+use crate::wrap_mode::WrapMode;
 use rand::rngs::StdRng;
 use rand::{Rng, RngExt, SeedableRng};
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 pub struct Wilson4 {
     rng_seed: u64,
@@ -19,72 +22,131 @@ impl Default for Wilson4 {
 }
 
 impl Wilson4 {
-    fn random_neighbor<R: Rng>(rng: &mut R, grid: &Wall4Grid, cell: GridCoord2D) -> GridCoord2D {
-        let neighbors: Vec<_> = grid.neighbors(cell).collect();
+    fn random_neighbor<R: Rng>(
+        rng: &mut R,
+        grid: &Wall4Grid,
+        mask: &GridMask,
+        cell: GridCoord2D,
+    ) -> GridCoord2D {
+        let neighbors: Vec<_> = grid
+            .neighbors(cell)
+            .filter(|&n| mask.is_enabled(n))
+            .collect();
         neighbors[rng.random_range(0..neighbors.len())]
     }
 
-    fn generate_with_steps(&self, width: usize, height: usize) -> (Wall4Grid, Vec<GenerationStep>) {
-        let mut grid = Wall4Grid::new(width, height);
-        let mut visitor = VecGenerationVisitor::default();
-        if width == 0 || height == 0 {
-            visitor.on_step(&GenerationStep::Complete);
-            return (grid, visitor.into_steps());
-        }
+    fn steps(&self, mask: &GridMask, wrap: WrapMode) -> GenerationSteps {
+        let (width, height) = (mask.width(), mask.height());
+        let grid = Wall4Grid::new_wrapped(width, height, wrap);
+        let in_maze = VisitMap2D::new_like(&grid);
+        // A full mask is a single region of all cells, so it is not partitioned.
+        let roots = if mask.is_full() {
+            Vec::new()
+        } else {
+            mask.regions()
+        };
+
+        GenerationSteps::new(Wilson4Process {
+            rng: StdRng::seed_from_u64(self.rng_seed),
+            grid,
+            mask: mask.clone(),
+            all_cells: mask.enabled_cells().collect(),
+            in_maze,
+            roots: roots.into_iter(),
+            root_in_all_cells: mask.is_full(),
+        })
+    }
+}
 
-        let mut rng = StdRng::seed_from_u64(self.rng_seed);
-        let all_cells: Vec<_> = grid.coords().collect();
-        let mut in_maze = VisitMap2D::new_like(&grid);
+/// A [`Wilson4`] maze, paused between two loop-erased random walks.
+struct Wilson4Process {
+    rng: StdRng,
+    grid: Wall4Grid,
+    mask: GridMask,
+    all_cells: Vec<GridCoord2D>,
+    in_maze: VisitMap2D,
+    roots: std::vec::IntoIter<Vec<GridCoord2D>>,
+    root_in_all_cells: bool,
+}
 
-        let first = all_cells[rng.random_range(0..all_cells.len())];
-        in_maze[first] = true;
-        visitor.on_step(&GenerationStep::Visit { cell: first });
+impl GenerationProcess for Wilson4Process {
+    type Grid = Wall4Grid;
+    type Step = GenerationStep;
 
-        while all_cells.iter().copied().any(|c| !in_maze[c]) {
-            let mut start = all_cells[rng.random_range(0..all_cells.len())];
-            while in_maze[start] {
-                start = all_cells[rng.random_range(0..all_cells.len())];
-            }
+    fn advance(&mut self, steps: &mut VecDeque<GenerationStep>) -> bool {
+        let rng = &mut self.rng;
+        let in_maze = &mut self.in_maze;
+
+        // Every region of the mask needs its own root, otherwise a random walk
+        // starting in a region without one would never terminate.
+        let all_cells = &self.all_cells;
+        let root = if std::mem::take(&mut self.root_in_all_cells) && !all_cells.is_empty() {
+            Some(all_cells[rng.random_range(0..all_cells.len())])
+        } else {
+            self.roots
+                .next()
+                .map(|region| region[rng.random_range(0..region.len())])
+        };
+        if let Some(first) = root {
+            in_maze[first] = true;
+            steps.push_back(GenerationStep::Visit { cell: first });
+            return true;
+        }
+
+        if all_cells.iter().all(|&c| in_maze[c]) {
+            steps.push_back(GenerationStep::Complete);
+            return false;
+        }
 
-            let mut walk = vec![start];
-            let mut pos: HashMap<GridCoord2D, usize> = HashMap::new();
-            pos.insert(start, 0);
-            let mut current = start;
-
-            while !in_maze[current] {
-                let next = Self::random_neighbor(&mut rng, &grid, current);
-                if let Some(&at) = pos.get(&next) {
-                    walk.truncate(at + 1);
-                    pos = walk
-                        .iter()
-                        .copied()
-                        .enumerate()
-                        .map(|(i, c)| (c, i))
-                        .collect();
-                    current = next;
-                } else {
-                    walk.push(next);
-                    pos.insert(next, walk.len() - 1);
-                    current = next;
-                }
+        let mut start = all_cells[rng.random_range(0..all_cells.len())];
+        while in_maze[start] {
+            start = all_cells[rng.random_range(0..all_cells.len())];
+        }
+
+        let mut walk = vec![start];
+        let mut pos: HashMap<GridCoord2D, usize> = HashMap::new();
+        pos.insert(start, 0);
+        let mut current = start;
+
+        while !in_maze[current] {
+            let next = Wilson4::random_neighbor(rng, &self.grid, &self.mask, current);
+            if let Some(&at) = pos.get(&next) {
+                walk.truncate(at + 1);
+                pos = walk
+                    .iter()
+                    .copied()
+                    .enumerate()
+                    .map(|(i, c)| (c, i))
+                    .collect();
+                current = next;
+            } else {
+                walk.push(next);
+                pos.insert(next, walk.len() - 1);
+                current = next;
             }
+        }
 
-            let mut emitted: HashSet<GridCoord2D> = HashSet::new();
-            for pair in walk.windows(2) {
-                let from = pair[0];
-                let to = pair[1];
-                grid.remove_wall_between(from, to);
-                visitor.on_step(&GenerationStep::Carve { from, to });
-                if emitted.insert(from) {
-                    visitor.on_step(&GenerationStep::Visit { cell: from });
-                }
-                in_maze[from] = true;
-                in_maze[to] = true;
+        let mut emitted: HashSet<GridCoord2D> = HashSet::new();
+        for pair in walk.windows(2) {
+            let from = pair[0];
+            let to = pair[1];
+            self.grid.remove_wall_between(from, to);
+            steps.push_back(GenerationStep::Carve { from, to });
+            if emitted.insert(from) {
+                steps.push_back(GenerationStep::Visit { cell: from });
             }
+            in_maze[from] = true;
+            in_maze[to] = true;
         }
+        true
+    }
+
+    fn grid(&self) -> &Wall4Grid {
+        &self.grid
+    }
 
-        visitor.on_step(&GenerationStep::Complete);
-        (grid, visitor.into_steps())
+    fn into_grid(self: Box<Self>) -> Wall4Grid {
+        self.grid
     }
 }
 
@@ -104,11 +166,11 @@ impl MazeGenerator2D for Wilson4 {
     }
 
     fn generate(&self, width: usize, height: usize) -> Wall4Grid {
-        self.generate_with_steps(width, height).0
+        self.generate_steps(width, height).finish()
     }
 
     fn generate_steps(&self, width: usize, height: usize) -> GenerationSteps {
-        GenerationSteps::new(self.generate_with_steps(width, height).1)
+        self.steps(&GridMask::new(width, height), WrapMode::None)
     }
 
     fn name(&self) -> &'static str {
@@ -119,3 +181,28 @@ impl MazeGenerator2D for Wilson4 {
         "Unbiased loop-erased random walk spanning tree generator"
     }
 }
+
+impl MaskedMazeGenerator2D for Wilson4 {
+    fn generate_masked(&self, mask: &GridMask) -> Wall4Grid {
+        self.generate_masked_steps(mask).finish()
+    }
+
+    fn generate_masked_steps(&self, mask: &GridMask) -> GenerationSteps {
+        self.steps(mask, WrapMode::None)
+    }
+}
+
+impl WrappingMazeGenerator2D for Wilson4 {
+    fn generate_wrapped(&self, width: usize, height: usize, wrap: WrapMode) -> Wall4Grid {
+        self.generate_wrapped_steps(width, height, wrap).finish()
+    }
+
+    fn generate_wrapped_steps(
+        &self,
+        width: usize,
+        height: usize,
+        wrap: WrapMode,
+    ) -> GenerationSteps {
+        self.steps(&GridMask::new(width, height), wrap)
+    }
+}
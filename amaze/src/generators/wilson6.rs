@@ -0,0 +1,207 @@
+use crate::generators::{
+    GenerationProcess, HexGenerationStep, HexGenerationSteps, MazeGenerator6D,
+    WrappingMazeGenerator6D,
+};
+use crate::hex_coord::HexCoord;
+use crate::wall6_grid::Wall6Grid;
+use crate::wrap_mode::WrapMode;
+use rand::rngs::StdRng;
+use rand::{Rng, RngExt, SeedableRng};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Wilson's loop-erased random walk algorithm for hexagonal grids.
+///
+/// See [`Wilson4`](crate::generators::Wilson4) for the rectangular counterpart.
+pub struct Wilson6 {
+    rng_seed: u64,
+}
+
+impl Default for Wilson6 {
+    fn default() -> Self {
+        Self::new_random()
+    }
+}
+
+impl Wilson6 {
+    pub fn new_random() -> Self {
+        Self {
+            rng_seed: rand::random(),
+        }
+    }
+
+    pub fn new_from_seed(rng_seed: u64) -> Self {
+        if rng_seed == 0 {
+            Self::new_random()
+        } else {
+            Self { rng_seed }
+        }
+    }
+
+    pub fn generate(&self, width: usize, height: usize) -> Wall6Grid {
+        self.generate_steps(width, height).finish()
+    }
+
+    pub fn generate_steps(&self, width: usize, height: usize) -> HexGenerationSteps {
+        self.steps(width, height, WrapMode::None)
+    }
+
+    fn steps(&self, width: usize, height: usize, wrap: WrapMode) -> HexGenerationSteps {
+        let grid = Wall6Grid::new_wrapped(width, height, wrap);
+        let all_cells: Vec<_> = grid.coords().collect();
+        let mut rng = StdRng::seed_from_u64(self.rng_seed);
+        let first =
+            (!all_cells.is_empty()).then(|| all_cells[rng.random_range(0..all_cells.len())]);
+
+        HexGenerationSteps::new(Wilson6Process {
+            rng,
+            grid,
+            in_maze: vec![false; all_cells.len()],
+            in_maze_count: 0,
+            all_cells,
+            first,
+        })
+    }
+}
+
+/// The random walks of a [`Wilson6`], paused between two loop-erased walks.
+struct Wilson6Process {
+    rng: StdRng,
+    grid: Wall6Grid,
+    all_cells: Vec<HexCoord>,
+    in_maze: Vec<bool>,
+    in_maze_count: usize,
+    /// The root of the maze, until it has been visited.
+    first: Option<HexCoord>,
+}
+
+impl Wilson6Process {
+    fn index(&self, cell: HexCoord) -> usize {
+        (cell.r as usize) * self.grid.width() + cell.q as usize
+    }
+
+    fn add_to_maze(&mut self, cell: HexCoord) {
+        let idx = self.index(cell);
+        if !self.in_maze[idx] {
+            self.in_maze[idx] = true;
+            self.in_maze_count += 1;
+        }
+    }
+
+    fn random_cell(&mut self) -> HexCoord {
+        self.all_cells[self.rng.random_range(0..self.all_cells.len())]
+    }
+
+    fn random_neighbor<R: Rng>(rng: &mut R, grid: &Wall6Grid, cell: HexCoord) -> HexCoord {
+        let neighbors: Vec<_> = grid.neighbors(cell).collect();
+        neighbors[rng.random_range(0..neighbors.len())]
+    }
+}
+
+impl GenerationProcess for Wilson6Process {
+    type Grid = Wall6Grid;
+    type Step = HexGenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<HexGenerationStep>) -> bool {
+        if let Some(first) = self.first.take() {
+            self.add_to_maze(first);
+            steps.push_back(HexGenerationStep::Visit { cell: first });
+            return true;
+        }
+
+        if self.in_maze_count == self.all_cells.len() {
+            steps.push_back(HexGenerationStep::Complete);
+            return false;
+        }
+
+        let mut start = self.random_cell();
+        while self.in_maze[self.index(start)] {
+            start = self.random_cell();
+        }
+
+        let mut walk = vec![start];
+        let mut pos: HashMap<HexCoord, usize> = HashMap::new();
+        pos.insert(start, 0);
+        let mut current = start;
+
+        while !self.in_maze[self.index(current)] {
+            let next = Self::random_neighbor(&mut self.rng, &self.grid, current);
+            if let Some(&at) = pos.get(&next) {
+                // Erase the loop the walk just closed.
+                walk.truncate(at + 1);
+                pos = walk
+                    .iter()
+                    .copied()
+                    .enumerate()
+                    .map(|(i, c)| (c, i))
+                    .collect();
+            } else {
+                walk.push(next);
+                pos.insert(next, walk.len() - 1);
+            }
+            current = next;
+        }
+
+        let mut emitted: HashSet<HexCoord> = HashSet::new();
+        for pair in walk.windows(2) {
+            let from = pair[0];
+            let to = pair[1];
+            self.grid.remove_wall_between(from, to);
+            steps.push_back(HexGenerationStep::Carve { from, to });
+            if emitted.insert(from) {
+                steps.push_back(HexGenerationStep::Visit { cell: from });
+            }
+            self.add_to_maze(from);
+            self.add_to_maze(to);
+        }
+        true
+    }
+
+    fn grid(&self) -> &Wall6Grid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> Wall6Grid {
+        self.grid
+    }
+}
+
+impl MazeGenerator6D for Wilson6 {
+    fn new_random() -> Self {
+        Wilson6::new_random()
+    }
+
+    fn new_from_seed(rng_seed: u64) -> Self {
+        Wilson6::new_from_seed(rng_seed)
+    }
+
+    fn generate(&self, width: usize, height: usize) -> Wall6Grid {
+        self.generate(width, height)
+    }
+
+    fn generate_steps(&self, width: usize, height: usize) -> HexGenerationSteps {
+        self.generate_steps(width, height)
+    }
+
+    fn name(&self) -> &'static str {
+        "hex-wilson"
+    }
+
+    fn description(&self) -> &'static str {
+        "Unbiased loop-erased random walk spanning tree generator for hexagonal grids"
+    }
+}
+
+impl WrappingMazeGenerator6D for Wilson6 {
+    fn generate_wrapped(&self, width: usize, height: usize, wrap: WrapMode) -> Wall6Grid {
+        self.generate_wrapped_steps(width, height, wrap).finish()
+    }
+
+    fn generate_wrapped_steps(
+        &self,
+        width: usize,
+        height: usize,
+        wrap: WrapMode,
+    ) -> HexGenerationSteps {
+        self.steps(width, height, wrap)
+    }
+}
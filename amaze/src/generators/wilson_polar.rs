@@ -0,0 +1,187 @@
+use crate::generators::{
+    GenerationProcess, MazeGeneratorPolar, PolarGenerationStep, PolarGenerationSteps,
+};
+use crate::maze_grid::MazeGrid;
+use crate::polar_grid::{PolarCoord, PolarGrid};
+use rand::rngs::StdRng;
+use rand::{Rng, RngExt, SeedableRng};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Wilson's loop-erased random walk algorithm for circular mazes.
+///
+/// See [`Wilson4`](crate::generators::Wilson4) for the rectangular counterpart.
+pub struct WilsonPolar {
+    rng_seed: u64,
+}
+
+impl Default for WilsonPolar {
+    fn default() -> Self {
+        Self::new_random()
+    }
+}
+
+impl WilsonPolar {
+    pub fn new_random() -> Self {
+        Self {
+            rng_seed: rand::random(),
+        }
+    }
+
+    pub fn new_from_seed(rng_seed: u64) -> Self {
+        if rng_seed == 0 {
+            Self::new_random()
+        } else {
+            Self { rng_seed }
+        }
+    }
+
+    pub fn generate(&self, rings: usize) -> PolarGrid {
+        self.generate_steps(rings).finish()
+    }
+
+    pub fn generate_steps(&self, rings: usize) -> PolarGenerationSteps {
+        let grid = PolarGrid::new(rings);
+        // A single center cell has no neighbors to walk to.
+        let all_cells: Vec<_> = if rings < 2 {
+            Vec::new()
+        } else {
+            grid.coords().collect()
+        };
+        let mut rng = StdRng::seed_from_u64(self.rng_seed);
+        let first =
+            (!all_cells.is_empty()).then(|| all_cells[rng.random_range(0..all_cells.len())]);
+
+        PolarGenerationSteps::new(WilsonPolarProcess {
+            rng,
+            grid,
+            in_maze: vec![false; all_cells.len()],
+            in_maze_count: 0,
+            all_cells,
+            first,
+        })
+    }
+}
+
+/// The random walks of a [`WilsonPolar`], paused between two loop-erased walks.
+struct WilsonPolarProcess {
+    rng: StdRng,
+    grid: PolarGrid,
+    all_cells: Vec<PolarCoord>,
+    in_maze: Vec<bool>,
+    in_maze_count: usize,
+    /// The root of the maze, until it has been visited.
+    first: Option<PolarCoord>,
+}
+
+impl WilsonPolarProcess {
+    fn add_to_maze(&mut self, cell: PolarCoord) {
+        let idx = self.grid.linearize(cell);
+        if !self.in_maze[idx] {
+            self.in_maze[idx] = true;
+            self.in_maze_count += 1;
+        }
+    }
+
+    fn random_cell(&mut self) -> PolarCoord {
+        self.all_cells[self.rng.random_range(0..self.all_cells.len())]
+    }
+
+    fn random_neighbor<R: Rng>(rng: &mut R, grid: &PolarGrid, cell: PolarCoord) -> PolarCoord {
+        let neighbors: Vec<_> = grid.neighbors(cell).collect();
+        neighbors[rng.random_range(0..neighbors.len())]
+    }
+}
+
+impl GenerationProcess for WilsonPolarProcess {
+    type Grid = PolarGrid;
+    type Step = PolarGenerationStep;
+
+    fn advance(&mut self, steps: &mut VecDeque<PolarGenerationStep>) -> bool {
+        if let Some(first) = self.first.take() {
+            self.add_to_maze(first);
+            steps.push_back(PolarGenerationStep::Visit { cell: first });
+            return true;
+        }
+
+        if self.in_maze_count == self.all_cells.len() {
+            steps.push_back(PolarGenerationStep::Complete);
+            return false;
+        }
+
+        let mut start = self.random_cell();
+        while self.in_maze[self.grid.linearize(start)] {
+            start = self.random_cell();
+        }
+
+        let mut walk = vec![start];
+        let mut pos: HashMap<PolarCoord, usize> = HashMap::new();
+        pos.insert(start, 0);
+        let mut current = start;
+
+        while !self.in_maze[self.grid.linearize(current)] {
+            let next = Self::random_neighbor(&mut self.rng, &self.grid, current);
+            if let Some(&at) = pos.get(&next) {
+                // Erase the loop the walk just closed.
+                walk.truncate(at + 1);
+                pos = walk
+                    .iter()
+                    .copied()
+                    .enumerate()
+                    .map(|(i, c)| (c, i))
+                    .collect();
+            } else {
+                walk.push(next);
+                pos.insert(next, walk.len() - 1);
+            }
+            current = next;
+        }
+
+        let mut emitted: HashSet<PolarCoord> = HashSet::new();
+        for pair in walk.windows(2) {
+            let from = pair[0];
+            let to = pair[1];
+            self.grid.remove_wall_between(from, to);
+            steps.push_back(PolarGenerationStep::Carve { from, to });
+            if emitted.insert(from) {
+                steps.push_back(PolarGenerationStep::Visit { cell: from });
+            }
+            self.add_to_maze(from);
+            self.add_to_maze(to);
+        }
+        true
+    }
+
+    fn grid(&self) -> &PolarGrid {
+        &self.grid
+    }
+
+    fn into_grid(self: Box<Self>) -> PolarGrid {
+        self.grid
+    }
+}
+
+impl MazeGeneratorPolar for WilsonPolar {
+    fn new_random() -> Self {
+        WilsonPolar::new_random()
+    }
+
+    fn new_from_seed(rng_seed: u64) -> Self {
+        WilsonPolar::new_from_seed(rng_seed)
+    }
+
+    fn generate(&self, rings: usize) -> PolarGrid {
+        self.generate(rings)
+    }
+
+    fn generate_steps(&self, rings: usize) -> PolarGenerationSteps {
+        self.generate_steps(rings)
+    }
+
+    fn name(&self) -> &'static str {
+        "polar-wilson"
+    }
+
+    fn description(&self) -> &'static str {
+        "Unbiased loop-erased random walk spanning tree generator for circular mazes"
+    }
+}
@@ -0,0 +1,81 @@
+use crate::direction3d::Direction3D;
+
+/// The address of a cell in a layered [`Wall3DGrid`](crate::preamble::Wall3DGrid).
+///
+/// `z` is the layer; [`Direction3D::UP`] increases it.
+#[derive(Debug, Default, Eq, PartialEq, Copy, Clone, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridCoord3D {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
+}
+
+impl GridCoord3D {
+    #[inline]
+    pub fn new(x: usize, y: usize, z: usize) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns the adjacent cell in the given direction, unless it would have
+    /// a negative coordinate.
+    #[inline]
+    pub fn neighbor(&self, dir: Direction3D) -> Option<Self> {
+        let Self { x, y, z } = *self;
+        match dir {
+            Direction3D::NORTH => y.checked_sub(1).map(|y| Self::new(x, y, z)),
+            Direction3D::SOUTH => Some(Self::new(x, y + 1, z)),
+            Direction3D::EAST => Some(Self::new(x + 1, y, z)),
+            Direction3D::WEST => x.checked_sub(1).map(|x| Self::new(x, y, z)),
+            Direction3D::UP => Some(Self::new(x, y, z + 1)),
+            Direction3D::DOWN => z.checked_sub(1).map(|z| Self::new(x, y, z)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn try_neighbor(
+        &self,
+        dir: Direction3D,
+        width: usize,
+        height: usize,
+        depth: usize,
+    ) -> Option<Self> {
+        self.neighbor(dir)
+            .filter(|n| n.x < width && n.y < height && n.z < depth)
+    }
+
+    /// Returns the direction leading to an adjacent cell.
+    pub fn direction_to(&self, other: GridCoord3D) -> Option<Direction3D> {
+        Direction3D::CARDINALS
+            .into_iter()
+            .find(|&dir| self.neighbor(dir) == Some(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbors_are_mutual() {
+        let cell = GridCoord3D::new(1, 1, 1);
+        for dir in Direction3D::CARDINALS {
+            let neighbor = cell.neighbor(dir).expect("interior cell");
+            assert_eq!(neighbor.neighbor(dir.opposite()), Some(cell));
+            assert_eq!(cell.direction_to(neighbor), Some(dir));
+        }
+    }
+
+    #[test]
+    fn try_neighbor_stays_in_bounds() {
+        let corner = GridCoord3D::new(0, 0, 0);
+        assert_eq!(corner.neighbor(Direction3D::DOWN), None);
+        assert_eq!(
+            corner.try_neighbor(Direction3D::UP, 2, 2, 2),
+            Some(GridCoord3D::new(0, 0, 1))
+        );
+        assert_eq!(corner.try_neighbor(Direction3D::UP, 2, 2, 1), None);
+        assert_eq!(corner.direction_to(GridCoord3D::new(1, 1, 0)), None);
+    }
+}
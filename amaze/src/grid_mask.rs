@@ -0,0 +1,385 @@
+//! Cell masks for generating shaped mazes.
+//!
+//! A [`GridMask`] marks which cells of a rectangular grid take part in a maze.
+//! Masked (disabled) cells are never visited by mask-aware generators and stay
+//! fully walled, which allows mazes shaped like letters, logos or circles.
+
+use crate::grid_coord_2d::{GetCoordinateBounds2D, GridCoord2D, LinearizeCoords2D};
+use std::collections::VecDeque;
+
+/// A bitset of enabled cells in a rectangular grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridMask {
+    width: usize,
+    height: usize,
+    bits: Vec<u64>,
+}
+
+/// Errors produced when parsing a [`GridMask`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MaskError {
+    /// The PBM header is missing or malformed.
+    InvalidHeader(String),
+    /// The pixel data is malformed or incomplete.
+    InvalidData(String),
+}
+
+impl std::fmt::Display for MaskError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MaskError::InvalidHeader(msg) => write!(f, "Invalid header: {msg}"),
+            MaskError::InvalidData(msg) => write!(f, "Invalid data: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for MaskError {}
+
+impl GridMask {
+    /// Creates a mask with all cells enabled.
+    pub fn new(width: usize, height: usize) -> Self {
+        let mut mask = Self::new_disabled(width, height);
+        for word in &mut mask.bits {
+            *word = u64::MAX;
+        }
+        mask.clear_padding();
+        mask
+    }
+
+    /// Creates a mask with all cells disabled.
+    pub fn new_disabled(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            bits: vec![0; (width * height).div_ceil(64)],
+        }
+    }
+
+    /// Creates a mask by evaluating `f` for every cell.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::grid_mask::GridMask;
+    /// use amaze::preamble::GridCoord2D;
+    ///
+    /// // A circle of radius 5.
+    /// let mask = GridMask::from_fn(11, 11, |c| {
+    ///     let dx = c.x as f64 - 5.0;
+    ///     let dy = c.y as f64 - 5.0;
+    ///     dx * dx + dy * dy <= 25.0
+    /// });
+    /// assert!(mask.is_enabled(GridCoord2D::new(5, 5)));
+    /// assert!(!mask.is_enabled(GridCoord2D::new(0, 0)));
+    /// ```
+    pub fn from_fn<F>(width: usize, height: usize, f: F) -> Self
+    where
+        F: Fn(GridCoord2D) -> bool,
+    {
+        let mut mask = Self::new_disabled(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                let coord = GridCoord2D::new(x, y);
+                mask.set(coord, f(coord));
+            }
+        }
+        mask
+    }
+
+    /// Parses a mask from an ASCII picture, one line per row.
+    ///
+    /// Spaces and `.` denote disabled cells; any other character enables the cell.
+    /// The mask is as wide as the longest line, shorter lines are padded with
+    /// disabled cells.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::grid_mask::GridMask;
+    /// use amaze::preamble::GridCoord2D;
+    ///
+    /// let mask = GridMask::from_ascii("#.#\n###\n#.#");
+    /// assert_eq!(mask.width(), 3);
+    /// assert_eq!(mask.height(), 3);
+    /// assert_eq!(mask.enabled_count(), 7);
+    /// assert!(!mask.is_enabled(GridCoord2D::new(1, 0)));
+    /// ```
+    pub fn from_ascii(picture: &str) -> Self {
+        let lines: Vec<&str> = picture.lines().collect();
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let mut mask = Self::new_disabled(width, lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            for (x, ch) in line.chars().enumerate() {
+                mask.set(GridCoord2D::new(x, y), !matches!(ch, ' ' | '.'));
+            }
+        }
+        mask
+    }
+
+    /// Parses a mask from a plain (`P1`) PBM image, one pixel per cell.
+    ///
+    /// Black pixels (`1`) are enabled, white pixels (`0`) are disabled.
+    /// This is the format written by [`ImageRenderer`](crate::renderers::ImageRenderer)
+    /// with [`ImageFormat::PBM`](crate::renderers::ImageFormat::PBM).
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::grid_mask::GridMask;
+    ///
+    /// let mask = GridMask::from_pbm("P1\n# a plus sign\n3 3\n0 1 0\n1 1 1\n0 1 0\n").unwrap();
+    /// assert_eq!(mask.enabled_count(), 5);
+    /// ```
+    pub fn from_pbm(data: &str) -> Result<Self, MaskError> {
+        let mut tokens = data
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or(""))
+            .flat_map(|line| line.split_whitespace());
+
+        if tokens.next() != Some("P1") {
+            return Err(MaskError::InvalidHeader("expected plain PBM (P1)".into()));
+        }
+
+        let mut dimension = |name: &str| -> Result<usize, MaskError> {
+            tokens
+                .next()
+                .ok_or_else(|| MaskError::InvalidHeader(format!("missing {name}")))?
+                .parse()
+                .map_err(|_| MaskError::InvalidHeader(format!("invalid {name}")))
+        };
+        let width = dimension("width")?;
+        let height = dimension("height")?;
+        let count = width
+            .checked_mul(height)
+            .ok_or_else(|| MaskError::InvalidHeader("image is too large".into()))?;
+
+        // Plain PBM allows pixels to be written without separating whitespace.
+        // The pixels are counted before the mask is allocated, so a header cannot
+        // request more memory than the data backs.
+        let pixels: Vec<char> = tokens.flat_map(|token| token.chars()).collect();
+        if pixels.len() < count {
+            return Err(MaskError::InvalidData(format!("expected {count} pixels")));
+        }
+
+        let mut mask = Self::new_disabled(width, height);
+        for (index, pixel) in pixels.into_iter().take(count).enumerate() {
+            let enabled = match pixel {
+                '1' => true,
+                '0' => false,
+                other => {
+                    return Err(MaskError::InvalidData(format!(
+                        "unexpected pixel value '{other}'"
+                    )));
+                }
+            };
+            mask.set(GridCoord2D::new(index % width, index / width), enabled);
+        }
+
+        Ok(mask)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Tests whether the cell takes part in the maze.
+    ///
+    /// Out-of-bounds coordinates are reported as disabled.
+    #[inline]
+    pub fn is_enabled(&self, coord: GridCoord2D) -> bool {
+        if coord.x >= self.width || coord.y >= self.height {
+            return false;
+        }
+        let index = self.linearize_coords(coord);
+        self.bits[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Enables or disables the cell.
+    ///
+    /// ## Panics
+    /// Panics if the coordinate lies outside the mask.
+    pub fn set(&mut self, coord: GridCoord2D, enabled: bool) {
+        assert!(
+            coord.x < self.width && coord.y < self.height,
+            "coordinate {coord:?} is outside the {}x{} mask",
+            self.width,
+            self.height
+        );
+        let index = self.linearize_coords(coord);
+        if enabled {
+            self.bits[index / 64] |= 1 << (index % 64);
+        } else {
+            self.bits[index / 64] &= !(1 << (index % 64));
+        }
+    }
+
+    /// Returns the number of enabled cells.
+    pub fn enabled_count(&self) -> usize {
+        self.bits.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Tests whether all cells are enabled.
+    pub fn is_full(&self) -> bool {
+        self.enabled_count() == self.width * self.height
+    }
+
+    /// Returns an iterator over all enabled cells in row-major order.
+    pub fn enabled_cells(&self) -> impl Iterator<Item = GridCoord2D> + '_ {
+        (0..self.height)
+            .flat_map(move |y| (0..self.width).map(move |x| GridCoord2D::new(x, y)))
+            .filter(|&c| self.is_enabled(c))
+    }
+
+    /// Returns the enabled 4-neighbors of an enabled cell.
+    pub fn enabled_neighbors(&self, cell: GridCoord2D) -> Vec<GridCoord2D> {
+        [cell.up(), cell.right(), cell.down(), cell.left()]
+            .into_iter()
+            .flatten()
+            .filter(|&c| self.is_enabled(c))
+            .collect()
+    }
+
+    /// Partitions the enabled cells into 4-connected regions.
+    ///
+    /// Regions are ordered by their first cell and list their cells in row-major order.
+    pub fn regions(&self) -> Vec<Vec<GridCoord2D>> {
+        let mut labels: Vec<Option<usize>> = vec![None; self.width * self.height];
+        let mut regions: Vec<Vec<GridCoord2D>> = Vec::new();
+
+        for cell in self.enabled_cells() {
+            let label = match labels[self.linearize_coords(cell)] {
+                Some(label) => label,
+                None => {
+                    let label = regions.len();
+                    regions.push(Vec::new());
+                    labels[self.linearize_coords(cell)] = Some(label);
+                    let mut queue = VecDeque::from([cell]);
+                    while let Some(current) = queue.pop_front() {
+                        for n in self.enabled_neighbors(current) {
+                            let idx = self.linearize_coords(n);
+                            if labels[idx].is_none() {
+                                labels[idx] = Some(label);
+                                queue.push_back(n);
+                            }
+                        }
+                    }
+                    label
+                }
+            };
+            regions[label].push(cell);
+        }
+
+        regions
+    }
+
+    fn clear_padding(&mut self) {
+        let used = self.width * self.height;
+        if used % 64 != 0 {
+            if let Some(last) = self.bits.last_mut() {
+                *last &= (1u64 << (used % 64)) - 1;
+            }
+        }
+    }
+}
+
+impl GetCoordinateBounds2D for GridMask {
+    #[inline]
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_mask_is_full() {
+        let mask = GridMask::new(9, 9);
+        assert!(mask.is_full());
+        assert_eq!(mask.enabled_count(), 81);
+    }
+
+    #[test]
+    fn set_and_clear_cells() {
+        let mut mask = GridMask::new_disabled(70, 2);
+        let coord = GridCoord2D::new(65, 1);
+        mask.set(coord, true);
+        assert!(mask.is_enabled(coord));
+        assert_eq!(mask.enabled_count(), 1);
+        mask.set(coord, false);
+        assert!(!mask.is_enabled(coord));
+    }
+
+    #[test]
+    fn ascii_pads_short_lines() {
+        let mask = GridMask::from_ascii("###\n#");
+        assert_eq!(mask.width(), 3);
+        assert_eq!(mask.height(), 2);
+        assert!(!mask.is_enabled(GridCoord2D::new(2, 1)));
+    }
+
+    #[test]
+    fn pbm_without_separators() {
+        let mask = GridMask::from_pbm("P1 2 2 1001").unwrap();
+        assert!(mask.is_enabled(GridCoord2D::new(0, 0)));
+        assert!(!mask.is_enabled(GridCoord2D::new(1, 0)));
+        assert!(mask.is_enabled(GridCoord2D::new(1, 1)));
+    }
+
+    #[test]
+    fn pbm_rejects_truncated_data() {
+        assert!(matches!(
+            GridMask::from_pbm("P1\n2 2\n1 0 1"),
+            Err(MaskError::InvalidData(_))
+        ));
+        assert!(matches!(
+            GridMask::from_pbm("P3\n2 2\n"),
+            Err(MaskError::InvalidHeader(_))
+        ));
+    }
+
+    #[test]
+    fn pbm_rejects_oversized_headers() {
+        assert!(matches!(
+            GridMask::from_pbm("P1 5000000000 5000000000"),
+            Err(MaskError::InvalidHeader(_))
+        ));
+        assert!(matches!(
+            GridMask::from_pbm("P1 100000 100000 1 0 1"),
+            Err(MaskError::InvalidData(_))
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "outside")]
+    fn set_rejects_out_of_range_cells() {
+        let mut mask = GridMask::new_disabled(3, 3);
+        mask.set(GridCoord2D::new(3, 0), true);
+    }
+
+    #[test]
+    fn regions_finds_islands() {
+        let mask = GridMask::from_ascii("##..#\n##..#\n.....\n..###");
+        let regions = mask.regions();
+        assert_eq!(regions.len(), 3);
+        assert_eq!(regions[0].len(), 4);
+        assert_eq!(
+            regions[1],
+            vec![GridCoord2D::new(4, 0), GridCoord2D::new(4, 1)]
+        );
+        assert_eq!(regions[2][0], GridCoord2D::new(2, 3));
+    }
+}
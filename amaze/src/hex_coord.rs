@@ -1,4 +1,5 @@
 use crate::direction6::Direction6;
+use crate::wrap_mode::WrapMode;
 use std::ops::{Add, Sub};
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Ord, PartialOrd)]
@@ -44,6 +45,32 @@ impl HexCoord {
             None
         }
     }
+
+    /// Like [`try_neighbor`](Self::try_neighbor), but steps across the edges joined by `wrap`.
+    #[inline]
+    pub fn try_neighbor_wrapped(
+        &self,
+        dir: Direction6,
+        width: usize,
+        height: usize,
+        wrap: WrapMode,
+    ) -> Option<Self> {
+        if self.q < 0 || self.q >= width as isize || self.r < 0 || self.r >= height as isize {
+            return None;
+        }
+        let n = self.neighbor(dir);
+        let q = WrapMode::wrap_axis(n.q, width, wrap.wraps_horizontally())?;
+        let r = WrapMode::wrap_axis(n.r, height, wrap.wraps_vertically())?;
+        Some(Self::new(q as isize, r as isize))
+    }
+
+    /// Returns the number of hex steps between two coordinates.
+    #[inline]
+    pub fn distance(&self, other: HexCoord) -> usize {
+        let dq = self.q - other.q;
+        let dr = self.r - other.r;
+        ((dq.abs() + dr.abs() + (dq + dr).abs()) / 2) as usize
+    }
 }
 
 impl Add<HexCoord> for HexCoord {
@@ -143,6 +170,15 @@ mod tests {
         assert!(c.try_neighbor(Direction6::NW, 5, 5).is_none());
     }
 
+    #[test]
+    fn distance_counts_hex_steps() {
+        let origin = HexCoord::new(0, 0);
+        assert_eq!(origin.distance(origin), 0);
+        assert_eq!(origin.distance(HexCoord::new(1, -1)), 1);
+        assert_eq!(origin.distance(HexCoord::new(2, 1)), 3);
+        assert_eq!(HexCoord::new(2, 1).distance(origin), 3);
+    }
+
     #[test]
     fn add_works() {
         let a = HexCoord::new(1, 2);
@@ -160,4 +196,25 @@ mod tests {
         assert_eq!(c.q, 3);
         assert_eq!(c.r, 4);
     }
+
+    #[test]
+    fn try_neighbor_wrapped_crosses_edges() {
+        let c = HexCoord::new(0, 0);
+        assert_eq!(
+            c.try_neighbor_wrapped(Direction6::WEST, 4, 3, WrapMode::None),
+            None
+        );
+        assert_eq!(
+            c.try_neighbor_wrapped(Direction6::WEST, 4, 3, WrapMode::Horizontal),
+            Some(HexCoord::new(3, 0))
+        );
+        assert_eq!(
+            c.try_neighbor_wrapped(Direction6::NE, 4, 3, WrapMode::Torus),
+            Some(HexCoord::new(1, 2))
+        );
+        assert_eq!(
+            c.try_neighbor_wrapped(Direction6::NE, 4, 3, WrapMode::Horizontal),
+            None
+        );
+    }
 }
@@ -9,14 +9,43 @@
 //!
 //! - **Perfect-maze generators** for 4-connected grids: recursive backtracker,
 //!   growing tree, Kruskal, Eller, Wilson, hunt-and-kill, sidewinder,
-//!   binary tree, and Prim.
+//!   binary tree, Prim, Aldous-Broder, and the wall-adding recursive division.
 //! - **Hexagonal (6-connected) generators**: recursive backtracker,
-//!   growing tree, and Aldous-Broder.
+//!   growing tree, Aldous-Broder, Wilson, Kruskal, and Prim.
+//! - **Triangular (3-connected) generators**: recursive backtracker and
+//!   Kruskal on a [`preamble::Wall3Grid`].
+//! - **Cylinder and torus mazes**: square and hex grids whose edges wrap
+//!   according to a [`wrap_mode::WrapMode`].
+//! - **Weave mazes**: passages that tunnel under perpendicular corridors on a
+//!   [`preamble::WeaveGrid`], generated by a weave-aware Kruskal.
+//! - **3D mazes**: recursive backtracker, growing tree, and Kruskal on a
+//!   [`preamble::Wall3DGrid`] of stacked layers joined by stairs.
+//! - **Circular (polar) mazes**: recursive backtracker, Wilson, and growing
+//!   tree on a [`preamble::PolarGrid`] of concentric rings.
+//! - **Masked mazes**: generate inside arbitrary [`grid_mask::GridMask`] shapes,
+//!   loaded from ASCII pictures or PBM images.
+//! - **Braiding**: post-processors that remove dead ends to create loops.
+//! - **Custom topologies**: solvers, statistics, representations and storage
+//!   are generic over the [`maze_grid::MazeGrid`] trait.
 //! - **Procedural dungeons**: caverns, rooms, and winding layouts.
-//! - **Pathfinding solvers**: BFS, DFS, A\*, and dead-end filling,
-//!   all implementing the shared [`preamble::MazeSolver`] trait.
-//! - **Renderers**: Unicode box-drawing characters and PGM images,
-//!   plus statistics via [`preamble::MazeStats`].
+//! - **Pathfinding solvers**: BFS, DFS, A\*, and dead-end filling for square,
+//!   hex and custom grids, all implementing the shared [`preamble::MazeSolver`] trait.
+//! - **Agent solvers**: wall follower, Pledge and Trémaux walkers that report
+//!   their full trajectory, for comparing human effort with the shortest path.
+//! - **Weighted solvers**: Dijkstra and weighted A\* over weighted adjacency
+//!   lists, returning the path together with its total passage cost.
+//! - **Distance maps**: multi-source [`distance_map::DistanceMap`]s over mazes
+//!   and dungeons, with farthest cells, iso-distance bands and flow fields.
+//! - **Renderers**: Unicode box-drawing characters with optional solution
+//!   overlays, classic ASCII and Braille text, pointy or flat hex mazes as
+//!   text, PGM images of square and hex mazes, SVG images of square, hex,
+//!   triangle and circular mazes and of dungeons, PNG images
+//!   of square and hex mazes and of dungeons, and distance heatmaps in both
+//!   image formats and as ANSI true-color terminal text,
+//!   plus statistics via [`preamble::MazeStats`] and difficulty metrics via
+//!   [`preamble::MazeMetrics`].
+//! - **Fingerprinting**: [`fingerprint::identify`] ranks the generators most
+//!   likely to have produced a square maze, based on reference texture profiles.
 //! - **Graph representations**: adjacency lists, edge lists, passability
 //!   grids (with hex variants), and optional `petgraph` integration.
 //! - **Serialization**: binary format, JSON, and file I/O support.
@@ -48,8 +77,11 @@
 //! |---------|-------------|
 //! | `generators` | All 4-connected grid maze generation algorithms |
 //! | `generators-hex` | All hexagonal (6-connected) maze generation algorithms |
-//! | `solvers` | All maze solving algorithms (BFS, DFS, A\*, dead-end filling) |
-//! | `renderers` | All rendering backends (Unicode + PGM) |
+//! | `generators-tri` | All triangular (3-connected) maze generation algorithms |
+//! | `generators-3d` | All 3D (layered) maze generation algorithms |
+//! | `generators-polar` | All polar (circular) maze generation algorithms |
+//! | `solvers` | All maze solving algorithms (BFS, DFS, A\*, dead-end filling, Dijkstra, weighted A\*) |
+//! | `renderers` | All rendering backends (Unicode + ASCII + Braille + PGM + PNG + SVG + ANSI heatmap + triangle + polar SVG) |
 //! | `representations` | Standard 4-connected graph representations |
 //! | `hex-representations` | Hexagonal maze representations |
 //! | `dungeon-representations` | Dungeon/cave representations |
@@ -77,17 +109,35 @@
 //!
 //! Licensed under EUPL-1.2 OR MIT OR Apache-2.0.
 
+pub mod direction3;
+pub mod direction3d;
 pub mod direction4;
 pub mod direction6;
+pub mod distance_map;
 pub mod dungeon;
+pub mod fingerprint;
 pub mod generators;
 mod grid_coord_2d;
+mod grid_coord_3d;
+pub mod grid_mask;
 mod hex_coord;
+pub mod maze_grid;
+pub mod metrics;
 pub mod path;
+mod polar_grid;
 #[cfg(feature = "representations")]
 pub mod representations;
 
-#[cfg(any(feature = "unicode-renderer", feature = "pgm-renderer"))]
+#[cfg(any(
+    feature = "unicode-renderer",
+    feature = "pgm-renderer",
+    feature = "polar-renderer",
+    feature = "svg-renderer",
+    feature = "png-renderer",
+    feature = "ansi-renderer",
+    feature = "ascii-renderer",
+    feature = "braille-renderer"
+))]
 pub mod renderers;
 pub mod room4;
 pub mod room4_list;
@@ -100,17 +150,30 @@ pub mod stats;
     feature = "file-io"
 ))]
 pub mod storage;
+mod tri_coord;
 mod visit_map_2d;
+mod wall3_grid;
+mod wall3d_grid;
 mod wall4_grid;
 mod wall6_grid;
+mod weave_grid;
+pub mod wrap_mode;
 
 pub mod preamble {
+    pub use crate::direction3::{Direction3, Direction3Iterator};
+    pub use crate::direction3d::{Direction3D, Direction3DIterator};
     pub use crate::direction4::{Direction4, Direction4Iterator};
     pub use crate::direction6::{Direction6, Direction6Iterator};
+    pub use crate::distance_map::{DistanceMap, WalkableGrid};
     pub use crate::dungeon::{DungeonGrid, DungeonType, TileType};
     pub use crate::grid_coord_2d::{GetCoordinateBounds2D, GridCoord2D, LinearizeCoords2D};
+    pub use crate::grid_coord_3d::GridCoord3D;
+    pub use crate::grid_mask::GridMask;
     pub use crate::hex_coord::HexCoord;
-    pub use crate::path::Path;
+    pub use crate::maze_grid::MazeGrid;
+    pub use crate::metrics::{MazeMetrics, MeasurableGrid};
+    pub use crate::path::{HexPath, Path};
+    pub use crate::polar_grid::{PolarCoord, PolarGrid, PolarWalls};
     #[cfg(feature = "representations")]
     pub use crate::representations::{
         AdjacencyList, Edge, EdgeList, HexAdjacencyList, HexEdge, HexEdgeList, HexWeightedEdge,
@@ -120,8 +183,20 @@ pub mod preamble {
     pub use crate::room4::{Door4, Room4, Wall4};
     pub use crate::room4_list::{Room4List, RoomIndex};
     #[cfg(feature = "solvers")]
-    pub use crate::solvers::{AStarSolver, BfsSolver, DeadEndFillingSolver, DfsSolver, MazeSolver};
-    pub use crate::stats::MazeStats;
+    pub use crate::solvers::{
+        AStarSolver, BfsSolver, DeadEndFillingSolver, DfsSolver, MazeSolver, MazeSolver3D,
+        MazeSolver6,
+    };
+    #[cfg(all(feature = "solvers", feature = "representations"))]
+    pub use crate::solvers::{
+        DijkstraSolver, WeightedAStarSolver, WeightedMazeSolver, WeightedPath,
+    };
+    pub use crate::stats::{MazeStats, PathLengthMode, StatsOptions};
+    pub use crate::tri_coord::TriCoord;
+    pub use crate::wall3_grid::{Wall3, Wall3Grid};
+    pub use crate::wall3d_grid::{Wall3D, Wall3DGrid};
     pub use crate::wall4_grid::Wall4Grid;
     pub use crate::wall6_grid::{Wall6, Wall6Grid};
+    pub use crate::weave_grid::WeaveGrid;
+    pub use crate::wrap_mode::WrapMode;
 }
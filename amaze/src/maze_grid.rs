@@ -0,0 +1,547 @@
+//! A topology-generic interface over maze grids.
+//!
+//! [`MazeGrid`] captures what solvers, statistics, representations and storage
+//! formats need to know about a grid: how to enumerate and index its cells,
+//! which cells are adjacent, and which of those adjacencies are open passages.
+//! [`Wall4Grid`], [`Wall6Grid`], [`Wall3Grid`], [`WeaveGrid`] and [`Wall3DGrid`] implement it,
+//! and custom topologies can implement it to reuse the crate's algorithms.
+
+use crate::direction3::Direction3;
+use crate::direction3d::Direction3D;
+use crate::direction4::Direction4;
+use crate::direction6::Direction6;
+use crate::grid_coord_2d::{GridCoord2D, LinearizeCoords2D};
+use crate::grid_coord_3d::GridCoord3D;
+use crate::hex_coord::HexCoord;
+use crate::tri_coord::TriCoord;
+use crate::wall3_grid::Wall3Grid;
+use crate::wall3d_grid::Wall3DGrid;
+use crate::wall4_grid::Wall4Grid;
+use crate::wall6_grid::Wall6Grid;
+use crate::weave_grid::WeaveGrid;
+use crate::wrap_mode::WrapMode;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// A grid of cells separated by walls.
+///
+/// Every cell has a dense index in `0..cell_count()` given by [`linearize`](Self::linearize),
+/// which algorithms use to store per-cell data in flat vectors.
+///
+/// ## Example
+/// ```
+/// use amaze::maze_grid::MazeGrid;
+/// use amaze::preamble::{GridCoord2D, Wall4Grid};
+///
+/// fn dead_ends<G: MazeGrid>(grid: &G) -> usize {
+///     grid.coords()
+///         .filter(|&c| grid.open_neighbors(c).count() == 1)
+///         .count()
+/// }
+///
+/// let mut grid = Wall4Grid::new(2, 1);
+/// grid.remove_wall_between(GridCoord2D::new(0, 0), GridCoord2D::new(1, 0));
+/// assert_eq!(dead_ends(&grid), 2);
+/// ```
+pub trait MazeGrid {
+    /// The coordinate type addressing a single cell.
+    type Coord: Copy + Eq + Ord + Hash + Debug;
+
+    /// The wall bitset type stored per cell.
+    type Direction: Copy + Eq + Debug;
+
+    fn width(&self) -> usize;
+
+    fn height(&self) -> usize;
+
+    /// Returns the total number of cells.
+    fn cell_count(&self) -> usize {
+        self.width() * self.height()
+    }
+
+    /// Tests whether the coordinate addresses a cell of this grid.
+    fn contains(&self, coord: Self::Coord) -> bool;
+
+    /// Maps a cell to its dense index in `0..cell_count()`.
+    fn linearize(&self, coord: Self::Coord) -> usize;
+
+    /// Maps a dense index back to its cell; the inverse of [`linearize`](Self::linearize).
+    fn delinearize(&self, index: usize) -> Self::Coord;
+
+    /// Returns an iterator over all cells in index order.
+    fn coords(&self) -> impl Iterator<Item = Self::Coord> + '_ {
+        (0..self.cell_count()).map(move |index| self.delinearize(index))
+    }
+
+    /// Returns the walls of a cell.
+    fn walls(&self, coord: Self::Coord) -> Self::Direction;
+
+    /// Returns all cells adjacent to `cell`, regardless of walls.
+    fn neighbors(&self, cell: Self::Coord) -> impl Iterator<Item = Self::Coord> + '_;
+
+    /// Returns the cells reachable from `cell` through an open passage.
+    fn open_neighbors(&self, cell: Self::Coord) -> impl Iterator<Item = Self::Coord> + '_;
+
+    /// Carves a passage between two adjacent cells.
+    fn remove_wall_between(&mut self, a: Self::Coord, b: Self::Coord);
+
+    /// Closes the passage between two adjacent cells.
+    fn add_wall_between(&mut self, a: Self::Coord, b: Self::Coord);
+
+    /// Returns a lower bound of the number of steps between two cells.
+    ///
+    /// This is used as the heuristic of [`AStarSolver`](crate::solvers::AStarSolver)
+    /// and must never overestimate. The default of `0` is always admissible.
+    fn distance_estimate(&self, _a: Self::Coord, _b: Self::Coord) -> usize {
+        0
+    }
+
+    /// Computes the number of steps from `start` to every cell, indexed by
+    /// [`linearize`](Self::linearize). Unreachable cells are `None`.
+    ///
+    /// Use a [`DistanceMap`](crate::distance_map::DistanceMap) for several sources
+    /// or to follow the distances back to a source.
+    fn bfs_distances(&self, start: Self::Coord) -> Vec<Option<usize>> {
+        let mut dist = vec![None; self.cell_count()];
+        if !self.contains(start) {
+            return dist;
+        }
+
+        let mut q = VecDeque::new();
+        dist[self.linearize(start)] = Some(0);
+        q.push_back(start);
+
+        while let Some(cell) = q.pop_front() {
+            let base = dist[self.linearize(cell)].unwrap_or(0);
+            for n in self.open_neighbors(cell) {
+                let idx = self.linearize(n);
+                if dist[idx].is_none() {
+                    dist[idx] = Some(base + 1);
+                    q.push_back(n);
+                }
+            }
+        }
+
+        dist
+    }
+}
+
+impl MazeGrid for Wall4Grid {
+    type Coord = GridCoord2D;
+    type Direction = Direction4;
+
+    #[inline]
+    fn width(&self) -> usize {
+        Wall4Grid::width(self)
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        Wall4Grid::height(self)
+    }
+
+    #[inline]
+    fn contains(&self, coord: GridCoord2D) -> bool {
+        self.get(coord).is_some()
+    }
+
+    #[inline]
+    fn linearize(&self, coord: GridCoord2D) -> usize {
+        self.linearize_coords(coord)
+    }
+
+    #[inline]
+    fn delinearize(&self, index: usize) -> GridCoord2D {
+        GridCoord2D::new(
+            index % Wall4Grid::width(self),
+            index / Wall4Grid::width(self),
+        )
+    }
+
+    fn coords(&self) -> impl Iterator<Item = GridCoord2D> + '_ {
+        Wall4Grid::coords(self)
+    }
+
+    #[inline]
+    fn walls(&self, coord: GridCoord2D) -> Direction4 {
+        self[coord]
+    }
+
+    fn neighbors(&self, cell: GridCoord2D) -> impl Iterator<Item = GridCoord2D> + '_ {
+        Wall4Grid::neighbors(self, cell)
+    }
+
+    fn open_neighbors(&self, cell: GridCoord2D) -> impl Iterator<Item = GridCoord2D> + '_ {
+        Wall4Grid::open_neighbors(self, cell)
+    }
+
+    fn remove_wall_between(&mut self, a: GridCoord2D, b: GridCoord2D) {
+        Wall4Grid::remove_wall_between(self, a, b)
+    }
+
+    fn add_wall_between(&mut self, a: GridCoord2D, b: GridCoord2D) {
+        Wall4Grid::add_wall_between(self, a, b)
+    }
+
+    /// The Manhattan distance.
+    #[inline]
+    fn distance_estimate(&self, a: GridCoord2D, b: GridCoord2D) -> usize {
+        let wrap = self.wrap_mode();
+        WrapMode::axis_distance(a.x, b.x, self.width(), wrap.wraps_horizontally())
+            + WrapMode::axis_distance(a.y, b.y, self.height(), wrap.wraps_vertically())
+    }
+}
+
+impl MazeGrid for WeaveGrid {
+    type Coord = GridCoord2D;
+    type Direction = Direction4;
+
+    #[inline]
+    fn width(&self) -> usize {
+        WeaveGrid::width(self)
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        WeaveGrid::height(self)
+    }
+
+    #[inline]
+    fn contains(&self, coord: GridCoord2D) -> bool {
+        self.get(coord).is_some()
+    }
+
+    #[inline]
+    fn linearize(&self, coord: GridCoord2D) -> usize {
+        MazeGrid::linearize(self.grid(), coord)
+    }
+
+    #[inline]
+    fn delinearize(&self, index: usize) -> GridCoord2D {
+        MazeGrid::delinearize(self.grid(), index)
+    }
+
+    fn coords(&self) -> impl Iterator<Item = GridCoord2D> + '_ {
+        WeaveGrid::coords(self)
+    }
+
+    #[inline]
+    fn walls(&self, coord: GridCoord2D) -> Direction4 {
+        self[coord]
+    }
+
+    fn neighbors(&self, cell: GridCoord2D) -> impl Iterator<Item = GridCoord2D> + '_ {
+        WeaveGrid::neighbors(self, cell)
+    }
+
+    fn open_neighbors(&self, cell: GridCoord2D) -> impl Iterator<Item = GridCoord2D> + '_ {
+        WeaveGrid::open_neighbors(self, cell)
+    }
+
+    fn remove_wall_between(&mut self, a: GridCoord2D, b: GridCoord2D) {
+        WeaveGrid::remove_wall_between(self, a, b)
+    }
+
+    fn add_wall_between(&mut self, a: GridCoord2D, b: GridCoord2D) {
+        WeaveGrid::add_wall_between(self, a, b)
+    }
+
+    /// Half the Manhattan distance, since a tunnel covers two cells in one step.
+    #[inline]
+    fn distance_estimate(&self, a: GridCoord2D, b: GridCoord2D) -> usize {
+        self.grid().distance_estimate(a, b).div_ceil(2)
+    }
+}
+
+impl MazeGrid for Wall6Grid {
+    type Coord = HexCoord;
+    type Direction = Direction6;
+
+    #[inline]
+    fn width(&self) -> usize {
+        Wall6Grid::width(self)
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        Wall6Grid::height(self)
+    }
+
+    #[inline]
+    fn contains(&self, coord: HexCoord) -> bool {
+        self.get(coord).is_some()
+    }
+
+    #[inline]
+    fn linearize(&self, coord: HexCoord) -> usize {
+        (coord.r as usize) * Wall6Grid::width(self) + coord.q as usize
+    }
+
+    #[inline]
+    fn delinearize(&self, index: usize) -> HexCoord {
+        let width = Wall6Grid::width(self);
+        HexCoord::new((index % width) as isize, (index / width) as isize)
+    }
+
+    fn coords(&self) -> impl Iterator<Item = HexCoord> + '_ {
+        Wall6Grid::coords(self)
+    }
+
+    #[inline]
+    fn walls(&self, coord: HexCoord) -> Direction6 {
+        self[coord]
+    }
+
+    fn neighbors(&self, cell: HexCoord) -> impl Iterator<Item = HexCoord> + '_ {
+        Wall6Grid::neighbors(self, cell)
+    }
+
+    fn open_neighbors(&self, cell: HexCoord) -> impl Iterator<Item = HexCoord> + '_ {
+        Wall6Grid::open_neighbors(self, cell)
+    }
+
+    fn remove_wall_between(&mut self, a: HexCoord, b: HexCoord) {
+        Wall6Grid::remove_wall_between(self, a, b)
+    }
+
+    fn add_wall_between(&mut self, a: HexCoord, b: HexCoord) {
+        Wall6Grid::add_wall_between(self, a, b)
+    }
+
+    /// The axial hex distance, taking the shortest way around joined edges.
+    fn distance_estimate(&self, a: HexCoord, b: HexCoord) -> usize {
+        let wrap = self.wrap_mode();
+        let (width, height) = (
+            Wall6Grid::width(self) as isize,
+            Wall6Grid::height(self) as isize,
+        );
+        let q_shifts: &[isize] = if wrap.wraps_horizontally() && width > 2 {
+            &[-width, 0, width]
+        } else {
+            &[0]
+        };
+        let r_shifts: &[isize] = if wrap.wraps_vertically() && height > 2 {
+            &[-height, 0, height]
+        } else {
+            &[0]
+        };
+        q_shifts
+            .iter()
+            .flat_map(|&dq| r_shifts.iter().map(move |&dr| b.offset(dq, dr)))
+            .map(|shifted| a.distance(shifted))
+            .min()
+            .unwrap_or(0)
+    }
+}
+
+impl MazeGrid for Wall3Grid {
+    type Coord = TriCoord;
+    type Direction = Direction3;
+
+    #[inline]
+    fn width(&self) -> usize {
+        Wall3Grid::width(self)
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        Wall3Grid::height(self)
+    }
+
+    #[inline]
+    fn contains(&self, coord: TriCoord) -> bool {
+        self.get(coord).is_some()
+    }
+
+    #[inline]
+    fn linearize(&self, coord: TriCoord) -> usize {
+        coord.y * Wall3Grid::width(self) + coord.x
+    }
+
+    #[inline]
+    fn delinearize(&self, index: usize) -> TriCoord {
+        TriCoord::new(
+            index % Wall3Grid::width(self),
+            index / Wall3Grid::width(self),
+        )
+    }
+
+    fn coords(&self) -> impl Iterator<Item = TriCoord> + '_ {
+        Wall3Grid::coords(self)
+    }
+
+    #[inline]
+    fn walls(&self, coord: TriCoord) -> Direction3 {
+        self[coord]
+    }
+
+    fn neighbors(&self, cell: TriCoord) -> impl Iterator<Item = TriCoord> + '_ {
+        Wall3Grid::neighbors(self, cell)
+    }
+
+    fn open_neighbors(&self, cell: TriCoord) -> impl Iterator<Item = TriCoord> + '_ {
+        Wall3Grid::open_neighbors(self, cell)
+    }
+
+    fn remove_wall_between(&mut self, a: TriCoord, b: TriCoord) {
+        Wall3Grid::remove_wall_between(self, a, b)
+    }
+
+    fn add_wall_between(&mut self, a: TriCoord, b: TriCoord) {
+        Wall3Grid::add_wall_between(self, a, b)
+    }
+
+    /// The Manhattan distance; every step changes either `x` or `y` by one.
+    #[inline]
+    fn distance_estimate(&self, a: TriCoord, b: TriCoord) -> usize {
+        a.x.abs_diff(b.x) + a.y.abs_diff(b.y)
+    }
+}
+
+impl MazeGrid for Wall3DGrid {
+    type Coord = GridCoord3D;
+    type Direction = Direction3D;
+
+    #[inline]
+    fn width(&self) -> usize {
+        Wall3DGrid::width(self)
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        Wall3DGrid::height(self)
+    }
+
+    #[inline]
+    fn cell_count(&self) -> usize {
+        self.width() * self.height() * self.depth()
+    }
+
+    #[inline]
+    fn contains(&self, coord: GridCoord3D) -> bool {
+        self.get(coord).is_some()
+    }
+
+    #[inline]
+    fn linearize(&self, coord: GridCoord3D) -> usize {
+        (coord.z * Wall3DGrid::height(self) + coord.y) * Wall3DGrid::width(self) + coord.x
+    }
+
+    #[inline]
+    fn delinearize(&self, index: usize) -> GridCoord3D {
+        let (width, height) = (Wall3DGrid::width(self), Wall3DGrid::height(self));
+        GridCoord3D::new(
+            index % width,
+            index / width % height,
+            index / (width * height),
+        )
+    }
+
+    fn coords(&self) -> impl Iterator<Item = GridCoord3D> + '_ {
+        Wall3DGrid::coords(self)
+    }
+
+    #[inline]
+    fn walls(&self, coord: GridCoord3D) -> Direction3D {
+        self[coord]
+    }
+
+    fn neighbors(&self, cell: GridCoord3D) -> impl Iterator<Item = GridCoord3D> + '_ {
+        Wall3DGrid::neighbors(self, cell)
+    }
+
+    fn open_neighbors(&self, cell: GridCoord3D) -> impl Iterator<Item = GridCoord3D> + '_ {
+        Wall3DGrid::open_neighbors(self, cell)
+    }
+
+    fn remove_wall_between(&mut self, a: GridCoord3D, b: GridCoord3D) {
+        Wall3DGrid::remove_wall_between(self, a, b)
+    }
+
+    fn add_wall_between(&mut self, a: GridCoord3D, b: GridCoord3D) {
+        Wall3DGrid::add_wall_between(self, a, b)
+    }
+
+    /// The Manhattan distance over all three axes.
+    #[inline]
+    fn distance_estimate(&self, a: GridCoord3D, b: GridCoord3D) -> usize {
+        a.x.abs_diff(b.x) + a.y.abs_diff(b.y) + a.z.abs_diff(b.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_linearization_roundtrips<G: MazeGrid>(grid: &G) {
+        for (index, cell) in grid.coords().enumerate() {
+            assert!(grid.contains(cell));
+            assert_eq!(grid.linearize(cell), index);
+            assert_eq!(grid.delinearize(index), cell);
+        }
+        assert_eq!(grid.coords().count(), grid.cell_count());
+    }
+
+    fn carve_and_close<G: MazeGrid>(grid: &mut G, a: G::Coord, b: G::Coord) {
+        let closed = grid.walls(a);
+        grid.remove_wall_between(a, b);
+        assert_eq!(grid.open_neighbors(a).collect::<Vec<_>>(), vec![b]);
+        assert_eq!(grid.open_neighbors(b).collect::<Vec<_>>(), vec![a]);
+        assert_eq!(grid.bfs_distances(a)[grid.linearize(b)], Some(1));
+
+        grid.add_wall_between(a, b);
+        assert_eq!(grid.walls(a), closed);
+        assert_eq!(grid.open_neighbors(a).count(), 0);
+    }
+
+    #[test]
+    fn square_grid_implements_maze_grid() {
+        let mut grid = Wall4Grid::new(4, 3);
+        assert_linearization_roundtrips(&grid);
+        carve_and_close(&mut grid, GridCoord2D::new(1, 1), GridCoord2D::new(1, 2));
+        assert_eq!(
+            grid.distance_estimate(GridCoord2D::new(0, 0), GridCoord2D::new(3, 2)),
+            5
+        );
+    }
+
+    #[test]
+    fn hex_grid_implements_maze_grid() {
+        let mut grid = Wall6Grid::new(4, 3);
+        assert_linearization_roundtrips(&grid);
+        carve_and_close(&mut grid, HexCoord::new(1, 1), HexCoord::new(2, 0));
+        assert_eq!(
+            grid.distance_estimate(HexCoord::new(0, 0), HexCoord::new(3, 2)),
+            5
+        );
+        assert!(!MazeGrid::contains(&grid, HexCoord::new(-1, 0)));
+    }
+
+    #[test]
+    fn triangle_grid_implements_maze_grid() {
+        let mut grid = Wall3Grid::new(5, 3);
+        assert_linearization_roundtrips(&grid);
+        carve_and_close(&mut grid, TriCoord::new(2, 1), TriCoord::new(2, 0));
+        assert_eq!(
+            grid.distance_estimate(TriCoord::new(0, 0), TriCoord::new(4, 2)),
+            6
+        );
+    }
+
+    #[test]
+    fn layered_grid_implements_maze_grid() {
+        let mut grid = Wall3DGrid::new(3, 2, 4);
+        assert_eq!(grid.cell_count(), 24);
+        assert_linearization_roundtrips(&grid);
+        carve_and_close(
+            &mut grid,
+            GridCoord3D::new(2, 1, 1),
+            GridCoord3D::new(2, 1, 2),
+        );
+        assert_eq!(
+            grid.distance_estimate(GridCoord3D::new(0, 0, 0), GridCoord3D::new(2, 1, 3)),
+            6
+        );
+    }
+}
@@ -0,0 +1,358 @@
+//! Difficulty and texture metrics of mazes and dungeons.
+//!
+//! [`MazeMetrics`] complements [`MazeStats`](crate::stats::MazeStats) with measures
+//! used to grade puzzles: how long corridors run before the next decision, how often
+//! the solution turns and branches, and how much of the maze hangs off the solution
+//! as dead-end regions.
+//!
+//! ## Example
+//! ```
+//! use amaze::generators::RecursiveBacktracker4;
+//! use amaze::metrics::MazeMetrics;
+//! use amaze::preamble::GridCoord2D;
+//!
+//! let maze = RecursiveBacktracker4::new_from_seed(7).generate(10, 10);
+//! let metrics = MazeMetrics::from_grid(&maze, GridCoord2D::new(0, 0), GridCoord2D::new(9, 9));
+//!
+//! assert_eq!(metrics.loops, 0);
+//! assert!(metrics.solution_length.is_some());
+//! assert!(metrics.river_factor >= 1.0);
+//! ```
+
+use crate::direction4::Direction4;
+use crate::direction6::Direction6;
+use crate::distance_map::{DistanceMap, WalkableGrid};
+use crate::dungeon::DungeonGrid;
+use crate::grid_coord_2d::{GetCoordinateBounds2D, GridCoord2D};
+use crate::grid_mask::GridMask;
+use crate::hex_coord::HexCoord;
+use crate::wall4_grid::Wall4Grid;
+use crate::wall6_grid::Wall6Grid;
+use std::collections::{HashSet, VecDeque};
+
+/// A grid whose passages have a heading, so that [`MazeMetrics`] can count turns and
+/// directional bias.
+///
+/// Implemented by [`Wall4Grid`], [`Wall6Grid`] and [`DungeonGrid`].
+pub trait MeasurableGrid: WalkableGrid {
+    /// The direction of a step between two adjacent cells.
+    type Heading: Copy + Eq;
+
+    /// Returns all walkable cells in a deterministic order.
+    fn walkable_cells(&self) -> impl Iterator<Item = Self::Coord> + '_;
+
+    /// Returns the heading of the step from `from` to the adjacent cell `to`.
+    fn heading(&self, from: Self::Coord, to: Self::Coord) -> Self::Heading;
+
+    /// Tests whether a heading runs horizontally (east or west).
+    fn is_horizontal(heading: Self::Heading) -> bool;
+}
+
+impl MeasurableGrid for Wall4Grid {
+    type Heading = Direction4;
+
+    fn walkable_cells(&self) -> impl Iterator<Item = GridCoord2D> + '_ {
+        self.coords()
+    }
+
+    fn heading(&self, from: GridCoord2D, to: GridCoord2D) -> Direction4 {
+        self.direction_between(from, to)
+    }
+
+    fn is_horizontal(heading: Direction4) -> bool {
+        heading == Direction4::EAST || heading == Direction4::WEST
+    }
+}
+
+impl MeasurableGrid for Wall6Grid {
+    type Heading = Direction6;
+
+    fn walkable_cells(&self) -> impl Iterator<Item = HexCoord> + '_ {
+        self.coords()
+    }
+
+    fn heading(&self, from: HexCoord, to: HexCoord) -> Direction6 {
+        self.direction_between(from, to)
+    }
+
+    /// The four diagonal hex directions count as vertical.
+    fn is_horizontal(heading: Direction6) -> bool {
+        heading == Direction6::EAST || heading == Direction6::WEST
+    }
+}
+
+impl MeasurableGrid for DungeonGrid {
+    type Heading = Direction4;
+
+    fn walkable_cells(&self) -> impl Iterator<Item = GridCoord2D> + '_ {
+        (0..self.height())
+            .flat_map(move |y| (0..self.width()).map(move |x| GridCoord2D::new(x, y)))
+            .filter(|&c| self.is_floor(c))
+    }
+
+    fn heading(&self, from: GridCoord2D, to: GridCoord2D) -> Direction4 {
+        if to.x > from.x {
+            Direction4::EAST
+        } else if to.x < from.x {
+            Direction4::WEST
+        } else if to.y > from.y {
+            Direction4::SOUTH
+        } else {
+            Direction4::NORTH
+        }
+    }
+
+    fn is_horizontal(heading: Direction4) -> bool {
+        heading == Direction4::EAST || heading == Direction4::WEST
+    }
+}
+
+/// Metrics describing how hard a maze is to solve and what its passages look like.
+///
+/// Solution metrics refer to the shortest path between the start and end cells
+/// passed to [`MazeMetrics::from_grid`]; they are zero if the end cannot be reached.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MazeMetrics {
+    /// Number of walkable cells.
+    pub cells: usize,
+    /// Number of cells with at most one open passage.
+    pub dead_ends: usize,
+    /// Number of independent loops (the cyclomatic number `E - V + C`).
+    pub loops: usize,
+    /// The average number of passages in a corridor run, a chain of cells with exactly
+    /// two passages between two cells that are not. High values mean long, winding
+    /// corridors ("rivers"); low values mean frequent decisions.
+    pub river_factor: f64,
+    /// Number of passages running east or west.
+    pub horizontal_passages: usize,
+    /// Number of passages running in any other direction.
+    pub vertical_passages: usize,
+    /// Number of passages along the solution, or `None` if the end cannot be reached.
+    pub solution_length: Option<usize>,
+    /// Number of times the solution changes direction.
+    pub solution_turns: usize,
+    /// Fraction of solution moves that continue in the direction of the previous move.
+    pub solution_straightness: f64,
+    /// Number of solution cells, excluding the end, that offer more than one way on.
+    pub decision_points: usize,
+    /// Average number of ways on from the solution cells, excluding the end and the
+    /// passage the solver arrived through.
+    pub branching_factor: f64,
+    /// Sizes of the regions off the solution, reached through a branch of a solution
+    /// cell, in descending order. In perfect mazes these are the dead-end subtrees.
+    pub dead_end_subtrees: Vec<usize>,
+}
+
+impl MazeMetrics {
+    /// Computes metrics for the maze, grading the solution from `start` to `end`.
+    pub fn from_grid<G: MeasurableGrid>(grid: &G, start: G::Coord, end: G::Coord) -> Self {
+        let cells: Vec<_> = grid.walkable_cells().collect();
+        Self::from_cells(grid, &cells, start, end)
+    }
+
+    /// Computes metrics over the enabled cells of the mask only, grading the solution
+    /// from `start` to `end`.
+    ///
+    /// Masked cells are fully walled by mask-aware generators and would otherwise
+    /// be counted as dead ends and disconnected components.
+    pub fn from_grid_masked(
+        grid: &Wall4Grid,
+        mask: &GridMask,
+        start: GridCoord2D,
+        end: GridCoord2D,
+    ) -> Self {
+        let cells: Vec<_> = mask
+            .enabled_cells()
+            .filter(|c| c.x < grid.width() && c.y < grid.height())
+            .collect();
+        Self::from_cells(grid, &cells, start, end)
+    }
+
+    fn from_cells<G: MeasurableGrid>(
+        grid: &G,
+        cells: &[G::Coord],
+        start: G::Coord,
+        end: G::Coord,
+    ) -> Self {
+        let mut dead_ends = 0usize;
+        let mut degree_sum = 0usize;
+        let mut horizontal_passages = 0usize;
+        for &cell in cells {
+            let mut degree = 0usize;
+            for n in grid.walkable_neighbors(cell) {
+                degree += 1;
+                if G::is_horizontal(grid.heading(cell, n)) {
+                    horizontal_passages += 1;
+                }
+            }
+            degree_sum += degree;
+            if degree <= 1 {
+                dead_ends += 1;
+            }
+        }
+
+        let components = connected_components(grid, cells);
+        let loops = (degree_sum / 2 + components).saturating_sub(cells.len());
+
+        let mut metrics = Self {
+            cells: cells.len(),
+            dead_ends,
+            loops,
+            river_factor: river_factor(grid, cells),
+            horizontal_passages: horizontal_passages / 2,
+            vertical_passages: (degree_sum - horizontal_passages) / 2,
+            ..Self::default()
+        };
+
+        if let Some(path) = DistanceMap::new(grid, end).path_to_source(start) {
+            metrics.grade_solution(grid, path.cells());
+        }
+
+        metrics
+    }
+
+    /// Returns the fraction of passages that run horizontally.
+    ///
+    /// Unbiased square mazes score around `0.5`; on hex grids only two of six directions
+    /// are horizontal, so unbiased hex mazes score around `1/3`.
+    pub fn horizontal_bias(&self) -> f64 {
+        let total = self.horizontal_passages + self.vertical_passages;
+        if total == 0 {
+            0.0
+        } else {
+            self.horizontal_passages as f64 / total as f64
+        }
+    }
+
+    fn grade_solution<G: MeasurableGrid>(&mut self, grid: &G, solution: &[G::Coord]) {
+        let moves = solution.len() - 1;
+        self.solution_length = Some(moves);
+
+        let headings: Vec<_> = solution
+            .windows(2)
+            .map(|step| grid.heading(step[0], step[1]))
+            .collect();
+        self.solution_turns = headings.windows(2).filter(|h| h[0] != h[1]).count();
+        self.solution_straightness = if headings.len() < 2 {
+            1.0
+        } else {
+            1.0 - self.solution_turns as f64 / (headings.len() - 1) as f64
+        };
+
+        let mut exits_sum = 0usize;
+        for (i, &cell) in solution[..moves].iter().enumerate() {
+            let exits = grid.walkable_neighbors(cell).count() - usize::from(i > 0);
+            exits_sum += exits;
+            if exits > 1 {
+                self.decision_points += 1;
+            }
+        }
+        self.branching_factor = if moves == 0 {
+            0.0
+        } else {
+            exits_sum as f64 / moves as f64
+        };
+
+        self.dead_end_subtrees = regions_off_solution(grid, solution);
+    }
+}
+
+fn connected_components<G: WalkableGrid>(grid: &G, cells: &[G::Coord]) -> usize {
+    let mut seen = HashSet::new();
+    let mut components = 0usize;
+
+    for &cell in cells {
+        if seen.contains(&cell) {
+            continue;
+        }
+        components += 1;
+        flood(grid, cell, &mut seen);
+    }
+
+    components
+}
+
+/// Marks every cell reachable from `start` without entering a cell already in `seen`,
+/// returning how many cells were marked.
+fn flood<G: WalkableGrid>(grid: &G, start: G::Coord, seen: &mut HashSet<G::Coord>) -> usize {
+    if !seen.insert(start) {
+        return 0;
+    }
+
+    let mut size = 0usize;
+    let mut queue = VecDeque::from([start]);
+    while let Some(cell) = queue.pop_front() {
+        size += 1;
+        for n in grid.walkable_neighbors(cell) {
+            if seen.insert(n) {
+                queue.push_back(n);
+            }
+        }
+    }
+
+    size
+}
+
+/// Averages the number of passages between consecutive cells that are not plain
+/// corridor cells.
+///
+/// Every run is walked from both of its ends, which keeps the average unchanged.
+/// Rings made only of corridor cells count as a single run around the ring.
+fn river_factor<G: WalkableGrid>(grid: &G, cells: &[G::Coord]) -> f64 {
+    let is_corridor = |cell| grid.walkable_neighbors(cell).count() == 2;
+    let mut walked = HashSet::new();
+    let mut runs = 0usize;
+    let mut passages = 0usize;
+
+    for &cell in cells.iter().filter(|&&c| !is_corridor(c)) {
+        for first in grid.walkable_neighbors(cell) {
+            let (mut prev, mut cur) = (cell, first);
+            let mut length = 1usize;
+            while is_corridor(cur) {
+                walked.insert(cur);
+                let next = grid
+                    .walkable_neighbors(cur)
+                    .find(|&n| n != prev)
+                    .unwrap_or(prev);
+                (prev, cur) = (cur, next);
+                length += 1;
+            }
+            runs += 1;
+            passages += length;
+        }
+    }
+
+    for &cell in cells {
+        if is_corridor(cell) && !walked.contains(&cell) {
+            let ring = flood(grid, cell, &mut walked);
+            runs += 2;
+            passages += 2 * ring;
+        }
+    }
+
+    if runs == 0 {
+        0.0
+    } else {
+        passages as f64 / runs as f64
+    }
+}
+
+/// Returns the sizes of the regions entered by leaving the solution through a passage
+/// that is not part of it, in descending order.
+fn regions_off_solution<G: WalkableGrid>(grid: &G, solution: &[G::Coord]) -> Vec<usize> {
+    let mut seen: HashSet<_> = solution.iter().copied().collect();
+    let mut sizes = Vec::new();
+
+    for &cell in solution {
+        for n in grid.walkable_neighbors(cell) {
+            let size = flood(grid, n, &mut seen);
+            if size > 0 {
+                sizes.push(size);
+            }
+        }
+    }
+
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    sizes
+}
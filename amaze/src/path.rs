@@ -1,28 +1,44 @@
 use crate::grid_coord_2d::GridCoord2D;
+use crate::hex_coord::HexCoord;
 
 /// A solution path through a maze.
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+///
+/// The coordinate type defaults to [`GridCoord2D`]; paths through other
+/// topologies carry their grid's [`MazeGrid::Coord`](crate::maze_grid::MazeGrid::Coord).
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Path {
-    cells: Vec<GridCoord2D>,
+pub struct Path<C = GridCoord2D> {
+    cells: Vec<C>,
     pub length: usize,
 }
 
-impl Path {
-    pub fn new(cells: Vec<GridCoord2D>) -> Self {
+/// A solution path through a hexagonal maze.
+pub type HexPath = Path<HexCoord>;
+
+impl<C> Default for Path<C> {
+    fn default() -> Self {
+        Self {
+            cells: Vec::new(),
+            length: 0,
+        }
+    }
+}
+
+impl<C: Copy> Path<C> {
+    pub fn new(cells: Vec<C>) -> Self {
         let length = cells.len();
         Self { cells, length }
     }
 
-    pub fn cells(&self) -> &[GridCoord2D] {
+    pub fn cells(&self) -> &[C] {
         &self.cells
     }
 
-    pub fn start(&self) -> Option<GridCoord2D> {
+    pub fn start(&self) -> Option<C> {
         self.cells.first().copied()
     }
 
-    pub fn end(&self) -> Option<GridCoord2D> {
+    pub fn end(&self) -> Option<C> {
         self.cells.last().copied()
     }
 
@@ -31,18 +47,18 @@ impl Path {
     }
 }
 
-impl IntoIterator for Path {
-    type Item = GridCoord2D;
-    type IntoIter = std::vec::IntoIter<GridCoord2D>;
+impl<C> IntoIterator for Path<C> {
+    type Item = C;
+    type IntoIter = std::vec::IntoIter<C>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.cells.into_iter()
     }
 }
 
-impl<'a> IntoIterator for &'a Path {
-    type Item = &'a GridCoord2D;
-    type IntoIter = std::slice::Iter<'a, GridCoord2D>;
+impl<'a, C> IntoIterator for &'a Path<C> {
+    type Item = &'a C;
+    type IntoIter = std::slice::Iter<'a, C>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.cells.iter()
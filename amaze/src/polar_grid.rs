@@ -0,0 +1,440 @@
+//! Circular (polar or "theta") maze grids.
+//!
+//! A [`PolarGrid`] consists of concentric rings around a single center cell.
+//! Ring `r` has a circumference proportional to `r`, so rings subdivide their
+//! cells outward whenever the cells would otherwise grow too wide; every cell
+//! therefore has one inward neighbor but may have several outward neighbors.
+
+use crate::maze_grid::MazeGrid;
+use crate::stats::MazeStats;
+use std::f64::consts::TAU;
+use std::ops::{Add, AddAssign, SubAssign};
+
+/// The address of a cell in a [`PolarGrid`].
+///
+/// Ring `0` is the center cell; `index` counts clockwise within the ring,
+/// starting at the positive x axis.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PolarCoord {
+    pub ring: usize,
+    pub index: usize,
+}
+
+impl PolarCoord {
+    #[inline]
+    pub fn new(ring: usize, index: usize) -> Self {
+        Self { ring, index }
+    }
+}
+
+/// The walls of a [`PolarGrid`] cell.
+///
+/// [`OUTWARD`](Self::OUTWARD) is set when none of the cell's outward neighbors
+/// can be reached, which is always the case on the outermost ring.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PolarWalls(u8);
+
+impl PolarWalls {
+    /// The wall towards the center.
+    pub const INWARD: Self = Self(0b0001);
+    /// The wall(s) towards the rim.
+    pub const OUTWARD: Self = Self(0b0010);
+    /// The wall towards the next cell in clockwise direction.
+    pub const CLOCKWISE: Self = Self(0b0100);
+    /// The wall towards the next cell in counter-clockwise direction.
+    pub const COUNTER_CLOCKWISE: Self = Self(0b1000);
+    pub const ALL: Self = Self(0b1111);
+    pub const NONE: Self = Self(0b0000);
+
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    pub const fn is_all(self) -> bool {
+        self.0 == Self::ALL.0
+    }
+
+    #[inline]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+}
+
+impl Add for PolarWalls {
+    type Output = Self;
+
+    /// Joins two wall sets.
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl AddAssign for PolarWalls {
+    /// Joins two wall sets while assigning.
+    #[inline]
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl SubAssign for PolarWalls {
+    /// Removes walls from a wall set while assigning.
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 = (self.0 & !rhs.0) & Self::ALL.0;
+    }
+}
+
+impl std::fmt::Debug for PolarWalls {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names = [
+            (Self::INWARD, "INWARD"),
+            (Self::OUTWARD, "OUTWARD"),
+            (Self::CLOCKWISE, "CLOCKWISE"),
+            (Self::COUNTER_CLOCKWISE, "COUNTER_CLOCKWISE"),
+        ];
+        let set: Vec<_> = names
+            .iter()
+            .filter(|(wall, _)| self.contains(*wall))
+            .map(|(_, name)| *name)
+            .collect();
+        write!(f, "PolarWalls({})", set.join(" | "))
+    }
+}
+
+/// A circular maze grid of concentric rings.
+///
+/// Each cell owns two walls: the one towards its inward neighbor and the one
+/// towards its clockwise neighbor. All other walls are owned by the neighbors,
+/// which keeps both sides of a wall consistent.
+///
+/// ## Example
+/// ```
+/// use amaze::preamble::{PolarCoord, PolarGrid};
+///
+/// let grid = PolarGrid::new(4);
+/// assert_eq!(grid.ring_len(0), 1);
+/// assert_eq!(grid.ring_len(1), 6);
+/// assert_eq!(grid.outward(PolarCoord::new(0, 0)).count(), 6);
+/// ```
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PolarGrid {
+    ring_lens: Vec<usize>,
+    offsets: Vec<usize>,
+    walls: Vec<PolarWalls>,
+}
+
+impl PolarGrid {
+    /// Creates a fully walled grid with `rings` rings, including the center cell.
+    pub fn new(rings: usize) -> Self {
+        let mut ring_lens = Vec::with_capacity(rings);
+        for ring in 0..rings {
+            let len = match ring {
+                0 => 1,
+                _ => {
+                    let previous = ring_lens[ring - 1];
+                    // Subdivide when cells would be about twice as wide as they are tall.
+                    let cell_width = TAU * ring as f64 / previous as f64;
+                    previous * (cell_width.round() as usize).max(1)
+                }
+            };
+            ring_lens.push(len);
+        }
+
+        let mut offsets = Vec::with_capacity(rings);
+        let mut total = 0;
+        for &len in &ring_lens {
+            offsets.push(total);
+            total += len;
+        }
+
+        Self {
+            ring_lens,
+            offsets,
+            walls: vec![PolarWalls::INWARD + PolarWalls::CLOCKWISE; total],
+        }
+    }
+
+    /// Returns the number of rings, including the center cell.
+    pub fn rings(&self) -> usize {
+        self.ring_lens.len()
+    }
+
+    /// Returns the number of cells in a ring.
+    pub fn ring_len(&self, ring: usize) -> usize {
+        self.ring_lens[ring]
+    }
+
+    /// Returns the total number of cells.
+    pub fn cell_count(&self) -> usize {
+        self.walls.len()
+    }
+
+    pub fn contains(&self, coord: PolarCoord) -> bool {
+        coord.ring < self.rings() && coord.index < self.ring_lens[coord.ring]
+    }
+
+    pub fn coords(&self) -> impl Iterator<Item = PolarCoord> + '_ {
+        self.ring_lens
+            .iter()
+            .enumerate()
+            .flat_map(|(ring, &len)| (0..len).map(move |index| PolarCoord::new(ring, index)))
+    }
+
+    /// Returns the neighbor towards the center, if any.
+    pub fn inward(&self, cell: PolarCoord) -> Option<PolarCoord> {
+        if cell.ring == 0 {
+            return None;
+        }
+        let ratio = self.ring_lens[cell.ring] / self.ring_lens[cell.ring - 1];
+        Some(PolarCoord::new(cell.ring - 1, cell.index / ratio))
+    }
+
+    /// Returns the neighbors towards the rim; empty on the outermost ring.
+    pub fn outward(&self, cell: PolarCoord) -> impl Iterator<Item = PolarCoord> + '_ {
+        let ring = cell.ring + 1;
+        let ratio = if ring < self.rings() {
+            self.ring_lens[ring] / self.ring_lens[cell.ring]
+        } else {
+            0
+        };
+        (cell.index * ratio..(cell.index + 1) * ratio)
+            .map(move |index| PolarCoord::new(ring, index))
+    }
+
+    /// Returns the next cell in clockwise direction, if the ring has more than one cell.
+    pub fn clockwise(&self, cell: PolarCoord) -> Option<PolarCoord> {
+        let len = self.ring_lens[cell.ring];
+        (len > 1).then(|| PolarCoord::new(cell.ring, (cell.index + 1) % len))
+    }
+
+    /// Returns the next cell in counter-clockwise direction, if the ring has more than one cell.
+    pub fn counter_clockwise(&self, cell: PolarCoord) -> Option<PolarCoord> {
+        let len = self.ring_lens[cell.ring];
+        (len > 1).then(|| PolarCoord::new(cell.ring, (cell.index + len - 1) % len))
+    }
+
+    /// Returns all adjacent cells: inward, clockwise, counter-clockwise, then outward.
+    pub fn neighbors(&self, cell: PolarCoord) -> impl Iterator<Item = PolarCoord> + '_ {
+        self.inward(cell)
+            .into_iter()
+            .chain(self.clockwise(cell))
+            .chain(self.counter_clockwise(cell))
+            .chain(self.outward(cell))
+    }
+
+    /// Returns the adjacent cells that are reachable through an open passage.
+    pub fn open_neighbors(&self, cell: PolarCoord) -> impl Iterator<Item = PolarCoord> + '_ {
+        self.neighbors(cell)
+            .filter(move |&n| !self.has_wall_between(cell, n))
+    }
+
+    /// Tests whether a wall separates two adjacent cells.
+    pub fn has_wall_between(&self, a: PolarCoord, b: PolarCoord) -> bool {
+        let (owner, wall) = self.wall_owner(a, b);
+        self.walls[self.index_of(owner)].contains(wall)
+    }
+
+    /// Returns the walls of a cell, including the ones owned by its neighbors.
+    pub fn walls(&self, cell: PolarCoord) -> PolarWalls {
+        let own = self.walls[self.index_of(cell)];
+        let mut walls = PolarWalls::NONE;
+
+        if own.contains(PolarWalls::INWARD) {
+            walls += PolarWalls::INWARD;
+        }
+        if own.contains(PolarWalls::CLOCKWISE) {
+            walls += PolarWalls::CLOCKWISE;
+        }
+        let ccw_closed = match self.counter_clockwise(cell) {
+            Some(ccw) => self.walls[self.index_of(ccw)].contains(PolarWalls::CLOCKWISE),
+            None => true,
+        };
+        if ccw_closed {
+            walls += PolarWalls::COUNTER_CLOCKWISE;
+        }
+        if self
+            .outward(cell)
+            .all(|n| self.walls[self.index_of(n)].contains(PolarWalls::INWARD))
+        {
+            walls += PolarWalls::OUTWARD;
+        }
+
+        walls
+    }
+
+    pub fn remove_wall_between(&mut self, a: PolarCoord, b: PolarCoord) {
+        let (owner, wall) = self.wall_owner(a, b);
+        let index = self.index_of(owner);
+        self.walls[index] -= wall;
+    }
+
+    /// Adds the wall between two adjacent cells; the inverse of [`remove_wall_between`](Self::remove_wall_between).
+    pub fn add_wall_between(&mut self, a: PolarCoord, b: PolarCoord) {
+        let (owner, wall) = self.wall_owner(a, b);
+        let index = self.index_of(owner);
+        self.walls[index] += wall;
+    }
+
+    pub fn stats(&self) -> MazeStats {
+        MazeStats::from_grid(self)
+    }
+
+    /// Finds the cell owning the wall between two adjacent cells.
+    fn wall_owner(&self, a: PolarCoord, b: PolarCoord) -> (PolarCoord, PolarWalls) {
+        if self.inward(a) == Some(b) {
+            (a, PolarWalls::INWARD)
+        } else if self.inward(b) == Some(a) {
+            (b, PolarWalls::INWARD)
+        } else if self.clockwise(a) == Some(b) {
+            (a, PolarWalls::CLOCKWISE)
+        } else if self.clockwise(b) == Some(a) {
+            (b, PolarWalls::CLOCKWISE)
+        } else {
+            panic!("Cells are not adjacent: {a:?}, {b:?}");
+        }
+    }
+
+    #[inline]
+    fn index_of(&self, coord: PolarCoord) -> usize {
+        debug_assert!(self.contains(coord), "PolarCoord out of bounds");
+        self.offsets[coord.ring] + coord.index
+    }
+}
+
+impl MazeGrid for PolarGrid {
+    type Coord = PolarCoord;
+    type Direction = PolarWalls;
+
+    /// The number of cells in the outermost ring.
+    fn width(&self) -> usize {
+        self.ring_lens.last().copied().unwrap_or(0)
+    }
+
+    /// The number of rings.
+    fn height(&self) -> usize {
+        self.rings()
+    }
+
+    fn cell_count(&self) -> usize {
+        PolarGrid::cell_count(self)
+    }
+
+    fn contains(&self, coord: PolarCoord) -> bool {
+        PolarGrid::contains(self, coord)
+    }
+
+    fn linearize(&self, coord: PolarCoord) -> usize {
+        self.index_of(coord)
+    }
+
+    fn delinearize(&self, index: usize) -> PolarCoord {
+        let ring = self.offsets.partition_point(|&offset| offset <= index) - 1;
+        PolarCoord::new(ring, index - self.offsets[ring])
+    }
+
+    fn coords(&self) -> impl Iterator<Item = PolarCoord> + '_ {
+        PolarGrid::coords(self)
+    }
+
+    fn walls(&self, coord: PolarCoord) -> PolarWalls {
+        PolarGrid::walls(self, coord)
+    }
+
+    fn neighbors(&self, cell: PolarCoord) -> impl Iterator<Item = PolarCoord> + '_ {
+        PolarGrid::neighbors(self, cell)
+    }
+
+    fn open_neighbors(&self, cell: PolarCoord) -> impl Iterator<Item = PolarCoord> + '_ {
+        PolarGrid::open_neighbors(self, cell)
+    }
+
+    fn remove_wall_between(&mut self, a: PolarCoord, b: PolarCoord) {
+        PolarGrid::remove_wall_between(self, a, b)
+    }
+
+    fn add_wall_between(&mut self, a: PolarCoord, b: PolarCoord) {
+        PolarGrid::add_wall_between(self, a, b)
+    }
+
+    /// Every step changes the ring by at most one.
+    fn distance_estimate(&self, a: PolarCoord, b: PolarCoord) -> usize {
+        a.ring.abs_diff(b.ring)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rings_subdivide_outward() {
+        let grid = PolarGrid::new(8);
+        let lens: Vec<_> = (0..grid.rings()).map(|r| grid.ring_len(r)).collect();
+        assert_eq!(lens, vec![1, 6, 12, 24, 24, 24, 48, 48]);
+        assert_eq!(grid.cell_count(), lens.iter().sum::<usize>());
+    }
+
+    #[test]
+    fn neighbors_are_symmetric() {
+        let grid = PolarGrid::new(6);
+        for cell in grid.coords() {
+            for n in grid.neighbors(cell) {
+                assert!(grid.contains(n));
+                assert!(grid.neighbors(n).any(|m| m == cell), "{cell:?} <-> {n:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn new_grid_is_fully_walled() {
+        let grid = PolarGrid::new(4);
+        for cell in grid.coords() {
+            assert!(grid.walls(cell).is_all());
+            assert_eq!(grid.open_neighbors(cell).count(), 0);
+        }
+    }
+
+    #[test]
+    fn carving_updates_both_sides() {
+        let mut grid = PolarGrid::new(4);
+        let center = PolarCoord::new(0, 0);
+        let child = PolarCoord::new(1, 3);
+        let next = PolarCoord::new(1, 4);
+
+        grid.remove_wall_between(center, child);
+        grid.remove_wall_between(next, child);
+        assert!(!grid.walls(child).contains(PolarWalls::INWARD));
+        assert!(!grid.walls(child).contains(PolarWalls::CLOCKWISE));
+        assert!(!grid.walls(next).contains(PolarWalls::COUNTER_CLOCKWISE));
+        assert!(!grid.walls(center).contains(PolarWalls::OUTWARD));
+        assert_eq!(grid.open_neighbors(child).count(), 2);
+
+        grid.add_wall_between(child, next);
+        assert!(grid.walls(next).contains(PolarWalls::COUNTER_CLOCKWISE));
+    }
+
+    #[test]
+    fn delinearize_inverts_linearize() {
+        let grid = PolarGrid::new(5);
+        for (index, cell) in grid.coords().enumerate() {
+            assert_eq!(MazeGrid::linearize(&grid, cell), index);
+            assert_eq!(grid.delinearize(index), cell);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not adjacent")]
+    fn carving_non_adjacent_cells_panics() {
+        let mut grid = PolarGrid::new(4);
+        grid.remove_wall_between(PolarCoord::new(0, 0), PolarCoord::new(2, 0));
+    }
+}
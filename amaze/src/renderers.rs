@@ -1,11 +1,67 @@
+#[cfg(feature = "ansi-renderer")]
+mod ansi_heatmap_renderer;
+#[cfg(feature = "ascii-renderer")]
+mod ascii_renderer;
+#[cfg(feature = "braille-renderer")]
+mod braille_renderer;
+#[cfg(any(feature = "svg-renderer", feature = "png-renderer"))]
+mod drawable_grid;
+#[cfg(any(
+    feature = "svg-renderer",
+    feature = "png-renderer",
+    feature = "ansi-renderer"
+))]
+mod gradient;
+#[cfg(any(feature = "unicode-renderer", feature = "ascii-renderer"))]
+mod hex_text_renderer;
+#[cfg(any(feature = "svg-renderer", feature = "png-renderer"))]
+mod image_style;
 #[cfg(feature = "pgm-renderer")]
 mod pgm_renderer;
+#[cfg(feature = "png-renderer")]
+mod png_renderer;
+#[cfg(feature = "polar-renderer")]
+mod polar_svg_renderer;
+#[cfg(feature = "svg-renderer")]
+mod svg_renderer;
+#[cfg(feature = "triangle-renderer")]
+mod triangle_renderer;
 #[cfg(feature = "unicode-renderer")]
 mod unicode_renderer;
 
+#[cfg(feature = "ansi-renderer")]
+pub use ansi_heatmap_renderer::AnsiHeatmapRenderer;
+#[cfg(feature = "ascii-renderer")]
+pub use ascii_renderer::AsciiRenderer;
+#[cfg(feature = "braille-renderer")]
+pub use braille_renderer::BrailleRenderer;
+#[cfg(any(feature = "svg-renderer", feature = "png-renderer"))]
+pub use drawable_grid::{DrawableGrid, Point, Segment, StrokeCap};
+#[cfg(any(
+    feature = "svg-renderer",
+    feature = "png-renderer",
+    feature = "ansi-renderer"
+))]
+pub use gradient::Gradient;
+#[cfg(feature = "ascii-renderer")]
+pub use hex_text_renderer::HexAsciiRenderer;
+#[cfg(any(feature = "unicode-renderer", feature = "ascii-renderer"))]
+pub use hex_text_renderer::HexLayout;
+#[cfg(feature = "unicode-renderer")]
+pub use hex_text_renderer::HexUnicodeRenderer;
+#[cfg(any(feature = "svg-renderer", feature = "png-renderer"))]
+pub use image_style::ImageStyle;
 #[cfg(feature = "pgm-renderer")]
 pub use pgm_renderer::{ImageFormat, ImageRenderer};
+#[cfg(feature = "png-renderer")]
+pub use png_renderer::PngRenderer;
+#[cfg(feature = "polar-renderer")]
+pub use polar_svg_renderer::PolarSvgRenderer;
 use std::str::FromStr;
+#[cfg(feature = "svg-renderer")]
+pub use svg_renderer::SvgRenderer;
+#[cfg(feature = "triangle-renderer")]
+pub use triangle_renderer::TriangleRenderer;
 #[cfg(feature = "unicode-renderer")]
 pub use unicode_renderer::{UnicodeRenderStyle, UnicodeRenderer};
 
@@ -16,6 +72,15 @@ pub enum RenderStyle {
 
     #[cfg(feature = "pgm-renderer")]
     Image(ImageFormat),
+
+    #[cfg(feature = "png-renderer")]
+    Png,
+
+    #[cfg(feature = "ascii-renderer")]
+    Ascii,
+
+    #[cfg(feature = "braille-renderer")]
+    Braille,
 }
 
 #[cfg(feature = "unicode-renderer")]
@@ -42,8 +107,14 @@ impl FromStr for RenderStyle {
             "ppm" => Ok(RenderStyle::Image(ImageFormat::PPM)),
             #[cfg(feature = "pgm-renderer")]
             "pbm" => Ok(RenderStyle::Image(ImageFormat::PBM)),
+            #[cfg(feature = "png-renderer")]
+            "png" => Ok(RenderStyle::Png),
+            #[cfg(feature = "ascii-renderer")]
+            "ascii" => Ok(RenderStyle::Ascii),
+            #[cfg(feature = "braille-renderer")]
+            "braille" => Ok(RenderStyle::Braille),
             _ => Err(format!(
-                "Invalid style '{}'. Valid styles are: heavy, thin, double, hex (with feature unicode-renderer); ppm, pbm (with feature pgm-renderer).",
+                "Invalid style '{}'. Valid styles are: heavy, thin, double, hex (with feature unicode-renderer); ppm, pbm (with feature pgm-renderer); png (with feature png-renderer); ascii (with feature ascii-renderer); braille (with feature braille-renderer).",
                 input
             )),
         }
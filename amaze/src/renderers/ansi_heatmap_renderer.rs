@@ -0,0 +1,230 @@
+use crate::direction4::Direction4;
+use crate::distance_map::DistanceMap;
+use crate::dungeon::{DungeonGrid, TileType};
+use crate::grid_coord_2d::{GetCoordinateBounds2D, GridCoord2D};
+use crate::hex_coord::HexCoord;
+use crate::renderers::Gradient;
+use crate::wall4_grid::Wall4Grid;
+use crate::wall6_grid::Wall6Grid;
+use std::fmt::Write;
+
+type Color = (u8, u8, u8);
+
+/// Renderer for distance heatmaps in terminals that support 24-bit ANSI colors.
+///
+/// Cells are drawn as two spaces on a true-color background, which is about square
+/// in most terminal fonts. Every reachable cell is colored by its distance from the
+/// nearest source of a [`DistanceMap`]; unreachable cells keep the background color.
+pub struct AnsiHeatmapRenderer {
+    wall_color: Color,
+    background_color: Color,
+    solid_color: Color,
+    gradient: Gradient,
+}
+
+impl Default for AnsiHeatmapRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnsiHeatmapRenderer {
+    /// Creates a new renderer with black walls and the default [`Gradient`].
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::renderers::AnsiHeatmapRenderer;
+    ///
+    /// let renderer = AnsiHeatmapRenderer::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            wall_color: (0, 0, 0),
+            background_color: (255, 255, 255),
+            solid_color: (200, 200, 210), // Default: Light gray dungeon rock
+            gradient: Gradient::default(),
+        }
+    }
+
+    /// Sets custom colors for walls and for cells that cannot be reached.
+    pub fn set_colors(&mut self, wall_color: Color, background_color: Color) {
+        self.wall_color = wall_color;
+        self.background_color = background_color;
+    }
+
+    /// Sets the color of dungeon wall tiles.
+    pub fn set_solid_color(&mut self, solid_color: Color) {
+        self.solid_color = solid_color;
+    }
+
+    /// Sets the gradient that colors cells, from the source to the farthest cell.
+    pub fn set_gradient(&mut self, gradient: Gradient) {
+        self.gradient = gradient;
+    }
+
+    /// Renders a square maze with a block between every two cells that shows the wall,
+    /// or the blended colors of both cells where the passage is open.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::generators::RecursiveBacktracker4;
+    /// use amaze::preamble::*;
+    /// use amaze::renderers::AnsiHeatmapRenderer;
+    ///
+    /// let maze = RecursiveBacktracker4::new_from_seed(3).generate(8, 8);
+    /// let distances = DistanceMap::from_diameter_end(&maze, GridCoord2D::new(0, 0));
+    /// let text = AnsiHeatmapRenderer::new().render(&maze, &distances);
+    /// assert_eq!(text.lines().count(), 2 * 8 + 1);
+    /// ```
+    pub fn render(&self, grid: &Wall4Grid, distances: &DistanceMap) -> String {
+        let (width, height) = (grid.width(), grid.height());
+        let max_distance = distances.max_distance().unwrap_or(0);
+        let heat = |cells: &[Option<GridCoord2D>]| {
+            let reached: Vec<_> = cells
+                .iter()
+                .flatten()
+                .filter_map(|&cell| distances.distance(cell))
+                .collect();
+            if reached.is_empty() {
+                return self.background_color;
+            }
+            let mean = reached.iter().sum::<usize>() as f64 / reached.len() as f64;
+            if max_distance == 0 {
+                self.gradient.sample(0.0)
+            } else {
+                self.gradient.sample(mean / max_distance as f64)
+            }
+        };
+
+        let mut rows = Vec::with_capacity(2 * height + 1);
+        for by in 0..=2 * height {
+            let mut row = Vec::with_capacity(2 * width + 1);
+            for bx in 0..=2 * width {
+                let color = match (bx % 2, by % 2) {
+                    (0, 0) => self.wall_color,
+                    (1, 1) => heat(&[Some(GridCoord2D::new(bx / 2, by / 2))]),
+                    (0, _) => {
+                        let (x, y) = (bx / 2, by / 2);
+                        let west = x.checked_sub(1).map(|x| GridCoord2D::new(x, y));
+                        let east = (x < width).then(|| GridCoord2D::new(x, y));
+                        let closed = match (west, east) {
+                            (Some(west), _) => grid[west].contains(Direction4::EAST),
+                            (None, Some(east)) => grid[east].contains(Direction4::WEST),
+                            (None, None) => true,
+                        };
+                        self.passage(closed, || heat(&[west, east]))
+                    }
+                    _ => {
+                        let (x, y) = (bx / 2, by / 2);
+                        let north = y.checked_sub(1).map(|y| GridCoord2D::new(x, y));
+                        let south = (y < height).then(|| GridCoord2D::new(x, y));
+                        let closed = match (north, south) {
+                            (Some(north), _) => grid[north].contains(Direction4::SOUTH),
+                            (None, Some(south)) => grid[south].contains(Direction4::NORTH),
+                            (None, None) => true,
+                        };
+                        self.passage(closed, || heat(&[north, south]))
+                    }
+                };
+                row.extend([Some(color); 2]);
+            }
+            rows.push(row);
+        }
+        write_rows(&rows)
+    }
+
+    /// Renders a hex maze as a parallelogram of colored cells, each row shifted half a
+    /// cell to the right of the previous one. Walls are not drawn; the colors alone
+    /// trace the passages.
+    pub fn render_hex(&self, grid: &Wall6Grid, distances: &DistanceMap<HexCoord>) -> String {
+        let max_distance = distances.max_distance().unwrap_or(0);
+        let rows: Vec<_> = (0..grid.height())
+            .map(|r| {
+                let mut row = vec![None; r];
+                for q in 0..grid.width() {
+                    let cell = HexCoord::new(q as isize, r as isize);
+                    let color = distances.distance(cell).map_or(self.background_color, |d| {
+                        self.gradient.sample_distance(d, max_distance)
+                    });
+                    row.extend([Some(color); 2]);
+                }
+                row
+            })
+            .collect();
+        write_rows(&rows)
+    }
+
+    /// Renders a dungeon with floor tiles colored by distance, wall tiles in the solid
+    /// color and empty tiles left uncolored.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::preamble::*;
+    /// use amaze::renderers::AnsiHeatmapRenderer;
+    ///
+    /// let mut dungeon = DungeonGrid::new(5, 3);
+    /// for x in 1..4 {
+    ///     dungeon.set(GridCoord2D::new(x, 1), TileType::Floor);
+    /// }
+    /// dungeon.place_walls();
+    ///
+    /// let distances = DistanceMap::new(&dungeon, GridCoord2D::new(1, 1));
+    /// let text = AnsiHeatmapRenderer::new().render_dungeon(&dungeon, &distances);
+    /// assert_eq!(text.lines().count(), 3);
+    /// ```
+    pub fn render_dungeon(&self, dungeon: &DungeonGrid, distances: &DistanceMap) -> String {
+        let max_distance = distances.max_distance().unwrap_or(0);
+        let rows: Vec<_> = (0..dungeon.height())
+            .map(|y| {
+                let mut row = Vec::with_capacity(2 * dungeon.width());
+                for x in 0..dungeon.width() {
+                    let cell = GridCoord2D::new(x, y);
+                    let color = match dungeon.get(cell) {
+                        Some(TileType::Floor) => {
+                            Some(distances.distance(cell).map_or(self.background_color, |d| {
+                                self.gradient.sample_distance(d, max_distance)
+                            }))
+                        }
+                        Some(TileType::Wall) => Some(self.solid_color),
+                        _ => None,
+                    };
+                    row.extend([color; 2]);
+                }
+                row
+            })
+            .collect();
+        write_rows(&rows)
+    }
+
+    /// Returns the wall color for a closed passage, and the color of the cells it
+    /// joins for an open one.
+    fn passage(&self, closed: bool, open: impl FnOnce() -> Color) -> Color {
+        if closed { self.wall_color } else { open() }
+    }
+}
+
+/// Writes rows of character cells with the given background colors, or the terminal's
+/// own background for `None`. Escape codes are only written where the color changes.
+fn write_rows(rows: &[Vec<Option<Color>>]) -> String {
+    let mut text = String::new();
+    for row in rows {
+        let mut current = None;
+        for &color in row {
+            if color != current {
+                match color {
+                    Some((r, g, b)) => {
+                        let _ = write!(text, "\x1b[48;2;{r};{g};{b}m");
+                    }
+                    None => text.push_str("\x1b[0m"),
+                }
+                current = color;
+            }
+            text.push(' ');
+        }
+        if current.is_some() {
+            text.push_str("\x1b[0m");
+        }
+        text.push('\n');
+    }
+    text
+}
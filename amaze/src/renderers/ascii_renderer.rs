@@ -0,0 +1,130 @@
+use crate::direction4::Direction4;
+use crate::grid_coord_2d::GridCoord2D;
+use crate::renderers::HexAsciiRenderer;
+use crate::wall4_grid::Wall4Grid;
+use crate::wall6_grid::Wall6Grid;
+
+/// Renderer for classic plain-ASCII mazes that survive being pasted into tickets,
+/// e-mails and code comments.
+///
+/// Square mazes use the `+---+` / `|   |` layout with a `+` on every corner. Hex
+/// mazes are drawn with pointy-top `/ \` outlines, each row shifted half a cell to
+/// the right of the previous one.
+pub struct AsciiRenderer {
+    cell_width: usize,
+}
+
+impl Default for AsciiRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AsciiRenderer {
+    /// Creates a new renderer with cells three characters wide.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::renderers::AsciiRenderer;
+    ///
+    /// let renderer = AsciiRenderer::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { cell_width: 3 }
+    }
+
+    /// Sets the number of characters between two walls. Widths below one are raised
+    /// to one.
+    ///
+    /// Hex cells are an odd number of characters wide, and at least three, so other
+    /// widths are rounded up for them. Their slanted edges grow with the width.
+    pub fn set_cell_width(&mut self, cell_width: usize) {
+        self.cell_width = cell_width.max(1);
+    }
+
+    /// Renders a square maze.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::preamble::{GridCoord2D, Wall4Grid};
+    /// use amaze::renderers::AsciiRenderer;
+    ///
+    /// let mut grid = Wall4Grid::new(2, 2);
+    /// grid.remove_wall_between(GridCoord2D::new(0, 0), GridCoord2D::new(1, 0));
+    /// grid.remove_wall_between(GridCoord2D::new(1, 0), GridCoord2D::new(1, 1));
+    ///
+    /// let expected = "\
+    /// +---+---+
+    /// |       |
+    /// +---+   +
+    /// |   |   |
+    /// +---+---+
+    /// ";
+    /// assert_eq!(AsciiRenderer::new().render(&grid), expected);
+    /// ```
+    pub fn render(&self, grid: &Wall4Grid) -> String {
+        let (width, height) = (grid.width(), grid.height());
+        let closed = |cell: GridCoord2D, dir: Direction4| grid[cell].contains(dir);
+        let horizontal = |is_closed: bool| if is_closed { '-' } else { ' ' };
+        let vertical = |is_closed: bool| if is_closed { '|' } else { ' ' };
+
+        let mut text = String::new();
+        for y in 0..height {
+            for x in 0..width {
+                text.push('+');
+                let wall = closed(GridCoord2D::new(x, y), Direction4::NORTH);
+                text.extend(std::iter::repeat_n(horizontal(wall), self.cell_width));
+            }
+            text.push_str("+\n");
+
+            for x in 0..width {
+                text.push(vertical(closed(GridCoord2D::new(x, y), Direction4::WEST)));
+                text.extend(std::iter::repeat_n(' ', self.cell_width));
+            }
+            if width > 0 {
+                text.push(vertical(closed(
+                    GridCoord2D::new(width - 1, y),
+                    Direction4::EAST,
+                )));
+            }
+            text.push('\n');
+        }
+
+        for x in 0..width {
+            text.push('+');
+            let wall = height == 0 || closed(GridCoord2D::new(x, height - 1), Direction4::SOUTH);
+            text.extend(std::iter::repeat_n(horizontal(wall), self.cell_width));
+        }
+        text.push_str("+\n");
+        text
+    }
+
+    /// Renders a hex maze with pointy `/ \` outlines. Use [`HexAsciiRenderer`] for
+    /// flat hexagons.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::preamble::{HexCoord, Wall6Grid};
+    /// use amaze::renderers::AsciiRenderer;
+    ///
+    /// let mut grid = Wall6Grid::new(2, 2);
+    /// grid.remove_wall_between(HexCoord::new(0, 0), HexCoord::new(1, 0));
+    /// grid.remove_wall_between(HexCoord::new(1, 0), HexCoord::new(0, 1));
+    /// grid.remove_wall_between(HexCoord::new(0, 1), HexCoord::new(1, 1));
+    ///
+    /// let text = AsciiRenderer::new().render_hex(&grid);
+    /// let expected = [
+    ///     r" / \ / \",
+    ///     r"|       |",
+    ///     r" \ /   / \",
+    ///     r"  |       |",
+    ///     r"   \ / \ /",
+    /// ];
+    /// assert_eq!(text.lines().collect::<Vec<_>>(), expected);
+    /// ```
+    pub fn render_hex(&self, grid: &Wall6Grid) -> String {
+        let mut renderer = HexAsciiRenderer::new();
+        renderer.set_cell_size(self.cell_width);
+        renderer.render(grid)
+    }
+}
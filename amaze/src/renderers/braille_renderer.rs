@@ -0,0 +1,117 @@
+use crate::direction4::Direction4;
+use crate::grid_coord_2d::GridCoord2D;
+use crate::wall4_grid::Wall4Grid;
+
+/// The first Braille pattern, with no dots raised.
+const BRAILLE_BLANK: u32 = 0x2800;
+
+/// The bit of each dot of a Braille pattern, indexed by row and column.
+const BRAILLE_DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// Renderer that packs mazes into Unicode Braille patterns, for mazes too large for
+/// one character per cell.
+///
+/// The maze is first drawn as a bitmap in which every cell, wall and post is one
+/// pixel, so a maze `w` cells wide is `2w + 1` pixels wide. Each character then shows
+/// a block of 2x4 pixels as raised dots.
+pub struct BrailleRenderer {
+    inverted: bool,
+}
+
+impl Default for BrailleRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BrailleRenderer {
+    /// Creates a new renderer that raises the dots of walls.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::renderers::BrailleRenderer;
+    ///
+    /// let renderer = BrailleRenderer::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { inverted: false }
+    }
+
+    /// Raises the dots of passages instead of walls, which reads better as light text
+    /// on a dark terminal.
+    pub fn set_inverted(&mut self, inverted: bool) {
+        self.inverted = inverted;
+    }
+
+    /// Renders a square maze.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::generators::RecursiveBacktracker4;
+    /// use amaze::renderers::BrailleRenderer;
+    ///
+    /// let maze = RecursiveBacktracker4::new_from_seed(7).generate(40, 20);
+    /// let text = BrailleRenderer::new().render(&maze);
+    ///
+    /// // 81x41 pixels fit into 41 characters in 11 lines.
+    /// assert_eq!(text.lines().count(), 11);
+    /// assert!(text.lines().all(|line| line.chars().count() == 41));
+    /// ```
+    pub fn render(&self, grid: &Wall4Grid) -> String {
+        let pixels = wall_pixels(grid);
+        let height = pixels.len();
+        let width = pixels.first().map_or(0, Vec::len);
+        let raised = |x: usize, y: usize| x < width && y < height && pixels[y][x] != self.inverted;
+
+        let mut text = String::new();
+        for y in (0..height).step_by(4) {
+            for x in (0..width).step_by(2) {
+                let mut pattern = BRAILLE_BLANK;
+                for (dy, row) in BRAILLE_DOTS.iter().enumerate() {
+                    for (dx, bit) in row.iter().enumerate() {
+                        if raised(x + dx, y + dy) {
+                            pattern |= bit;
+                        }
+                    }
+                }
+                text.push(char::from_u32(pattern).expect("Braille patterns are characters"));
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+/// Draws the maze as a bitmap of `2w + 1` by `2h + 1` pixels, `true` where there is a
+/// wall. Posts between cells are always walls.
+fn wall_pixels(grid: &Wall4Grid) -> Vec<Vec<bool>> {
+    let (width, height) = (grid.width(), grid.height());
+    let mut pixels = vec![vec![false; 2 * width + 1]; 2 * height + 1];
+    for (y, row) in pixels.iter_mut().enumerate() {
+        for (x, pixel) in row.iter_mut().enumerate() {
+            *pixel = match (x % 2, y % 2) {
+                (0, 0) => true,
+                (1, 1) => false,
+                (0, _) => {
+                    let cell = GridCoord2D::new(x.saturating_sub(1) / 2, y / 2);
+                    let dir = if x == 0 {
+                        Direction4::WEST
+                    } else {
+                        Direction4::EAST
+                    };
+                    grid.get(cell).is_none_or(|walls| walls.contains(dir))
+                }
+                _ => {
+                    let cell = GridCoord2D::new(x / 2, y.saturating_sub(1) / 2);
+                    let dir = if y == 0 {
+                        Direction4::NORTH
+                    } else {
+                        Direction4::SOUTH
+                    };
+                    grid.get(cell).is_none_or(|walls| walls.contains(dir))
+                }
+            };
+        }
+    }
+    pixels
+}
@@ -0,0 +1,308 @@
+use crate::direction4::Direction4;
+use crate::direction6::Direction6;
+use crate::dungeon::{DungeonGrid, TileType};
+use crate::grid_coord_2d::{GetCoordinateBounds2D, GridCoord2D};
+use crate::hex_coord::HexCoord;
+use crate::wall4_grid::Wall4Grid;
+use crate::wall6_grid::Wall6Grid;
+use std::hash::Hash;
+
+/// A point in drawing coordinates, measured in cells.
+pub type Point = (f64, f64);
+
+/// A wall segment between two points.
+pub type Segment = (Point, Point);
+
+/// The shape drawn at the ends of wall strokes.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum StrokeCap {
+    /// Walls end flush with their corner points.
+    Butt,
+    /// Walls end in a half circle, which joins corners seamlessly.
+    #[default]
+    Round,
+    /// Walls extend past their corner points by half the wall thickness.
+    Square,
+}
+
+impl StrokeCap {
+    /// Returns the SVG `stroke-linecap` value.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StrokeCap::Butt => "butt",
+            StrokeCap::Round => "round",
+            StrokeCap::Square => "square",
+        }
+    }
+}
+
+/// The geometry of a grid as drawn by the vector and raster image renderers.
+///
+/// All coordinates are measured in cells, with the origin in the top-left corner
+/// and y pointing down; renderers scale them by their cell size.
+///
+/// Implemented by [`Wall4Grid`], [`Wall6Grid`] (pointy-top hexagons, one cell wide
+/// from vertex to vertex) and [`DungeonGrid`].
+pub trait DrawableGrid {
+    /// The coordinate type of the grid's cells.
+    type Coord: Copy + Eq + Hash;
+
+    /// Returns the width and height of the drawing.
+    fn extent(&self) -> (f64, f64);
+
+    /// Returns the center of a cell.
+    fn center(&self, cell: Self::Coord) -> Point;
+
+    /// Returns the outline of a cell, which heatmaps fill.
+    fn cell_outline(&self, cell: Self::Coord) -> Vec<Point>;
+
+    /// Returns every closed wall exactly once.
+    fn wall_segments(&self) -> Vec<Segment>;
+
+    /// Returns the outlines of areas that are solid rock rather than open space,
+    /// such as the wall tiles of a dungeon.
+    fn solid_areas(&self) -> Vec<Vec<Point>> {
+        Vec::new()
+    }
+
+    /// Returns the point on the border where a step from `from` to the adjacent cell
+    /// `to` leaves the drawing, if the step crosses the joined edges of a wrapped grid.
+    fn wrap_exit(&self, _from: Self::Coord, _to: Self::Coord) -> Option<Point> {
+        None
+    }
+}
+
+/// Splits a path into the runs of points drawn through the cell centers. Steps across
+/// the joined edges of wrapped grids end one run at the border they leave through and
+/// start the next one at the border they come back in through.
+pub(crate) fn route_runs<G: DrawableGrid>(grid: &G, cells: &[G::Coord]) -> Vec<Vec<Point>> {
+    let Some(&first) = cells.first() else {
+        return Vec::new();
+    };
+
+    let mut runs = vec![vec![grid.center(first)]];
+    for step in cells.windows(2) {
+        let (a, b) = (step[0], step[1]);
+        if let (Some(exit), Some(entry)) = (grid.wrap_exit(a, b), grid.wrap_exit(b, a)) {
+            runs.last_mut().expect("runs are never empty").push(exit);
+            runs.push(vec![entry]);
+        }
+        runs.last_mut()
+            .expect("runs are never empty")
+            .push(grid.center(b));
+    }
+    runs
+}
+
+/// Each cell draws its east and south walls; the north and west walls only on the
+/// first row and column, where no neighbor shares them.
+impl DrawableGrid for Wall4Grid {
+    type Coord = GridCoord2D;
+
+    fn extent(&self) -> (f64, f64) {
+        (self.width() as f64, self.height() as f64)
+    }
+
+    fn center(&self, cell: GridCoord2D) -> Point {
+        (cell.x as f64 + 0.5, cell.y as f64 + 0.5)
+    }
+
+    fn cell_outline(&self, cell: GridCoord2D) -> Vec<Point> {
+        square_outline(cell)
+    }
+
+    /// Steps between cells that are not next to each other in the drawing wrap around.
+    fn wrap_exit(&self, from: GridCoord2D, to: GridCoord2D) -> Option<Point> {
+        if from.x.abs_diff(to.x) + from.y.abs_diff(to.y) == 1 {
+            return None;
+        }
+        let dir = Direction4::CARDINALS
+            .into_iter()
+            .find(|&dir| self.neighbor(from, dir) == Some(to))?;
+        let (x, y) = self.center(from);
+        Some(match dir {
+            Direction4::NORTH => (x, y - 0.5),
+            Direction4::SOUTH => (x, y + 0.5),
+            Direction4::EAST => (x + 0.5, y),
+            _ => (x - 0.5, y),
+        })
+    }
+
+    fn wall_segments(&self) -> Vec<Segment> {
+        let mut segments = Vec::new();
+        for cell in self.coords() {
+            let walls = self[cell];
+            let (x0, y0) = (cell.x as f64, cell.y as f64);
+            let (x1, y1) = (x0 + 1.0, y0 + 1.0);
+
+            if cell.y == 0 && walls.contains(Direction4::NORTH) {
+                segments.push(((x0, y0), (x1, y0)));
+            }
+            if cell.x == 0 && walls.contains(Direction4::WEST) {
+                segments.push(((x0, y0), (x0, y1)));
+            }
+            if walls.contains(Direction4::EAST) {
+                segments.push(((x1, y0), (x1, y1)));
+            }
+            if walls.contains(Direction4::SOUTH) {
+                segments.push(((x0, y1), (x1, y1)));
+            }
+        }
+        segments
+    }
+}
+
+/// Horizontal distance between the centers of neighboring hexagons.
+const HEX_SPACING_X: f64 = 0.866_025_403_784_438_6; // sqrt(3) / 2
+
+/// Vertical distance between the centers of neighboring hexagon rows.
+const HEX_SPACING_Y: f64 = 0.75;
+
+/// The walls of a hexagon, each paired with the corners it runs between. Corners are
+/// numbered clockwise from the lower right, as returned by `hex_corners`.
+const HEX_EDGES: [(Direction6, usize, usize); 6] = [
+    (Direction6::EAST, 5, 0),
+    (Direction6::SE, 0, 1),
+    (Direction6::SW, 1, 2),
+    (Direction6::WEST, 2, 3),
+    (Direction6::NW, 3, 4),
+    (Direction6::NE, 4, 5),
+];
+
+/// Each cell draws its east, south-east and south-west walls; the other three only
+/// where the neighbor sharing them lies outside the grid.
+impl DrawableGrid for Wall6Grid {
+    type Coord = HexCoord;
+
+    fn extent(&self) -> (f64, f64) {
+        if self.width() == 0 || self.height() == 0 {
+            return (0.0, 0.0);
+        }
+        let rows = (self.height() - 1) as f64;
+        (
+            self.width() as f64 * HEX_SPACING_X + rows * HEX_SPACING_X / 2.0,
+            rows * HEX_SPACING_Y + 1.0,
+        )
+    }
+
+    fn center(&self, cell: HexCoord) -> Point {
+        (
+            (cell.q as f64 + cell.r as f64 / 2.0 + 0.5) * HEX_SPACING_X,
+            cell.r as f64 * HEX_SPACING_Y + 0.5,
+        )
+    }
+
+    fn cell_outline(&self, cell: HexCoord) -> Vec<Point> {
+        hex_corners(self.center(cell)).to_vec()
+    }
+
+    /// The step leaves halfway to where the neighbor would lie without wrapping.
+    fn wrap_exit(&self, from: HexCoord, to: HexCoord) -> Option<Point> {
+        if from.distance(to) == 1 {
+            return None;
+        }
+        let dir = Direction6::CARDINALS
+            .into_iter()
+            .find(|&dir| self.neighbor(from, dir) == Some(to))?;
+        let ((ax, ay), (bx, by)) = (self.center(from), self.center(from.neighbor(dir)));
+        Some(((ax + bx) / 2.0, (ay + by) / 2.0))
+    }
+
+    fn wall_segments(&self) -> Vec<Segment> {
+        let (width, height) = (self.width(), self.height());
+        let mut segments = Vec::new();
+        for cell in self.coords() {
+            let walls = self[cell];
+            let corners = hex_corners(self.center(cell));
+            for (dir, a, b) in HEX_EDGES {
+                let shared = matches!(dir, Direction6::WEST | Direction6::NW | Direction6::NE)
+                    && cell.try_neighbor(dir, width, height).is_some();
+                if walls.contains(dir) && !shared {
+                    segments.push((corners[a], corners[b]));
+                }
+            }
+        }
+        segments
+    }
+}
+
+/// Returns the corners of a pointy-top hexagon, clockwise from the lower right.
+fn hex_corners((cx, cy): Point) -> [Point; 6] {
+    std::array::from_fn(|i| {
+        let angle = (30.0 + 60.0 * i as f64).to_radians();
+        (cx + 0.5 * angle.cos(), cy + 0.5 * angle.sin())
+    })
+}
+
+/// Walls run along every edge between a floor tile and a tile that is not floor;
+/// wall tiles are solid.
+impl DrawableGrid for DungeonGrid {
+    type Coord = GridCoord2D;
+
+    fn extent(&self) -> (f64, f64) {
+        (self.width() as f64, self.height() as f64)
+    }
+
+    fn center(&self, cell: GridCoord2D) -> Point {
+        (cell.x as f64 + 0.5, cell.y as f64 + 0.5)
+    }
+
+    fn cell_outline(&self, cell: GridCoord2D) -> Vec<Point> {
+        square_outline(cell)
+    }
+
+    fn wall_segments(&self) -> Vec<Segment> {
+        let is_floor = |x: Option<usize>, y: Option<usize>| match (x, y) {
+            (Some(x), Some(y)) => self.is_floor(GridCoord2D::new(x, y)),
+            _ => false,
+        };
+
+        let mut segments = Vec::new();
+        for (x, y) in tiles(self) {
+            if !is_floor(Some(x), Some(y)) {
+                continue;
+            }
+            let (x0, y0) = (x as f64, y as f64);
+            let (x1, y1) = (x0 + 1.0, y0 + 1.0);
+
+            if !is_floor(Some(x), y.checked_sub(1)) {
+                segments.push(((x0, y0), (x1, y0)));
+            }
+            if !is_floor(x.checked_sub(1), Some(y)) {
+                segments.push(((x0, y0), (x0, y1)));
+            }
+            if !is_floor(Some(x + 1), Some(y)) {
+                segments.push(((x1, y0), (x1, y1)));
+            }
+            if !is_floor(Some(x), Some(y + 1)) {
+                segments.push(((x0, y1), (x1, y1)));
+            }
+        }
+        segments
+    }
+
+    fn solid_areas(&self) -> Vec<Vec<Point>> {
+        tiles(self)
+            .filter(|&(x, y)| self.get(GridCoord2D::new(x, y)) == Some(TileType::Wall))
+            .map(|(x, y)| square_outline(GridCoord2D::new(x, y)))
+            .collect()
+    }
+}
+
+/// Returns the corners of a square cell, clockwise from the top left.
+fn square_outline(cell: GridCoord2D) -> Vec<Point> {
+    let (x0, y0) = (cell.x as f64, cell.y as f64);
+    vec![
+        (x0, y0),
+        (x0 + 1.0, y0),
+        (x0 + 1.0, y0 + 1.0),
+        (x0, y0 + 1.0),
+    ]
+}
+
+/// Returns the positions of all dungeon tiles in row-major order, so that drawings
+/// are deterministic.
+fn tiles(grid: &DungeonGrid) -> impl Iterator<Item = (usize, usize)> + use<> {
+    let (width, height) = (grid.width(), grid.height());
+    (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+}
@@ -0,0 +1,126 @@
+use std::str::FromStr;
+
+/// A color gradient that maps values between 0 and 1 to colors, used to color
+/// heatmaps by distance.
+///
+/// The colors are evenly spaced stops that are interpolated linearly. The default
+/// is [`Gradient::viridis`], which stays readable for color-blind viewers and in
+/// grayscale.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gradient {
+    stops: Vec<(u8, u8, u8)>,
+}
+
+impl Default for Gradient {
+    fn default() -> Self {
+        Self::viridis()
+    }
+}
+
+impl Gradient {
+    /// Creates a gradient through the given colors, from the start to the end.
+    ///
+    /// ## Panics
+    /// Panics if `stops` is empty.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::renderers::Gradient;
+    ///
+    /// let gradient = Gradient::new(vec![(0, 0, 0), (255, 255, 255)]);
+    /// assert_eq!(gradient.sample(0.5), (128, 128, 128));
+    /// ```
+    pub fn new(stops: Vec<(u8, u8, u8)>) -> Self {
+        assert!(!stops.is_empty(), "a gradient needs at least one color");
+        Self { stops }
+    }
+
+    /// Dark purple through blue and green to yellow.
+    pub fn viridis() -> Self {
+        Self::new(vec![
+            (68, 1, 84),
+            (59, 82, 139),
+            (33, 145, 140),
+            (94, 201, 98),
+            (253, 231, 37),
+        ])
+    }
+
+    /// Black through red and yellow to white.
+    pub fn heat() -> Self {
+        Self::new(vec![
+            (0, 0, 0),
+            (200, 30, 0),
+            (255, 200, 0),
+            (255, 255, 255),
+        ])
+    }
+
+    /// White to black.
+    pub fn grayscale() -> Self {
+        Self::new(vec![(255, 255, 255), (0, 0, 0)])
+    }
+
+    /// Returns the colors the gradient runs through.
+    pub fn stops(&self) -> &[(u8, u8, u8)] {
+        &self.stops
+    }
+
+    /// Returns the color at `t`, which is clamped to `0..=1`.
+    pub fn sample(&self, t: f64) -> (u8, u8, u8) {
+        let position = t.clamp(0.0, 1.0) * (self.stops.len() - 1) as f64;
+        let index = (position.floor() as usize).min(self.stops.len() - 1);
+        let (a, b) = (
+            self.stops[index],
+            self.stops[(index + 1).min(self.stops.len() - 1)],
+        );
+        let f = position - index as f64;
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * f).round() as u8;
+        (mix(a.0, b.0), mix(a.1, b.1), mix(a.2, b.2))
+    }
+
+    /// Returns the color of a cell `distance` steps from the source when the farthest
+    /// cell is `max_distance` steps away.
+    pub fn sample_distance(&self, distance: usize, max_distance: usize) -> (u8, u8, u8) {
+        if max_distance == 0 {
+            return self.sample(0.0);
+        }
+        self.sample(distance as f64 / max_distance as f64)
+    }
+}
+
+/// Parses a preset name (`viridis`, `heat` or `gray`), or a comma-separated list of
+/// `#rrggbb` colors.
+impl FromStr for Gradient {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "viridis" => return Ok(Self::viridis()),
+            "heat" => return Ok(Self::heat()),
+            "gray" | "grey" | "grayscale" => return Ok(Self::grayscale()),
+            _ => {}
+        }
+
+        let stops = input
+            .split(',')
+            .map(|color| parse_hex_color(color.trim()))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| {
+                format!(
+                    "Invalid gradient '{}'. Use viridis, heat, gray, or comma-separated colors such as #000000,#ff0000.",
+                    input
+                )
+            })?;
+        Ok(Self::new(stops))
+    }
+}
+
+fn parse_hex_color(color: &str) -> Option<(u8, u8, u8)> {
+    let hex = color.strip_prefix('#')?;
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some((channel(0)?, channel(2)?, channel(4)?))
+}
@@ -0,0 +1,378 @@
+use crate::direction6::Direction6;
+#[cfg(feature = "ascii-renderer")]
+use crate::hex_coord::HexCoord;
+use crate::wall6_grid::Wall6Grid;
+use std::str::FromStr;
+
+/// The orientation of hexagons in text renderings of hex mazes.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum HexLayout {
+    /// Hexagons with a corner at the top and bottom and vertical sides. Each row of
+    /// cells is shifted half a cell to the right of the previous one.
+    ///
+    /// ```text
+    ///  / \ / \
+    /// |   |   |
+    ///  \ / \ / \
+    ///   |   |   |
+    ///    \ / \ /
+    /// ```
+    #[default]
+    Pointy,
+    /// Hexagons with flat tops and bottoms. Each column of cells is shifted half a
+    /// cell down from the previous one, and the north-west wall is drawn on top.
+    ///
+    /// ```text
+    ///  ___
+    /// /   \___
+    /// \___/   \
+    /// /   \___/
+    /// \___/   \
+    ///     \___/
+    /// ```
+    Flat,
+}
+
+/// Parses `pointy` or `flat`.
+impl FromStr for HexLayout {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "pointy" => Ok(HexLayout::Pointy),
+            "flat" => Ok(HexLayout::Flat),
+            _ => Err(format!(
+                "Invalid hex layout '{}'. Valid layouts are: pointy, flat.",
+                input
+            )),
+        }
+    }
+}
+
+/// The characters hex edges are drawn with.
+struct HexGlyphs {
+    /// Edges rising to the right.
+    rising: char,
+    /// Edges falling to the right.
+    falling: char,
+    /// The sides of pointy hexagons.
+    vertical: char,
+    /// The tops and bottoms of flat hexagons.
+    horizontal: char,
+}
+
+#[cfg(feature = "ascii-renderer")]
+const ASCII_GLYPHS: HexGlyphs = HexGlyphs {
+    rising: '/',
+    falling: '\\',
+    vertical: '|',
+    horizontal: '_',
+};
+
+#[cfg(feature = "unicode-renderer")]
+const UNICODE_GLYPHS: HexGlyphs = HexGlyphs {
+    rising: '\u{2571}',   // ╱
+    falling: '\u{2572}',  // ╲
+    vertical: '\u{2502}', // │
+    horizontal: '_',
+};
+
+/// Renderer for hex mazes as plain ASCII outlines, for pasting into plain-text
+/// tickets and e-mails.
+///
+/// See [`HexLayout`] for examples of both layouts.
+#[cfg(feature = "ascii-renderer")]
+pub struct HexAsciiRenderer {
+    layout: HexLayout,
+    cell_size: usize,
+}
+
+#[cfg(feature = "ascii-renderer")]
+impl Default for HexAsciiRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "ascii-renderer")]
+impl HexAsciiRenderer {
+    /// Creates a new renderer for pointy hexagons three characters wide.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::renderers::HexAsciiRenderer;
+    ///
+    /// let renderer = HexAsciiRenderer::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            layout: HexLayout::Pointy,
+            cell_size: 3,
+        }
+    }
+
+    /// Sets the orientation of the hexagons.
+    pub fn set_layout(&mut self, layout: HexLayout) {
+        self.layout = layout;
+    }
+
+    /// Sets the size of a cell in characters: the width between the sides of pointy
+    /// hexagons, which is rounded up to an odd number of at least three, or the
+    /// length of the top and bottom edges of flat hexagons. The slanted edges grow
+    /// with the size.
+    pub fn set_cell_size(&mut self, cell_size: usize) {
+        self.cell_size = cell_size.max(1);
+    }
+
+    /// Renders a hex maze.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::preamble::{HexCoord, Wall6Grid};
+    /// use amaze::renderers::{HexAsciiRenderer, HexLayout};
+    ///
+    /// let mut grid = Wall6Grid::new(2, 1);
+    /// grid.remove_wall_between(HexCoord::new(0, 0), HexCoord::new(1, 0));
+    ///
+    /// let mut renderer = HexAsciiRenderer::new();
+    /// renderer.set_layout(HexLayout::Flat);
+    /// let expected = [
+    ///     r" ___",
+    ///     r"/   \___",
+    ///     r"\___    \",
+    ///     r"    \___/",
+    /// ];
+    /// assert_eq!(renderer.render(&grid).lines().collect::<Vec<_>>(), expected);
+    /// ```
+    pub fn render(&self, grid: &Wall6Grid) -> String {
+        draw(grid, self.layout, self.cell_size, &ASCII_GLYPHS)
+    }
+
+    /// Prints the wall bits of every cell as two hexadecimal digits, like
+    /// [`UnicodeRenderStyle::Hexadecimal`](crate::renderers::UnicodeRenderStyle)
+    /// does for square mazes. Odd rows are indented by one character.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::preamble::Wall6Grid;
+    /// use amaze::renderers::HexAsciiRenderer;
+    ///
+    /// let text = HexAsciiRenderer::new().render_wall_bits(&Wall6Grid::new(2, 2));
+    /// assert_eq!(text, "3F 3F\n 3F 3F\n");
+    /// ```
+    pub fn render_wall_bits(&self, grid: &Wall6Grid) -> String {
+        let mut text = String::new();
+        for r in 0..grid.height() {
+            if r % 2 == 1 {
+                text.push(' ');
+            }
+            let row: Vec<_> = (0..grid.width())
+                .map(|q| format!("{:02X}", *grid[HexCoord::new(q as isize, r as isize)]))
+                .collect();
+            text.push_str(&row.join(" "));
+            text.push('\n');
+        }
+        text
+    }
+}
+
+/// Renderer for hex mazes with Unicode box-drawing diagonals, which join at the
+/// corners more cleanly than ASCII slashes.
+///
+/// See [`HexLayout`] for the shapes of both layouts.
+#[cfg(feature = "unicode-renderer")]
+pub struct HexUnicodeRenderer {
+    layout: HexLayout,
+    cell_size: usize,
+}
+
+#[cfg(feature = "unicode-renderer")]
+impl Default for HexUnicodeRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "unicode-renderer")]
+impl HexUnicodeRenderer {
+    /// Creates a new renderer for pointy hexagons three characters wide.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::renderers::HexUnicodeRenderer;
+    ///
+    /// let renderer = HexUnicodeRenderer::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            layout: HexLayout::Pointy,
+            cell_size: 3,
+        }
+    }
+
+    /// Sets the orientation of the hexagons.
+    pub fn set_layout(&mut self, layout: HexLayout) {
+        self.layout = layout;
+    }
+
+    /// Sets the size of a cell in characters: the width between the sides of pointy
+    /// hexagons, which is rounded up to an odd number of at least three, or the
+    /// length of the top and bottom edges of flat hexagons. The slanted edges grow
+    /// with the size.
+    pub fn set_cell_size(&mut self, cell_size: usize) {
+        self.cell_size = cell_size.max(1);
+    }
+
+    /// Renders a hex maze.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::preamble::{HexCoord, Wall6Grid};
+    /// use amaze::renderers::HexUnicodeRenderer;
+    ///
+    /// let mut grid = Wall6Grid::new(2, 1);
+    /// grid.remove_wall_between(HexCoord::new(0, 0), HexCoord::new(1, 0));
+    ///
+    /// let expected = " ╱ ╲ ╱ ╲\n│       │\n ╲ ╱ ╲ ╱\n";
+    /// assert_eq!(HexUnicodeRenderer::new().render(&grid), expected);
+    /// ```
+    pub fn render(&self, grid: &Wall6Grid) -> String {
+        draw(grid, self.layout, self.cell_size, &UNICODE_GLYPHS)
+    }
+}
+
+/// Draws the closed walls of every cell onto a character canvas. Neighboring cells
+/// share their edges, so walls closed on both sides land on the same characters.
+fn draw(grid: &Wall6Grid, layout: HexLayout, cell_size: usize, glyphs: &HexGlyphs) -> String {
+    let (width, height) = (grid.width(), grid.height());
+    if width == 0 || height == 0 {
+        return String::new();
+    }
+
+    let canvas = match layout {
+        HexLayout::Pointy => draw_pointy(grid, cell_size, glyphs),
+        HexLayout::Flat => draw_flat(grid, cell_size, glyphs),
+    };
+
+    let mut text = String::new();
+    for row in canvas {
+        let line: String = row.iter().collect();
+        text.push_str(line.trim_end());
+        text.push('\n');
+    }
+    text
+}
+
+/// Pointy hexagons have `slant` rows of slanted edges above and below their sides,
+/// which are as many rows high and lie `half` columns to either side of the center.
+fn draw_pointy(grid: &Wall6Grid, cell_size: usize, glyphs: &HexGlyphs) -> Vec<Vec<char>> {
+    let (width, height) = (grid.width(), grid.height());
+    let slant = (cell_size / 2).max(1);
+    let half = slant + 1;
+    let columns = 2 * half * width + half * (height - 1) + 1;
+    let rows = 2 * slant * height + slant;
+    let mut canvas = vec![vec![' '; columns]; rows];
+
+    for cell in grid.coords() {
+        let walls = grid[cell];
+        let (q, r) = (cell.q as usize, cell.r as usize);
+        let center = half + 2 * half * q + half * r;
+        let top = 2 * slant * r;
+        let mut put = |row: usize, column: usize, dir: Direction6, c: char| {
+            if walls.contains(dir) {
+                canvas[row][column] = c;
+            }
+        };
+
+        for i in 0..slant {
+            put(top + i, center - 1 - i, Direction6::NW, glyphs.rising);
+            put(top + i, center + 1 + i, Direction6::NE, glyphs.falling);
+            put(
+                top + slant + i,
+                center - half,
+                Direction6::WEST,
+                glyphs.vertical,
+            );
+            put(
+                top + slant + i,
+                center + half,
+                Direction6::EAST,
+                glyphs.vertical,
+            );
+            put(
+                top + 2 * slant + i,
+                center + 1 + i - half,
+                Direction6::SW,
+                glyphs.falling,
+            );
+            put(
+                top + 2 * slant + i,
+                center + half - 1 - i,
+                Direction6::SE,
+                glyphs.rising,
+            );
+        }
+    }
+    canvas
+}
+
+/// Flat hexagons have top and bottom edges `length` characters long, and `slant` rows
+/// of slanted edges on each side between them. Cells `(q, r)` and `(q, r + 1)` are
+/// stacked on top of each other; `(q + 1, r)` lies to the lower right.
+fn draw_flat(grid: &Wall6Grid, cell_size: usize, glyphs: &HexGlyphs) -> Vec<Vec<char>> {
+    let (width, height) = (grid.width(), grid.height());
+    let length = cell_size.max(1);
+    let slant = length.div_ceil(4);
+    let columns = (slant + length) * width + slant;
+    let rows = 2 * slant * height + slant * (width - 1) + 1;
+    let mut canvas = vec![vec![' '; columns]; rows];
+
+    for cell in grid.coords() {
+        let walls = grid[cell];
+        let (q, r) = (cell.q as usize, cell.r as usize);
+        let left = (slant + length) * q;
+        let top = 2 * slant * r + slant * q;
+        let mut put = |row: usize, column: usize, dir: Direction6, c: char| {
+            if walls.contains(dir) {
+                canvas[row][column] = c;
+            }
+        };
+
+        for i in 0..length {
+            put(top, left + slant + i, Direction6::NW, glyphs.horizontal);
+            put(
+                top + 2 * slant,
+                left + slant + i,
+                Direction6::SE,
+                glyphs.horizontal,
+            );
+        }
+        for i in 0..slant {
+            put(
+                top + 1 + i,
+                left + slant - 1 - i,
+                Direction6::WEST,
+                glyphs.rising,
+            );
+            put(
+                top + 1 + i,
+                left + slant + length + i,
+                Direction6::NE,
+                glyphs.falling,
+            );
+            put(
+                top + slant + 1 + i,
+                left + i,
+                Direction6::SW,
+                glyphs.falling,
+            );
+            put(
+                top + slant + 1 + i,
+                left + 2 * slant + length - 1 - i,
+                Direction6::EAST,
+                glyphs.rising,
+            );
+        }
+    }
+    canvas
+}
@@ -0,0 +1,119 @@
+use crate::renderers::{Gradient, Point, StrokeCap};
+
+/// Sizes and colors shared by the `SvgRenderer` and the `PngRenderer`, so that one
+/// style can be handed to both with their `set_style`.
+///
+/// Lengths are measured in SVG units or pixels.
+///
+/// ## Example
+/// ```
+/// use amaze::renderers::{Gradient, ImageStyle};
+///
+/// let mut style = ImageStyle::new();
+/// style.set_cell_size(12.0);
+/// style.set_colors((255, 0, 0), (255, 255, 255)); // Red walls, white background
+/// style.set_gradient(Gradient::heat());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageStyle {
+    pub(crate) cell_size: f64,
+    pub(crate) stroke_width: f64,
+    pub(crate) stroke_cap: StrokeCap,
+    pub(crate) margin: f64,
+    pub(crate) wall_color: (u8, u8, u8),
+    pub(crate) background_color: (u8, u8, u8),
+    pub(crate) solid_color: (u8, u8, u8),
+    pub(crate) solution_color: (u8, u8, u8),
+    pub(crate) start_color: (u8, u8, u8),
+    pub(crate) end_color: (u8, u8, u8),
+    pub(crate) gradient: Gradient,
+}
+
+impl Default for ImageStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ImageStyle {
+    /// Creates a style with 20 units per cell and dark walls on white.
+    pub fn new() -> Self {
+        Self {
+            cell_size: 20.0,
+            stroke_width: 2.0,
+            stroke_cap: StrokeCap::Round,
+            margin: 10.0,
+            wall_color: (12, 12, 72), // Default: (Almost) Black walls
+            background_color: (255, 255, 255), // Default: White paths
+            solid_color: (200, 200, 210), // Default: Light gray dungeon rock
+            solution_color: (220, 40, 40),
+            start_color: (40, 160, 40),
+            end_color: (40, 80, 220),
+            gradient: Gradient::default(),
+        }
+    }
+
+    /// Sets the size of each cell. Hexagons are this wide from vertex to vertex.
+    pub fn set_cell_size(&mut self, cell_size: f64) {
+        self.cell_size = cell_size;
+    }
+
+    /// Sets the width of the wall strokes.
+    pub fn set_stroke_width(&mut self, stroke_width: f64) {
+        self.stroke_width = stroke_width;
+    }
+
+    /// Sets the shape of the wall ends.
+    pub fn set_stroke_cap(&mut self, stroke_cap: StrokeCap) {
+        self.stroke_cap = stroke_cap;
+    }
+
+    /// Sets the empty space around the maze on every side.
+    pub fn set_margin(&mut self, margin: f64) {
+        self.margin = margin;
+    }
+
+    /// Sets custom colors for walls and the background.
+    pub fn set_colors(&mut self, wall_color: (u8, u8, u8), background_color: (u8, u8, u8)) {
+        self.wall_color = wall_color;
+        self.background_color = background_color;
+    }
+
+    /// Sets the fill color of solid areas, such as dungeon wall tiles.
+    pub fn set_solid_color(&mut self, solid_color: (u8, u8, u8)) {
+        self.solid_color = solid_color;
+    }
+
+    /// Sets the color of the solution overlay.
+    pub fn set_solution_color(&mut self, solution_color: (u8, u8, u8)) {
+        self.solution_color = solution_color;
+    }
+
+    /// Sets the colors of the start and end markers.
+    pub fn set_marker_colors(&mut self, start_color: (u8, u8, u8), end_color: (u8, u8, u8)) {
+        self.start_color = start_color;
+        self.end_color = end_color;
+    }
+
+    /// Sets the gradient that colors heatmap cells, from the source to the farthest
+    /// cell.
+    pub fn set_gradient(&mut self, gradient: Gradient) {
+        self.gradient = gradient;
+    }
+
+    /// Returns the size of the image of a grid with the given extent in cells.
+    pub(crate) fn image_extent(&self, (width, height): Point) -> Point {
+        (
+            width * self.cell_size + 2.0 * self.margin,
+            height * self.cell_size + 2.0 * self.margin,
+        )
+    }
+
+    /// Converts a point measured in cells to image units.
+    pub(crate) fn scale(&self, (x, y): Point) -> Point {
+        (
+            self.margin + x * self.cell_size,
+            self.margin + y * self.cell_size,
+        )
+    }
+}
@@ -1,6 +1,9 @@
+use crate::direction6::Direction6;
 use crate::grid_coord_2d::GridCoord2D;
 use crate::room4::Wall4;
 use crate::wall4_grid::Wall4Grid;
+use crate::wall6_grid::Wall6Grid;
+use crate::weave_grid::WeaveGrid;
 use std::ops::Index;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -15,8 +18,9 @@ pub enum ImageFormat {
 pub struct ImageRenderer {
     format: ImageFormat,
     // Optional: Customize colors for PPM
-    wall_color: (u8, u8, u8), // RGB for walls
-    path_color: (u8, u8, u8), // RGB for paths
+    wall_color: (u8, u8, u8),   // RGB for walls
+    path_color: (u8, u8, u8),   // RGB for paths
+    tunnel_color: (u8, u8, u8), // RGB for tunnel entrances in weave mazes
 }
 
 impl ImageRenderer {
@@ -33,8 +37,9 @@ impl ImageRenderer {
     pub fn new(format: ImageFormat) -> Self {
         Self {
             format,
-            wall_color: (12, 12, 72),    // Default: (Almost) Black walls
-            path_color: (255, 255, 255), // Default: White paths
+            wall_color: (12, 12, 72),      // Default: (Almost) Black walls
+            path_color: (255, 255, 255),   // Default: White paths
+            tunnel_color: (176, 176, 200), // Default: Light gray tunnels
         }
     }
 
@@ -51,6 +56,18 @@ impl ImageRenderer {
         self.path_color = path_color;
     }
 
+    /// Sets the color of tunnel entrances in weave mazes (only applicable for PPM).
+    ///
+    /// ## Example
+    /// ```
+    /// # use amaze::renderers::{ImageRenderer, ImageFormat};
+    /// let mut renderer = ImageRenderer::new(ImageFormat::PPM);
+    /// renderer.set_tunnel_color((160, 160, 160));
+    /// ```
+    pub fn set_tunnel_color(&mut self, tunnel_color: (u8, u8, u8)) {
+        self.tunnel_color = tunnel_color;
+    }
+
     /// Renders the maze into the specified image format.
     ///
     /// Returns the image data as a `String`.
@@ -67,56 +84,81 @@ impl ImageRenderer {
     /// assert!(!image_data.is_empty());
     /// ```
     pub fn render(&self, grid: &Wall4Grid) -> String {
+        self.render_pixels(&carve_pixels(grid))
+    }
+
+    /// Renders a weave maze. In PPM images, the passage between each tunnel entrance
+    /// and its crossing is drawn in the tunnel color; PBM has only two colors and
+    /// draws it as an open path.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use amaze::generators::{MazeGeneratorWeave, WeaveKruskal4};
+    /// use amaze::renderers::{ImageFormat, ImageRenderer};
+    ///
+    /// let grid = WeaveKruskal4::new_from_seed(7).generate(6, 6);
+    /// let image_data = ImageRenderer::new(ImageFormat::PPM).render_weave(&grid);
+    /// assert!(image_data.starts_with("P3\n13 13\n"));
+    /// ```
+    pub fn render_weave(&self, grid: &WeaveGrid) -> String {
+        let mut pixels = carve_pixels(grid.grid());
+        for cell in grid.crossings() {
+            let tunnel = grid.tunnel_directions(cell);
+            let (img_x, img_y) = (cell.x * 2 + 1, cell.y * 2 + 1);
+            if tunnel.contains(Wall4::EAST) {
+                pixels[img_y][img_x - 1] = Pixel::Tunnel;
+                pixels[img_y][img_x + 1] = Pixel::Tunnel;
+            } else {
+                pixels[img_y - 1][img_x] = Pixel::Tunnel;
+                pixels[img_y + 1][img_x] = Pixel::Tunnel;
+            }
+        }
+        self.render_pixels(&pixels)
+    }
+
+    /// Renders a hex maze as pointy-top hexagons 9 pixels wide and high, with one
+    /// pixel thick walls. Each row is shifted half a cell to the right of the
+    /// previous one, and pixels outside the maze take the path color.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use amaze::preamble::Wall6Grid;
+    /// use amaze::renderers::{ImageFormat, ImageRenderer};
+    ///
+    /// let grid = Wall6Grid::new(3, 2);
+    /// let image_data = ImageRenderer::new(ImageFormat::PBM).render_hex(&grid);
+    /// assert!(image_data.starts_with("P1\n29 15\n"));
+    /// ```
+    pub fn render_hex(&self, grid: &Wall6Grid) -> String {
+        self.render_pixels(&hex_pixels(grid))
+    }
+
+    fn render_pixels(&self, pixels: &[Vec<Pixel>]) -> String {
         match self.format {
-            ImageFormat::PPM => self.render_ppm(grid),
-            ImageFormat::PBM => self.render_pbm(grid),
+            ImageFormat::PPM => self.render_ppm(pixels),
+            ImageFormat::PBM => self.render_pbm(pixels),
         }
     }
 
-    /// Renders the maze as a PPM image.
-    fn render_ppm(&self, grid: &Wall4Grid) -> String {
-        let image_width = grid.width() * 2 + 1;
-        let image_height = grid.height() * 2 + 1;
+    /// Renders the pixels as a PPM image.
+    fn render_ppm(&self, pixels: &[Vec<Pixel>]) -> String {
+        let image_height = pixels.len();
+        let image_width = pixels.first().map_or(0, Vec::len);
 
         let mut ppm = String::new();
 
         // PPM Header
         ppm.push_str(&format!("P3\n{} {}\n255\n", image_width, image_height));
 
-        // Initialize all pixels to walls (black)
-        let mut pixels = vec![vec![self.wall_color; image_width]; image_height];
-
-        // Carve out paths
-        for y in 0..grid.height() {
-            for x in 0..grid.width() {
-                let cell = GridCoord2D::new(x, y);
-                let img_x = x * 2 + 1;
-                let img_y = y * 2 + 1;
-
-                // Set the cell position to path color
-                pixels[img_y][img_x] = self.path_color;
-
-                // Check and carve passages
-                let wall = grid.index(cell);
-                if !wall.contains(Wall4::NORTH) && y > 0 {
-                    pixels[img_y - 1][img_x] = self.path_color;
-                }
-                if !wall.contains(Wall4::SOUTH) && y < grid.height() - 1 {
-                    pixels[img_y + 1][img_x] = self.path_color;
-                }
-                if !wall.contains(Wall4::EAST) && x < grid.width() - 1 {
-                    pixels[img_y][img_x + 1] = self.path_color;
-                }
-                if !wall.contains(Wall4::WEST) && x > 0 {
-                    pixels[img_y][img_x - 1] = self.path_color;
-                }
-            }
-        }
-
         // Convert pixel data to PPM format
         for row in pixels {
             for (i, pixel) in row.iter().enumerate() {
-                ppm.push_str(&format!("{} {} {} ", pixel.0, pixel.1, pixel.2));
+                let color = match pixel {
+                    Pixel::Wall => self.wall_color,
+                    Pixel::Path => self.path_color,
+                    Pixel::Tunnel => self.tunnel_color,
+                };
+                ppm.push_str(&format!("{} {} {} ", color.0, color.1, color.2));
                 // Optional: Add line breaks every 5 pixels for readability
                 if i % 5 == 4 {
                     ppm.push('\n');
@@ -128,54 +170,133 @@ impl ImageRenderer {
         ppm
     }
 
-    /// Renders the maze as a PBM image.
-    fn render_pbm(&self, grid: &Wall4Grid) -> String {
-        let image_width = grid.width() * 2 + 1;
-        let image_height = grid.height() * 2 + 1;
+    /// Renders the pixels as a PBM image.
+    fn render_pbm(&self, pixels: &[Vec<Pixel>]) -> String {
+        let image_height = pixels.len();
+        let image_width = pixels.first().map_or(0, Vec::len);
 
         let mut pbm = String::new();
 
         // PBM Header
         pbm.push_str(&format!("P1\n{} {}\n", image_width, image_height));
 
-        // Initialize all pixels to walls (1)
-        let mut pixels = vec![vec![1; image_width]; image_height];
+        // Convert pixel data to PBM format
+        for row in pixels {
+            for pixel in row {
+                let bit = if *pixel == Pixel::Wall { 1 } else { 0 };
+                pbm.push_str(&format!("{} ", bit));
+            }
+            pbm.push('\n');
+        }
 
-        // Carve out paths
-        for y in 0..grid.height() {
-            for x in 0..grid.width() {
-                let cell = GridCoord2D::new(x, y);
-                let img_x = x * 2 + 1;
-                let img_y = y * 2 + 1;
+        pbm
+    }
+}
 
-                // Set the cell position to path (0)
-                pixels[img_y][img_x] = 0;
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Pixel {
+    Wall,
+    Path,
+    Tunnel,
+}
 
-                // Check and carve passages
-                let wall = grid.index(cell);
-                if !wall.contains(Wall4::NORTH) && y > 0 {
-                    pixels[img_y - 1][img_x] = 0;
-                }
-                if !wall.contains(Wall4::SOUTH) && y < grid.height() - 1 {
-                    pixels[img_y + 1][img_x] = 0;
-                }
-                if !wall.contains(Wall4::EAST) && x < grid.width() - 1 {
-                    pixels[img_y][img_x + 1] = 0;
-                }
-                if !wall.contains(Wall4::WEST) && x > 0 {
-                    pixels[img_y][img_x - 1] = 0;
-                }
+/// Lays out the maze on a pixel grid with one pixel per cell and one per wall.
+fn carve_pixels(grid: &Wall4Grid) -> Vec<Vec<Pixel>> {
+    let image_width = grid.width() * 2 + 1;
+    let image_height = grid.height() * 2 + 1;
+
+    // Initialize all pixels to walls
+    let mut pixels = vec![vec![Pixel::Wall; image_width]; image_height];
+
+    // Carve out paths
+    for y in 0..grid.height() {
+        for x in 0..grid.width() {
+            let cell = GridCoord2D::new(x, y);
+            let img_x = x * 2 + 1;
+            let img_y = y * 2 + 1;
+
+            // Set the cell position to path
+            pixels[img_y][img_x] = Pixel::Path;
+
+            // Check and carve passages
+            let wall = grid.index(cell);
+            if !wall.contains(Wall4::NORTH) && y > 0 {
+                pixels[img_y - 1][img_x] = Pixel::Path;
+            }
+            if !wall.contains(Wall4::SOUTH) && y < grid.height() - 1 {
+                pixels[img_y + 1][img_x] = Pixel::Path;
+            }
+            if !wall.contains(Wall4::EAST) && x < grid.width() - 1 {
+                pixels[img_y][img_x + 1] = Pixel::Path;
+            }
+            if !wall.contains(Wall4::WEST) && x > 0 {
+                pixels[img_y][img_x - 1] = Pixel::Path;
             }
         }
+    }
 
-        // Convert pixel data to PBM format
-        for row in pixels {
-            for pixel in row {
-                pbm.push_str(&format!("{} ", pixel));
+    pixels
+}
+
+/// The corners of a pointy-top hexagon around its center on the pixel grid, starting
+/// at the top and going clockwise.
+const HEX_CORNERS: [(isize, isize); 6] = [(0, -4), (4, -2), (4, 2), (0, 4), (-4, 2), (-4, -2)];
+/// The wall between each corner and the next.
+const HEX_EDGES: [Direction6; 6] = [
+    Direction6::NE,
+    Direction6::EAST,
+    Direction6::SE,
+    Direction6::SW,
+    Direction6::WEST,
+    Direction6::NW,
+];
+
+/// Lays out a hex maze on a pixel grid, with hexagon centers 8 pixels apart within a
+/// row and rows 6 pixels apart.
+fn hex_pixels(grid: &Wall6Grid) -> Vec<Vec<Pixel>> {
+    let (width, height) = (grid.width(), grid.height());
+    if width == 0 || height == 0 {
+        return Vec::new();
+    }
+    let image_width = 8 * width + 4 * (height - 1) + 1;
+    let image_height = 6 * height + 3;
+    let mut pixels = vec![vec![Pixel::Path; image_width]; image_height];
+
+    for cell in grid.coords() {
+        let walls = grid[cell];
+        let center = (4 + 8 * cell.q + 4 * cell.r, 4 + 6 * cell.r);
+        for (i, dir) in HEX_EDGES.into_iter().enumerate() {
+            if walls.contains(dir) {
+                let corner = |(dx, dy): (isize, isize)| (center.0 + dx, center.1 + dy);
+                let a = corner(HEX_CORNERS[i]);
+                let b = corner(HEX_CORNERS[(i + 1) % 6]);
+                draw_line(&mut pixels, a.min(b), a.max(b));
             }
-            pbm.push('\n');
         }
+    }
+    pixels
+}
 
-        pbm
+/// Draws a line between two pixels with Bresenham's algorithm. Neighboring cells
+/// draw their shared wall with the same end first, so both draw the same pixels.
+fn draw_line(pixels: &mut [Vec<Pixel>], from: (isize, isize), to: (isize, isize)) {
+    let (dx, dy) = ((to.0 - from.0).abs(), -(to.1 - from.1).abs());
+    let (sx, sy) = ((to.0 - from.0).signum(), (to.1 - from.1).signum());
+    let (mut x, mut y) = from;
+    let mut error = dx + dy;
+    loop {
+        pixels[y as usize][x as usize] = Pixel::Wall;
+        if (x, y) == to {
+            break;
+        }
+        let doubled = 2 * error;
+        if doubled >= dy {
+            error += dy;
+            x += sx;
+        }
+        if doubled <= dx {
+            error += dx;
+            y += sy;
+        }
     }
 }
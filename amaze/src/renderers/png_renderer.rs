@@ -0,0 +1,386 @@
+use crate::distance_map::DistanceMap;
+use crate::path::Path;
+use crate::renderers::drawable_grid::route_runs;
+use crate::renderers::{DrawableGrid, ImageStyle, Point, StrokeCap};
+use std::io;
+
+/// Renderer for generating PNG images of square mazes, hex mazes and dungeons.
+///
+/// Any [`DrawableGrid`] can be rendered, optionally with its solution drawn through
+/// the cell centers and with markers on the start and end cells, or as a heatmap of
+/// the distances from a source cell. Walls are
+/// anti-aliased, so thin and fractional wall thicknesses stay legible.
+pub struct PngRenderer {
+    style: ImageStyle,
+}
+
+impl Default for PngRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PngRenderer {
+    /// Creates a new renderer with the default [`ImageStyle`].
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::renderers::PngRenderer;
+    ///
+    /// let renderer = PngRenderer::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            style: ImageStyle::new(),
+        }
+    }
+
+    /// Replaces the sizes and colors of the image.
+    pub fn set_style(&mut self, style: ImageStyle) {
+        self.style = style;
+    }
+
+    /// Returns the sizes and colors of the image for changing them in place.
+    ///
+    /// ## Example
+    /// ```
+    /// # use amaze::renderers::PngRenderer;
+    /// let mut renderer = PngRenderer::new();
+    /// renderer.style_mut().set_cell_size(8.0);
+    /// ```
+    pub fn style_mut(&mut self) -> &mut ImageStyle {
+        &mut self.style
+    }
+
+    /// Returns the width and height of the image of a grid, in pixels.
+    pub fn image_size<G: DrawableGrid>(&self, grid: &G) -> (u32, u32) {
+        let (width, height) = self.style.image_extent(grid.extent());
+        (width.ceil().max(1.0) as u32, height.ceil().max(1.0) as u32)
+    }
+
+    /// Renders the maze as a PNG image.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::preamble::Wall4Grid;
+    /// use amaze::renderers::PngRenderer;
+    ///
+    /// let png = PngRenderer::new().render(&Wall4Grid::new(8, 5));
+    /// assert!(png.starts_with(b"\x89PNG"));
+    /// ```
+    pub fn render<G: DrawableGrid>(&self, grid: &G) -> Vec<u8> {
+        self.render_with(grid, None, None)
+    }
+
+    /// Renders the maze as a PNG image, drawing the `solution` through the cell
+    /// centers and marking the `(start, end)` cells.
+    pub fn render_with<G: DrawableGrid>(
+        &self,
+        grid: &G,
+        solution: Option<&Path<G::Coord>>,
+        markers: Option<(G::Coord, G::Coord)>,
+    ) -> Vec<u8> {
+        let mut png = Vec::new();
+        self.write_with(grid, solution, markers, &mut png)
+            .expect("writing to a Vec cannot fail");
+        png
+    }
+
+    /// Writes the maze as a PNG image.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use amaze::generators::RecursiveBacktracker4;
+    /// use amaze::renderers::PngRenderer;
+    /// use std::fs::File;
+    ///
+    /// let maze = RecursiveBacktracker4::new_from_seed(3).generate(32, 32);
+    /// PngRenderer::new().write(&maze, File::create("maze.png")?)?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn write<G: DrawableGrid, W: io::Write>(&self, grid: &G, writer: W) -> io::Result<()> {
+        self.write_with(grid, None, None, writer)
+    }
+
+    /// Writes the maze as a PNG image, drawing the `solution` through the cell
+    /// centers and marking the `(start, end)` cells.
+    pub fn write_with<G: DrawableGrid, W: io::Write>(
+        &self,
+        grid: &G,
+        solution: Option<&Path<G::Coord>>,
+        markers: Option<(G::Coord, G::Coord)>,
+        writer: W,
+    ) -> io::Result<()> {
+        Self::encode(&self.draw(grid, None, solution, markers), writer)
+    }
+
+    /// Renders the maze as a PNG image with every reachable cell colored by its
+    /// distance from the nearest source of `distances`. Unreachable cells keep the
+    /// background color.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::generators::RecursiveBacktracker4;
+    /// use amaze::preamble::*;
+    /// use amaze::renderers::PngRenderer;
+    ///
+    /// let maze = RecursiveBacktracker4::new_from_seed(3).generate(8, 8);
+    /// let distances = DistanceMap::from_diameter_end(&maze, GridCoord2D::new(0, 0));
+    /// let png = PngRenderer::new().render_heatmap(&maze, &distances);
+    /// assert!(png.starts_with(b"\x89PNG"));
+    /// ```
+    pub fn render_heatmap<G: DrawableGrid>(
+        &self,
+        grid: &G,
+        distances: &DistanceMap<G::Coord>,
+    ) -> Vec<u8> {
+        let mut png = Vec::new();
+        self.write_heatmap(grid, distances, &mut png)
+            .expect("writing to a Vec cannot fail");
+        png
+    }
+
+    /// Writes the maze as a PNG image with every reachable cell colored by its
+    /// distance from the nearest source of `distances`.
+    pub fn write_heatmap<G: DrawableGrid, W: io::Write>(
+        &self,
+        grid: &G,
+        distances: &DistanceMap<G::Coord>,
+        writer: W,
+    ) -> io::Result<()> {
+        Self::encode(&self.draw(grid, Some(distances), None, None), writer)
+    }
+
+    fn draw<G: DrawableGrid>(
+        &self,
+        grid: &G,
+        heatmap: Option<&DistanceMap<G::Coord>>,
+        solution: Option<&Path<G::Coord>>,
+        markers: Option<(G::Coord, G::Coord)>,
+    ) -> Canvas {
+        let style = &self.style;
+        let (width, height) = self.image_size(grid);
+        let mut canvas = Canvas::new(width as usize, height as usize, style.background_color);
+
+        if let Some(distances) = heatmap {
+            let max_distance = distances.max_distance().unwrap_or(0);
+            for (cell, distance) in distances.iter() {
+                let outline: Vec<_> = grid
+                    .cell_outline(cell)
+                    .into_iter()
+                    .map(|p| style.scale(p))
+                    .collect();
+                let color = style.gradient.sample_distance(distance, max_distance);
+                canvas.fill_polygon(&outline, color);
+            }
+        }
+
+        // One layer is reused for every color, since each holds a value per pixel.
+        let mut layer = Layer::new(&canvas);
+        for area in grid.solid_areas() {
+            let area: Vec<_> = area.into_iter().map(|p| style.scale(p)).collect();
+            layer.fill_polygon(&area);
+        }
+        canvas.paint(&mut layer, style.solid_color);
+
+        let half = style.stroke_width / 2.0;
+        for (a, b) in grid.wall_segments() {
+            layer.stroke(style.scale(a), style.scale(b), half, style.stroke_cap);
+        }
+        canvas.paint(&mut layer, style.wall_color);
+
+        if let Some(solution) = solution {
+            let half = style.cell_size / 8.0;
+            for run in route_runs(grid, solution.cells()) {
+                for step in run.windows(2) {
+                    layer.stroke(
+                        style.scale(step[0]),
+                        style.scale(step[1]),
+                        half,
+                        StrokeCap::Round,
+                    );
+                }
+            }
+            canvas.paint(&mut layer, style.solution_color);
+        }
+
+        if let Some((start, end)) = markers {
+            for (cell, color) in [(start, style.start_color), (end, style.end_color)] {
+                let center = style.scale(grid.center(cell));
+                layer.stroke(center, center, style.cell_size * 0.3, StrokeCap::Round);
+                canvas.paint(&mut layer, color);
+            }
+        }
+
+        canvas
+    }
+
+    fn encode<W: io::Write>(canvas: &Canvas, writer: W) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(writer, canvas.width as u32, canvas.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&canvas.pixels)?;
+        writer.finish()?;
+        Ok(())
+    }
+}
+
+/// An RGB image.
+struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize, (r, g, b): (u8, u8, u8)) -> Self {
+        Self {
+            width,
+            height,
+            pixels: [r, g, b].repeat(width * height),
+        }
+    }
+
+    /// Sets the pixels whose centers lie inside a polygon to a color, without
+    /// anti-aliasing, so that polygons sharing an edge tile without seams.
+    fn fill_polygon(&mut self, polygon: &[Point], color: (u8, u8, u8)) {
+        let Some(&first) = polygon.first() else {
+            return;
+        };
+        let (min, max) = bounds(polygon, first);
+        let x0 = min.0.floor().max(0.0) as usize;
+        let y0 = min.1.floor().max(0.0) as usize;
+        let x1 = (max.0.ceil().max(0.0) as usize).min(self.width);
+        let y1 = (max.1.ceil().max(0.0) as usize).min(self.height);
+
+        for y in y0..y1 {
+            for x in x0..x1 {
+                if contains(polygon, (x as f64 + 0.5, y as f64 + 0.5)) {
+                    let i = (y * self.width + x) * 3;
+                    self.pixels[i..i + 3].copy_from_slice(&[color.0, color.1, color.2]);
+                }
+            }
+        }
+    }
+
+    /// Blends a color into the image wherever the layer covers it, and clears the
+    /// layer so that it can be reused for the next color.
+    fn paint(&mut self, layer: &mut Layer, color: (u8, u8, u8)) {
+        let color = [color.0, color.1, color.2];
+        for (pixel, coverage) in self.pixels.chunks_exact_mut(3).zip(&mut layer.coverage) {
+            let coverage = std::mem::take(coverage);
+            if coverage <= 0.0 {
+                continue;
+            }
+            for (channel, &target) in pixel.iter_mut().zip(&color) {
+                let blended = *channel as f32 + (target as f32 - *channel as f32) * coverage;
+                *channel = blended.round() as u8;
+            }
+        }
+    }
+}
+
+/// The coverage of each pixel by one color, so that overlapping shapes of the same
+/// color are blended only once.
+struct Layer {
+    width: usize,
+    height: usize,
+    coverage: Vec<f32>,
+}
+
+impl Layer {
+    fn new(canvas: &Canvas) -> Self {
+        Self {
+            width: canvas.width,
+            height: canvas.height,
+            coverage: vec![0.0; canvas.width * canvas.height],
+        }
+    }
+
+    /// Covers the pixels in the bounding box for which `distance` returns a signed
+    /// distance below half a pixel, anti-aliasing the edge.
+    fn cover(&mut self, (min, max): (Point, Point), distance: impl Fn(Point) -> f64) {
+        let x0 = min.0.floor().max(0.0) as usize;
+        let y0 = min.1.floor().max(0.0) as usize;
+        let x1 = (max.0.ceil().max(0.0) as usize).min(self.width);
+        let y1 = (max.1.ceil().max(0.0) as usize).min(self.height);
+
+        for y in y0..y1 {
+            for x in x0..x1 {
+                let d = distance((x as f64 + 0.5, y as f64 + 0.5));
+                let coverage = (0.5 - d).clamp(0.0, 1.0) as f32;
+                let pixel = &mut self.coverage[y * self.width + x];
+                *pixel = pixel.max(coverage);
+            }
+        }
+    }
+
+    /// Covers a line of `half` the given thickness from `a` to `b`.
+    fn stroke(&mut self, a: Point, b: Point, half: f64, cap: StrokeCap) {
+        let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+        let length = dx.hypot(dy);
+        let (ux, uy) = if length > 0.0 {
+            (dx / length, dy / length)
+        } else {
+            (1.0, 0.0)
+        };
+
+        let pad = half + 1.0;
+        let bounds = (
+            (a.0.min(b.0) - pad, a.1.min(b.1) - pad),
+            (a.0.max(b.0) + pad, a.1.max(b.1) + pad),
+        );
+        self.cover(bounds, |(px, py)| {
+            let (rx, ry) = (px - a.0, py - a.1);
+            let along = rx * ux + ry * uy;
+            let across = (rx * uy - ry * ux).abs();
+            match cap {
+                StrokeCap::Round => {
+                    let t = along.clamp(0.0, length);
+                    (rx - t * ux).hypot(ry - t * uy) - half
+                }
+                StrokeCap::Butt => (across - half).max(-along).max(along - length),
+                StrokeCap::Square => (across - half)
+                    .max(-along - half)
+                    .max(along - length - half),
+            }
+        });
+    }
+
+    /// Covers the inside of a polygon.
+    fn fill_polygon(&mut self, polygon: &[Point]) {
+        let Some(&first) = polygon.first() else {
+            return;
+        };
+        self.cover(bounds(polygon, first), |point| {
+            if contains(polygon, point) {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            }
+        });
+    }
+}
+
+/// Returns the top-left and bottom-right corners of the bounding box of a polygon
+/// that starts at `first`.
+fn bounds(polygon: &[Point], first: Point) -> (Point, Point) {
+    polygon.iter().fold((first, first), |(min, max), &(x, y)| {
+        ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+    })
+}
+
+/// Tests whether a point lies inside a polygon, using the even-odd rule.
+fn contains(polygon: &[Point], (x, y): Point) -> bool {
+    let mut inside = false;
+    let mut j = polygon.len() - 1;
+    for (i, &(xi, yi)) in polygon.iter().enumerate() {
+        let (xj, yj) = polygon[j];
+        if (yi > y) != (yj > y) && x < (xj - xi) * (y - yi) / (yj - yi) + xi {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
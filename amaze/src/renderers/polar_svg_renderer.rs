@@ -0,0 +1,154 @@
+use crate::polar_grid::{PolarCoord, PolarGrid, PolarWalls};
+use std::f64::consts::TAU;
+use std::fmt::Write;
+
+/// Renderer for generating SVG images of circular mazes.
+///
+/// Walls between rings are drawn as arcs, walls between cells of the same
+/// ring as radial lines. Cell index `0` starts at the positive x axis and
+/// indices increase clockwise on screen.
+pub struct PolarSvgRenderer {
+    cell_size: f64,
+    stroke_width: f64,
+    wall_color: (u8, u8, u8),
+    background_color: (u8, u8, u8),
+}
+
+impl Default for PolarSvgRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PolarSvgRenderer {
+    /// Creates a new renderer with 20 units per ring and black walls on white.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::renderers::PolarSvgRenderer;
+    ///
+    /// let renderer = PolarSvgRenderer::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            cell_size: 20.0,
+            stroke_width: 2.0,
+            wall_color: (0, 0, 0),
+            background_color: (255, 255, 255),
+        }
+    }
+
+    /// Sets the radial height of each ring.
+    pub fn set_cell_size(&mut self, cell_size: f64) {
+        self.cell_size = cell_size;
+    }
+
+    /// Sets the width of the wall strokes.
+    pub fn set_stroke_width(&mut self, stroke_width: f64) {
+        self.stroke_width = stroke_width;
+    }
+
+    /// Sets custom colors for walls and the background.
+    ///
+    /// ## Example
+    /// ```
+    /// # use amaze::renderers::PolarSvgRenderer;
+    /// let mut renderer = PolarSvgRenderer::new();
+    /// renderer.set_colors((255, 0, 0), (255, 255, 255)); // Red walls, white background
+    /// ```
+    pub fn set_colors(&mut self, wall_color: (u8, u8, u8), background_color: (u8, u8, u8)) {
+        self.wall_color = wall_color;
+        self.background_color = background_color;
+    }
+
+    /// Renders the maze as an SVG document.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::preamble::PolarGrid;
+    /// use amaze::renderers::PolarSvgRenderer;
+    ///
+    /// let svg = PolarSvgRenderer::new().render(&PolarGrid::new(5));
+    /// assert!(svg.starts_with("<svg"));
+    /// ```
+    pub fn render(&self, grid: &PolarGrid) -> String {
+        let radius = grid.rings() as f64 * self.cell_size;
+        let margin = self.stroke_width.max(1.0);
+        let size = 2.0 * (radius + margin);
+        let center = radius + margin;
+
+        let mut path = String::new();
+        for cell in grid.coords().filter(|cell| cell.ring > 0) {
+            let walls = grid.walls(cell);
+            if walls.contains(PolarWalls::INWARD) {
+                self.write_arc(&mut path, grid, cell, center);
+            }
+            if walls.contains(PolarWalls::CLOCKWISE) {
+                self.write_radial(&mut path, grid, cell, center);
+            }
+        }
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{size:.2}" height="{size:.2}" viewBox="0 0 {size:.2} {size:.2}">"#
+        );
+        let _ = writeln!(
+            svg,
+            r#"<rect width="100%" height="100%" fill="{}"/>"#,
+            Self::color(self.background_color)
+        );
+        let stroke = Self::color(self.wall_color);
+        let _ = writeln!(
+            svg,
+            r#"<g fill="none" stroke="{stroke}" stroke-width="{:.2}" stroke-linecap="round">"#,
+            self.stroke_width
+        );
+        if !path.is_empty() {
+            let _ = writeln!(svg, r#"<path d="{}"/>"#, path.trim_end());
+        }
+        if grid.rings() > 0 {
+            let _ = writeln!(
+                svg,
+                r#"<circle cx="{center:.2}" cy="{center:.2}" r="{radius:.2}"/>"#
+            );
+        }
+        svg.push_str("</g>\n</svg>\n");
+        svg
+    }
+
+    /// Draws the arc separating a cell from the ring inside it.
+    fn write_arc(&self, path: &mut String, grid: &PolarGrid, cell: PolarCoord, center: f64) {
+        let r = cell.ring as f64 * self.cell_size;
+        let (start, end) = Self::angles(grid, cell);
+        let (x0, y0) = Self::point(center, r, start);
+        let (x1, y1) = Self::point(center, r, end);
+        let _ = write!(
+            path,
+            "M{x0:.2} {y0:.2} A{r:.2} {r:.2} 0 0 1 {x1:.2} {y1:.2} "
+        );
+    }
+
+    /// Draws the radial line separating a cell from its clockwise neighbor.
+    fn write_radial(&self, path: &mut String, grid: &PolarGrid, cell: PolarCoord, center: f64) {
+        let inner = cell.ring as f64 * self.cell_size;
+        let outer = inner + self.cell_size;
+        let (_, angle) = Self::angles(grid, cell);
+        let (x0, y0) = Self::point(center, inner, angle);
+        let (x1, y1) = Self::point(center, outer, angle);
+        let _ = write!(path, "M{x0:.2} {y0:.2} L{x1:.2} {y1:.2} ");
+    }
+
+    fn angles(grid: &PolarGrid, cell: PolarCoord) -> (f64, f64) {
+        let theta = TAU / grid.ring_len(cell.ring) as f64;
+        (cell.index as f64 * theta, (cell.index + 1) as f64 * theta)
+    }
+
+    fn point(center: f64, radius: f64, angle: f64) -> (f64, f64) {
+        (center + radius * angle.cos(), center + radius * angle.sin())
+    }
+
+    fn color((r, g, b): (u8, u8, u8)) -> String {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    }
+}
@@ -0,0 +1,239 @@
+use crate::distance_map::DistanceMap;
+use crate::path::Path;
+use crate::renderers::drawable_grid::route_runs;
+use crate::renderers::{DrawableGrid, ImageStyle, Point};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Renderer for generating scalable SVG images of square mazes, hex mazes and
+/// dungeons.
+///
+/// Any [`DrawableGrid`] can be rendered, optionally with its solution drawn through
+/// the cell centers and with markers on the start and end cells, or as a heatmap of
+/// the distances from a source cell.
+pub struct SvgRenderer {
+    style: ImageStyle,
+}
+
+impl Default for SvgRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SvgRenderer {
+    /// Creates a new renderer with the default [`ImageStyle`].
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::renderers::SvgRenderer;
+    ///
+    /// let renderer = SvgRenderer::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            style: ImageStyle::new(),
+        }
+    }
+
+    /// Replaces the sizes and colors of the image.
+    pub fn set_style(&mut self, style: ImageStyle) {
+        self.style = style;
+    }
+
+    /// Returns the sizes and colors of the image for changing them in place.
+    ///
+    /// ## Example
+    /// ```
+    /// # use amaze::renderers::SvgRenderer;
+    /// let mut renderer = SvgRenderer::new();
+    /// renderer.style_mut().set_colors((255, 0, 0), (255, 255, 255)); // Red walls, white background
+    /// ```
+    pub fn style_mut(&mut self) -> &mut ImageStyle {
+        &mut self.style
+    }
+
+    /// Renders the maze as an SVG document.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::preamble::Wall4Grid;
+    /// use amaze::renderers::SvgRenderer;
+    ///
+    /// let svg = SvgRenderer::new().render(&Wall4Grid::new(8, 5));
+    /// assert!(svg.starts_with("<svg"));
+    /// ```
+    pub fn render<G: DrawableGrid>(&self, grid: &G) -> String {
+        self.render_with(grid, None, None)
+    }
+
+    /// Renders the maze as an SVG document, drawing the `solution` through the cell
+    /// centers and marking the `(start, end)` cells.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::generators::RecursiveBacktracker4;
+    /// use amaze::preamble::*;
+    /// use amaze::renderers::SvgRenderer;
+    ///
+    /// let maze = RecursiveBacktracker4::new_from_seed(3).generate(8, 8);
+    /// let (start, end) = (GridCoord2D::new(0, 0), GridCoord2D::new(7, 7));
+    /// let solution = DistanceMap::new(&maze, end).path_to_source(start);
+    ///
+    /// let svg = SvgRenderer::new().render_with(&maze, solution.as_ref(), Some((start, end)));
+    /// assert!(svg.contains("<polyline"));
+    /// ```
+    pub fn render_with<G: DrawableGrid>(
+        &self,
+        grid: &G,
+        solution: Option<&Path<G::Coord>>,
+        markers: Option<(G::Coord, G::Coord)>,
+    ) -> String {
+        self.draw(grid, None, solution, markers)
+    }
+
+    /// Renders the maze as an SVG document with every reachable cell colored by its
+    /// distance from the nearest source of `distances`. Unreachable cells keep the
+    /// background color.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::generators::RecursiveBacktracker4;
+    /// use amaze::preamble::*;
+    /// use amaze::renderers::{Gradient, SvgRenderer};
+    ///
+    /// let maze = RecursiveBacktracker4::new_from_seed(3).generate(8, 8);
+    /// let distances = DistanceMap::from_diameter_end(&maze, GridCoord2D::new(0, 0));
+    ///
+    /// let mut renderer = SvgRenderer::new();
+    /// renderer.style_mut().set_gradient(Gradient::heat());
+    /// let svg = renderer.render_heatmap(&maze, &distances);
+    /// assert!(svg.contains("#000000")); // The source is black.
+    /// ```
+    pub fn render_heatmap<G: DrawableGrid>(
+        &self,
+        grid: &G,
+        distances: &DistanceMap<G::Coord>,
+    ) -> String {
+        self.draw(grid, Some(distances), None, None)
+    }
+
+    fn draw<G: DrawableGrid>(
+        &self,
+        grid: &G,
+        heatmap: Option<&DistanceMap<G::Coord>>,
+        solution: Option<&Path<G::Coord>>,
+        markers: Option<(G::Coord, G::Coord)>,
+    ) -> String {
+        let style = &self.style;
+        let (w, h) = style.image_extent(grid.extent());
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w:.2}" height="{h:.2}" viewBox="0 0 {w:.2} {h:.2}">"#
+        );
+        let _ = writeln!(
+            svg,
+            r#"<rect width="100%" height="100%" fill="{}"/>"#,
+            Self::color(style.background_color)
+        );
+
+        if let Some(distances) = heatmap {
+            // One path per color keeps the document small; the thin outline in the
+            // same color hides the anti-aliasing seams between neighboring cells.
+            let max_distance = distances.max_distance().unwrap_or(0);
+            let mut cells: BTreeMap<(u8, u8, u8), String> = BTreeMap::new();
+            for (cell, distance) in distances.iter() {
+                let color = style.gradient.sample_distance(distance, max_distance);
+                let outline = cells.entry(color).or_default();
+                Self::write_polygon(
+                    outline,
+                    grid.cell_outline(cell).into_iter().map(|p| style.scale(p)),
+                );
+            }
+            for (color, outline) in cells {
+                let color = Self::color(color);
+                let _ = writeln!(
+                    svg,
+                    r#"<path d="{}" fill="{color}" stroke="{color}" stroke-width="0.50"/>"#,
+                    outline.trim_end()
+                );
+            }
+        }
+
+        let mut solids = String::new();
+        for area in grid.solid_areas() {
+            Self::write_polygon(&mut solids, area.into_iter().map(|p| style.scale(p)));
+        }
+        if !solids.is_empty() {
+            let _ = writeln!(
+                svg,
+                r#"<path d="{}" fill="{}"/>"#,
+                solids.trim_end(),
+                Self::color(style.solid_color)
+            );
+        }
+
+        let mut walls = String::new();
+        for (a, b) in grid.wall_segments() {
+            let ((x0, y0), (x1, y1)) = (style.scale(a), style.scale(b));
+            let _ = write!(walls, "M{x0:.2} {y0:.2} L{x1:.2} {y1:.2} ");
+        }
+        if !walls.is_empty() {
+            let _ = writeln!(
+                svg,
+                r#"<path d="{}" fill="none" stroke="{}" stroke-width="{:.2}" stroke-linecap="{}"/>"#,
+                walls.trim_end(),
+                Self::color(style.wall_color),
+                style.stroke_width,
+                style.stroke_cap.as_str()
+            );
+        }
+
+        if let Some(solution) = solution {
+            for run in route_runs(grid, solution.cells()) {
+                let mut points = String::new();
+                for point in run {
+                    let (x, y) = style.scale(point);
+                    let _ = write!(points, "{x:.2},{y:.2} ");
+                }
+                let _ = writeln!(
+                    svg,
+                    r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{:.2}" stroke-linecap="round" stroke-linejoin="round"/>"#,
+                    points.trim_end(),
+                    Self::color(style.solution_color),
+                    style.cell_size / 4.0
+                );
+            }
+        }
+
+        if let Some((start, end)) = markers {
+            for (cell, color) in [(start, style.start_color), (end, style.end_color)] {
+                let (cx, cy) = style.scale(grid.center(cell));
+                let _ = writeln!(
+                    svg,
+                    r#"<circle cx="{cx:.2}" cy="{cy:.2}" r="{:.2}" fill="{}"/>"#,
+                    style.cell_size * 0.3,
+                    Self::color(color)
+                );
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Appends a closed polygon to SVG path data.
+    fn write_polygon(path: &mut String, points: impl Iterator<Item = Point>) {
+        for (i, (x, y)) in points.enumerate() {
+            let command = if i == 0 { 'M' } else { 'L' };
+            let _ = write!(path, "{command}{x:.2} {y:.2} ");
+        }
+        path.push_str("Z ");
+    }
+
+    fn color((r, g, b): (u8, u8, u8)) -> String {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    }
+}
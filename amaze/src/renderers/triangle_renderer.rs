@@ -0,0 +1,228 @@
+use crate::direction3::Direction3;
+use crate::renderers::ImageFormat;
+use crate::tri_coord::TriCoord;
+use crate::wall3_grid::Wall3Grid;
+use std::fmt::Write;
+
+/// A wall segment in image coordinates.
+type Segment = ((f64, f64), (f64, f64));
+
+/// Renderer for triangle mazes, producing SVG documents or PPM/PBM images.
+///
+/// Triangles have equal sides of [`set_cell_size`](Self::set_cell_size) units;
+/// each row is offset by half a side so that neighboring cells share an edge.
+pub struct TriangleRenderer {
+    cell_size: f64,
+    stroke_width: f64,
+    wall_color: (u8, u8, u8),
+    path_color: (u8, u8, u8),
+}
+
+impl Default for TriangleRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TriangleRenderer {
+    /// Creates a new renderer with 16 units per triangle side.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::renderers::TriangleRenderer;
+    ///
+    /// let renderer = TriangleRenderer::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            cell_size: 16.0,
+            stroke_width: 2.0,
+            wall_color: (12, 12, 72),    // Default: (Almost) Black walls
+            path_color: (255, 255, 255), // Default: White paths
+        }
+    }
+
+    /// Sets the side length of each triangle, in SVG units or pixels.
+    pub fn set_cell_size(&mut self, cell_size: f64) {
+        self.cell_size = cell_size;
+    }
+
+    /// Sets the width of the wall strokes in SVG output.
+    pub fn set_stroke_width(&mut self, stroke_width: f64) {
+        self.stroke_width = stroke_width;
+    }
+
+    /// Sets custom colors for walls and paths (not applicable for PBM).
+    pub fn set_colors(&mut self, wall_color: (u8, u8, u8), path_color: (u8, u8, u8)) {
+        self.wall_color = wall_color;
+        self.path_color = path_color;
+    }
+
+    /// Renders the maze as an SVG document.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::preamble::Wall3Grid;
+    /// use amaze::renderers::TriangleRenderer;
+    ///
+    /// let grid = Wall3Grid::new(10, 5);
+    /// let svg = TriangleRenderer::new().render_svg(&grid);
+    /// assert!(svg.starts_with("<svg"));
+    /// ```
+    pub fn render_svg(&self, grid: &Wall3Grid) -> String {
+        let margin = self.stroke_width.max(1.0);
+        let (width, height) = self.extent(grid);
+
+        let mut path = String::new();
+        for ((x0, y0), (x1, y1)) in self.segments(grid) {
+            let _ = write!(
+                path,
+                "M{:.2} {:.2} L{:.2} {:.2} ",
+                x0 + margin,
+                y0 + margin,
+                x1 + margin,
+                y1 + margin
+            );
+        }
+
+        let (w, h) = (width + 2.0 * margin, height + 2.0 * margin);
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w:.2}" height="{h:.2}" viewBox="0 0 {w:.2} {h:.2}">"#
+        );
+        let _ = writeln!(
+            svg,
+            r#"<rect width="100%" height="100%" fill="{}"/>"#,
+            Self::color(self.path_color)
+        );
+        if !path.is_empty() {
+            let _ = writeln!(
+                svg,
+                r#"<path d="{}" fill="none" stroke="{}" stroke-width="{:.2}" stroke-linecap="round"/>"#,
+                path.trim_end(),
+                Self::color(self.wall_color),
+                self.stroke_width
+            );
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Renders the maze into the specified image format with one-pixel walls.
+    ///
+    /// ## Example
+    /// ```
+    /// use amaze::preamble::Wall3Grid;
+    /// use amaze::renderers::{ImageFormat, TriangleRenderer};
+    ///
+    /// let grid = Wall3Grid::new(10, 5);
+    /// let image = TriangleRenderer::new().render_image(&grid, ImageFormat::PBM);
+    /// assert!(image.starts_with("P1"));
+    /// ```
+    pub fn render_image(&self, grid: &Wall3Grid, format: ImageFormat) -> String {
+        let (width, height) = self.extent(grid);
+        let image_width = width.ceil() as usize + 1;
+        let image_height = height.ceil() as usize + 1;
+
+        let mut pixels = vec![vec![false; image_width]; image_height];
+        for ((x0, y0), (x1, y1)) in self.segments(grid) {
+            let steps = (x1 - x0).abs().max((y1 - y0).abs()).ceil().max(1.0) as usize;
+            for i in 0..=steps {
+                let t = i as f64 / steps as f64;
+                let x = (x0 + (x1 - x0) * t).round() as usize;
+                let y = (y0 + (y1 - y0) * t).round() as usize;
+                pixels[y.min(image_height - 1)][x.min(image_width - 1)] = true;
+            }
+        }
+
+        let mut image = String::new();
+        match format {
+            ImageFormat::PPM => {
+                let _ = writeln!(image, "P3\n{image_width} {image_height}\n255");
+                for row in pixels {
+                    for wall in row {
+                        let (r, g, b) = if wall {
+                            self.wall_color
+                        } else {
+                            self.path_color
+                        };
+                        let _ = write!(image, "{r} {g} {b} ");
+                    }
+                    image.push('\n');
+                }
+            }
+            ImageFormat::PBM => {
+                let _ = writeln!(image, "P1\n{image_width} {image_height}");
+                for row in pixels {
+                    for wall in row {
+                        let _ = write!(image, "{} ", u8::from(wall));
+                    }
+                    image.push('\n');
+                }
+            }
+        }
+        image
+    }
+
+    /// Returns the width and height of the drawing, without margins.
+    fn extent(&self, grid: &Wall3Grid) -> (f64, f64) {
+        if grid.width() == 0 || grid.height() == 0 {
+            return (0.0, 0.0);
+        }
+        (
+            (grid.width() + 1) as f64 * self.cell_size / 2.0,
+            grid.height() as f64 * self.row_height(),
+        )
+    }
+
+    fn row_height(&self) -> f64 {
+        self.cell_size * 3f64.sqrt() / 2.0
+    }
+
+    /// Collects every closed wall exactly once.
+    ///
+    /// Each cell draws its west wall; the east wall only on the last column.
+    /// Downward triangles draw their base (the top edge), upward triangles only
+    /// on the bottom row, where no downward triangle below shares it.
+    fn segments(&self, grid: &Wall3Grid) -> Vec<Segment> {
+        let mut segments = Vec::new();
+        for cell in grid.coords() {
+            let walls = grid[cell];
+            let [left, right, apex] = self.corners(cell);
+
+            if walls.contains(Direction3::WEST) {
+                segments.push((left, apex));
+            }
+            if walls.contains(Direction3::EAST) && cell.x + 1 == grid.width() {
+                segments.push((apex, right));
+            }
+            let draw_base = !cell.is_upward() || cell.y + 1 == grid.height();
+            if walls.contains(Direction3::BASE) && draw_base {
+                segments.push((left, right));
+            }
+        }
+        segments
+    }
+
+    /// Returns the left and right corners of the base, followed by the apex.
+    fn corners(&self, cell: TriCoord) -> [(f64, f64); 3] {
+        let half = self.cell_size / 2.0;
+        let h = self.row_height();
+        let left = cell.x as f64 * half;
+        let (base_y, apex_y) = if cell.is_upward() {
+            ((cell.y + 1) as f64 * h, cell.y as f64 * h)
+        } else {
+            (cell.y as f64 * h, (cell.y + 1) as f64 * h)
+        };
+        [
+            (left, base_y),
+            (left + self.cell_size, base_y),
+            (left + half, apex_y),
+        ]
+    }
+
+    fn color((r, g, b): (u8, u8, u8)) -> String {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    }
+}
@@ -1,6 +1,12 @@
+use crate::direction3d::Direction3D;
+use crate::direction4::Direction4;
 use crate::grid_coord_2d::GridCoord2D;
+use crate::grid_coord_3d::GridCoord3D;
+use crate::path::Path;
 use crate::room4::Door4;
+use crate::wall3d_grid::Wall3DGrid;
 use crate::wall4_grid::Wall4Grid;
+use crate::weave_grid::WeaveGrid;
 
 /// See [UnicodeRenderStyle::Thin] for a usage example.
 #[allow(dead_code)]
@@ -86,6 +92,26 @@ const ASCII_SET_HEX: &[char] = &[
     'F', // ╬ 0b1111 - WESN
 ];
 
+/// Route glyphs for the hexadecimal style, which has no line-drawing characters.
+const ASCII_SET_ROUTE: &[char] = &[
+    ' ', '|', '|', '|', '-', '+', '+', '+', '-', '+', '+', '+', '-', '+', '+', '+',
+];
+
+/// ANSI graphic rendition codes of the route, the start and end markers, and
+/// highlighted cells.
+const ANSI_ROUTE: &str = "1;31";
+const ANSI_START: &str = "1;32";
+const ANSI_END: &str = "1;34";
+const ANSI_HIGHLIGHT: &str = "43";
+
+/// Crossing glyphs for passages running over a tunnel, as `[north-south, east-west]`.
+/// The over passage is drawn heavier than the tunnel beneath it.
+const CROSSINGS_HEAVY: [char; 2] = ['\u{2542}', '\u{253F}']; // ╂ ┿
+const CROSSINGS_DOUBLE: [char; 2] = ['\u{256B}', '\u{256A}']; // ╫ ╪
+
+/// Stair markers following each cell of a 3D maze, indexed by `up | down << 1`.
+const STAIRS: [char; 4] = [' ', '\u{2191}', '\u{2193}', '\u{2195}']; // ↑ ↓ ↕
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum UnicodeRenderStyle {
     /// Renders the grid as thin unicode lines.
@@ -149,7 +175,12 @@ pub enum UnicodeRenderStyle {
 
 #[derive(Debug, Clone)]
 pub struct UnicodeRenderer {
+    style: UnicodeRenderStyle,
     table: &'static [char],
+    crossings: Option<[char; 2]>,
+    /// The glyphs of solution routes, which contrast with the walls.
+    route_table: &'static [char],
+    colored: bool,
     line_breaks: bool,
 }
 
@@ -173,16 +204,34 @@ impl UnicodeRenderer {
     /// ```
     pub fn new(style: UnicodeRenderStyle, line_breaks: bool) -> Self {
         Self {
+            style,
             table: match style {
                 UnicodeRenderStyle::Thin => UNICODE_SET_THIN,
                 UnicodeRenderStyle::Double => UNICODE_SET_DOUBLE,
                 UnicodeRenderStyle::Heavy => UNICODE_SET_HEAVY,
                 UnicodeRenderStyle::Hexadecimal => ASCII_SET_HEX,
             },
+            crossings: match style {
+                UnicodeRenderStyle::Thin | UnicodeRenderStyle::Heavy => Some(CROSSINGS_HEAVY),
+                UnicodeRenderStyle::Double => Some(CROSSINGS_DOUBLE),
+                UnicodeRenderStyle::Hexadecimal => None,
+            },
+            route_table: match style {
+                UnicodeRenderStyle::Thin => UNICODE_SET_HEAVY,
+                UnicodeRenderStyle::Double | UnicodeRenderStyle::Heavy => UNICODE_SET_THIN,
+                UnicodeRenderStyle::Hexadecimal => ASCII_SET_ROUTE,
+            },
+            colored: false,
             line_breaks,
         }
     }
 
+    /// Enables ANSI colors in [Self::render_with]: the route is drawn in red, the start
+    /// and end markers in green and blue, and highlighted cells on a yellow background.
+    pub fn set_colored(&mut self, colored: bool) {
+        self.colored = colored;
+    }
+
     fn lookup(&self, doors: Door4) -> char {
         self.table[*doors as usize]
     }
@@ -192,12 +241,309 @@ impl UnicodeRenderer {
     /// ## Example
     /// See [Self::new].
     pub fn render(&self, grid: &Wall4Grid) -> String {
+        self.render_cells(grid.width(), grid.height(), |coord| {
+            self.lookup(!grid[coord])
+        })
+    }
+
+    /// Renders the maze at double resolution with the `solution` drawn through the cell
+    /// centers, marking its first cell `S` and its last cell `E`.
+    ///
+    /// ## Example
+    /// See [Self::render_with].
+    pub fn render_solution(&self, grid: &Wall4Grid, solution: &Path) -> String {
+        let markers = solution.start().zip(solution.end());
+        self.render_with(grid, Some(solution), markers, &[])
+    }
+
+    /// Renders the maze at double resolution: walls run between the cells, and the
+    /// `solution` is drawn through the cell centers in a contrasting line style. The
+    /// `(start, end)` cells are marked `S` and `E`, and the `highlighted` cells, such
+    /// as those a solver explored, are shaded. Steps across the joined edges of
+    /// wrapped grids are drawn out to both borders.
+    ///
+    /// Each cell and each wall takes one character, so a maze `w` cells wide is drawn
+    /// `2w + 1` characters wide. The hexadecimal style prints the wall directions of
+    /// every character and an ASCII route.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use amaze::preamble::{GridCoord2D, Path, Wall4Grid};
+    /// use amaze::renderers::{UnicodeRenderStyle, UnicodeRenderer};
+    ///
+    /// let mut grid = Wall4Grid::new(3, 2);
+    /// grid.remove_wall_between(GridCoord2D::new(0, 0), GridCoord2D::new(1, 0));
+    /// grid.remove_wall_between(GridCoord2D::new(1, 0), GridCoord2D::new(1, 1));
+    /// grid.remove_wall_between(GridCoord2D::new(1, 1), GridCoord2D::new(2, 1));
+    /// grid.remove_wall_between(GridCoord2D::new(2, 0), GridCoord2D::new(2, 1));
+    /// let solution = Path::new(vec![
+    ///     GridCoord2D::new(0, 0),
+    ///     GridCoord2D::new(1, 0),
+    ///     GridCoord2D::new(1, 1),
+    ///     GridCoord2D::new(2, 1),
+    /// ]);
+    ///
+    /// let renderer = UnicodeRenderer::new(UnicodeRenderStyle::Thin, true);
+    /// let expected = "┌───┬─┐\n│S━┓│ │\n├─┐┃╵ │\n│ │┗━E│\n└─┴───┘\n";
+    /// assert_eq!(renderer.render_solution(&grid, &solution), expected);
+    /// ```
+    pub fn render_with(
+        &self,
+        grid: &Wall4Grid,
+        solution: Option<&Path>,
+        markers: Option<(GridCoord2D, GridCoord2D)>,
+        highlighted: &[GridCoord2D],
+    ) -> String {
+        let (width, height) = (grid.width(), grid.height());
+        let columns = 2 * width + 1;
+        let rows = 2 * height + 1;
+        let position = |cell: GridCoord2D| (2 * cell.y + 1) * columns + 2 * cell.x + 1;
+
+        // Whether the wall at a position between two cells, or on the border, is closed.
+        let closed = |bx: usize, by: usize| {
+            let (x, y) = (bx / 2, by / 2);
+            if bx % 2 == 0 {
+                match x.checked_sub(1) {
+                    Some(west) => grid[GridCoord2D::new(west, y)].contains(Direction4::EAST),
+                    None => width > 0 && grid[GridCoord2D::new(0, y)].contains(Direction4::WEST),
+                }
+            } else {
+                match y.checked_sub(1) {
+                    Some(north) => grid[GridCoord2D::new(x, north)].contains(Direction4::SOUTH),
+                    None => height > 0 && grid[GridCoord2D::new(x, 0)].contains(Direction4::NORTH),
+                }
+            }
+        };
+
+        let mut route = vec![Direction4::NONE; columns * rows];
+        for step in solution.map_or(&[][..], |path| path.cells()).windows(2) {
+            let (a, b) = (step[0], step[1]);
+            if grid.get(a).is_none() || grid.get(b).is_none() {
+                continue;
+            }
+            let dx = b.x as isize - a.x as isize;
+            let dy = b.y as isize - a.y as isize;
+            let (from, to) = (position(a), position(b));
+            if let Some(direction) = Direction4::from_delta(dx, dy) {
+                route[from] += direction;
+                route[(from + to) / 2] += direction + direction.opposite();
+                route[to] += direction.opposite();
+                continue;
+            }
+
+            // Steps across the joined edges of cylinders and tori leave through one
+            // border and come back in through the opposite one.
+            let Some(direction) = Direction4::CARDINALS
+                .into_iter()
+                .find(|&dir| grid.neighbor(a, dir) == Some(b))
+            else {
+                continue;
+            };
+            let beside = |i: usize, dir: Direction4| match dir {
+                Direction4::NORTH => i - columns,
+                Direction4::SOUTH => i + columns,
+                Direction4::EAST => i + 1,
+                _ => i - 1,
+            };
+            let through = direction + direction.opposite();
+            route[from] += direction;
+            route[beside(from, direction)] += through;
+            route[beside(to, direction.opposite())] += through;
+            route[to] += direction.opposite();
+        }
+
+        // Open passages between two highlighted cells are shaded as well.
+        let mut shaded = vec![false; columns * rows];
+        let highlighted: Vec<_> = highlighted
+            .iter()
+            .copied()
+            .filter(|&cell| grid.get(cell).is_some())
+            .collect();
+        for &cell in &highlighted {
+            shaded[position(cell)] = true;
+        }
+        for &cell in &highlighted {
+            let i = position(cell);
+            if cell.x + 1 < width && shaded[i + 2] && !grid[cell].contains(Direction4::EAST) {
+                shaded[i + 1] = true;
+            }
+            if cell.y + 1 < height
+                && shaded[i + 2 * columns]
+                && !grid[cell].contains(Direction4::SOUTH)
+            {
+                shaded[i + columns] = true;
+            }
+        }
+
+        let shade = match self.style {
+            UnicodeRenderStyle::Hexadecimal => '.',
+            _ => '\u{2591}', // ░
+        };
+
+        let mut output = String::default();
+        for by in 0..rows {
+            let mut current = (None, false);
+            for bx in 0..columns {
+                let i = by * columns + bx;
+                let marker = markers.and_then(|(start, end)| {
+                    if i == position(start) && grid.get(start).is_some() {
+                        Some(('S', ANSI_START))
+                    } else if i == position(end) && grid.get(end).is_some() {
+                        Some(('E', ANSI_END))
+                    } else {
+                        None
+                    }
+                });
+
+                let (glyph, color) = if let Some((glyph, color)) = marker {
+                    (glyph, Some(color))
+                } else if !route[i].is_none() {
+                    (self.route_table[*route[i] as usize], Some(ANSI_ROUTE))
+                } else {
+                    let mut walls = Direction4::NONE;
+                    match (bx % 2, by % 2) {
+                        (0, 0) => {
+                            if by > 0 && closed(bx, by - 1) {
+                                walls += Direction4::NORTH;
+                            }
+                            if by + 1 < rows && closed(bx, by + 1) {
+                                walls += Direction4::SOUTH;
+                            }
+                            if bx + 1 < columns && closed(bx + 1, by) {
+                                walls += Direction4::EAST;
+                            }
+                            if bx > 0 && closed(bx - 1, by) {
+                                walls += Direction4::WEST;
+                            }
+                        }
+                        (0, 1) if closed(bx, by) => walls = Direction4::NORTH + Direction4::SOUTH,
+                        (1, 0) if closed(bx, by) => walls = Direction4::EAST + Direction4::WEST,
+                        _ => {}
+                    }
+                    if walls.is_none() && shaded[i] && !self.colored {
+                        (shade, None)
+                    } else {
+                        (self.table[*walls as usize], None)
+                    }
+                };
+
+                if self.colored {
+                    let next = (color, shaded[i]);
+                    if next != current {
+                        if current != (None, false) {
+                            output.push_str("\x1b[0m");
+                        }
+                        if let Some(color) = color {
+                            output.push_str(&format!("\x1b[{color}m"));
+                        }
+                        if shaded[i] {
+                            output.push_str(&format!("\x1b[{ANSI_HIGHLIGHT}m"));
+                        }
+                        current = next;
+                    }
+                }
+                output.push(glyph);
+            }
+            if current != (None, false) {
+                output.push_str("\x1b[0m");
+            }
+            if self.line_breaks {
+                output.push('\n');
+            }
+        }
+
+        output
+    }
+
+    /// Renders a weave maze, drawing each crossing as its over passage
+    /// with the tunnel passing beneath it.
+    ///
+    /// The hexadecimal style has no crossing glyphs and prints the over passage only.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use amaze::preamble::{Direction4, GridCoord2D, WeaveGrid};
+    /// use amaze::renderers::{UnicodeRenderStyle, UnicodeRenderer};
+    ///
+    /// let mut grid = WeaveGrid::new(3, 3);
+    /// grid.remove_wall_between(GridCoord2D::new(1, 1), GridCoord2D::new(1, 0));
+    /// grid.remove_wall_between(GridCoord2D::new(1, 1), GridCoord2D::new(1, 2));
+    /// grid.add_tunnel(GridCoord2D::new(0, 1), Direction4::EAST);
+    ///
+    /// let renderer = UnicodeRenderer::new(UnicodeRenderStyle::Heavy, true);
+    /// assert_eq!(renderer.render_weave(&grid), " ╻ \n╺╂╸\n ╹ \n");
+    /// ```
+    pub fn render_weave(&self, grid: &WeaveGrid) -> String {
+        self.render_cells(grid.width(), grid.height(), |coord| match self.crossings {
+            Some([vertical, _]) if grid.tunnel_directions(coord).contains(Direction4::EAST) => {
+                vertical
+            }
+            Some([_, horizontal]) if grid.is_crossing(coord) => horizontal,
+            _ => self.lookup(!grid[coord]),
+        })
+    }
+
+    /// Renders a single layer of a 3D maze.
+    ///
+    /// Each cell is drawn as its horizontal passages followed by a stair marker:
+    /// `↑` leads up, `↓` leads down and `↕` both. The hexadecimal style instead
+    /// prints all six door bits as two digits per cell.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use amaze::preamble::{GridCoord3D, Wall3DGrid};
+    /// use amaze::renderers::{UnicodeRenderStyle, UnicodeRenderer};
+    ///
+    /// let mut grid = Wall3DGrid::new(2, 1, 2);
+    /// grid.remove_wall_between(GridCoord3D::new(0, 0, 0), GridCoord3D::new(1, 0, 0));
+    /// grid.remove_wall_between(GridCoord3D::new(1, 0, 0), GridCoord3D::new(1, 0, 1));
+    ///
+    /// let renderer = UnicodeRenderer::new(UnicodeRenderStyle::Heavy, true);
+    /// assert_eq!(renderer.render_layer(&grid, 0), "╺ ╸↑\n");
+    /// assert_eq!(renderer.render_layer(&grid, 1), "   ↓\n");
+    /// ```
+    pub fn render_layer(&self, grid: &Wall3DGrid, z: usize) -> String {
         let mut output = String::default();
         for y in 0..grid.height() {
             for x in 0..grid.width() {
-                let coord = GridCoord2D::new(x, y);
-                let wall: Door4 = !grid[coord];
-                output.push(self.lookup(wall));
+                let doors = !grid[GridCoord3D::new(x, y, z)];
+                if self.style == UnicodeRenderStyle::Hexadecimal {
+                    output.push_str(&format!("{:02X}", *doors));
+                    continue;
+                }
+
+                output.push(self.table[(*doors & 0b1111) as usize]);
+                let up = doors.contains(Direction3D::UP) as usize;
+                let down = doors.contains(Direction3D::DOWN) as usize;
+                output.push(STAIRS[up | down << 1]);
+            }
+            if self.line_breaks {
+                output.push('\n');
+            }
+        }
+
+        output
+    }
+
+    /// Renders all layers of a 3D maze from the bottom up, separated by an
+    /// empty line if line breaks are enabled. See [Self::render_layer].
+    pub fn render_3d(&self, grid: &Wall3DGrid) -> String {
+        let layers: Vec<_> = (0..grid.depth())
+            .map(|z| self.render_layer(grid, z))
+            .collect();
+        layers.join(if self.line_breaks { "\n" } else { "" })
+    }
+
+    fn render_cells(
+        &self,
+        width: usize,
+        height: usize,
+        cell: impl Fn(GridCoord2D) -> char,
+    ) -> String {
+        let mut output = String::default();
+        for y in 0..height {
+            for x in 0..width {
+                output.push(cell(GridCoord2D::new(x, y)));
             }
             if self.line_breaks {
                 output.push('\n');
@@ -212,6 +558,7 @@ impl UnicodeRenderer {
 mod tests {
     use super::*;
     use crate::generators::RecursiveBacktracker4;
+    use crate::wrap_mode::WrapMode;
     use indoc::indoc;
 
     #[test]
@@ -298,4 +645,78 @@ mod tests {
         let expected = "26CCCA37CCA135CA5A5CA16B6836935CD949";
         assert_eq!(str, expected);
     }
+
+    /// A 3x1 corridor whose route runs from the west to the east end.
+    fn corridor() -> (Wall4Grid, Path) {
+        let mut grid = Wall4Grid::new(3, 1);
+        grid.remove_wall_between(GridCoord2D::new(0, 0), GridCoord2D::new(1, 0));
+        grid.remove_wall_between(GridCoord2D::new(1, 0), GridCoord2D::new(2, 0));
+        let path = Path::new(vec![GridCoord2D::new(0, 0), GridCoord2D::new(1, 0)]);
+        (grid, path)
+    }
+
+    #[test]
+    fn solution_styles() {
+        let (grid, path) = corridor();
+        let markers = Some((GridCoord2D::new(0, 0), GridCoord2D::new(1, 0)));
+        let highlighted = [GridCoord2D::new(2, 0)];
+
+        let render = |style| {
+            UnicodeRenderer::new(style, true).render_with(&grid, Some(&path), markers, &highlighted)
+        };
+        assert_eq!(
+            render(UnicodeRenderStyle::Thin),
+            "┌─────┐\n│S━E ░│\n└─────┘\n"
+        );
+        assert_eq!(
+            render(UnicodeRenderStyle::Heavy),
+            "┏━━━━━┓\n┃S─E ░┃\n┗━━━━━┛\n"
+        );
+        assert_eq!(
+            render(UnicodeRenderStyle::Double),
+            "╔═════╗\n║S─E ░║\n╚═════╝\n"
+        );
+        assert_eq!(
+            render(UnicodeRenderStyle::Hexadecimal),
+            "6CCCCCA\n3S-E0.3\n5CCCCC9\n"
+        );
+    }
+
+    #[test]
+    fn solution_without_line_breaks() {
+        let (grid, path) = corridor();
+        let renderer = UnicodeRenderer::new(UnicodeRenderStyle::Thin, false);
+        assert_eq!(
+            renderer.render_with(&grid, Some(&path), None, &[]),
+            "┌─────┐│╺━╸  │└─────┘"
+        );
+    }
+
+    #[test]
+    fn wrapped_solution() {
+        let mut grid = Wall4Grid::new_wrapped(3, 1, WrapMode::Horizontal);
+        grid.remove_wall_between(GridCoord2D::new(0, 0), GridCoord2D::new(2, 0));
+        let path = Path::new(vec![GridCoord2D::new(0, 0), GridCoord2D::new(2, 0)]);
+
+        let renderer = UnicodeRenderer::new(UnicodeRenderStyle::Thin, true);
+        assert_eq!(
+            renderer.render_solution(&grid, &path),
+            "╶─┬─┬─╴\n━S│ │E━\n╶─┴─┴─╴\n"
+        );
+    }
+
+    #[test]
+    fn colored_solution() {
+        let (grid, path) = corridor();
+        let mut renderer = UnicodeRenderer::new(UnicodeRenderStyle::Thin, true);
+        renderer.set_colored(true);
+        let text = renderer.render_solution(&grid, &path);
+
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(lines[0], "┌─────┐");
+        assert!(lines[1].contains("\x1b[1;32mS\x1b[0m"));
+        assert!(lines[1].contains("\x1b[1;31m━\x1b[0m"));
+        assert!(lines[1].contains("\x1b[1;34mE\x1b[0m"));
+        assert!(!lines[1].contains("\x1b[43m"));
+    }
 }
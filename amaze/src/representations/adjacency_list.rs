@@ -45,11 +45,7 @@ impl Index<GridCoord2D> for AdjacencyList {
 
 impl From<&Wall4Grid> for AdjacencyList {
     fn from(value: &Wall4Grid) -> Self {
-        let mut neighbors = vec![Vec::with_capacity(4); value.width() * value.height()];
-
-        for cell in value.coords() {
-            neighbors[value.linearize_coords(cell)] = value.open_neighbors(cell).collect();
-        }
+        let neighbors = super::adjacency(value);
 
         Self {
             width: value.width(),
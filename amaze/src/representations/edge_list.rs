@@ -25,15 +25,10 @@ impl EdgeList {
 
 impl From<&Wall4Grid> for EdgeList {
     fn from(value: &Wall4Grid) -> Self {
-        let mut edges = Vec::new();
-
-        for from in value.coords() {
-            for to in value.open_neighbors(from) {
-                if from < to {
-                    edges.push(Edge { from, to });
-                }
-            }
-        }
+        let edges = super::open_edges(value)
+            .into_iter()
+            .map(|(from, to)| Edge { from, to })
+            .collect();
 
         Self {
             width: value.width(),
@@ -33,12 +33,7 @@ impl HexAdjacencyList {
 
 impl From<&Wall6Grid> for HexAdjacencyList {
     fn from(value: &Wall6Grid) -> Self {
-        let mut neighbors = vec![Vec::with_capacity(6); value.width() * value.height()];
-
-        for cell in value.coords() {
-            let idx = (cell.r as usize) * value.width() + (cell.q as usize);
-            neighbors[idx] = value.open_neighbors(cell).collect();
-        }
+        let neighbors = super::adjacency(value);
 
         Self {
             width: value.width(),
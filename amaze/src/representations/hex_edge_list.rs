@@ -25,15 +25,10 @@ impl HexEdgeList {
 
 impl From<&Wall6Grid> for HexEdgeList {
     fn from(value: &Wall6Grid) -> Self {
-        let mut edges = Vec::new();
-
-        for from in value.coords() {
-            for to in value.open_neighbors(from) {
-                if from < to {
-                    edges.push(HexEdge { from, to });
-                }
-            }
-        }
+        let edges = super::open_edges(value)
+            .into_iter()
+            .map(|(from, to)| HexEdge { from, to })
+            .collect();
 
         Self {
             width: value.width(),
@@ -1,3 +1,9 @@
+//! Alternative graph representations of carved mazes.
+//!
+//! The concrete types are built from [`Wall4Grid`](crate::preamble::Wall4Grid) and
+//! [`Wall6Grid`](crate::preamble::Wall6Grid); the builder functions in this module
+//! work for any [`MazeGrid`] and can back representations of custom topologies.
+
 mod adjacency_list;
 mod edge_list;
 mod hex_adjacency_list;
@@ -17,3 +23,51 @@ pub use weighted_adjacency_list::WeightedAdjacencyList;
 pub use weighted_edge_list::{WeightedEdge, WeightedEdgeList};
 pub use weighted_hex_adjacency_list::WeightedHexAdjacencyList;
 pub use weighted_hex_edge_list::{HexWeightedEdge, WeightedHexEdgeList};
+
+use crate::maze_grid::MazeGrid;
+
+/// Collects the open neighbors of every cell, indexed by [`MazeGrid::linearize`].
+pub fn adjacency<G: MazeGrid>(grid: &G) -> Vec<Vec<G::Coord>> {
+    let mut neighbors = vec![Vec::new(); grid.cell_count()];
+
+    for cell in grid.coords() {
+        neighbors[grid.linearize(cell)] = grid.open_neighbors(cell).collect();
+    }
+
+    neighbors
+}
+
+/// Collects the open neighbors of every cell together with the weight of the
+/// passage, indexed by [`MazeGrid::linearize`].
+pub fn weighted_adjacency<G, W, F>(grid: &G, mut weight_fn: F) -> Vec<Vec<(G::Coord, W)>>
+where
+    G: MazeGrid,
+    F: FnMut(G::Coord, G::Coord) -> W,
+{
+    let mut neighbors: Vec<Vec<(G::Coord, W)>> = Vec::with_capacity(grid.cell_count());
+    neighbors.resize_with(grid.cell_count(), Vec::new);
+
+    for from in grid.coords() {
+        neighbors[grid.linearize(from)] = grid
+            .open_neighbors(from)
+            .map(|to| (to, weight_fn(from, to)))
+            .collect();
+    }
+
+    neighbors
+}
+
+/// Collects every open passage once as a `(from, to)` pair with `from < to`.
+pub fn open_edges<G: MazeGrid>(grid: &G) -> Vec<(G::Coord, G::Coord)> {
+    let mut edges = Vec::new();
+
+    for from in grid.coords() {
+        for to in grid.open_neighbors(from) {
+            if from < to {
+                edges.push((from, to));
+            }
+        }
+    }
+
+    edges
+}
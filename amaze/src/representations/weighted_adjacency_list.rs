@@ -11,20 +11,11 @@ pub struct WeightedAdjacencyList {
 }
 
 impl WeightedAdjacencyList {
-    pub fn from_wall_grid_with<F>(value: &Wall4Grid, mut weight_fn: F) -> Self
+    pub fn from_wall_grid_with<F>(value: &Wall4Grid, weight_fn: F) -> Self
     where
         F: FnMut(GridCoord2D, GridCoord2D) -> f32,
     {
-        let mut neighbors = vec![Vec::with_capacity(4); value.width() * value.height()];
-
-        for from in value.coords() {
-            let weighted_neighbors = value
-                .open_neighbors(from)
-                .map(|to| (to, weight_fn(from, to)))
-                .collect();
-
-            neighbors[value.linearize_coords(from)] = weighted_neighbors;
-        }
+        let neighbors = super::weighted_adjacency(value, weight_fn);
 
         Self {
             width: value.width(),
@@ -10,21 +10,11 @@ pub struct WeightedHexAdjacencyList {
 }
 
 impl WeightedHexAdjacencyList {
-    pub fn from_wall_grid_with<F>(value: &Wall6Grid, mut weight_fn: F) -> Self
+    pub fn from_wall_grid_with<F>(value: &Wall6Grid, weight_fn: F) -> Self
     where
         F: FnMut(HexCoord, HexCoord) -> f32,
     {
-        let mut neighbors = vec![Vec::with_capacity(6); value.width() * value.height()];
-
-        for from in value.coords() {
-            let idx = (from.r as usize) * value.width() + (from.q as usize);
-            let weighted_neighbors = value
-                .open_neighbors(from)
-                .map(|to| (to, weight_fn(from, to)))
-                .collect();
-
-            neighbors[idx] = weighted_neighbors;
-        }
+        let neighbors = super::weighted_adjacency(value, weight_fn);
 
         Self {
             width: value.width(),
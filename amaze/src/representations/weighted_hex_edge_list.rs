@@ -22,19 +22,14 @@ impl WeightedHexEdgeList {
     where
         F: FnMut(HexCoord, HexCoord) -> f32,
     {
-        let mut edges = Vec::new();
-
-        for from in value.coords() {
-            for to in value.open_neighbors(from) {
-                if from < to {
-                    edges.push(HexWeightedEdge {
-                        from,
-                        to,
-                        weight: weight_fn(from, to),
-                    });
-                }
-            }
-        }
+        let edges = super::open_edges(value)
+            .into_iter()
+            .map(|(from, to)| HexWeightedEdge {
+                from,
+                to,
+                weight: weight_fn(from, to),
+            })
+            .collect();
 
         Self {
             width: value.width(),
@@ -0,0 +1,131 @@
+use crate::direction4::Direction4;
+use crate::grid_coord_2d::GridCoord2D;
+use crate::path::Path;
+use crate::wall4_grid::Wall4Grid;
+use std::collections::HashMap;
+
+/// The number of steps per cell an agent may walk before it is stopped, unless
+/// configured otherwise.
+pub(crate) const DEFAULT_STEPS_PER_CELL: usize = 16;
+
+/// The hand an agent keeps on the wall.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum Hand {
+    #[default]
+    Left,
+    Right,
+}
+
+impl Hand {
+    /// Returns the direction on this hand's side when facing `heading`.
+    pub(crate) fn side(self, heading: Direction4) -> Direction4 {
+        match self {
+            Hand::Left => heading.turn_left(),
+            Hand::Right => heading.turn_right(),
+        }
+    }
+}
+
+/// How an agent's walk ended.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum WalkOutcome {
+    /// The agent reached the end cell.
+    Reached,
+    /// The agent provably cannot reach the end: it returned to a state it was in
+    /// before, or ran out of unexplored passages.
+    Trapped,
+    /// The agent was stopped after walking the maximum number of steps.
+    StepLimit,
+}
+
+/// The walk of an agent that only uses local information, such as a wall follower.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentWalk {
+    /// Every cell the agent stood on, in order and including revisits.
+    pub trajectory: Vec<GridCoord2D>,
+    /// The trajectory with all loops erased, if the agent reached the end.
+    pub path: Option<Path>,
+    pub outcome: WalkOutcome,
+}
+
+impl AgentWalk {
+    pub(crate) fn new(trajectory: Vec<GridCoord2D>, outcome: WalkOutcome) -> Self {
+        let path = (outcome == WalkOutcome::Reached).then(|| erase_loops(&trajectory));
+        Self {
+            trajectory,
+            path,
+            outcome,
+        }
+    }
+
+    /// Returns the number of moves the agent made, counting revisits.
+    pub fn steps(&self) -> usize {
+        self.trajectory.len().saturating_sub(1)
+    }
+}
+
+/// A solver that walks the maze like a person would, seeing only the walls around it.
+///
+/// Agents report their full [`AgentWalk`]; their [`MazeSolver`](crate::solvers::MazeSolver)
+/// implementation returns the loop-erased path of a successful walk. Comparing
+/// [`AgentWalk::steps`] with the length of the shortest path gives a measure of
+/// how hard a maze is to solve by hand.
+pub trait AgentSolver {
+    fn walk(&self, maze: &Wall4Grid, start: GridCoord2D, end: GridCoord2D) -> AgentWalk;
+}
+
+/// Returns the cell reached by leaving `cell` through the open side `dir`.
+pub(crate) fn step(maze: &Wall4Grid, cell: GridCoord2D, dir: Direction4) -> Option<GridCoord2D> {
+    let walls = maze.get(cell)?;
+    if walls.contains(dir) {
+        return None;
+    }
+    maze.neighbor(cell, dir)
+}
+
+/// Returns the step budget of a walk through `maze`.
+pub(crate) fn step_limit(maze: &Wall4Grid, max_steps: Option<usize>) -> usize {
+    max_steps.unwrap_or(maze.width() * maze.height() * DEFAULT_STEPS_PER_CELL)
+}
+
+/// Removes every loop from a walk, leaving a simple path between its ends.
+fn erase_loops(trajectory: &[GridCoord2D]) -> Path {
+    let mut path: Vec<GridCoord2D> = Vec::new();
+    let mut position = HashMap::new();
+
+    for &cell in trajectory {
+        if let Some(&index) = position.get(&cell) {
+            for erased in path.drain(index + 1..) {
+                position.remove(&erased);
+            }
+        } else {
+            position.insert(cell, path.len());
+            path.push(cell);
+        }
+    }
+
+    Path::new(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn erase_loops_removes_detours() {
+        let c = |x, y| GridCoord2D::new(x, y);
+        let walk = [
+            c(0, 0),
+            c(1, 0),
+            c(2, 0),
+            c(1, 0),
+            c(1, 1),
+            c(0, 1),
+            c(1, 1),
+        ];
+
+        let path = erase_loops(&walk);
+
+        assert_eq!(path.cells(), &[c(0, 0), c(1, 0), c(1, 1)]);
+    }
+}
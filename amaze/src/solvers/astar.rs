@@ -1,54 +1,93 @@
-use crate::grid_coord_2d::{GridCoord2D, LinearizeCoords2D};
-use crate::solvers::{MazeSolver, rebuild_path};
-use crate::wall4_grid::Wall4Grid;
+use crate::maze_grid::MazeGrid;
+use crate::path::Path;
+use crate::solvers::{MazeSolver, SolveProcess, SolveStep, SolveSteps, rebuild_path};
 use std::cmp::Reverse;
-use std::collections::BinaryHeap;
+use std::collections::{BinaryHeap, VecDeque};
 
 #[derive(Debug, Copy, Clone, Default)]
 pub struct AStarSolver;
 
-fn heuristic(a: GridCoord2D, b: GridCoord2D) -> usize {
-    a.x.abs_diff(b.x) + a.y.abs_diff(b.y)
-}
+impl<G: MazeGrid> MazeSolver<G> for AStarSolver {
+    fn solve(&self, maze: &G, start: G::Coord, end: G::Coord) -> Option<Path<G::Coord>> {
+        self.solve_steps(maze, start, end).finish()
+    }
 
-impl MazeSolver for AStarSolver {
-    fn solve(
+    fn solve_steps<'a>(
         &self,
-        maze: &Wall4Grid,
-        start: GridCoord2D,
-        end: GridCoord2D,
-    ) -> Option<crate::path::Path> {
-        if maze.get(start).is_none() || maze.get(end).is_none() {
-            return None;
+        maze: &'a G,
+        start: G::Coord,
+        end: G::Coord,
+    ) -> SolveSteps<'a, G::Coord> {
+        if !maze.contains(start) || !maze.contains(end) {
+            return SolveSteps::finished(None);
         }
 
-        let total = maze.width() * maze.height();
+        let total = maze.cell_count();
         let mut g = vec![usize::MAX; total];
-        let mut parent = vec![None; total];
-        let mut open = BinaryHeap::new();
+        g[maze.linearize(start)] = 0;
 
-        let sidx = maze.linearize_coords(start);
-        g[sidx] = 0;
-        open.push((Reverse(heuristic(start, end)), start));
+        SolveSteps::new(AStarProcess {
+            maze,
+            start,
+            end,
+            g,
+            parent: vec![None; total],
+            open: BinaryHeap::from([(Reverse(maze.distance_estimate(start, end)), start)]),
+            path: None,
+        })
+    }
+}
 
-        while let Some((_, cell)) = open.pop() {
-            if cell == end {
-                return rebuild_path(&parent, maze, start, end);
-            }
+/// The open set of an [`AStarSolver`], paused between two expanded cells.
+struct AStarProcess<'a, G: MazeGrid> {
+    maze: &'a G,
+    start: G::Coord,
+    end: G::Coord,
+    g: Vec<usize>,
+    parent: Vec<Option<G::Coord>>,
+    open: BinaryHeap<(Reverse<usize>, G::Coord)>,
+    path: Option<Path<G::Coord>>,
+}
+
+impl<G: MazeGrid> SolveProcess for AStarProcess<'_, G> {
+    type Coord = G::Coord;
 
-            let cell_cost = g[maze.linearize_coords(cell)];
-            for next in maze.open_neighbors(cell) {
-                let nidx = maze.linearize_coords(next);
-                let tentative = cell_cost + 1;
-                if tentative < g[nidx] {
-                    g[nidx] = tentative;
-                    parent[nidx] = Some(cell);
-                    let f = tentative + heuristic(next, end);
-                    open.push((Reverse(f), next));
-                }
+    fn advance(&mut self, steps: &mut VecDeque<SolveStep<G::Coord>>) -> bool {
+        let Some((Reverse(f), cell)) = self.open.pop() else {
+            return false;
+        };
+
+        let cell_cost = self.g[self.maze.linearize(cell)];
+        // Entries superseded by a cheaper route are skipped without being reported.
+        if f > cell_cost + self.maze.distance_estimate(cell, self.end) {
+            return true;
+        }
+
+        steps.push_back(SolveStep::Explore { cell });
+        if cell == self.end {
+            self.path = rebuild_path(&self.parent, self.maze, self.start, self.end);
+            steps.extend(self.path.clone().map(|path| SolveStep::PathFound { path }));
+            return false;
+        }
+
+        for next in self.maze.open_neighbors(cell) {
+            let nidx = self.maze.linearize(next);
+            let tentative = cell_cost + 1;
+            if tentative < self.g[nidx] {
+                self.g[nidx] = tentative;
+                self.parent[nidx] = Some(cell);
+                let f = tentative + self.maze.distance_estimate(next, self.end);
+                self.open.push((Reverse(f), next));
+                steps.push_back(SolveStep::Enqueue {
+                    cell: next,
+                    from: cell,
+                });
             }
         }
+        true
+    }
 
-        None
+    fn into_path(self: Box<Self>) -> Option<Path<G::Coord>> {
+        self.path
     }
 }
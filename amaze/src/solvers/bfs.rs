@@ -1,44 +1,83 @@
-use crate::grid_coord_2d::{GridCoord2D, LinearizeCoords2D};
-use crate::solvers::{MazeSolver, rebuild_path};
-use crate::wall4_grid::Wall4Grid;
+use crate::maze_grid::MazeGrid;
+use crate::path::Path;
+use crate::solvers::{MazeSolver, SolveProcess, SolveStep, SolveSteps, rebuild_path};
 use std::collections::VecDeque;
 
 #[derive(Debug, Copy, Clone, Default)]
 pub struct BfsSolver;
 
-impl MazeSolver for BfsSolver {
-    fn solve(
+impl<G: MazeGrid> MazeSolver<G> for BfsSolver {
+    fn solve(&self, maze: &G, start: G::Coord, end: G::Coord) -> Option<Path<G::Coord>> {
+        self.solve_steps(maze, start, end).finish()
+    }
+
+    fn solve_steps<'a>(
         &self,
-        maze: &Wall4Grid,
-        start: GridCoord2D,
-        end: GridCoord2D,
-    ) -> Option<crate::path::Path> {
-        if maze.get(start).is_none() || maze.get(end).is_none() {
-            return None;
+        maze: &'a G,
+        start: G::Coord,
+        end: G::Coord,
+    ) -> SolveSteps<'a, G::Coord> {
+        if !maze.contains(start) || !maze.contains(end) {
+            return SolveSteps::finished(None);
         }
 
-        let mut queue = VecDeque::new();
-        let mut seen = vec![false; maze.width() * maze.height()];
-        let mut parent = vec![None; maze.width() * maze.height()];
+        let mut seen = vec![false; maze.cell_count()];
+        seen[maze.linearize(start)] = true;
 
-        seen[maze.linearize_coords(start)] = true;
-        queue.push_back(start);
+        SolveSteps::new(BfsProcess {
+            maze,
+            start,
+            end,
+            queue: VecDeque::from([start]),
+            seen,
+            parent: vec![None; maze.cell_count()],
+            path: None,
+        })
+    }
+}
 
-        while let Some(cell) = queue.pop_front() {
-            if cell == end {
-                return rebuild_path(&parent, maze, start, end);
-            }
+/// The queue of a [`BfsSolver`], paused between two expanded cells.
+struct BfsProcess<'a, G: MazeGrid> {
+    maze: &'a G,
+    start: G::Coord,
+    end: G::Coord,
+    queue: VecDeque<G::Coord>,
+    seen: Vec<bool>,
+    parent: Vec<Option<G::Coord>>,
+    path: Option<Path<G::Coord>>,
+}
 
-            for next in maze.open_neighbors(cell) {
-                let idx = maze.linearize_coords(next);
-                if !seen[idx] {
-                    seen[idx] = true;
-                    parent[idx] = Some(cell);
-                    queue.push_back(next);
-                }
+impl<G: MazeGrid> SolveProcess for BfsProcess<'_, G> {
+    type Coord = G::Coord;
+
+    fn advance(&mut self, steps: &mut VecDeque<SolveStep<G::Coord>>) -> bool {
+        let Some(cell) = self.queue.pop_front() else {
+            return false;
+        };
+
+        steps.push_back(SolveStep::Explore { cell });
+        if cell == self.end {
+            self.path = rebuild_path(&self.parent, self.maze, self.start, self.end);
+            steps.extend(self.path.clone().map(|path| SolveStep::PathFound { path }));
+            return false;
+        }
+
+        for next in self.maze.open_neighbors(cell) {
+            let idx = self.maze.linearize(next);
+            if !self.seen[idx] {
+                self.seen[idx] = true;
+                self.parent[idx] = Some(cell);
+                self.queue.push_back(next);
+                steps.push_back(SolveStep::Enqueue {
+                    cell: next,
+                    from: cell,
+                });
             }
         }
+        true
+    }
 
-        None
+    fn into_path(self: Box<Self>) -> Option<Path<G::Coord>> {
+        self.path
     }
 }
@@ -1,60 +1,76 @@
-use crate::grid_coord_2d::{GridCoord2D, LinearizeCoords2D};
+use crate::maze_grid::MazeGrid;
 use crate::path::Path;
-use crate::solvers::MazeSolver;
-use crate::wall4_grid::Wall4Grid;
+use crate::solvers::{MazeSolver, SolveProcess, SolveStep, SolveSteps};
 use std::collections::{HashSet, VecDeque};
 
 #[derive(Debug, Copy, Clone, Default)]
 pub struct DeadEndFillingSolver;
 
-impl MazeSolver for DeadEndFillingSolver {
-    fn solve(&self, maze: &Wall4Grid, start: GridCoord2D, end: GridCoord2D) -> Option<Path> {
-        if maze.get(start).is_none() || maze.get(end).is_none() {
-            return None;
+impl<G: MazeGrid> MazeSolver<G> for DeadEndFillingSolver {
+    fn solve(&self, maze: &G, start: G::Coord, end: G::Coord) -> Option<Path<G::Coord>> {
+        self.solve_steps(maze, start, end).finish()
+    }
+
+    fn solve_steps<'a>(
+        &self,
+        maze: &'a G,
+        start: G::Coord,
+        end: G::Coord,
+    ) -> SolveSteps<'a, G::Coord> {
+        if !maze.contains(start) || !maze.contains(end) {
+            return SolveSteps::finished(None);
         }
 
-        let mut degree = vec![0usize; maze.width() * maze.height()];
+        let mut degree = vec![0usize; maze.cell_count()];
         for cell in maze.coords() {
-            degree[maze.linearize_coords(cell)] = maze.open_neighbors(cell).count();
+            degree[maze.linearize(cell)] = maze.open_neighbors(cell).count();
         }
 
-        let mut removed: HashSet<GridCoord2D> = HashSet::new();
-        let mut q = VecDeque::new();
+        let mut queue = VecDeque::new();
         for cell in maze.coords() {
-            let idx = maze.linearize_coords(cell);
+            let idx = maze.linearize(cell);
             if cell != start && cell != end && degree[idx] <= 1 {
-                q.push_back(cell);
+                queue.push_back(cell);
             }
         }
 
-        while let Some(cell) = q.pop_front() {
-            if !removed.insert(cell) {
-                continue;
-            }
+        SolveSteps::new(DeadEndFillingProcess {
+            maze,
+            start,
+            end,
+            degree,
+            removed: HashSet::new(),
+            queue,
+            path: None,
+        })
+    }
+}
 
-            for n in maze.open_neighbors(cell) {
-                if removed.contains(&n) {
-                    continue;
-                }
-                let idx = maze.linearize_coords(n);
-                if degree[idx] > 0 {
-                    degree[idx] -= 1;
-                    if n != start && n != end && degree[idx] <= 1 {
-                        q.push_back(n);
-                    }
-                }
-            }
-        }
+/// The dead ends still to be filled by a [`DeadEndFillingSolver`], paused between two
+/// filled cells.
+struct DeadEndFillingProcess<'a, G: MazeGrid> {
+    maze: &'a G,
+    start: G::Coord,
+    end: G::Coord,
+    degree: Vec<usize>,
+    removed: HashSet<G::Coord>,
+    queue: VecDeque<G::Coord>,
+    path: Option<Path<G::Coord>>,
+}
 
-        let mut current = start;
-        let mut path = vec![start];
-        let mut prev: Option<GridCoord2D> = None;
+impl<G: MazeGrid> DeadEndFillingProcess<'_, G> {
+    /// Follows the corridor that survived filling from the start to the end.
+    fn walk(&self) -> Option<Path<G::Coord>> {
+        let mut current = self.start;
+        let mut path = vec![self.start];
+        let mut prev: Option<G::Coord> = None;
 
-        while current != end {
-            let mut next_options = maze
+        while current != self.end {
+            let mut next_options = self
+                .maze
                 .open_neighbors(current)
                 .filter(|n| Some(*n) != prev)
-                .filter(|n| !removed.contains(n) || *n == end)
+                .filter(|n| !self.removed.contains(n) || *n == self.end)
                 .collect::<Vec<_>>();
 
             if next_options.is_empty() {
@@ -70,3 +86,38 @@ impl MazeSolver for DeadEndFillingSolver {
         Some(Path::new(path))
     }
 }
+
+impl<G: MazeGrid> SolveProcess for DeadEndFillingProcess<'_, G> {
+    type Coord = G::Coord;
+
+    fn advance(&mut self, steps: &mut VecDeque<SolveStep<G::Coord>>) -> bool {
+        let Some(cell) = self.queue.pop_front() else {
+            self.path = self.walk();
+            steps.extend(self.path.clone().map(|path| SolveStep::PathFound { path }));
+            return false;
+        };
+
+        if !self.removed.insert(cell) {
+            return true;
+        }
+        steps.push_back(SolveStep::FillDeadEnd { cell });
+
+        for n in self.maze.open_neighbors(cell) {
+            if self.removed.contains(&n) {
+                continue;
+            }
+            let idx = self.maze.linearize(n);
+            if self.degree[idx] > 0 {
+                self.degree[idx] -= 1;
+                if n != self.start && n != self.end && self.degree[idx] <= 1 {
+                    self.queue.push_back(n);
+                }
+            }
+        }
+        true
+    }
+
+    fn into_path(self: Box<Self>) -> Option<Path<G::Coord>> {
+        self.path
+    }
+}
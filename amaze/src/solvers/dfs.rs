@@ -1,41 +1,93 @@
-use crate::grid_coord_2d::{GridCoord2D, LinearizeCoords2D};
-use crate::solvers::{MazeSolver, rebuild_path};
-use crate::wall4_grid::Wall4Grid;
+use crate::maze_grid::MazeGrid;
+use crate::path::Path;
+use crate::solvers::{MazeSolver, SolveProcess, SolveStep, SolveSteps, rebuild_path};
+use std::collections::VecDeque;
 
 #[derive(Debug, Copy, Clone, Default)]
 pub struct DfsSolver;
 
-impl MazeSolver for DfsSolver {
-    fn solve(
+impl<G: MazeGrid> MazeSolver<G> for DfsSolver {
+    fn solve(&self, maze: &G, start: G::Coord, end: G::Coord) -> Option<Path<G::Coord>> {
+        self.solve_steps(maze, start, end).finish()
+    }
+
+    fn solve_steps<'a>(
         &self,
-        maze: &Wall4Grid,
-        start: GridCoord2D,
-        end: GridCoord2D,
-    ) -> Option<crate::path::Path> {
-        if maze.get(start).is_none() || maze.get(end).is_none() {
-            return None;
+        maze: &'a G,
+        start: G::Coord,
+        end: G::Coord,
+    ) -> SolveSteps<'a, G::Coord> {
+        if !maze.contains(start) || !maze.contains(end) {
+            return SolveSteps::finished(None);
         }
 
-        let mut stack = vec![start];
-        let mut seen = vec![false; maze.width() * maze.height()];
-        let mut parent = vec![None; maze.width() * maze.height()];
-        seen[maze.linearize_coords(start)] = true;
+        let mut seen = vec![false; maze.cell_count()];
+        seen[maze.linearize(start)] = true;
 
-        while let Some(cell) = stack.pop() {
-            if cell == end {
-                return rebuild_path(&parent, maze, start, end);
-            }
+        SolveSteps::new(DfsProcess {
+            maze,
+            start,
+            end,
+            stack: vec![start],
+            seen,
+            parent: vec![None; maze.cell_count()],
+            last: None,
+            path: None,
+        })
+    }
+}
+
+/// The stack of a [`DfsSolver`], paused between two expanded cells.
+struct DfsProcess<'a, G: MazeGrid> {
+    maze: &'a G,
+    start: G::Coord,
+    end: G::Coord,
+    stack: Vec<G::Coord>,
+    seen: Vec<bool>,
+    parent: Vec<Option<G::Coord>>,
+    /// The most recently expanded cell, used to detect backtracking.
+    last: Option<G::Coord>,
+    path: Option<Path<G::Coord>>,
+}
 
-            for next in maze.open_neighbors(cell) {
-                let idx = maze.linearize_coords(next);
-                if !seen[idx] {
-                    seen[idx] = true;
-                    parent[idx] = Some(cell);
-                    stack.push(next);
-                }
+impl<G: MazeGrid> SolveProcess for DfsProcess<'_, G> {
+    type Coord = G::Coord;
+
+    fn advance(&mut self, steps: &mut VecDeque<SolveStep<G::Coord>>) -> bool {
+        let Some(cell) = self.stack.pop() else {
+            return false;
+        };
+
+        // Continuing from anywhere but the cell just expanded abandons a branch.
+        let parent = self.parent[self.maze.linearize(cell)];
+        if let Some(to) = parent.filter(|&p| Some(p) != self.last) {
+            steps.push_back(SolveStep::Backtrack { to });
+        }
+        self.last = Some(cell);
+
+        steps.push_back(SolveStep::Explore { cell });
+        if cell == self.end {
+            self.path = rebuild_path(&self.parent, self.maze, self.start, self.end);
+            steps.extend(self.path.clone().map(|path| SolveStep::PathFound { path }));
+            return false;
+        }
+
+        for next in self.maze.open_neighbors(cell) {
+            let idx = self.maze.linearize(next);
+            if !self.seen[idx] {
+                self.seen[idx] = true;
+                self.parent[idx] = Some(cell);
+                self.stack.push(next);
+                steps.push_back(SolveStep::Enqueue {
+                    cell: next,
+                    from: cell,
+                });
             }
         }
+        true
+    }
 
-        None
+    fn into_path(self: Box<Self>) -> Option<Path<G::Coord>> {
+        self.path
     }
 }
@@ -0,0 +1,12 @@
+use crate::solvers::weighted::{WeightedGraph, WeightedMazeSolver, WeightedPath, ZeroHeuristic};
+use crate::solvers::weighted_search;
+
+/// Finds the cheapest path through a weighted maze using Dijkstra's algorithm.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct DijkstraSolver;
+
+impl<W: WeightedGraph> WeightedMazeSolver<W> for DijkstraSolver {
+    fn solve(&self, graph: &W, start: W::Coord, end: W::Coord) -> Option<WeightedPath<W::Coord>> {
+        weighted_search(graph, start, end, &ZeroHeuristic)
+    }
+}
@@ -1,37 +1,139 @@
-//! Maze solving algorithms for [`Wall4Grid`].
+//! Maze solving algorithms for any [`MazeGrid`], such as [`Wall4Grid`] and
+//! [`Wall6Grid`].
 //!
-//! Solvers return a [`Path`] from start to end when one exists.
+//! Solvers return a [`Path`] from start to end when one exists. In a
+//! [`WeaveGrid`](crate::preamble::WeaveGrid), a step through a tunnel skips the
+//! crossing it passes under, so consecutive path cells need not be adjacent.
+//!
+//! Mazes with passage costs, such as a
+//! [`WeightedAdjacencyList`](crate::preamble::WeightedAdjacencyList), are solved by
+//! the [`WeightedMazeSolver`] implementations, which return a [`WeightedPath`]
+//! carrying the total cost.
+//!
+//! [`MazeSolver::solve_steps`] reports the search as a lazy stream of [`SolveStep`]s,
+//! the solving counterpart of a generator's step stream, for animating how the
+//! BFS, DFS, A\* and dead-end filling solvers explore a maze.
 
+mod agent;
 mod astar;
 mod bfs;
 mod dead_end;
 mod dfs;
+#[cfg(feature = "representations")]
+mod dijkstra;
+mod pledge;
+mod solve_steps;
+mod tremaux;
+mod wall_follower;
+#[cfg(feature = "representations")]
+mod weighted;
+#[cfg(feature = "representations")]
+mod weighted_astar;
 
-use crate::grid_coord_2d::GridCoord2D;
+use crate::maze_grid::MazeGrid;
 use crate::path::Path;
+use crate::wall3d_grid::Wall3DGrid;
 use crate::wall4_grid::Wall4Grid;
+use crate::wall6_grid::Wall6Grid;
 
+pub use agent::{AgentSolver, AgentWalk, Hand, WalkOutcome};
 pub use astar::AStarSolver;
 pub use bfs::BfsSolver;
 pub use dead_end::DeadEndFillingSolver;
 pub use dfs::DfsSolver;
+#[cfg(feature = "representations")]
+pub use dijkstra::DijkstraSolver;
+pub use pledge::PledgeSolver;
+pub use solve_steps::{SolveProcess, SolveStep, SolveSteps, SolveVisitor};
+pub use tremaux::TremauxSolver;
+pub use wall_follower::WallFollowerSolver;
+#[cfg(feature = "representations")]
+use weighted::weighted_search;
+#[cfg(feature = "representations")]
+pub use weighted::{
+    DistanceHeuristic, Heuristic, WeightedGraph, WeightedMazeSolver, WeightedPath, ZeroHeuristic,
+};
+#[cfg(feature = "representations")]
+pub use weighted_astar::WeightedAStarSolver;
 
-pub trait MazeSolver {
-    fn solve(&self, maze: &Wall4Grid, start: GridCoord2D, end: GridCoord2D) -> Option<Path>;
+/// A maze solving algorithm over grids of type `G`.
+///
+/// The grid type defaults to [`Wall4Grid`], so `dyn MazeSolver` continues to
+/// denote a solver for rectangular mazes.
+pub trait MazeSolver<G: MazeGrid = Wall4Grid> {
+    fn solve(&self, maze: &G, start: G::Coord, end: G::Coord) -> Option<Path<G::Coord>>;
+
+    /// Returns the steps of the search as a lazy stream, for animating how the solver
+    /// explores the maze.
+    ///
+    /// The default implementation solves the maze at once and only reports
+    /// [`SolveStep::PathFound`].
+    fn solve_steps<'a>(
+        &self,
+        maze: &'a G,
+        start: G::Coord,
+        end: G::Coord,
+    ) -> SolveSteps<'a, G::Coord> {
+        SolveSteps::finished(self.solve(maze, start, end))
+    }
 }
 
-fn rebuild_path(
-    parent: &[Option<GridCoord2D>],
-    maze: &Wall4Grid,
-    start: GridCoord2D,
-    end: GridCoord2D,
-) -> Option<Path> {
-    use crate::grid_coord_2d::LinearizeCoords2D;
+/// A solver for hexagonal [`Wall6Grid`] mazes, returning a [`HexPath`](crate::path::HexPath).
+///
+/// Every [`MazeSolver`] over [`Wall6Grid`] implements it, so the BFS, DFS, A\*
+/// and dead-end filling solvers all solve hex mazes; A\* is guided by the hex
+/// distance between [`HexCoord`](crate::preamble::HexCoord)s.
+///
+/// ## Example
+/// ```
+/// use amaze::preamble::{HexCoord, Wall6Grid};
+/// use amaze::solvers::{AStarSolver, MazeSolver6};
+///
+/// let mut grid = Wall6Grid::new(3, 1);
+/// grid.remove_wall_between(HexCoord::new(0, 0), HexCoord::new(1, 0));
+/// grid.remove_wall_between(HexCoord::new(1, 0), HexCoord::new(2, 0));
+///
+/// let solver: &dyn MazeSolver6 = &AStarSolver;
+/// let path = solver.solve(&grid, HexCoord::new(0, 0), HexCoord::new(2, 0));
+/// assert_eq!(path.map(|p| p.cells().len()), Some(3));
+/// ```
+pub trait MazeSolver6: MazeSolver<Wall6Grid> {}
+
+impl<S: MazeSolver<Wall6Grid> + ?Sized> MazeSolver6 for S {}
+
+/// A solver for layered [`Wall3DGrid`] mazes.
+///
+/// Every [`MazeSolver`] over [`Wall3DGrid`] implements it, so the BFS, DFS, A\*
+/// and dead-end filling solvers all walk stairs between layers.
+///
+/// ## Example
+/// ```
+/// use amaze::preamble::{GridCoord3D, Wall3DGrid};
+/// use amaze::solvers::{BfsSolver, MazeSolver3D};
+///
+/// let mut grid = Wall3DGrid::new(1, 1, 3);
+/// grid.remove_wall_between(GridCoord3D::new(0, 0, 0), GridCoord3D::new(0, 0, 1));
+/// grid.remove_wall_between(GridCoord3D::new(0, 0, 1), GridCoord3D::new(0, 0, 2));
+///
+/// let solver: &dyn MazeSolver3D = &BfsSolver;
+/// let path = solver.solve(&grid, GridCoord3D::new(0, 0, 0), GridCoord3D::new(0, 0, 2));
+/// assert_eq!(path.map(|p| p.cells().len()), Some(3));
+/// ```
+pub trait MazeSolver3D: MazeSolver<Wall3DGrid> {}
+
+impl<S: MazeSolver<Wall3DGrid> + ?Sized> MazeSolver3D for S {}
+
+fn rebuild_path<G: MazeGrid>(
+    parent: &[Option<G::Coord>],
+    maze: &G,
+    start: G::Coord,
+    end: G::Coord,
+) -> Option<Path<G::Coord>> {
     let mut cur = end;
     let mut out = vec![end];
 
     while cur != start {
-        let idx = maze.linearize_coords(cur);
+        let idx = maze.linearize(cur);
         cur = parent.get(idx).copied().flatten()?;
         out.push(cur);
     }
@@ -0,0 +1,118 @@
+use crate::direction4::Direction4;
+use crate::grid_coord_2d::GridCoord2D;
+use crate::path::Path;
+use crate::solvers::MazeSolver;
+use crate::solvers::agent::{AgentSolver, AgentWalk, Hand, WalkOutcome, step, step_limit};
+use crate::wall4_grid::Wall4Grid;
+use std::collections::HashSet;
+
+/// Solves a maze with the Pledge algorithm.
+///
+/// The agent walks straight in its preferred heading until it hits a wall, then follows
+/// the wall with one hand while counting its quarter turns, and leaves the wall once
+/// the count returns to zero. Unlike a plain wall follower it escapes islands, which
+/// makes it effective at reaching exits on the maze boundary; it can still miss targets
+/// inside the maze. Revisiting a cell with the same heading and turn count is detected
+/// as [`WalkOutcome::Trapped`]; a turn count that keeps drifting, as on a torus, is
+/// stopped by the step limit.
+#[derive(Debug, Copy, Clone)]
+pub struct PledgeSolver {
+    heading: Direction4,
+    hand: Hand,
+    max_steps: Option<usize>,
+}
+
+impl Default for PledgeSolver {
+    fn default() -> Self {
+        Self::new(Direction4::NORTH, Hand::Left)
+    }
+}
+
+impl PledgeSolver {
+    /// Creates a solver walking towards `heading` and following walls with `hand`.
+    ///
+    /// ## Panics
+    /// Panics if `heading` is not a single cardinal direction.
+    pub fn new(heading: Direction4, hand: Hand) -> Self {
+        assert!(heading.is_trivial(), "heading must be a cardinal direction");
+        Self {
+            heading,
+            hand,
+            max_steps: None,
+        }
+    }
+
+    /// Stops the walk after `max_steps` moves.
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = Some(max_steps);
+        self
+    }
+
+    pub fn heading(&self) -> Direction4 {
+        self.heading
+    }
+
+    pub fn hand(&self) -> Hand {
+        self.hand
+    }
+}
+
+impl AgentSolver for PledgeSolver {
+    fn walk(&self, maze: &Wall4Grid, start: GridCoord2D, end: GridCoord2D) -> AgentWalk {
+        if maze.get(start).is_none() || maze.get(end).is_none() {
+            return AgentWalk::new(Vec::new(), WalkOutcome::Trapped);
+        }
+
+        let mut seen = HashSet::new();
+        let mut trajectory = vec![start];
+        let mut cell = start;
+        let mut heading = self.heading;
+        let mut counter: i64 = 0;
+
+        for _ in 0..step_limit(maze, self.max_steps) {
+            if cell == end {
+                return AgentWalk::new(trajectory, WalkOutcome::Reached);
+            }
+            if !seen.insert((cell, heading, counter)) {
+                return AgentWalk::new(trajectory, WalkOutcome::Trapped);
+            }
+
+            // Quarter turns towards the hand count +1, away from it -1; at zero the
+            // agent faces its preferred heading again.
+            let side = self.hand.side(heading);
+            let away = side.opposite();
+            let back = heading.opposite();
+            let candidates = if counter == 0 {
+                // Walk straight; when blocked, turn away from the wall to put the hand on it.
+                [(heading, 0), (away, -1), (back, -2), (side, -3)]
+            } else {
+                [(side, 1), (heading, 0), (away, -1), (back, -2)]
+            };
+
+            let Some((dir, turns, next)) = candidates
+                .into_iter()
+                .find_map(|(dir, turns)| step(maze, cell, dir).map(|next| (dir, turns, next)))
+            else {
+                return AgentWalk::new(trajectory, WalkOutcome::Trapped);
+            };
+
+            counter += turns;
+            heading = dir;
+            cell = next;
+            trajectory.push(cell);
+        }
+
+        let outcome = if cell == end {
+            WalkOutcome::Reached
+        } else {
+            WalkOutcome::StepLimit
+        };
+        AgentWalk::new(trajectory, outcome)
+    }
+}
+
+impl MazeSolver for PledgeSolver {
+    fn solve(&self, maze: &Wall4Grid, start: GridCoord2D, end: GridCoord2D) -> Option<Path> {
+        self.walk(maze, start, end).path
+    }
+}
@@ -0,0 +1,148 @@
+use crate::grid_coord_2d::GridCoord2D;
+use crate::path::Path;
+use std::collections::VecDeque;
+
+/// An event reported while a [`MazeSolver`](crate::solvers::MazeSolver) searches a maze.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SolveStep<C = GridCoord2D> {
+    /// The search expanded a cell, examining its open neighbors.
+    Explore { cell: C },
+    /// A cell was added to the search frontier, reached from `from`.
+    Enqueue { cell: C, from: C },
+    /// A depth-first search gave up on a branch and resumed at `to`.
+    Backtrack { to: C },
+    /// Dead-end filling walled off a cell that cannot lie on the solution.
+    FillDeadEnd { cell: C },
+    /// The search reached the end cell along `path`.
+    PathFound { path: Path<C> },
+}
+
+/// A search algorithm suspended between two iterations.
+///
+/// Solvers hand out their searches as processes wrapped in [`SolveSteps`], the
+/// solving counterpart of a generator's
+/// [`GenerationProcess`](crate::generators::GenerationProcess).
+pub trait SolveProcess {
+    /// The cell coordinate of the maze being solved.
+    type Coord;
+
+    /// Performs the next iteration of the search, appending the steps it took to `steps`.
+    ///
+    /// Returns `false` once the search has finished; if it found the end cell, the last
+    /// step it appends is [`SolveStep::PathFound`].
+    fn advance(&mut self, steps: &mut VecDeque<SolveStep<Self::Coord>>) -> bool;
+
+    /// Consumes the process and returns the path it found, if any.
+    fn into_path(self: Box<Self>) -> Option<Path<Self::Coord>>;
+}
+
+/// Receives the steps of a search as they happen.
+pub trait SolveVisitor<C = GridCoord2D> {
+    fn on_step(&mut self, step: &SolveStep<C>);
+}
+
+impl<C: Clone> SolveVisitor<C> for Vec<SolveStep<C>> {
+    fn on_step(&mut self, step: &SolveStep<C>) {
+        self.push(step.clone());
+    }
+}
+
+/// A lazy iterator over the steps of a [`SolveProcess`].
+///
+/// The stream borrows the maze it searches; steps are produced one iteration at a time.
+///
+/// ## Example
+/// ```
+/// use amaze::generators::RecursiveBacktracker4;
+/// use amaze::preamble::GridCoord2D;
+/// use amaze::solvers::{BfsSolver, MazeSolver, SolveStep};
+///
+/// let maze = RecursiveBacktracker4::new_from_seed(7).generate(8, 8);
+/// let steps: Vec<_> = BfsSolver
+///     .solve_steps(&maze, GridCoord2D::new(0, 0), GridCoord2D::new(7, 7))
+///     .collect();
+///
+/// assert_eq!(steps[0], SolveStep::Explore { cell: GridCoord2D::new(0, 0) });
+/// assert!(matches!(steps.last(), Some(SolveStep::PathFound { .. })));
+/// ```
+pub struct SolveSteps<'a, C> {
+    process: Box<dyn SolveProcess<Coord = C> + 'a>,
+    pending: VecDeque<SolveStep<C>>,
+    running: bool,
+}
+
+impl<'a, C: 'a> SolveSteps<'a, C> {
+    pub fn new<P>(process: P) -> Self
+    where
+        P: SolveProcess<Coord = C> + 'a,
+    {
+        Self {
+            process: Box::new(process),
+            pending: VecDeque::new(),
+            running: true,
+        }
+    }
+
+    /// Returns a stream over an already computed result that only yields
+    /// [`SolveStep::PathFound`] if a path exists.
+    pub fn finished(path: Option<Path<C>>) -> Self
+    where
+        C: Clone,
+    {
+        Self::new(Finished { path })
+    }
+}
+
+impl<C> SolveSteps<'_, C> {
+    /// Returns `true` once all steps have been yielded.
+    pub fn is_complete(&self) -> bool {
+        !self.running && self.pending.is_empty()
+    }
+
+    /// Runs the remaining iterations without collecting their steps and returns the path.
+    pub fn finish(mut self) -> Option<Path<C>> {
+        while self.running {
+            self.pending.clear();
+            self.running = self.process.advance(&mut self.pending);
+        }
+        self.process.into_path()
+    }
+
+    /// Reports every remaining step to `visitor` and returns the path.
+    pub fn visit<V: SolveVisitor<C> + ?Sized>(mut self, visitor: &mut V) -> Option<Path<C>> {
+        for step in self.by_ref() {
+            visitor.on_step(&step);
+        }
+        self.process.into_path()
+    }
+}
+
+impl<C> Iterator for SolveSteps<'_, C> {
+    type Item = SolveStep<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending.is_empty() && self.running {
+            self.running = self.process.advance(&mut self.pending);
+        }
+        self.pending.pop_front()
+    }
+}
+
+/// A search whose result was computed without reporting steps.
+struct Finished<C> {
+    path: Option<Path<C>>,
+}
+
+impl<C: Clone> SolveProcess for Finished<C> {
+    type Coord = C;
+
+    fn advance(&mut self, steps: &mut VecDeque<SolveStep<C>>) -> bool {
+        steps.extend(self.path.clone().map(|path| SolveStep::PathFound { path }));
+        false
+    }
+
+    fn into_path(self: Box<Self>) -> Option<Path<C>> {
+        self.path
+    }
+}
@@ -0,0 +1,109 @@
+use crate::direction4::Direction4;
+use crate::grid_coord_2d::{GridCoord2D, LinearizeCoords2D};
+use crate::path::Path;
+use crate::solvers::MazeSolver;
+use crate::solvers::agent::{AgentSolver, AgentWalk, WalkOutcome, step, step_limit};
+use crate::wall4_grid::Wall4Grid;
+
+/// Solves a maze with Trémaux's algorithm.
+///
+/// The agent marks each passage as it walks it. It prefers unmarked passages, turns
+/// back when a new passage leads into a junction it has seen before, and never enters
+/// a passage marked twice. Every passage is walked at most twice, so the walk always
+/// terminates: if the end is unreachable, the agent returns to the start with every
+/// passage marked twice and the walk ends as [`WalkOutcome::Trapped`].
+#[derive(Debug, Copy, Clone, Default)]
+pub struct TremauxSolver {
+    max_steps: Option<usize>,
+}
+
+impl TremauxSolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stops the walk after `max_steps` moves.
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = Some(max_steps);
+        self
+    }
+}
+
+/// Returns the slot of a cardinal direction in a cell's passage marks.
+fn slot(dir: Direction4) -> usize {
+    Direction4::CARDINALS
+        .iter()
+        .position(|&d| d == dir)
+        .expect("cardinal direction")
+}
+
+impl AgentSolver for TremauxSolver {
+    fn walk(&self, maze: &Wall4Grid, start: GridCoord2D, end: GridCoord2D) -> AgentWalk {
+        if maze.get(start).is_none() || maze.get(end).is_none() {
+            return AgentWalk::new(Vec::new(), WalkOutcome::Trapped);
+        }
+
+        let mut marks = vec![[0u8; 4]; maze.width() * maze.height()];
+        let mut visited = vec![false; maze.width() * maze.height()];
+        let mut trajectory = vec![start];
+        let mut cell = start;
+        // The side of the current cell the agent entered through.
+        let mut entry: Option<Direction4> = None;
+        let mut revisit = false;
+
+        for _ in 0..step_limit(maze, self.max_steps) {
+            if cell == end {
+                return AgentWalk::new(trajectory, WalkOutcome::Reached);
+            }
+
+            let idx = maze.linearize_coords(cell);
+            let exits = || {
+                Direction4::CARDINALS
+                    .into_iter()
+                    .filter_map(|dir| step(maze, cell, dir).map(|next| (dir, next)))
+            };
+            let with_marks = |count: u8| {
+                exits().find(|&(dir, _)| Some(dir) != entry && marks[idx][slot(dir)] == count)
+            };
+
+            let choice = match entry {
+                // A new passage led into a known junction: go back the way we came.
+                Some(back) if revisit && marks[idx][slot(back)] == 1 => {
+                    step(maze, cell, back).map(|next| (back, next))
+                }
+                _ => with_marks(0).or_else(|| with_marks(1)).or_else(|| {
+                    // Dead ends and exhausted junctions send the agent back.
+                    entry
+                        .filter(|&back| marks[idx][slot(back)] < 2)
+                        .and_then(|back| step(maze, cell, back).map(|next| (back, next)))
+                }),
+            };
+
+            let Some((dir, next)) = choice else {
+                return AgentWalk::new(trajectory, WalkOutcome::Trapped);
+            };
+
+            visited[idx] = true;
+            marks[idx][slot(dir)] += 1;
+            marks[maze.linearize_coords(next)][slot(dir.opposite())] += 1;
+
+            revisit = visited[maze.linearize_coords(next)];
+            entry = Some(dir.opposite());
+            cell = next;
+            trajectory.push(cell);
+        }
+
+        let outcome = if cell == end {
+            WalkOutcome::Reached
+        } else {
+            WalkOutcome::StepLimit
+        };
+        AgentWalk::new(trajectory, outcome)
+    }
+}
+
+impl MazeSolver for TremauxSolver {
+    fn solve(&self, maze: &Wall4Grid, start: GridCoord2D, end: GridCoord2D) -> Option<Path> {
+        self.walk(maze, start, end).path
+    }
+}
@@ -0,0 +1,105 @@
+use crate::direction4::Direction4;
+use crate::grid_coord_2d::{GridCoord2D, LinearizeCoords2D};
+use crate::path::Path;
+use crate::solvers::MazeSolver;
+use crate::solvers::agent::{AgentSolver, AgentWalk, Hand, WalkOutcome, step, step_limit};
+use crate::wall4_grid::Wall4Grid;
+
+/// Solves a maze by keeping one hand on the wall.
+///
+/// The walk is guaranteed to reach the end in a perfect maze. In mazes with loops,
+/// or wrapped mazes, the agent may circle an island forever; this is detected as soon
+/// as it faces the same way in the same cell twice, ending the walk as
+/// [`WalkOutcome::Trapped`].
+///
+/// ## Example
+/// ```
+/// use amaze::generators::RecursiveBacktracker4;
+/// use amaze::preamble::GridCoord2D;
+/// use amaze::solvers::{AgentSolver, BfsSolver, Hand, MazeSolver, WallFollowerSolver};
+///
+/// let maze = RecursiveBacktracker4::new_from_seed(7).generate(8, 8);
+/// let (start, end) = (GridCoord2D::new(0, 0), GridCoord2D::new(7, 7));
+///
+/// let walk = WallFollowerSolver::new(Hand::Right).walk(&maze, start, end);
+/// let shortest = BfsSolver.solve(&maze, start, end).unwrap();
+/// assert_eq!(walk.path, Some(shortest.clone()));
+/// assert!(walk.steps() >= shortest.length - 1);
+/// ```
+#[derive(Debug, Copy, Clone, Default)]
+pub struct WallFollowerSolver {
+    hand: Hand,
+    max_steps: Option<usize>,
+}
+
+impl WallFollowerSolver {
+    pub fn new(hand: Hand) -> Self {
+        Self {
+            hand,
+            max_steps: None,
+        }
+    }
+
+    /// Stops the walk after `max_steps` moves.
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = Some(max_steps);
+        self
+    }
+
+    pub fn hand(&self) -> Hand {
+        self.hand
+    }
+}
+
+impl AgentSolver for WallFollowerSolver {
+    fn walk(&self, maze: &Wall4Grid, start: GridCoord2D, end: GridCoord2D) -> AgentWalk {
+        if maze.get(start).is_none() || maze.get(end).is_none() {
+            return AgentWalk::new(Vec::new(), WalkOutcome::Trapped);
+        }
+
+        // The agent is deterministic, so facing the same way in the same cell twice
+        // means it is walking in circles.
+        let mut seen = vec![Direction4::NONE; maze.width() * maze.height()];
+        let mut trajectory = vec![start];
+        let mut cell = start;
+        let mut heading = Direction4::NORTH;
+
+        for _ in 0..step_limit(maze, self.max_steps) {
+            if cell == end {
+                return AgentWalk::new(trajectory, WalkOutcome::Reached);
+            }
+
+            let idx = maze.linearize_coords(cell);
+            if seen[idx].contains(heading) {
+                return AgentWalk::new(trajectory, WalkOutcome::Trapped);
+            }
+            seen[idx] += heading;
+
+            // Prefer the hand side, then straight ahead, the other side, and finally back.
+            let side = self.hand.side(heading);
+            let Some((dir, next)) = [side, heading, side.opposite(), heading.opposite()]
+                .into_iter()
+                .find_map(|dir| step(maze, cell, dir).map(|next| (dir, next)))
+            else {
+                return AgentWalk::new(trajectory, WalkOutcome::Trapped);
+            };
+
+            heading = dir;
+            cell = next;
+            trajectory.push(cell);
+        }
+
+        let outcome = if cell == end {
+            WalkOutcome::Reached
+        } else {
+            WalkOutcome::StepLimit
+        };
+        AgentWalk::new(trajectory, outcome)
+    }
+}
+
+impl MazeSolver for WallFollowerSolver {
+    fn solve(&self, maze: &Wall4Grid, start: GridCoord2D, end: GridCoord2D) -> Option<Path> {
+        self.walk(maze, start, end).path
+    }
+}
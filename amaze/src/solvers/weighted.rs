@@ -0,0 +1,241 @@
+use crate::grid_coord_2d::{GridCoord2D, LinearizeCoords2D};
+use crate::hex_coord::HexCoord;
+use crate::path::Path;
+use crate::representations::{WeightedAdjacencyList, WeightedHexAdjacencyList};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// A maze graph whose passages carry a traversal cost.
+///
+/// Implemented by [`WeightedAdjacencyList`] and [`WeightedHexAdjacencyList`];
+/// custom weighted representations can implement it to reuse the weighted solvers.
+pub trait WeightedGraph {
+    type Coord: Copy + Eq;
+
+    /// Returns the number of cells in the graph.
+    fn cell_count(&self) -> usize;
+
+    /// Maps a cell to its index in `0..cell_count()`, or `None` if it lies outside the graph.
+    fn index_of(&self, coord: Self::Coord) -> Option<usize>;
+
+    /// Returns the open neighbors of a cell together with the cost of stepping to them.
+    fn weighted_neighbors(&self, coord: Self::Coord) -> &[(Self::Coord, f32)];
+}
+
+impl WeightedGraph for WeightedAdjacencyList {
+    type Coord = GridCoord2D;
+
+    fn cell_count(&self) -> usize {
+        self.width * self.height
+    }
+
+    fn index_of(&self, coord: GridCoord2D) -> Option<usize> {
+        (coord.x < self.width && coord.y < self.height).then(|| self.linearize_coords(coord))
+    }
+
+    fn weighted_neighbors(&self, coord: GridCoord2D) -> &[(GridCoord2D, f32)] {
+        self.neighbors(coord)
+    }
+}
+
+impl WeightedGraph for WeightedHexAdjacencyList {
+    type Coord = HexCoord;
+
+    fn cell_count(&self) -> usize {
+        self.width * self.height
+    }
+
+    fn index_of(&self, coord: HexCoord) -> Option<usize> {
+        let q = usize::try_from(coord.q).ok().filter(|&q| q < self.width)?;
+        let r = usize::try_from(coord.r).ok().filter(|&r| r < self.height)?;
+        Some(r * self.width + q)
+    }
+
+    fn weighted_neighbors(&self, coord: HexCoord) -> &[(HexCoord, f32)] {
+        self.neighbors(coord)
+    }
+}
+
+/// A path through a weighted maze together with the summed cost of its passages.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeightedPath<C = GridCoord2D> {
+    pub path: Path<C>,
+    pub cost: f32,
+}
+
+/// A maze solving algorithm that minimizes the total passage cost of a [`WeightedGraph`].
+///
+/// Passage costs must be non-negative. The graph type defaults to
+/// [`WeightedAdjacencyList`], so `dyn WeightedMazeSolver` denotes a solver for
+/// weighted rectangular mazes.
+pub trait WeightedMazeSolver<W: WeightedGraph = WeightedAdjacencyList> {
+    fn solve(&self, graph: &W, start: W::Coord, end: W::Coord) -> Option<WeightedPath<W::Coord>>;
+}
+
+/// An estimate of the remaining cost between two cells, used to guide
+/// [`WeightedAStarSolver`](crate::solvers::WeightedAStarSolver).
+///
+/// The estimate must never exceed the true cost of the cheapest route for the
+/// solver to return optimal paths. Closures `Fn(C, C) -> f32` are heuristics.
+pub trait Heuristic<C> {
+    fn estimate(&self, from: C, to: C) -> f32;
+}
+
+impl<C, F: Fn(C, C) -> f32> Heuristic<C> for F {
+    fn estimate(&self, from: C, to: C) -> f32 {
+        self(from, to)
+    }
+}
+
+/// A heuristic that always estimates zero, turning A\* into Dijkstra's algorithm.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ZeroHeuristic;
+
+impl<C> Heuristic<C> for ZeroHeuristic {
+    fn estimate(&self, _from: C, _to: C) -> f32 {
+        0.0
+    }
+}
+
+/// Estimates the remaining cost as the step distance times the cheapest passage cost.
+///
+/// Uses the Manhattan distance on square grids and the hex distance on hex grids.
+/// It is admissible as long as no passage costs less than `min_cost`; it does not
+/// know about wrapped edges, so use a custom heuristic for cylinder and torus mazes.
+#[derive(Debug, Copy, Clone)]
+pub struct DistanceHeuristic {
+    pub min_cost: f32,
+}
+
+impl DistanceHeuristic {
+    pub fn new(min_cost: f32) -> Self {
+        Self { min_cost }
+    }
+}
+
+impl Default for DistanceHeuristic {
+    /// Assumes the default passage cost of `1.0` is the cheapest.
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl Heuristic<GridCoord2D> for DistanceHeuristic {
+    fn estimate(&self, from: GridCoord2D, to: GridCoord2D) -> f32 {
+        (from.x.abs_diff(to.x) + from.y.abs_diff(to.y)) as f32 * self.min_cost
+    }
+}
+
+impl Heuristic<HexCoord> for DistanceHeuristic {
+    fn estimate(&self, from: HexCoord, to: HexCoord) -> f32 {
+        from.distance(to) as f32 * self.min_cost
+    }
+}
+
+/// An open-set entry ordered so that [`BinaryHeap`] pops the lowest priority first.
+struct Candidate<C> {
+    priority: f32,
+    cell: C,
+}
+
+impl<C> PartialEq for Candidate<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C> Eq for Candidate<C> {}
+
+impl<C> PartialOrd for Candidate<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C> Ord for Candidate<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.total_cmp(&self.priority)
+    }
+}
+
+/// Runs A\* with the given heuristic; a zero heuristic yields Dijkstra's algorithm.
+pub(crate) fn weighted_search<W, H>(
+    graph: &W,
+    start: W::Coord,
+    end: W::Coord,
+    heuristic: &H,
+) -> Option<WeightedPath<W::Coord>>
+where
+    W: WeightedGraph,
+    H: Heuristic<W::Coord> + ?Sized,
+{
+    let start_idx = graph.index_of(start)?;
+    let end_idx = graph.index_of(end)?;
+
+    let total = graph.cell_count();
+    let mut cost = vec![f32::INFINITY; total];
+    let mut parent = vec![None; total];
+    let mut open = BinaryHeap::new();
+
+    cost[start_idx] = 0.0;
+    open.push(Candidate {
+        priority: heuristic.estimate(start, end),
+        cell: start,
+    });
+
+    while let Some(Candidate { priority, cell }) = open.pop() {
+        let idx = graph.index_of(cell)?;
+        if idx == end_idx {
+            return Some(WeightedPath {
+                path: rebuild_weighted_path(graph, &parent, start_idx, end),
+                cost: cost[end_idx],
+            });
+        }
+
+        // Skip entries superseded by a cheaper route found after they were queued.
+        let cell_cost = cost[idx];
+        if priority > cell_cost + heuristic.estimate(cell, end) {
+            continue;
+        }
+
+        for &(next, weight) in graph.weighted_neighbors(cell) {
+            let Some(nidx) = graph.index_of(next) else {
+                continue;
+            };
+            let tentative = cell_cost + weight;
+            if tentative < cost[nidx] {
+                cost[nidx] = tentative;
+                parent[nidx] = Some(cell);
+                open.push(Candidate {
+                    priority: tentative + heuristic.estimate(next, end),
+                    cell: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+fn rebuild_weighted_path<W: WeightedGraph>(
+    graph: &W,
+    parent: &[Option<W::Coord>],
+    start_idx: usize,
+    end: W::Coord,
+) -> Path<W::Coord> {
+    let mut cur = end;
+    let mut out = vec![end];
+
+    while let Some(prev) = graph
+        .index_of(cur)
+        .filter(|&idx| idx != start_idx)
+        .and_then(|idx| parent[idx])
+    {
+        cur = prev;
+        out.push(cur);
+    }
+
+    out.reverse();
+    Path::new(out)
+}
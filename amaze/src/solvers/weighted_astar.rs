@@ -0,0 +1,58 @@
+use crate::solvers::weighted::{
+    DistanceHeuristic, Heuristic, WeightedGraph, WeightedMazeSolver, WeightedPath,
+};
+use crate::solvers::weighted_search;
+
+/// Finds the cheapest path through a weighted maze using A\* guided by a [`Heuristic`].
+///
+/// The path is optimal as long as the heuristic never overestimates the remaining cost.
+///
+/// ## Example
+/// ```
+/// use amaze::generators::RecursiveBacktracker4;
+/// use amaze::preamble::{GridCoord2D, WeightedAdjacencyList};
+/// use amaze::solvers::{WeightedAStarSolver, WeightedMazeSolver};
+///
+/// let maze = RecursiveBacktracker4::new_from_seed(7).generate(8, 8);
+/// // Passages into the right half of the maze wade through mud.
+/// let graph = WeightedAdjacencyList::from_wall_grid_with(&maze, |_, to| {
+///     if to.x >= 4 { 3.0 } else { 1.0 }
+/// });
+///
+/// let solver = WeightedAStarSolver::with_min_cost(1.0);
+/// let solution = solver
+///     .solve(&graph, GridCoord2D::new(0, 0), GridCoord2D::new(7, 7))
+///     .expect("perfect mazes connect every cell");
+/// assert!(solution.cost >= (solution.path.length - 1) as f32);
+/// ```
+#[derive(Debug, Copy, Clone, Default)]
+pub struct WeightedAStarSolver<H = DistanceHeuristic> {
+    heuristic: H,
+}
+
+impl<H> WeightedAStarSolver<H> {
+    pub fn new(heuristic: H) -> Self {
+        Self { heuristic }
+    }
+
+    pub fn heuristic(&self) -> &H {
+        &self.heuristic
+    }
+}
+
+impl WeightedAStarSolver<DistanceHeuristic> {
+    /// Creates a solver guided by the step distance, assuming no passage costs less than `min_cost`.
+    pub fn with_min_cost(min_cost: f32) -> Self {
+        Self::new(DistanceHeuristic::new(min_cost))
+    }
+}
+
+impl<W, H> WeightedMazeSolver<W> for WeightedAStarSolver<H>
+where
+    W: WeightedGraph,
+    H: Heuristic<W::Coord>,
+{
+    fn solve(&self, graph: &W, start: W::Coord, end: W::Coord) -> Option<WeightedPath<W::Coord>> {
+        weighted_search(graph, start, end, &self.heuristic)
+    }
+}
@@ -1,26 +1,141 @@
-use crate::grid_coord_2d::{GridCoord2D, LinearizeCoords2D};
-use crate::hex_coord::HexCoord;
+//! Structural statistics of carved mazes.
+//!
+//! The average path length is the expensive part: measured exactly it needs a
+//! breadth-first search from every cell. [`StatsOptions`] selects how it is computed;
+//! by default perfect mazes use an exact linear-time method on their spanning tree,
+//! small mazes with loops are measured exactly, and large ones are sampled.
+
+use crate::grid_mask::GridMask;
+use crate::maze_grid::MazeGrid;
 use crate::wall4_grid::Wall4Grid;
 use crate::wall6_grid::Wall6Grid;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::collections::VecDeque;
 
+/// Structural statistics of a maze.
+///
+/// More statistics may be added over time, so the struct cannot be built or
+/// destructured exhaustively outside this crate.
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub struct MazeStats {
     pub dead_ends: usize,
     pub corridors: usize,
     pub junctions: usize,
     pub longest_path: usize,
     pub average_path_length: f64,
+    /// Half-width of the 95% confidence interval of `average_path_length` when it was
+    /// estimated by [`PathLengthMode::Sampled`]; `None` when it is exact.
+    pub average_path_length_margin: Option<f64>,
+    /// Number of independent loops (the cyclomatic number `E - V + C`).
+    /// This is zero for perfect mazes and grows as walls are removed by braiding.
+    pub loops: usize,
+}
+
+/// How [`MazeStats`] computes the average shortest path length between all pairs of
+/// connected cells.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum PathLengthMode {
+    /// Uses [`Tree`](Self::Tree) for perfect mazes. Mazes with loops are measured
+    /// [`Exact`](Self::Exact)ly up to [`AUTO_EXACT_CELL_LIMIT`] cells and
+    /// [`Sampled`](Self::Sampled) from [`AUTO_SAMPLE_SOURCES`] cells beyond that.
+    #[default]
+    Auto,
+    /// Runs a breadth-first search from every cell; takes quadratic time.
+    Exact,
+    /// Sums the distances along a spanning tree using subtree sizes in linear time.
+    ///
+    /// This is exact for perfect mazes. Loops offer shortcuts that the spanning tree
+    /// does not take, so for braided mazes the result is an upper bound.
+    Tree,
+    /// Runs a breadth-first search from `sources` randomly chosen cells and reports the
+    /// mean together with a confidence margin. Sampling at least as many sources as
+    /// there are cells is exact; at least one source is always sampled.
+    Sampled { sources: usize, seed: u64 },
+}
+
+/// The largest number of cells of a maze with loops that [`PathLengthMode::Auto`]
+/// measures exactly.
+pub const AUTO_EXACT_CELL_LIMIT: usize = 4096;
+
+/// The number of source cells [`PathLengthMode::Auto`] samples in larger mazes with loops.
+pub const AUTO_SAMPLE_SOURCES: usize = 256;
+
+/// Options for computing [`MazeStats`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct StatsOptions {
+    pub path_length: PathLengthMode,
+}
+
+impl StatsOptions {
+    pub fn new(path_length: PathLengthMode) -> Self {
+        Self { path_length }
+    }
+
+    /// Measures every path length exactly, regardless of the maze size.
+    pub fn exact() -> Self {
+        Self::new(PathLengthMode::Exact)
+    }
+
+    /// Sums the path lengths along a spanning tree; exact for perfect mazes.
+    pub fn tree() -> Self {
+        Self::new(PathLengthMode::Tree)
+    }
+
+    /// Estimates the average path length from `sources` random cells.
+    pub fn sampled(sources: usize, seed: u64) -> Self {
+        Self::new(PathLengthMode::Sampled { sources, seed })
+    }
 }
 
 impl MazeStats {
-    pub fn from_grid(grid: &Wall4Grid) -> Self {
+    /// Computes statistics over all cells of the grid.
+    pub fn from_grid<G: MazeGrid>(grid: &G) -> Self {
+        Self::from_grid_with(grid, StatsOptions::default())
+    }
+
+    /// Computes statistics over all cells of the grid using the given options.
+    pub fn from_grid_with<G: MazeGrid>(grid: &G, options: StatsOptions) -> Self {
+        let cells: Vec<_> = grid.coords().collect();
+        Self::from_cells(grid, &cells, options)
+    }
+
+    /// Computes statistics over the enabled cells of the mask only.
+    ///
+    /// Masked cells are fully walled by mask-aware generators and would otherwise
+    /// be counted as dead ends and disconnected components.
+    pub fn from_grid_masked(grid: &Wall4Grid, mask: &GridMask) -> Self {
+        Self::from_grid_masked_with(grid, mask, StatsOptions::default())
+    }
+
+    /// Computes statistics over the enabled cells of the mask using the given options.
+    pub fn from_grid_masked_with(grid: &Wall4Grid, mask: &GridMask, options: StatsOptions) -> Self {
+        let cells: Vec<_> = mask
+            .enabled_cells()
+            .filter(|c| c.x < grid.width() && c.y < grid.height())
+            .collect();
+        Self::from_cells(grid, &cells, options)
+    }
+
+    pub fn from_grid_hex(grid: &Wall6Grid) -> Self {
+        Self::from_grid(grid)
+    }
+
+    pub fn from_grid_hex_with(grid: &Wall6Grid, options: StatsOptions) -> Self {
+        Self::from_grid_with(grid, options)
+    }
+
+    fn from_cells<G: MazeGrid>(grid: &G, cells: &[G::Coord], options: StatsOptions) -> Self {
         let mut dead_ends = 0usize;
         let mut corridors = 0usize;
         let mut junctions = 0usize;
+        let mut degree_sum = 0usize;
 
-        for cell in grid.coords() {
+        for &cell in cells {
             let degree = grid.open_neighbors(cell).count();
+            degree_sum += degree;
             match degree {
                 0 | 1 => dead_ends += 1,
                 2 => corridors += 1,
@@ -28,8 +143,30 @@ impl MazeStats {
             }
         }
 
-        let avg = average_shortest_path_length(grid);
-        let longest_path = maze_diameter(grid);
+        let longest_path = maze_diameter(grid, cells);
+        let loops = cyclomatic_number(
+            degree_sum / 2,
+            cells.len(),
+            connected_components(grid, cells),
+        );
+
+        let mode = match options.path_length {
+            PathLengthMode::Auto if loops == 0 => PathLengthMode::Tree,
+            PathLengthMode::Auto if cells.len() <= AUTO_EXACT_CELL_LIMIT => PathLengthMode::Exact,
+            PathLengthMode::Auto => PathLengthMode::Sampled {
+                sources: AUTO_SAMPLE_SOURCES,
+                seed: 0,
+            },
+            mode => mode,
+        };
+        let (avg, margin) = match mode {
+            PathLengthMode::Tree => (spanning_tree_path_length(grid, cells), None),
+            PathLengthMode::Sampled { sources, seed } if sources < cells.len() => {
+                let (avg, margin) = sampled_path_length(grid, cells, sources.max(1), seed);
+                (avg, Some(margin))
+            }
+            _ => (average_shortest_path_length(grid, cells), None),
+        };
 
         Self {
             dead_ends,
@@ -37,52 +174,63 @@ impl MazeStats {
             junctions,
             longest_path,
             average_path_length: avg,
+            average_path_length_margin: margin,
+            loops,
         }
     }
+}
 
-    pub fn from_grid_hex(grid: &Wall6Grid) -> Self {
-        let mut dead_ends = 0usize;
-        let mut corridors = 0usize;
-        let mut junctions = 0usize;
+fn cyclomatic_number(edges: usize, vertices: usize, components: usize) -> usize {
+    (edges + components).saturating_sub(vertices)
+}
 
-        for cell in grid.coords() {
-            let degree = grid.open_neighbors(cell).count();
-            match degree {
-                0 | 1 => dead_ends += 1,
-                2 => corridors += 1,
-                _ => junctions += 1,
-            }
+fn connected_components<G: MazeGrid>(grid: &G, cells: &[G::Coord]) -> usize {
+    let mut seen = vec![false; grid.cell_count()];
+    let mut components = 0usize;
+
+    for &cell in cells {
+        if seen[grid.linearize(cell)] {
+            continue;
         }
 
-        let avg = average_shortest_path_length_hex(grid);
-        let longest_path = maze_diameter_hex(grid);
+        components += 1;
+        flood(grid, cell, &mut seen);
+    }
 
-        Self {
-            dead_ends,
-            corridors,
-            junctions,
-            longest_path,
-            average_path_length: avg,
+    components
+}
+
+/// Marks every cell reachable from `start` as seen. The buffer is shared between
+/// floods, so counting all components visits each cell once.
+fn flood<G: MazeGrid>(grid: &G, start: G::Coord, seen: &mut [bool]) {
+    seen[grid.linearize(start)] = true;
+    let mut queue = VecDeque::from([start]);
+    while let Some(cell) = queue.pop_front() {
+        for n in grid.open_neighbors(cell) {
+            let idx = grid.linearize(n);
+            if !seen[idx] {
+                seen[idx] = true;
+                queue.push_back(n);
+            }
         }
     }
 }
 
-fn maze_diameter(grid: &Wall4Grid) -> usize {
-    if grid.width() == 0 || grid.height() == 0 {
+fn maze_diameter<G: MazeGrid>(grid: &G, cells: &[G::Coord]) -> usize {
+    let Some(&start) = cells.first() else {
         return 0;
-    }
+    };
 
-    let start = GridCoord2D::default();
     let first = farthest_from(grid, start).0;
     farthest_from(grid, first).1
 }
 
-fn farthest_from(grid: &Wall4Grid, start: GridCoord2D) -> (GridCoord2D, usize) {
+fn farthest_from<G: MazeGrid>(grid: &G, start: G::Coord) -> (G::Coord, usize) {
     let dist = grid.bfs_distances(start);
     let mut farthest = (start, 0usize);
 
     for cell in grid.coords() {
-        if let Some(d) = dist[grid.linearize_coords(cell)] {
+        if let Some(d) = dist[grid.linearize(cell)] {
             if d > farthest.1 {
                 farthest = (cell, d);
             }
@@ -92,19 +240,14 @@ fn farthest_from(grid: &Wall4Grid, start: GridCoord2D) -> (GridCoord2D, usize) {
     farthest
 }
 
-fn average_shortest_path_length(grid: &Wall4Grid) -> f64 {
-    if grid.width() == 0 || grid.height() == 0 {
-        return 0.0;
-    }
-
-    let cells: Vec<_> = grid.coords().collect();
+fn average_shortest_path_length<G: MazeGrid>(grid: &G, cells: &[G::Coord]) -> f64 {
     let mut sum = 0usize;
     let mut count = 0usize;
 
     for (i, start) in cells.iter().copied().enumerate() {
         let dist = grid.bfs_distances(start);
         for end in cells.iter().copied().skip(i + 1) {
-            if let Some(d) = dist[grid.linearize_coords(end)] {
+            if let Some(d) = dist[grid.linearize(end)] {
                 sum += d;
                 count += 1;
             }
@@ -118,55 +261,99 @@ fn average_shortest_path_length(grid: &Wall4Grid) -> f64 {
     }
 }
 
-fn maze_diameter_hex(grid: &Wall6Grid) -> usize {
-    if grid.width() == 0 || grid.height() == 0 {
-        return 0;
-    }
+/// Averages the distances along a breadth-first spanning forest of the cells.
+///
+/// Every tree edge lies on the path between each pair of cells it separates, so the
+/// distance sum is the sum of `below * (component - below)` over all edges, where
+/// `below` is the size of the subtree under the edge.
+fn spanning_tree_path_length<G: MazeGrid>(grid: &G, cells: &[G::Coord]) -> f64 {
+    let mut seen = vec![false; grid.cell_count()];
+    let mut subtree = vec![0usize; grid.cell_count()];
+    let mut parent = vec![None; grid.cell_count()];
+    let mut order = Vec::new();
+    let mut queue = VecDeque::new();
+    let mut sum = 0usize;
+    let mut count = 0usize;
 
-    let start = HexCoord::new(0, 0);
-    let first = farthest_from_hex(grid, start).0;
-    farthest_from_hex(grid, first).1
-}
+    for &root in cells {
+        if seen[grid.linearize(root)] {
+            continue;
+        }
 
-fn farthest_from_hex(grid: &Wall6Grid, start: HexCoord) -> (HexCoord, usize) {
-    let dist = grid.bfs_distances(start);
-    let mut farthest = (start, 0usize);
+        order.clear();
+        seen[grid.linearize(root)] = true;
+        queue.push_back(root);
+        while let Some(cell) = queue.pop_front() {
+            order.push(cell);
+            for n in grid.open_neighbors(cell) {
+                let idx = grid.linearize(n);
+                if !seen[idx] {
+                    seen[idx] = true;
+                    parent[idx] = Some(cell);
+                    queue.push_back(n);
+                }
+            }
+        }
 
-    for cell in grid.coords() {
-        let idx = (cell.r as usize) * grid.width() + cell.q as usize;
-        if let Some(d) = dist[idx] {
-            if d > farthest.1 {
-                farthest = (cell, d);
+        let size = order.len();
+        for &cell in order.iter().rev() {
+            let idx = grid.linearize(cell);
+            subtree[idx] += 1;
+            if let Some(up) = parent[idx] {
+                subtree[grid.linearize(up)] += subtree[idx];
+                sum += subtree[idx] * (size - subtree[idx]);
             }
         }
+        count += size * (size - 1) / 2;
     }
 
-    farthest
+    if count == 0 {
+        0.0
+    } else {
+        sum as f64 / count as f64
+    }
 }
 
-fn average_shortest_path_length_hex(grid: &Wall6Grid) -> f64 {
-    if grid.width() == 0 || grid.height() == 0 {
-        return 0.0;
+/// Estimates the average path length from the mean distances of randomly chosen
+/// source cells, returning it with the half-width of its 95% confidence interval.
+fn sampled_path_length<G: MazeGrid>(
+    grid: &G,
+    cells: &[G::Coord],
+    sources: usize,
+    seed: u64,
+) -> (f64, f64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut in_cells = vec![false; grid.cell_count()];
+    for &cell in cells {
+        in_cells[grid.linearize(cell)] = true;
     }
 
-    let cells: Vec<_> = grid.coords().collect();
-    let mut sum = 0usize;
-    let mut count = 0usize;
-
-    for (i, start) in cells.iter().copied().enumerate() {
-        let dist = grid.bfs_distances(start);
-        for end in cells.iter().copied().skip(i + 1) {
-            let idx = (end.r as usize) * grid.width() + end.q as usize;
-            if let Some(d) = dist[idx] {
+    let mut means = Vec::with_capacity(sources);
+    for i in rand::seq::index::sample(&mut rng, cells.len(), sources) {
+        let start = cells[i];
+        let mut sum = 0usize;
+        let mut count = 0usize;
+        for (idx, d) in grid.bfs_distances(start).into_iter().enumerate() {
+            if let Some(d) = d.filter(|&d| d > 0 && in_cells[idx]) {
                 sum += d;
                 count += 1;
             }
         }
+        if count > 0 {
+            means.push(sum as f64 / count as f64);
+        }
     }
 
-    if count == 0 {
-        0.0
-    } else {
-        sum as f64 / count as f64
+    // Isolated sources say nothing about the paths elsewhere in the maze.
+    if means.is_empty() {
+        return (0.0, f64::INFINITY);
+    }
+
+    let n = means.len() as f64;
+    let mean = means.iter().sum::<f64>() / n;
+    if means.len() < 2 {
+        return (mean, f64::INFINITY);
     }
+    let variance = means.iter().map(|m| (m - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, 1.96 * (variance / n).sqrt())
 }
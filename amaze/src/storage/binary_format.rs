@@ -1,13 +1,14 @@
-use crate::direction4::Direction4;
-use crate::direction6::Direction6;
-use crate::wall4_grid::Wall4Grid;
-use crate::wall6_grid::Wall6Grid;
+use super::{StorableGrid, new_walled_grid};
+use crate::wrap_mode::WrapMode;
 use std::io::{self, Write};
 
 const MAGIC: [u8; 4] = *b"AMZE";
-const VERSION: u8 = 1;
-const TYPE_SQUARE: u8 = 0;
-const TYPE_HEX: u8 = 1;
+const VERSION: u8 = 2;
+/// Magic, version, type byte, two `u16` dimensions, the wrap mode byte and the `u16` depth.
+const HEADER_LEN: usize = 13;
+/// Version 1 headers lack the wrap mode byte and the depth; such grids never wrap and
+/// have a single layer.
+const HEADER_LEN_V1: usize = 10;
 
 #[derive(Debug)]
 pub enum BinaryError {
@@ -49,97 +50,60 @@ pub trait FromBinary: Sized {
     fn from_binary(data: &[u8]) -> Result<Self, BinaryError>;
 }
 
-impl ToBinary for Wall4Grid {
+impl<G: StorableGrid> ToBinary for G {
     fn to_binary(&self) -> Result<Vec<u8>, BinaryError> {
-        let mut buf = Vec::with_capacity(8 + self.width() * self.height());
+        let mut buf = Vec::with_capacity(HEADER_LEN + self.cell_count());
         buf.write_all(&MAGIC)?;
-        buf.write_all(&[VERSION, TYPE_SQUARE])?;
+        buf.write_all(&[VERSION, G::TYPE_ID])?;
         buf.write_all(&(self.width() as u16).to_le_bytes())?;
         buf.write_all(&(self.height() as u16).to_le_bytes())?;
+        buf.push(self.wrap_mode().to_byte());
+        buf.write_all(&(self.depth() as u16).to_le_bytes())?;
         for cell in self.coords() {
-            buf.push(*self[cell]);
+            buf.push(self.cell_bits(cell));
         }
         Ok(buf)
     }
 }
 
-impl FromBinary for Wall4Grid {
+impl<G: StorableGrid> FromBinary for G {
     fn from_binary(data: &[u8]) -> Result<Self, BinaryError> {
-        if data.len() < 8 {
+        if data.len() < HEADER_LEN_V1 {
             return Err(BinaryError::InvalidHeader("data too short".into()));
         }
         if data[0..4] != MAGIC {
             return Err(BinaryError::InvalidHeader("invalid magic bytes".into()));
         }
-        if data[4] != VERSION {
-            return Err(BinaryError::InvalidHeader(format!(
-                "unsupported version {}",
-                data[4]
-            )));
-        }
-        if data[5] != TYPE_SQUARE {
-            return Err(BinaryError::InvalidHeader("not a square maze".into()));
-        }
-
-        let width = u16::from_le_bytes([data[6], data[7]]) as usize;
-        let height = u16::from_le_bytes([data[8], data[9]]) as usize;
-        let expected = 10 + width * height;
-        if data.len() < expected {
-            return Err(BinaryError::InvalidData(format!(
-                "expected {expected} bytes, got {}",
-                data.len()
-            )));
-        }
-
-        let mut grid = Wall4Grid::new(width, height);
-        for (i, cell) in grid.coords().collect::<Vec<_>>().into_iter().enumerate() {
-            let byte = data[10 + i];
-            if byte & !0b00001111 != 0 {
-                return Err(BinaryError::InvalidData(format!(
-                    "invalid wall byte 0b{byte:08b}"
+        let header_len = match data[4] {
+            1 => HEADER_LEN_V1,
+            VERSION => HEADER_LEN,
+            version => {
+                return Err(BinaryError::InvalidHeader(format!(
+                    "unsupported version {version}"
                 )));
             }
-            grid[cell] = Direction4::from_bits(byte);
-        }
-        Ok(grid)
-    }
-}
-
-impl ToBinary for Wall6Grid {
-    fn to_binary(&self) -> Result<Vec<u8>, BinaryError> {
-        let mut buf = Vec::with_capacity(8 + self.width() * self.height());
-        buf.write_all(&MAGIC)?;
-        buf.write_all(&[VERSION, TYPE_HEX])?;
-        buf.write_all(&(self.width() as u16).to_le_bytes())?;
-        buf.write_all(&(self.height() as u16).to_le_bytes())?;
-        for cell in self.coords() {
-            buf.push(*self[cell]);
-        }
-        Ok(buf)
-    }
-}
-
-impl FromBinary for Wall6Grid {
-    fn from_binary(data: &[u8]) -> Result<Self, BinaryError> {
-        if data.len() < 8 {
+        };
+        if data.len() < header_len {
             return Err(BinaryError::InvalidHeader("data too short".into()));
         }
-        if data[0..4] != MAGIC {
-            return Err(BinaryError::InvalidHeader("invalid magic bytes".into()));
-        }
-        if data[4] != VERSION {
+        if data[5] != G::TYPE_ID {
             return Err(BinaryError::InvalidHeader(format!(
-                "unsupported version {}",
-                data[4]
+                "not a {} maze",
+                G::TYPE_NAME
             )));
         }
-        if data[5] != TYPE_HEX {
-            return Err(BinaryError::InvalidHeader("not a hex maze".into()));
-        }
 
         let width = u16::from_le_bytes([data[6], data[7]]) as usize;
         let height = u16::from_le_bytes([data[8], data[9]]) as usize;
-        let expected = 10 + width * height;
+        let (wrap, depth) = if header_len == HEADER_LEN {
+            let wrap = WrapMode::from_byte(data[10]).ok_or_else(|| {
+                BinaryError::InvalidHeader(format!("invalid wrap mode {}", data[10]))
+            })?;
+            (wrap, u16::from_le_bytes([data[11], data[12]]) as usize)
+        } else {
+            (WrapMode::None, 1)
+        };
+        let expected = header_len + width * height * depth;
         if data.len() < expected {
             return Err(BinaryError::InvalidData(format!(
                 "expected {expected} bytes, got {}",
@@ -147,15 +111,20 @@ impl FromBinary for Wall6Grid {
             )));
         }
 
-        let mut grid = Wall6Grid::new(width, height);
+        let mut grid = new_walled_grid::<G>(width, height, depth, wrap).ok_or_else(|| {
+            BinaryError::InvalidHeader(format!(
+                "{} mazes cannot have {depth} layers and wrap {wrap}",
+                G::TYPE_NAME
+            ))
+        })?;
         for (i, cell) in grid.coords().collect::<Vec<_>>().into_iter().enumerate() {
-            let byte = data[10 + i];
-            if byte & !0b00111111 != 0 {
+            let byte = data[header_len + i];
+            if byte & !G::WALL_BITS != 0 {
                 return Err(BinaryError::InvalidData(format!(
                     "invalid wall byte 0b{byte:08b}"
                 )));
             }
-            grid[cell] = Direction6::from_bits(byte);
+            grid.set_cell_bits(cell, byte);
         }
         Ok(grid)
     }
@@ -165,6 +134,8 @@ impl FromBinary for Wall6Grid {
 mod tests {
     use super::*;
     use crate::generators::RecursiveBacktracker4;
+    use crate::wall4_grid::Wall4Grid;
+    use crate::wall6_grid::Wall6Grid;
 
     #[test]
     fn roundtrip_square_maze() {
@@ -188,6 +159,60 @@ mod tests {
     #[test]
     fn too_short_returns_error() {
         assert!(Wall4Grid::from_binary(b"AMZE").is_err());
+        assert!(Wall4Grid::from_binary(&[b'A', b'M', b'Z', b'E', 1, 0, 2, 0]).is_err());
+    }
+
+    #[test]
+    fn type_byte_is_checked() {
+        let bytes = Wall4Grid::new(3, 3).to_binary().unwrap();
+        assert_eq!(bytes[5], Wall4Grid::TYPE_ID);
+        assert!(matches!(
+            Wall6Grid::from_binary(&bytes),
+            Err(BinaryError::InvalidHeader(_))
+        ));
+    }
+
+    #[test]
+    fn wrap_mode_is_stored_in_header() {
+        let grid = Wall4Grid::new_wrapped(4, 3, WrapMode::Torus);
+        let bytes = grid.to_binary().unwrap();
+        assert_eq!(bytes[4], VERSION);
+        assert_eq!(bytes[10], WrapMode::Torus.to_byte());
+
+        let restored = Wall4Grid::from_binary(&bytes).unwrap();
+        assert_eq!(restored.wrap_mode(), WrapMode::Torus);
+    }
+
+    #[test]
+    fn version_1_data_is_read_without_wrap_or_layers() {
+        let mut data = vec![b'A', b'M', b'Z', b'E', 1, 0, 2, 0, 1, 0];
+        data.extend_from_slice(&[0b1011, 0b1110]);
+        let grid = Wall4Grid::from_binary(&data).unwrap();
+        assert_eq!(grid.wrap_mode(), WrapMode::None);
+        assert_eq!(grid.width(), 2);
+        assert_eq!(*grid[crate::preamble::GridCoord2D::new(1, 0)], 0b1110);
+    }
+
+    #[test]
+    fn flat_grids_reject_layers() {
+        let mut bytes = Wall4Grid::new(2, 2).to_binary().unwrap();
+        assert_eq!(bytes[11..13], [1, 0]);
+        bytes[11] = 2;
+        bytes.extend_from_slice(&[0b1111; 4]);
+        assert!(matches!(
+            Wall4Grid::from_binary(&bytes),
+            Err(BinaryError::InvalidHeader(_))
+        ));
+    }
+
+    #[test]
+    fn invalid_wrap_byte_returns_error() {
+        let mut bytes = Wall4Grid::new(2, 2).to_binary().unwrap();
+        bytes[10] = 9;
+        assert!(matches!(
+            Wall4Grid::from_binary(&bytes),
+            Err(BinaryError::InvalidHeader(_))
+        ));
     }
 
     #[test]
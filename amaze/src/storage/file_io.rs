@@ -1,3 +1,4 @@
+use super::StorableGrid;
 use crate::wall4_grid::Wall4Grid;
 use crate::wall6_grid::Wall6Grid;
 use std::fs;
@@ -71,9 +72,12 @@ impl From<super::JsonError> for MazeIoError {
     }
 }
 
-pub fn save_wall4_grid(
+/// Saves any [`StorableGrid`] in the given format.
+///
+/// [`MazeFormat::Auto`] picks the format from the file extension (`.bin` or `.json`).
+pub fn save_grid<G: StorableGrid>(
     path: impl AsRef<Path>,
-    maze: &Wall4Grid,
+    maze: &G,
     format: MazeFormat,
 ) -> Result<(), MazeIoError> {
     let format = if format == MazeFormat::Auto {
@@ -96,16 +100,20 @@ pub fn save_wall4_grid(
             Ok(())
         }
         other => Err(MazeIoError::UnsupportedFormat(format!(
-            "cannot save square maze as {:?} (enable binary-format or json-format feature)",
+            "cannot save {} maze as {:?} (enable binary-format or json-format feature)",
+            G::TYPE_NAME,
             other
         ))),
     }
 }
 
-pub fn load_wall4_grid(
+/// Loads any [`StorableGrid`] in the given format.
+///
+/// [`MazeFormat::Auto`] picks the format from the file extension (`.bin` or `.json`).
+pub fn load_grid<G: StorableGrid>(
     path: impl AsRef<Path>,
     format: MazeFormat,
-) -> Result<Wall4Grid, MazeIoError> {
+) -> Result<G, MazeIoError> {
     let format = if format == MazeFormat::Auto {
         MazeFormat::from_extension(path.as_ref())
     } else {
@@ -116,73 +124,47 @@ pub fn load_wall4_grid(
 
     match format {
         #[cfg(feature = "binary-format")]
-        MazeFormat::Binary => Ok(Wall4Grid::from_binary(&data)?),
+        MazeFormat::Binary => Ok(G::from_binary(&data)?),
         #[cfg(feature = "json-format")]
-        MazeFormat::Json => Ok(Wall4Grid::from_json(std::str::from_utf8(&data).map_err(
-            |e| MazeIoError::Io(io::Error::new(io::ErrorKind::InvalidData, e)),
-        )?)?),
+        MazeFormat::Json => Ok(G::from_json(std::str::from_utf8(&data).map_err(|e| {
+            MazeIoError::Io(io::Error::new(io::ErrorKind::InvalidData, e))
+        })?)?),
         other => Err(MazeIoError::UnsupportedFormat(format!(
-            "cannot load square maze as {:?} (enable binary-format or json-format feature)",
+            "cannot load {} maze as {:?} (enable binary-format or json-format feature)",
+            G::TYPE_NAME,
             other
         ))),
     }
 }
 
+pub fn save_wall4_grid(
+    path: impl AsRef<Path>,
+    maze: &Wall4Grid,
+    format: MazeFormat,
+) -> Result<(), MazeIoError> {
+    save_grid(path, maze, format)
+}
+
+pub fn load_wall4_grid(
+    path: impl AsRef<Path>,
+    format: MazeFormat,
+) -> Result<Wall4Grid, MazeIoError> {
+    load_grid(path, format)
+}
+
 pub fn save_wall6_grid(
     path: impl AsRef<Path>,
     maze: &Wall6Grid,
     format: MazeFormat,
 ) -> Result<(), MazeIoError> {
-    let format = if format == MazeFormat::Auto {
-        MazeFormat::from_extension(path.as_ref())
-    } else {
-        format
-    };
-
-    match format {
-        #[cfg(feature = "binary-format")]
-        MazeFormat::Binary => {
-            let bytes = maze.to_binary()?;
-            fs::write(path, bytes)?;
-            Ok(())
-        }
-        #[cfg(feature = "json-format")]
-        MazeFormat::Json => {
-            let json = maze.to_json()?;
-            fs::write(path, json)?;
-            Ok(())
-        }
-        other => Err(MazeIoError::UnsupportedFormat(format!(
-            "cannot save hex maze as {:?} (enable binary-format or json-format feature)",
-            other
-        ))),
-    }
+    save_grid(path, maze, format)
 }
 
 pub fn load_wall6_grid(
     path: impl AsRef<Path>,
     format: MazeFormat,
 ) -> Result<Wall6Grid, MazeIoError> {
-    let format = if format == MazeFormat::Auto {
-        MazeFormat::from_extension(path.as_ref())
-    } else {
-        format
-    };
-
-    let data = fs::read(path)?;
-
-    match format {
-        #[cfg(feature = "binary-format")]
-        MazeFormat::Binary => Ok(Wall6Grid::from_binary(&data)?),
-        #[cfg(feature = "json-format")]
-        MazeFormat::Json => Ok(Wall6Grid::from_json(std::str::from_utf8(&data).map_err(
-            |e| MazeIoError::Io(io::Error::new(io::ErrorKind::InvalidData, e)),
-        )?)?),
-        other => Err(MazeIoError::UnsupportedFormat(format!(
-            "cannot load hex maze as {:?} (enable binary-format or json-format feature)",
-            other
-        ))),
-    }
+    load_grid(path, format)
 }
 
 #[cfg(test)]
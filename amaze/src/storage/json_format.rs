@@ -1,7 +1,5 @@
-use crate::direction4::Direction4;
-use crate::direction6::Direction6;
-use crate::wall4_grid::Wall4Grid;
-use crate::wall6_grid::Wall6Grid;
+use super::{StorableGrid, new_walled_grid};
+use crate::wrap_mode::WrapMode;
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 struct MazeJson {
@@ -10,9 +8,27 @@ struct MazeJson {
     maze_type: String,
     width: usize,
     height: usize,
+    /// Documents written before wrap modes existed have no `wrap` field.
+    #[serde(default = "default_wrap")]
+    wrap: String,
+    /// Only layered grids have more than one layer; flat documents omit the field.
+    #[serde(default = "default_depth", skip_serializing_if = "is_flat")]
+    depth: usize,
     cells: Vec<u8>,
 }
 
+fn default_wrap() -> String {
+    WrapMode::None.as_str().into()
+}
+
+fn default_depth() -> usize {
+    1
+}
+
+fn is_flat(depth: &usize) -> bool {
+    *depth == 1
+}
+
 #[derive(Debug)]
 pub enum JsonError {
     Serialize(serde_json::Error),
@@ -52,32 +68,34 @@ pub trait FromJson: Sized {
     fn from_json(json: &str) -> Result<Self, JsonError>;
 }
 
-impl ToJson for Wall4Grid {
+impl<G: StorableGrid> ToJson for G {
     fn to_json(&self) -> Result<String, JsonError> {
-        let cells: Vec<u8> = self.coords().map(|c| *self[c]).collect();
+        let cells: Vec<u8> = self.coords().map(|c| self.cell_bits(c)).collect();
         let maze = MazeJson {
             version: 1,
-            maze_type: "square".into(),
+            maze_type: G::TYPE_NAME.into(),
             width: self.width(),
             height: self.height(),
+            wrap: self.wrap_mode().as_str().into(),
+            depth: self.depth(),
             cells,
         };
         serde_json::to_string(&maze).map_err(JsonError::Serialize)
     }
 }
 
-impl FromJson for Wall4Grid {
+impl<G: StorableGrid> FromJson for G {
     fn from_json(json: &str) -> Result<Self, JsonError> {
         let maze: MazeJson = serde_json::from_str(json).map_err(JsonError::Deserialize)?;
 
         if maze.version != 1 {
             return Err(JsonError::InvalidVersion(maze.version));
         }
-        if maze.maze_type != "square" {
+        if maze.maze_type != G::TYPE_NAME {
             return Err(JsonError::InvalidType(maze.maze_type));
         }
 
-        let expected = maze.width * maze.height;
+        let expected = maze.width * maze.height * maze.depth;
         if maze.cells.len() != expected {
             return Err(JsonError::InvalidCellCount {
                 expected,
@@ -85,62 +103,24 @@ impl FromJson for Wall4Grid {
             });
         }
 
-        let mut grid = Wall4Grid::new(maze.width, maze.height);
-        for (i, coord) in grid.coords().collect::<Vec<_>>().into_iter().enumerate() {
-            let byte = maze.cells[i];
-            if byte & !0b00001111 != 0 {
-                return Err(JsonError::InvalidType(format!(
-                    "invalid wall byte 0b{byte:08b}"
-                )));
-            }
-            grid[coord] = Direction4::from_bits(byte);
-        }
-        Ok(grid)
-    }
-}
+        let wrap: WrapMode = maze.wrap.parse().map_err(JsonError::InvalidType)?;
+        let depth = maze.depth;
+        let mut grid =
+            new_walled_grid::<G>(maze.width, maze.height, depth, wrap).ok_or_else(|| {
+                JsonError::InvalidType(format!(
+                    "{} mazes cannot have {depth} layers and wrap {wrap}",
+                    G::TYPE_NAME
+                ))
+            })?;
 
-impl ToJson for Wall6Grid {
-    fn to_json(&self) -> Result<String, JsonError> {
-        let cells: Vec<u8> = self.coords().map(|c| *self[c]).collect();
-        let maze = MazeJson {
-            version: 1,
-            maze_type: "hex".into(),
-            width: self.width(),
-            height: self.height(),
-            cells,
-        };
-        serde_json::to_string(&maze).map_err(JsonError::Serialize)
-    }
-}
-
-impl FromJson for Wall6Grid {
-    fn from_json(json: &str) -> Result<Self, JsonError> {
-        let maze: MazeJson = serde_json::from_str(json).map_err(JsonError::Deserialize)?;
-
-        if maze.version != 1 {
-            return Err(JsonError::InvalidVersion(maze.version));
-        }
-        if maze.maze_type != "hex" {
-            return Err(JsonError::InvalidType(maze.maze_type));
-        }
-
-        let expected = maze.width * maze.height;
-        if maze.cells.len() != expected {
-            return Err(JsonError::InvalidCellCount {
-                expected,
-                got: maze.cells.len(),
-            });
-        }
-
-        let mut grid = Wall6Grid::new(maze.width, maze.height);
         for (i, coord) in grid.coords().collect::<Vec<_>>().into_iter().enumerate() {
             let byte = maze.cells[i];
-            if byte & !0b00111111 != 0 {
+            if byte & !G::WALL_BITS != 0 {
                 return Err(JsonError::InvalidType(format!(
                     "invalid wall byte 0b{byte:08b}"
                 )));
             }
-            grid[coord] = Direction6::from_bits(byte);
+            grid.set_cell_bits(coord, byte);
         }
         Ok(grid)
     }
@@ -150,6 +130,7 @@ impl FromJson for Wall6Grid {
 mod tests {
     use super::*;
     use crate::generators::RecursiveBacktracker4;
+    use crate::wall4_grid::Wall4Grid;
 
     #[test]
     fn roundtrip_square_json() {
@@ -174,13 +155,57 @@ mod tests {
         assert_eq!(parsed.maze_type, "square");
         assert_eq!(parsed.width, 5);
         assert_eq!(parsed.height, 5);
+        assert_eq!(parsed.wrap, "none");
         assert_eq!(parsed.cells.len(), 25);
     }
 
+    #[test]
+    fn wrap_mode_roundtrips_and_defaults_to_none() {
+        let grid = Wall4Grid::new_wrapped(3, 3, WrapMode::Vertical);
+        let restored = Wall4Grid::from_json(&grid.to_json().unwrap()).unwrap();
+        assert_eq!(restored.wrap_mode(), WrapMode::Vertical);
+
+        let legacy = r#"{"version":1,"type":"square","width":1,"height":1,"cells":[15]}"#;
+        assert_eq!(
+            Wall4Grid::from_json(legacy).unwrap().wrap_mode(),
+            WrapMode::None
+        );
+
+        let invalid =
+            r#"{"version":1,"type":"square","width":1,"height":1,"wrap":"sphere","cells":[15]}"#;
+        assert!(matches!(
+            Wall4Grid::from_json(invalid),
+            Err(JsonError::InvalidType(_))
+        ));
+    }
+
+    #[test]
+    fn depth_is_written_for_layered_grids_only() {
+        use crate::preamble::{GridCoord3D, Wall3DGrid};
+
+        assert!(!Wall4Grid::new(2, 2).to_json().unwrap().contains("depth"));
+
+        let mut grid = Wall3DGrid::new(2, 1, 2);
+        grid.remove_wall_between(GridCoord3D::new(1, 0, 0), GridCoord3D::new(1, 0, 1));
+        let json = grid.to_json().unwrap();
+        assert!(json.contains(r#""depth":2"#));
+        let restored = Wall3DGrid::from_json(&json).unwrap();
+        assert_eq!(restored.depth(), 2);
+        assert!(grid.coords().all(|c| grid[c] == restored[c]));
+
+        let layered_square =
+            r#"{"version":1,"type":"square","width":1,"height":1,"depth":2,"cells":[15,15]}"#;
+        assert!(matches!(
+            Wall4Grid::from_json(layered_square),
+            Err(JsonError::InvalidType(_))
+        ));
+    }
+
     #[test]
     #[cfg(feature = "generator-hex-recursive-backtracker")]
     fn hex_json_roundtrip() {
         use crate::generators::RecursiveBacktracker6;
+        use crate::wall6_grid::Wall6Grid;
         let maze = RecursiveBacktracker6::new_from_seed(42).generate(5, 5);
         let json = maze.to_json().unwrap();
         let restored = Wall6Grid::from_json(&json).unwrap();
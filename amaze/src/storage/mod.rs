@@ -11,9 +11,221 @@ pub use binary_format::BinaryError;
 pub use binary_format::{FromBinary, ToBinary};
 #[cfg(feature = "file-io")]
 pub use file_io::{
-    MazeFormat, MazeIoError, load_wall4_grid, load_wall6_grid, save_wall4_grid, save_wall6_grid,
+    MazeFormat, MazeIoError, load_grid, load_wall4_grid, load_wall6_grid, save_grid,
+    save_wall4_grid, save_wall6_grid,
 };
 #[cfg(feature = "json-format")]
 pub use json_format::JsonError;
 #[cfg(feature = "json-format")]
 pub use json_format::{FromJson, ToJson};
+
+use crate::direction3::Direction3;
+use crate::direction3d::Direction3D;
+use crate::direction4::Direction4;
+use crate::direction6::Direction6;
+use crate::grid_coord_2d::GridCoord2D;
+use crate::grid_coord_3d::GridCoord3D;
+use crate::hex_coord::HexCoord;
+use crate::maze_grid::MazeGrid;
+use crate::tri_coord::TriCoord;
+use crate::wall3_grid::Wall3Grid;
+use crate::wall3d_grid::Wall3DGrid;
+use crate::wall4_grid::Wall4Grid;
+use crate::wall6_grid::Wall6Grid;
+use crate::weave_grid::WeaveGrid;
+use crate::wrap_mode::WrapMode;
+
+/// A [`MazeGrid`] that can be written by the binary and JSON formats.
+///
+/// A grid is stored as its `width × height × depth` cells in [`MazeGrid::coords`] order,
+/// one byte of wall bits each.
+/// Any grid implementing this trait gets [`ToBinary`], [`FromBinary`],
+/// [`ToJson`] and [`FromJson`] for free.
+pub trait StorableGrid: MazeGrid + Sized {
+    /// The type byte written to the binary header.
+    const TYPE_ID: u8;
+
+    /// The `type` name written to JSON documents, e.g. `"square"`.
+    const TYPE_NAME: &'static str;
+
+    /// The bits that may be set in a cell's wall byte.
+    const WALL_BITS: u8;
+
+    /// Creates a fully walled grid.
+    fn new_walled(width: usize, height: usize) -> Self;
+
+    /// Creates a fully walled grid with the given edges joined,
+    /// or `None` if the topology does not support the wrap mode.
+    fn new_walled_wrapped(width: usize, height: usize, wrap: WrapMode) -> Option<Self> {
+        (wrap == WrapMode::None).then(|| Self::new_walled(width, height))
+    }
+
+    /// Returns which edges of the grid are joined; written to the header of both formats.
+    fn wrap_mode(&self) -> WrapMode {
+        WrapMode::None
+    }
+
+    /// Creates a fully walled grid of `depth` layers,
+    /// or `None` if the topology is flat and `depth` is not `1`.
+    fn new_walled_layered(width: usize, height: usize, depth: usize) -> Option<Self> {
+        (depth == 1).then(|| Self::new_walled(width, height))
+    }
+
+    /// Returns the number of layers; `1` for flat grids.
+    fn depth(&self) -> usize {
+        1
+    }
+
+    /// Returns the wall bits of a cell.
+    fn cell_bits(&self, coord: Self::Coord) -> u8;
+
+    /// Replaces the wall bits of a cell; `bits` never exceeds [`WALL_BITS`](Self::WALL_BITS).
+    fn set_cell_bits(&mut self, coord: Self::Coord, bits: u8);
+}
+
+impl StorableGrid for Wall4Grid {
+    const TYPE_ID: u8 = 0;
+    const TYPE_NAME: &'static str = "square";
+    const WALL_BITS: u8 = 0b00001111;
+
+    fn new_walled(width: usize, height: usize) -> Self {
+        Wall4Grid::new(width, height)
+    }
+
+    fn new_walled_wrapped(width: usize, height: usize, wrap: WrapMode) -> Option<Self> {
+        Some(Wall4Grid::new_wrapped(width, height, wrap))
+    }
+
+    fn wrap_mode(&self) -> WrapMode {
+        Wall4Grid::wrap_mode(self)
+    }
+
+    fn cell_bits(&self, coord: GridCoord2D) -> u8 {
+        *self[coord]
+    }
+
+    fn set_cell_bits(&mut self, coord: GridCoord2D, bits: u8) {
+        self[coord] = Direction4::from_bits(bits);
+    }
+}
+
+impl StorableGrid for Wall6Grid {
+    const TYPE_ID: u8 = 1;
+    const TYPE_NAME: &'static str = "hex";
+    const WALL_BITS: u8 = 0b00111111;
+
+    fn new_walled(width: usize, height: usize) -> Self {
+        Wall6Grid::new(width, height)
+    }
+
+    fn new_walled_wrapped(width: usize, height: usize, wrap: WrapMode) -> Option<Self> {
+        Some(Wall6Grid::new_wrapped(width, height, wrap))
+    }
+
+    fn wrap_mode(&self) -> WrapMode {
+        Wall6Grid::wrap_mode(self)
+    }
+
+    fn cell_bits(&self, coord: HexCoord) -> u8 {
+        *self[coord]
+    }
+
+    fn set_cell_bits(&mut self, coord: HexCoord, bits: u8) {
+        self[coord] = Direction6::from_bits(bits);
+    }
+}
+
+impl StorableGrid for Wall3Grid {
+    const TYPE_ID: u8 = 2;
+    const TYPE_NAME: &'static str = "triangle";
+    const WALL_BITS: u8 = 0b00000111;
+
+    fn new_walled(width: usize, height: usize) -> Self {
+        Wall3Grid::new(width, height)
+    }
+
+    fn cell_bits(&self, coord: TriCoord) -> u8 {
+        *self[coord]
+    }
+
+    fn set_cell_bits(&mut self, coord: TriCoord, bits: u8) {
+        self[coord] = Direction3::from_bits(bits);
+    }
+}
+
+impl StorableGrid for Wall3DGrid {
+    const TYPE_ID: u8 = 4;
+    const TYPE_NAME: &'static str = "3d";
+    const WALL_BITS: u8 = 0b00111111;
+
+    fn new_walled(width: usize, height: usize) -> Self {
+        Wall3DGrid::new(width, height, 1)
+    }
+
+    fn new_walled_layered(width: usize, height: usize, depth: usize) -> Option<Self> {
+        Some(Wall3DGrid::new(width, height, depth))
+    }
+
+    fn depth(&self) -> usize {
+        Wall3DGrid::depth(self)
+    }
+
+    fn cell_bits(&self, coord: GridCoord3D) -> u8 {
+        *self[coord]
+    }
+
+    fn set_cell_bits(&mut self, coord: GridCoord3D, bits: u8) {
+        self[coord] = Direction3D::from_bits(bits);
+    }
+}
+
+/// Creates the fully walled grid described by a stored header, or `None` if
+/// the grid type supports neither the layers nor the wrap mode.
+#[cfg(any(feature = "binary-format", feature = "json-format"))]
+fn new_walled_grid<G: StorableGrid>(
+    width: usize,
+    height: usize,
+    depth: usize,
+    wrap: WrapMode,
+) -> Option<G> {
+    match (depth, wrap) {
+        (1, wrap) => G::new_walled_wrapped(width, height, wrap),
+        (depth, WrapMode::None) => G::new_walled_layered(width, height, depth),
+        _ => None,
+    }
+}
+
+/// Marks a [`WeaveGrid`] cell that a tunnel passes beneath.
+const CROSSING_BIT: u8 = 0b00010000;
+
+impl StorableGrid for WeaveGrid {
+    const TYPE_ID: u8 = 3;
+    const TYPE_NAME: &'static str = "weave";
+    const WALL_BITS: u8 = Wall4Grid::WALL_BITS | CROSSING_BIT;
+
+    fn new_walled(width: usize, height: usize) -> Self {
+        WeaveGrid::new(width, height)
+    }
+
+    fn new_walled_wrapped(width: usize, height: usize, wrap: WrapMode) -> Option<Self> {
+        Some(WeaveGrid::new_wrapped(width, height, wrap))
+    }
+
+    fn wrap_mode(&self) -> WrapMode {
+        WeaveGrid::wrap_mode(self)
+    }
+
+    fn cell_bits(&self, coord: GridCoord2D) -> u8 {
+        let crossing = if self.is_crossing(coord) {
+            CROSSING_BIT
+        } else {
+            0
+        };
+        *self[coord] | crossing
+    }
+
+    fn set_cell_bits(&mut self, coord: GridCoord2D, bits: u8) {
+        self.grid_mut()[coord] = Direction4::from_bits(bits & Wall4Grid::WALL_BITS);
+        self.set_crossing(coord, bits & CROSSING_BIT != 0);
+    }
+}
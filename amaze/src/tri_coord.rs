@@ -0,0 +1,102 @@
+use crate::direction3::Direction3;
+
+/// The address of a cell in a triangular [`Wall3Grid`](crate::preamble::Wall3Grid).
+///
+/// Cells alternate between upward- and downward-pointing triangles along each
+/// row; the cell at `(0, 0)` points upward.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TriCoord {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl TriCoord {
+    #[inline]
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Tests whether the triangle points upward, i.e. has its base at the bottom.
+    #[inline]
+    pub fn is_upward(&self) -> bool {
+        (self.x + self.y) % 2 == 0
+    }
+
+    /// Returns the adjacent cell in the given direction, unless it would have
+    /// a negative coordinate.
+    #[inline]
+    pub fn neighbor(&self, dir: Direction3) -> Option<Self> {
+        match dir {
+            Direction3::EAST => Some(Self::new(self.x + 1, self.y)),
+            Direction3::WEST => self.x.checked_sub(1).map(|x| Self::new(x, self.y)),
+            Direction3::BASE if self.is_upward() => Some(Self::new(self.x, self.y + 1)),
+            Direction3::BASE => self.y.checked_sub(1).map(|y| Self::new(self.x, y)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn try_neighbor(&self, dir: Direction3, width: usize, height: usize) -> Option<Self> {
+        self.neighbor(dir).filter(|n| n.x < width && n.y < height)
+    }
+
+    /// Returns the direction leading to an adjacent cell.
+    pub fn direction_to(&self, other: TriCoord) -> Option<Direction3> {
+        Direction3::CARDINALS
+            .into_iter()
+            .find(|&dir| self.neighbor(dir) == Some(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orientation_alternates() {
+        assert!(TriCoord::new(0, 0).is_upward());
+        assert!(!TriCoord::new(1, 0).is_upward());
+        assert!(!TriCoord::new(0, 1).is_upward());
+        assert!(TriCoord::new(1, 1).is_upward());
+    }
+
+    #[test]
+    fn base_neighbor_depends_on_orientation() {
+        assert_eq!(
+            TriCoord::new(2, 2).neighbor(Direction3::BASE),
+            Some(TriCoord::new(2, 3))
+        );
+        assert_eq!(
+            TriCoord::new(1, 2).neighbor(Direction3::BASE),
+            Some(TriCoord::new(1, 1))
+        );
+        assert_eq!(TriCoord::new(1, 0).neighbor(Direction3::BASE), None);
+    }
+
+    #[test]
+    fn neighbors_are_mutual() {
+        for y in 0..4 {
+            for x in 0..4 {
+                let cell = TriCoord::new(x, y);
+                for dir in Direction3::CARDINALS {
+                    if let Some(n) = cell.neighbor(dir) {
+                        assert_eq!(n.neighbor(dir.opposite()), Some(cell));
+                        assert_eq!(cell.direction_to(n), Some(dir));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn try_neighbor_stays_in_bounds() {
+        let corner = TriCoord::new(2, 0);
+        assert_eq!(corner.try_neighbor(Direction3::EAST, 3, 1), None);
+        assert_eq!(corner.try_neighbor(Direction3::BASE, 3, 1), None);
+        assert_eq!(
+            corner.try_neighbor(Direction3::WEST, 3, 1),
+            Some(TriCoord::new(1, 0))
+        );
+    }
+}
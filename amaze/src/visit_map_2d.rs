@@ -1,4 +1,5 @@
 use crate::grid_coord_2d::{GetCoordinateBounds2D, GridCoord2D, LinearizeCoords2D};
+use crate::grid_mask::GridMask;
 use std::ops::{Index, IndexMut};
 
 #[derive(Debug, Default)]
@@ -24,6 +25,18 @@ impl VisitMap2D {
         Self::new(other.width(), other.height())
     }
 
+    /// Creates a visit map in which all cells disabled by the mask are already visited.
+    pub fn new_masked(mask: &GridMask) -> Self {
+        let mut map = Self::new_like(mask);
+        for y in 0..map.height {
+            for x in 0..map.width {
+                let coord = GridCoord2D::new(x, y);
+                map[coord] = !mask.is_enabled(coord);
+            }
+        }
+        map
+    }
+
     pub fn get(&self, coords: GridCoord2D) -> Option<&bool> {
         if coords.x >= self.width || coords.y >= self.height {
             return None;
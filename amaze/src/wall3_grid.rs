@@ -0,0 +1,181 @@
+use crate::direction3::Direction3;
+use crate::tri_coord::TriCoord;
+use std::ops::{Index, IndexMut};
+
+pub type Wall3 = Direction3;
+
+/// A grid of alternating upward- and downward-pointing triangles.
+///
+/// Each row holds `width` triangles; see [`TriCoord`] for the orientation of a cell.
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Wall3Grid {
+    width: usize,
+    height: usize,
+    walls: Vec<Wall3>,
+}
+
+impl Wall3Grid {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            walls: vec![Wall3::ALL; width * height],
+        }
+    }
+
+    pub fn get(&self, coord: TriCoord) -> Option<&Wall3> {
+        if coord.x >= self.width || coord.y >= self.height {
+            return None;
+        }
+        Some(&self[coord])
+    }
+
+    pub fn get_mut(&mut self, coord: TriCoord) -> Option<&mut Wall3> {
+        if coord.x >= self.width || coord.y >= self.height {
+            return None;
+        }
+        Some(&mut self[coord])
+    }
+
+    pub fn remove_wall_between(&mut self, a: TriCoord, b: TriCoord) {
+        let dir = a
+            .direction_to(b)
+            .expect("Cells must be adjacent triangle neighbors");
+
+        let a_idx = self.linearize_coord(a);
+        let b_idx = self.linearize_coord(b);
+
+        self.walls[a_idx] -= dir;
+        self.walls[b_idx] -= dir.opposite();
+    }
+
+    /// Adds the wall between two adjacent cells; the inverse of [`remove_wall_between`](Self::remove_wall_between).
+    pub fn add_wall_between(&mut self, a: TriCoord, b: TriCoord) {
+        let dir = a
+            .direction_to(b)
+            .expect("Cells must be adjacent triangle neighbors");
+
+        let a_idx = self.linearize_coord(a);
+        let b_idx = self.linearize_coord(b);
+
+        self.walls[a_idx] += dir;
+        self.walls[b_idx] += dir.opposite();
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn coords(&self) -> impl Iterator<Item = TriCoord> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| TriCoord::new(x, y)))
+    }
+
+    pub fn neighbors(&self, cell: TriCoord) -> impl Iterator<Item = TriCoord> + '_ {
+        Direction3::CARDINALS
+            .into_iter()
+            .filter_map(move |dir| cell.try_neighbor(dir, self.width, self.height))
+    }
+
+    pub fn open_neighbors(&self, cell: TriCoord) -> impl Iterator<Item = TriCoord> + '_ {
+        let walls = self.get(cell).copied().unwrap_or(Wall3::ALL);
+        Direction3::CARDINALS
+            .into_iter()
+            .filter(move |&dir| !walls.contains(dir))
+            .filter_map(move |dir| cell.try_neighbor(dir, self.width, self.height))
+    }
+
+    pub fn stats(&self) -> crate::stats::MazeStats {
+        crate::stats::MazeStats::from_grid(self)
+    }
+
+    #[inline]
+    fn linearize_coord(&self, coord: TriCoord) -> usize {
+        debug_assert!(
+            coord.x < self.width && coord.y < self.height,
+            "TriCoord out of bounds"
+        );
+        coord.y * self.width + coord.x
+    }
+}
+
+impl Index<TriCoord> for Wall3Grid {
+    type Output = Wall3;
+
+    fn index(&self, index: TriCoord) -> &Self::Output {
+        let index = self.linearize_coord(index);
+        &self.walls[index]
+    }
+}
+
+impl IndexMut<TriCoord> for Wall3Grid {
+    fn index_mut(&mut self, index: TriCoord) -> &mut Self::Output {
+        let index = self.linearize_coord(index);
+        &mut self.walls[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_grid_has_all_walls() {
+        let grid = Wall3Grid::new(4, 3);
+        for coord in grid.coords() {
+            assert!(grid[coord].is_all());
+        }
+    }
+
+    #[test]
+    fn remove_wall_between_works() {
+        let mut grid = Wall3Grid::new(4, 3);
+        let up = TriCoord::new(2, 0);
+        let below = TriCoord::new(2, 1);
+        grid.remove_wall_between(up, below);
+        assert!(!grid[up].contains(Direction3::BASE));
+        assert!(!grid[below].contains(Direction3::BASE));
+
+        grid.add_wall_between(below, up);
+        assert!(grid[up].is_all());
+        assert!(grid[below].is_all());
+    }
+
+    #[test]
+    fn neighbors_stays_in_bounds() {
+        let grid = Wall3Grid::new(4, 3);
+        let corner: Vec<_> = grid.neighbors(TriCoord::new(0, 0)).collect();
+        assert_eq!(corner, vec![TriCoord::new(1, 0), TriCoord::new(0, 1)]);
+        // A downward triangle on the top row has no base neighbor.
+        assert_eq!(grid.neighbors(TriCoord::new(1, 0)).count(), 2);
+        assert_eq!(grid.neighbors(TriCoord::new(1, 1)).count(), 3);
+    }
+
+    #[test]
+    fn open_neighbors_returns_passages() {
+        let mut grid = Wall3Grid::new(4, 3);
+        let cell = TriCoord::new(1, 1);
+        assert_eq!(grid.open_neighbors(cell).count(), 0);
+        grid.remove_wall_between(cell, TriCoord::new(0, 1));
+        let open: Vec<_> = grid.open_neighbors(cell).collect();
+        assert_eq!(open, vec![TriCoord::new(0, 1)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "adjacent")]
+    fn remove_wall_between_non_adjacent_panics() {
+        let mut grid = Wall3Grid::new(4, 3);
+        grid.remove_wall_between(TriCoord::new(1, 0), TriCoord::new(1, 1));
+    }
+
+    #[test]
+    fn get_out_of_bounds_returns_none() {
+        let grid = Wall3Grid::new(4, 3);
+        assert!(grid.get(TriCoord::new(4, 0)).is_none());
+        assert!(grid.get(TriCoord::new(0, 3)).is_none());
+    }
+}
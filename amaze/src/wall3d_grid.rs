@@ -0,0 +1,182 @@
+use crate::direction3d::Direction3D;
+use crate::grid_coord_3d::GridCoord3D;
+use std::ops::{Index, IndexMut};
+
+pub type Wall3D = Direction3D;
+
+/// A stack of `depth` rectangular layers, each `width × height` cells.
+///
+/// Every cell has four horizontal walls and a floor and ceiling
+/// ([`Direction3D::DOWN`] and [`Direction3D::UP`]); removing either connects
+/// the cell to the one directly below or above it.
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Wall3DGrid {
+    width: usize,
+    height: usize,
+    depth: usize,
+    walls: Vec<Wall3D>,
+}
+
+impl Wall3DGrid {
+    pub fn new(width: usize, height: usize, depth: usize) -> Self {
+        Self {
+            width,
+            height,
+            depth,
+            walls: vec![Wall3D::ALL; width * height * depth],
+        }
+    }
+
+    pub fn get(&self, coord: GridCoord3D) -> Option<&Wall3D> {
+        if coord.x >= self.width || coord.y >= self.height || coord.z >= self.depth {
+            return None;
+        }
+        Some(&self[coord])
+    }
+
+    pub fn get_mut(&mut self, coord: GridCoord3D) -> Option<&mut Wall3D> {
+        if coord.x >= self.width || coord.y >= self.height || coord.z >= self.depth {
+            return None;
+        }
+        Some(&mut self[coord])
+    }
+
+    pub fn remove_wall_between(&mut self, a: GridCoord3D, b: GridCoord3D) {
+        let dir = a.direction_to(b).expect("Cells must be adjacent");
+
+        let a_idx = self.linearize_coord(a);
+        let b_idx = self.linearize_coord(b);
+
+        self.walls[a_idx] -= dir;
+        self.walls[b_idx] -= dir.opposite();
+    }
+
+    /// Adds the wall between two adjacent cells; the inverse of [`remove_wall_between`](Self::remove_wall_between).
+    pub fn add_wall_between(&mut self, a: GridCoord3D, b: GridCoord3D) {
+        let dir = a.direction_to(b).expect("Cells must be adjacent");
+
+        let a_idx = self.linearize_coord(a);
+        let b_idx = self.linearize_coord(b);
+
+        self.walls[a_idx] += dir;
+        self.walls[b_idx] += dir.opposite();
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the number of layers.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns all cells, layer by layer from the bottom.
+    pub fn coords(&self) -> impl Iterator<Item = GridCoord3D> + '_ {
+        (0..self.depth).flat_map(move |z| self.layer_coords(z))
+    }
+
+    /// Returns the cells of a single layer in row-major order.
+    pub fn layer_coords(&self, z: usize) -> impl Iterator<Item = GridCoord3D> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| GridCoord3D::new(x, y, z)))
+    }
+
+    pub fn neighbors(&self, cell: GridCoord3D) -> impl Iterator<Item = GridCoord3D> + '_ {
+        Direction3D::CARDINALS
+            .into_iter()
+            .filter_map(move |dir| cell.try_neighbor(dir, self.width, self.height, self.depth))
+    }
+
+    pub fn open_neighbors(&self, cell: GridCoord3D) -> impl Iterator<Item = GridCoord3D> + '_ {
+        let walls = self.get(cell).copied().unwrap_or(Wall3D::ALL);
+        Direction3D::CARDINALS
+            .into_iter()
+            .filter(move |&dir| !walls.contains(dir))
+            .filter_map(move |dir| cell.try_neighbor(dir, self.width, self.height, self.depth))
+    }
+
+    pub fn stats(&self) -> crate::stats::MazeStats {
+        crate::stats::MazeStats::from_grid(self)
+    }
+
+    #[inline]
+    fn linearize_coord(&self, coord: GridCoord3D) -> usize {
+        debug_assert!(
+            coord.x < self.width && coord.y < self.height && coord.z < self.depth,
+            "GridCoord3D out of bounds"
+        );
+        (coord.z * self.height + coord.y) * self.width + coord.x
+    }
+}
+
+impl Index<GridCoord3D> for Wall3DGrid {
+    type Output = Wall3D;
+
+    fn index(&self, index: GridCoord3D) -> &Self::Output {
+        let index = self.linearize_coord(index);
+        &self.walls[index]
+    }
+}
+
+impl IndexMut<GridCoord3D> for Wall3DGrid {
+    fn index_mut(&mut self, index: GridCoord3D) -> &mut Self::Output {
+        let index = self.linearize_coord(index);
+        &mut self.walls[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_grid_has_all_walls() {
+        let grid = Wall3DGrid::new(3, 2, 4);
+        assert_eq!(grid.coords().count(), 24);
+        for coord in grid.coords() {
+            assert!(grid[coord].is_all());
+        }
+    }
+
+    #[test]
+    fn remove_wall_between_layers() {
+        let mut grid = Wall3DGrid::new(2, 2, 2);
+        let lower = GridCoord3D::new(1, 0, 0);
+        let upper = GridCoord3D::new(1, 0, 1);
+        grid.remove_wall_between(lower, upper);
+        assert_eq!(grid[lower], Wall3D::ALL - Wall3D::UP);
+        assert_eq!(grid[upper], Wall3D::ALL - Wall3D::DOWN);
+        assert_eq!(grid.open_neighbors(upper).collect::<Vec<_>>(), vec![lower]);
+
+        grid.add_wall_between(upper, lower);
+        assert!(grid[lower].is_all());
+        assert!(grid[upper].is_all());
+    }
+
+    #[test]
+    fn neighbors_stays_in_bounds() {
+        let grid = Wall3DGrid::new(3, 3, 3);
+        assert_eq!(grid.neighbors(GridCoord3D::new(0, 0, 0)).count(), 3);
+        assert_eq!(grid.neighbors(GridCoord3D::new(1, 1, 1)).count(), 6);
+        assert_eq!(grid.neighbors(GridCoord3D::new(1, 1, 2)).count(), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "adjacent")]
+    fn remove_wall_between_non_adjacent_panics() {
+        let mut grid = Wall3DGrid::new(3, 3, 3);
+        grid.remove_wall_between(GridCoord3D::new(0, 0, 0), GridCoord3D::new(0, 0, 2));
+    }
+
+    #[test]
+    fn get_out_of_bounds_returns_none() {
+        let grid = Wall3DGrid::new(2, 2, 2);
+        assert!(grid.get(GridCoord3D::new(0, 0, 2)).is_none());
+        assert!(grid.get(GridCoord3D::new(1, 1, 1)).is_some());
+    }
+}
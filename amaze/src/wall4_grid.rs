@@ -1,9 +1,10 @@
 use crate::direction4::Direction4;
 use crate::grid_coord_2d::{GetCoordinateBounds2D, GridCoord2D, LinearizeCoords2D};
+use crate::grid_mask::GridMask;
 use crate::room4::Wall4;
 use crate::room4_list::{Room4List, RoomIndex};
-use crate::stats::MazeStats;
-use std::collections::VecDeque;
+use crate::stats::{MazeStats, StatsOptions};
+use crate::wrap_mode::WrapMode;
 use std::ops::{Index, IndexMut};
 
 #[derive(Debug, Default)]
@@ -12,15 +13,91 @@ pub struct Wall4Grid {
     width: usize,
     height: usize,
     walls: Vec<Wall4>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    wrap: WrapMode,
 }
 
 impl Wall4Grid {
     pub fn new(width: usize, height: usize) -> Self {
+        Self::new_wrapped(width, height, WrapMode::None)
+    }
+
+    /// Creates a fully walled grid whose edges are joined according to `wrap`.
+    pub fn new_wrapped(width: usize, height: usize, wrap: WrapMode) -> Self {
         Self {
             width,
             height,
             walls: vec![Wall4::ALL; width * height],
+            wrap,
+        }
+    }
+
+    /// Creates a grid without interior walls; only the outer boundary is walled.
+    pub fn new_open(width: usize, height: usize) -> Self {
+        let mut grid = Self {
+            width,
+            height,
+            walls: vec![Wall4::NONE; width * height],
+            wrap: WrapMode::None,
+        };
+        if width == 0 || height == 0 {
+            return grid;
+        }
+
+        for x in 0..width {
+            grid[GridCoord2D::new(x, 0)] += Wall4::NORTH;
+            grid[GridCoord2D::new(x, height - 1)] += Wall4::SOUTH;
         }
+        for y in 0..height {
+            grid[GridCoord2D::new(0, y)] += Wall4::WEST;
+            grid[GridCoord2D::new(width - 1, y)] += Wall4::EAST;
+        }
+
+        grid
+    }
+
+    /// Returns which edges of the grid are joined.
+    pub fn wrap_mode(&self) -> WrapMode {
+        self.wrap
+    }
+
+    /// Returns the adjacent cell in the given direction, wrapping around joined edges.
+    pub fn neighbor(&self, cell: GridCoord2D, dir: Direction4) -> Option<GridCoord2D> {
+        let (dx, dy) = match dir {
+            Direction4::NORTH => (0, -1),
+            Direction4::EAST => (1, 0),
+            Direction4::SOUTH => (0, 1),
+            Direction4::WEST => (-1, 0),
+            _ => return None,
+        };
+        if cell.x >= self.width || cell.y >= self.height {
+            return None;
+        }
+
+        let x = WrapMode::wrap_axis(
+            cell.x as isize + dx,
+            self.width,
+            self.wrap.wraps_horizontally(),
+        )?;
+        let y = WrapMode::wrap_axis(
+            cell.y as isize + dy,
+            self.height,
+            self.wrap.wraps_vertically(),
+        )?;
+        Some(GridCoord2D::new(x, y))
+    }
+
+    /// Returns the direction leading from `from` to the adjacent cell `to`.
+    pub(crate) fn direction_between(&self, from: GridCoord2D, to: GridCoord2D) -> Direction4 {
+        [
+            Direction4::NORTH,
+            Direction4::EAST,
+            Direction4::SOUTH,
+            Direction4::WEST,
+        ]
+        .into_iter()
+        .find(|&dir| self.neighbor(from, dir) == Some(to))
+        .unwrap_or_else(|| panic!("Cells are not adjacent: {from:?}, {to:?}"))
     }
 
     pub fn get(&self, coords: GridCoord2D) -> Option<&Wall4> {
@@ -38,34 +115,24 @@ impl Wall4Grid {
     }
 
     pub fn remove_wall_between(&mut self, current: GridCoord2D, selected: GridCoord2D) {
-        // Check adjacency
-        let dx = current.x as isize - selected.x as isize;
-        let dy = current.y as isize - selected.y as isize;
-        assert!(
-            (dx.abs() == 1 && dy == 0) || (dy.abs() == 1 && dx == 0),
-            "Cells are not adjacent: {:?}, {:?}",
-            current,
-            selected
-        );
+        let dir = self.direction_between(current, selected);
 
         let cur = self.linearize_coords(current);
         let sel = self.linearize_coords(selected);
 
-        if current.x > selected.x {
-            self.walls[sel] -= Wall4::EAST;
-            self.walls[cur] -= Wall4::WEST;
-        } else if current.x < selected.x {
-            self.walls[sel] -= Wall4::WEST;
-            self.walls[cur] -= Wall4::EAST;
-        }
+        self.walls[cur] -= dir;
+        self.walls[sel] -= dir.opposite();
+    }
 
-        if current.y > selected.y {
-            self.walls[sel] -= Wall4::SOUTH;
-            self.walls[cur] -= Wall4::NORTH;
-        } else if current.y < selected.y {
-            self.walls[sel] -= Wall4::NORTH;
-            self.walls[cur] -= Wall4::SOUTH;
-        }
+    /// Adds the wall between two adjacent cells; the inverse of [`remove_wall_between`](Self::remove_wall_between).
+    pub fn add_wall_between(&mut self, current: GridCoord2D, selected: GridCoord2D) {
+        let dir = self.direction_between(current, selected);
+
+        let cur = self.linearize_coords(current);
+        let sel = self.linearize_coords(selected);
+
+        self.walls[cur] += dir;
+        self.walls[sel] += dir.opposite();
     }
 
     pub fn width(&self) -> usize {
@@ -81,55 +148,27 @@ impl Wall4Grid {
     }
 
     pub fn neighbors(&self, cell: GridCoord2D) -> impl Iterator<Item = GridCoord2D> + '_ {
-        let mut out = [None, None, None, None];
-        let mut n = 0;
-        if let Some(c) = cell.up().filter(|c| c.y < self.height) {
-            out[n] = Some(c);
-            n += 1;
-        }
-        if let Some(c) = cell.right().filter(|c| c.x < self.width) {
-            out[n] = Some(c);
-            n += 1;
-        }
-        if let Some(c) = cell.down().filter(|c| c.y < self.height) {
-            out[n] = Some(c);
-            n += 1;
-        }
-        if let Some(c) = cell.left().filter(|c| c.x < self.width) {
-            out[n] = Some(c);
-        }
-        out.into_iter().flatten()
+        [
+            Direction4::NORTH,
+            Direction4::EAST,
+            Direction4::SOUTH,
+            Direction4::WEST,
+        ]
+        .into_iter()
+        .filter_map(move |dir| self.neighbor(cell, dir))
     }
 
     pub fn open_neighbors(&self, cell: GridCoord2D) -> impl Iterator<Item = GridCoord2D> + '_ {
-        let mut out = [None, None, None, None];
-        let mut n = 0;
-        if let Some(walls) = self.get(cell) {
-            if !walls.contains(Direction4::NORTH) {
-                if let Some(c) = cell.up().filter(|c| c.y < self.height) {
-                    out[n] = Some(c);
-                    n += 1;
-                }
-            }
-            if !walls.contains(Direction4::EAST) {
-                if let Some(c) = cell.right().filter(|c| c.x < self.width) {
-                    out[n] = Some(c);
-                    n += 1;
-                }
-            }
-            if !walls.contains(Direction4::SOUTH) {
-                if let Some(c) = cell.down().filter(|c| c.y < self.height) {
-                    out[n] = Some(c);
-                    n += 1;
-                }
-            }
-            if !walls.contains(Direction4::WEST) {
-                if let Some(c) = cell.left().filter(|c| c.x < self.width) {
-                    out[n] = Some(c);
-                }
-            }
-        }
-        out.into_iter().flatten()
+        let walls = self.get(cell).copied().unwrap_or(Wall4::ALL);
+        [
+            Direction4::NORTH,
+            Direction4::EAST,
+            Direction4::SOUTH,
+            Direction4::WEST,
+        ]
+        .into_iter()
+        .filter(move |&dir| !walls.contains(dir))
+        .filter_map(move |dir| self.neighbor(cell, dir))
     }
 
     pub fn to_room_list<Tag, F>(&self, tag_fn: F) -> Room4List<Tag>
@@ -150,22 +189,22 @@ impl Wall4Grid {
             let doors = !self[cell];
             let room = list.get_mut(idx).expect("new room index must exist");
             if doors.contains(Direction4::NORTH) {
-                if let Some(n) = cell.up().filter(|c| c.y < self.height) {
+                if let Some(n) = self.neighbor(cell, Direction4::NORTH) {
                     room.set_north(index_of_cell[self.linearize_coords(n)]);
                 }
             }
             if doors.contains(Direction4::EAST) {
-                if let Some(e) = cell.right().filter(|c| c.x < self.width) {
+                if let Some(e) = self.neighbor(cell, Direction4::EAST) {
                     room.set_east(index_of_cell[self.linearize_coords(e)]);
                 }
             }
             if doors.contains(Direction4::SOUTH) {
-                if let Some(s) = cell.down().filter(|c| c.y < self.height) {
+                if let Some(s) = self.neighbor(cell, Direction4::SOUTH) {
                     room.set_south(index_of_cell[self.linearize_coords(s)]);
                 }
             }
             if doors.contains(Direction4::WEST) {
-                if let Some(w) = cell.left().filter(|c| c.x < self.width) {
+                if let Some(w) = self.neighbor(cell, Direction4::WEST) {
                     room.set_west(index_of_cell[self.linearize_coords(w)]);
                 }
             }
@@ -178,28 +217,14 @@ impl Wall4Grid {
         MazeStats::from_grid(self)
     }
 
-    pub(crate) fn bfs_distances(&self, start: GridCoord2D) -> Vec<Option<usize>> {
-        let mut dist = vec![None; self.width * self.height];
-        if self.get(start).is_none() {
-            return dist;
-        }
-
-        let mut q = VecDeque::new();
-        dist[self.linearize_coords(start)] = Some(0);
-        q.push_back(start);
-
-        while let Some(cell) = q.pop_front() {
-            let base = dist[self.linearize_coords(cell)].unwrap_or(0);
-            for n in self.open_neighbors(cell) {
-                let idx = self.linearize_coords(n);
-                if dist[idx].is_none() {
-                    dist[idx] = Some(base + 1);
-                    q.push_back(n);
-                }
-            }
-        }
+    /// Computes statistics, choosing how path lengths are measured.
+    pub fn stats_with(&self, options: StatsOptions) -> MazeStats {
+        MazeStats::from_grid_with(self, options)
+    }
 
-        dist
+    /// Computes statistics over the cells enabled by the mask.
+    pub fn stats_masked(&self, mask: &GridMask) -> MazeStats {
+        MazeStats::from_grid_masked(self, mask)
     }
 }
 
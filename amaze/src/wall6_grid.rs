@@ -1,6 +1,6 @@
 use crate::direction6::Direction6;
 use crate::hex_coord::HexCoord;
-use std::collections::VecDeque;
+use crate::wrap_mode::WrapMode;
 use std::ops::{Index, IndexMut};
 
 pub type Wall6 = Direction6;
@@ -11,17 +11,44 @@ pub struct Wall6Grid {
     width: usize,
     height: usize,
     walls: Vec<Wall6>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    wrap: WrapMode,
 }
 
 impl Wall6Grid {
     pub fn new(width: usize, height: usize) -> Self {
+        Self::new_wrapped(width, height, WrapMode::None)
+    }
+
+    /// Creates a fully walled grid whose edges are joined according to `wrap`.
+    pub fn new_wrapped(width: usize, height: usize, wrap: WrapMode) -> Self {
         Self {
             width,
             height,
             walls: vec![Wall6::ALL; width * height],
+            wrap,
         }
     }
 
+    /// Returns which edges of the grid are joined.
+    pub fn wrap_mode(&self) -> WrapMode {
+        self.wrap
+    }
+
+    /// Returns the adjacent cell in the given direction, wrapping around joined edges.
+    #[inline]
+    pub fn neighbor(&self, cell: HexCoord, dir: Direction6) -> Option<HexCoord> {
+        cell.try_neighbor_wrapped(dir, self.width, self.height, self.wrap)
+    }
+
+    /// Returns the direction leading from `a` to the adjacent cell `b`.
+    pub(crate) fn direction_between(&self, a: HexCoord, b: HexCoord) -> Direction6 {
+        Direction6::CARDINALS
+            .into_iter()
+            .find(|&dir| self.neighbor(a, dir) == Some(b))
+            .expect("Cells must be adjacent hex neighbors")
+    }
+
     pub fn get(&self, coord: HexCoord) -> Option<&Wall6> {
         if coord.q < 0
             || coord.q >= self.width as isize
@@ -45,9 +72,7 @@ impl Wall6Grid {
     }
 
     pub fn remove_wall_between(&mut self, a: HexCoord, b: HexCoord) {
-        let dq = b.q - a.q;
-        let dr = b.r - a.r;
-        let dir = Direction6::from_delta(dq, dr).expect("Cells must be adjacent hex neighbors");
+        let dir = self.direction_between(a, b);
         let opposite = dir.opposite();
 
         let a_idx = self.linearize_coord(a);
@@ -57,6 +82,18 @@ impl Wall6Grid {
         self.walls[b_idx] -= opposite;
     }
 
+    /// Adds the wall between two adjacent cells; the inverse of [`remove_wall_between`](Self::remove_wall_between).
+    pub fn add_wall_between(&mut self, a: HexCoord, b: HexCoord) {
+        let dir = self.direction_between(a, b);
+        let opposite = dir.opposite();
+
+        let a_idx = self.linearize_coord(a);
+        let b_idx = self.linearize_coord(b);
+
+        self.walls[a_idx] += dir;
+        self.walls[b_idx] += opposite;
+    }
+
     pub fn width(&self) -> usize {
         self.width
     }
@@ -75,7 +112,7 @@ impl Wall6Grid {
         let mut n = 0;
 
         for &dir in &Direction6::CARDINALS {
-            if let Some(neighbor) = cell.try_neighbor(dir, self.width, self.height) {
+            if let Some(neighbor) = self.neighbor(cell, dir) {
                 out[n] = Some(neighbor);
                 n += 1;
             }
@@ -92,7 +129,7 @@ impl Wall6Grid {
             let dirs = *walls;
             for &dir in &Direction6::CARDINALS {
                 if !dirs.contains(dir) {
-                    if let Some(neighbor) = cell.try_neighbor(dir, self.width, self.height) {
+                    if let Some(neighbor) = self.neighbor(cell, dir) {
                         out[n] = Some(neighbor);
                         n += 1;
                     }
@@ -107,28 +144,9 @@ impl Wall6Grid {
         crate::stats::MazeStats::from_grid_hex(self)
     }
 
-    pub(crate) fn bfs_distances(&self, start: HexCoord) -> Vec<Option<usize>> {
-        let mut dist = vec![None; self.width * self.height];
-        if self.get(start).is_none() {
-            return dist;
-        }
-
-        let mut q = VecDeque::new();
-        dist[self.linearize_coord(start)] = Some(0);
-        q.push_back(start);
-
-        while let Some(cell) = q.pop_front() {
-            let base = dist[self.linearize_coord(cell)].unwrap_or(0);
-            for n in self.open_neighbors(cell) {
-                let idx = self.linearize_coord(n);
-                if dist[idx].is_none() {
-                    dist[idx] = Some(base + 1);
-                    q.push_back(n);
-                }
-            }
-        }
-
-        dist
+    /// Computes statistics, choosing how path lengths are measured.
+    pub fn stats_with(&self, options: crate::stats::StatsOptions) -> crate::stats::MazeStats {
+        crate::stats::MazeStats::from_grid_hex_with(self, options)
     }
 
     #[inline]
@@ -159,6 +177,7 @@ impl IndexMut<HexCoord> for Wall6Grid {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::maze_grid::MazeGrid;
 
     #[test]
     fn new_grid_has_all_walls() {
@@ -0,0 +1,321 @@
+use crate::direction4::Direction4;
+use crate::grid_coord_2d::{GridCoord2D, LinearizeCoords2D};
+use crate::room4::Wall4;
+use crate::stats::MazeStats;
+use crate::wall4_grid::Wall4Grid;
+use crate::wrap_mode::WrapMode;
+use std::ops::Index;
+
+/// A [`Wall4Grid`] in which corridors can tunnel under perpendicular straight passages.
+///
+/// A crossing cell carries the *over* passage, which runs straight through it. Its two
+/// remaining walls are where the *under* passage dives beneath it: the cells on either
+/// side of a crossing are connected to each other, not to the crossing itself.
+///
+/// ## Example
+/// ```
+/// use amaze::preamble::{Direction4, GridCoord2D, MazeGrid, WeaveGrid};
+///
+/// let mut grid = WeaveGrid::new(3, 3);
+/// let center = GridCoord2D::new(1, 1);
+/// grid.remove_wall_between(center, GridCoord2D::new(1, 0));
+/// grid.remove_wall_between(center, GridCoord2D::new(1, 2));
+///
+/// let west = GridCoord2D::new(0, 1);
+/// let east = grid.add_tunnel(west, Direction4::EAST);
+/// assert!(grid.is_crossing(center));
+/// assert_eq!(grid.open_neighbors(west).collect::<Vec<_>>(), vec![east]);
+/// ```
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeaveGrid {
+    grid: Wall4Grid,
+    crossings: Vec<bool>,
+}
+
+impl WeaveGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::from(Wall4Grid::new(width, height))
+    }
+
+    /// Creates a fully walled grid whose edges are joined according to `wrap`.
+    pub fn new_wrapped(width: usize, height: usize, wrap: WrapMode) -> Self {
+        Self::from(Wall4Grid::new_wrapped(width, height, wrap))
+    }
+
+    /// Returns the underlying grid; tunnels appear as openings facing a walled crossing.
+    pub fn grid(&self) -> &Wall4Grid {
+        &self.grid
+    }
+
+    pub fn width(&self) -> usize {
+        self.grid.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid.height()
+    }
+
+    pub fn wrap_mode(&self) -> WrapMode {
+        self.grid.wrap_mode()
+    }
+
+    pub fn get(&self, coords: GridCoord2D) -> Option<&Wall4> {
+        self.grid.get(coords)
+    }
+
+    pub fn coords(&self) -> impl Iterator<Item = GridCoord2D> + '_ {
+        self.grid.coords()
+    }
+
+    /// Tests whether a tunnel passes beneath the cell.
+    pub fn is_crossing(&self, cell: GridCoord2D) -> bool {
+        self.grid.get(cell).is_some() && self.crossings[self.grid.linearize_coords(cell)]
+    }
+
+    /// Returns all crossing cells in index order.
+    pub fn crossings(&self) -> impl Iterator<Item = GridCoord2D> + '_ {
+        self.coords().filter(|&cell| self.is_crossing(cell))
+    }
+
+    /// Returns the two directions the tunnel beneath the cell runs in,
+    /// or [`Direction4::NONE`] if the cell is not a crossing.
+    pub fn tunnel_directions(&self, cell: GridCoord2D) -> Direction4 {
+        if self.is_crossing(cell) {
+            self.grid[cell]
+        } else {
+            Direction4::NONE
+        }
+    }
+
+    /// Tests whether a tunnel can be dug from `from` in direction `dir`, passing under the
+    /// adjacent cell. That cell must carry a straight passage perpendicular to `dir`,
+    /// and neither end of the tunnel may be a crossing or already open towards it.
+    pub fn can_tunnel(&self, from: GridCoord2D, dir: Direction4) -> bool {
+        let Some(under) = self.grid.neighbor(from, dir) else {
+            return false;
+        };
+        let Some(to) = self.grid.neighbor(under, dir) else {
+            return false;
+        };
+
+        to != from
+            && !self.is_crossing(from)
+            && !self.is_crossing(under)
+            && !self.is_crossing(to)
+            && self.grid[under] == dir + dir.opposite()
+            && self.grid[from].contains(dir)
+            && self.grid[to].contains(dir.opposite())
+    }
+
+    /// Digs a tunnel from `from` in direction `dir` under the adjacent cell and returns the
+    /// cell on the far side.
+    ///
+    /// ## Panics
+    /// Panics if [`can_tunnel`](Self::can_tunnel) is `false`.
+    pub fn add_tunnel(&mut self, from: GridCoord2D, dir: Direction4) -> GridCoord2D {
+        assert!(
+            self.can_tunnel(from, dir),
+            "Cannot tunnel from {from:?} towards {dir:?}"
+        );
+        let under = self
+            .grid
+            .neighbor(from, dir)
+            .expect("checked by can_tunnel");
+        let to = self
+            .grid
+            .neighbor(under, dir)
+            .expect("checked by can_tunnel");
+
+        let index = self.grid.linearize_coords(under);
+        self.crossings[index] = true;
+        self.grid[from] -= dir;
+        self.grid[to] -= dir.opposite();
+        to
+    }
+
+    /// Returns the direction and crossing of the tunnel connecting `a` and `b`, if any.
+    fn tunnel_between(&self, a: GridCoord2D, b: GridCoord2D) -> Option<(Direction4, GridCoord2D)> {
+        Direction4::CARDINALS.into_iter().find_map(|dir| {
+            let under = self.grid.neighbor(a, dir)?;
+            let tunnel = self.is_crossing(under)
+                && self.tunnel_directions(under).contains(dir)
+                && !self.grid[a].contains(dir)
+                && self.grid.neighbor(under, dir) == Some(b);
+            tunnel.then_some((dir, under))
+        })
+    }
+
+    /// Returns the cells adjacent to `cell`, regardless of walls and tunnels.
+    pub fn neighbors(&self, cell: GridCoord2D) -> impl Iterator<Item = GridCoord2D> + '_ {
+        self.grid.neighbors(cell)
+    }
+
+    /// Returns the cells reachable from `cell`, jumping over crossings when passing through a tunnel.
+    pub fn open_neighbors(&self, cell: GridCoord2D) -> impl Iterator<Item = GridCoord2D> + '_ {
+        let walls = self.grid.get(cell).copied().unwrap_or(Wall4::ALL);
+        Direction4::CARDINALS
+            .into_iter()
+            .filter(move |&dir| !walls.contains(dir))
+            .filter_map(move |dir| {
+                let next = self.grid.neighbor(cell, dir)?;
+                if self.is_crossing(next) && self.grid[next].contains(dir.opposite()) {
+                    self.grid.neighbor(next, dir)
+                } else {
+                    Some(next)
+                }
+            })
+    }
+
+    /// Carves a passage between two adjacent cells, or digs a tunnel between two cells on
+    /// opposite sides of a straight passage.
+    ///
+    /// ## Panics
+    /// Panics if either cell is a crossing, or if the cells are neither adjacent nor
+    /// separated by a cell that can be tunneled under.
+    pub fn remove_wall_between(&mut self, a: GridCoord2D, b: GridCoord2D) {
+        assert!(
+            !self.is_crossing(a) && !self.is_crossing(b),
+            "Cannot carve into a crossing: {a:?}, {b:?}"
+        );
+        if self.grid.neighbors(a).any(|n| n == b) {
+            self.grid.remove_wall_between(a, b);
+            return;
+        }
+
+        let dir = Direction4::CARDINALS
+            .into_iter()
+            .find(|&dir| {
+                self.grid
+                    .neighbor(a, dir)
+                    .and_then(|under| self.grid.neighbor(under, dir))
+                    == Some(b)
+            })
+            .unwrap_or_else(|| panic!("Cells are not adjacent: {a:?}, {b:?}"));
+        self.add_tunnel(a, dir);
+    }
+
+    /// Closes the passage or fills in the tunnel between two cells; the inverse of
+    /// [`remove_wall_between`](Self::remove_wall_between).
+    pub fn add_wall_between(&mut self, a: GridCoord2D, b: GridCoord2D) {
+        if let Some((dir, under)) = self.tunnel_between(a, b) {
+            let index = self.grid.linearize_coords(under);
+            self.crossings[index] = false;
+            self.grid[a] += dir;
+            self.grid[b] += dir.opposite();
+            return;
+        }
+
+        assert!(
+            !self.is_crossing(a) && !self.is_crossing(b),
+            "Cannot wall off a crossing: {a:?}, {b:?}"
+        );
+        self.grid.add_wall_between(a, b);
+    }
+
+    #[cfg(any(
+        feature = "binary-format",
+        feature = "json-format",
+        feature = "file-io"
+    ))]
+    /// Marks or clears a crossing without validating the surrounding walls.
+    pub(crate) fn set_crossing(&mut self, cell: GridCoord2D, crossing: bool) {
+        let index = self.grid.linearize_coords(cell);
+        self.crossings[index] = crossing;
+    }
+
+    #[cfg(any(
+        feature = "binary-format",
+        feature = "json-format",
+        feature = "file-io"
+    ))]
+    pub(crate) fn grid_mut(&mut self) -> &mut Wall4Grid {
+        &mut self.grid
+    }
+
+    pub fn stats(&self) -> MazeStats {
+        MazeStats::from_grid(self)
+    }
+}
+
+impl From<Wall4Grid> for WeaveGrid {
+    fn from(grid: Wall4Grid) -> Self {
+        let crossings = vec![false; grid.width() * grid.height()];
+        Self { grid, crossings }
+    }
+}
+
+impl Index<GridCoord2D> for WeaveGrid {
+    type Output = Wall4;
+
+    fn index(&self, index: GridCoord2D) -> &Self::Output {
+        &self.grid[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maze_grid::MazeGrid;
+
+    /// A 3×3 grid with a vertical passage through the center.
+    fn vertical_passage() -> WeaveGrid {
+        let mut grid = WeaveGrid::new(3, 3);
+        let center = GridCoord2D::new(1, 1);
+        grid.remove_wall_between(center, GridCoord2D::new(1, 0));
+        grid.remove_wall_between(center, GridCoord2D::new(1, 2));
+        grid
+    }
+
+    #[test]
+    fn tunnel_requires_straight_perpendicular_passage() {
+        let grid = vertical_passage();
+        let west = GridCoord2D::new(0, 1);
+        assert!(grid.can_tunnel(west, Direction4::EAST));
+        assert!(!grid.can_tunnel(GridCoord2D::new(1, 0), Direction4::SOUTH));
+        assert!(!grid.can_tunnel(west, Direction4::WEST));
+        assert!(!WeaveGrid::new(3, 3).can_tunnel(west, Direction4::EAST));
+    }
+
+    #[test]
+    fn tunnel_connects_far_sides() {
+        let mut grid = vertical_passage();
+        let center = GridCoord2D::new(1, 1);
+        let west = GridCoord2D::new(0, 1);
+        let east = GridCoord2D::new(2, 1);
+
+        grid.remove_wall_between(west, east);
+        assert!(grid.is_crossing(center));
+        assert_eq!(
+            grid.tunnel_directions(center),
+            Direction4::EAST + Direction4::WEST
+        );
+        assert_eq!(grid.open_neighbors(west).collect::<Vec<_>>(), vec![east]);
+        assert_eq!(grid.open_neighbors(east).collect::<Vec<_>>(), vec![west]);
+        assert_eq!(grid.open_neighbors(center).count(), 2);
+        assert_eq!(grid.crossings().collect::<Vec<_>>(), vec![center]);
+    }
+
+    #[test]
+    fn add_wall_between_fills_tunnel() {
+        let mut grid = vertical_passage();
+        let west = GridCoord2D::new(0, 1);
+        let east = grid.add_tunnel(west, Direction4::EAST);
+
+        grid.add_wall_between(east, west);
+        assert!(!grid.is_crossing(GridCoord2D::new(1, 1)));
+        assert!(grid[west].is_all());
+        assert!(grid[east].is_all());
+    }
+
+    #[test]
+    fn bfs_distances_pass_under_crossing() {
+        let mut grid = vertical_passage();
+        let west = GridCoord2D::new(0, 1);
+        grid.add_tunnel(west, Direction4::EAST);
+
+        let distances = grid.bfs_distances(west);
+        assert_eq!(distances[grid.linearize(GridCoord2D::new(2, 1))], Some(1));
+        assert_eq!(distances[grid.linearize(GridCoord2D::new(1, 1))], None);
+    }
+}
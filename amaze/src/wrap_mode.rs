@@ -0,0 +1,151 @@
+//! Edge wrapping for cylinder and torus mazes.
+//!
+//! A [`WrapMode`] joins opposite edges of a [`Wall4Grid`](crate::preamble::Wall4Grid)
+//! or [`Wall6Grid`](crate::preamble::Wall6Grid), so that cells on one border are
+//! adjacent to the cells on the opposite border.
+
+use std::str::FromStr;
+
+/// Which edges of a grid are joined.
+///
+/// An axis only wraps if the grid has more than two cells along it; with
+/// fewer, the wrapped neighbor would coincide with a regular neighbor or the
+/// cell itself.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WrapMode {
+    /// All borders are walls.
+    #[default]
+    None,
+    /// A horizontal cylinder: the west edge is joined to the east edge.
+    Horizontal,
+    /// A vertical cylinder: the north edge is joined to the south edge.
+    Vertical,
+    /// Both pairs of edges are joined.
+    Torus,
+}
+
+impl WrapMode {
+    /// All wrap modes, in the order of their storage bytes.
+    pub const ALL: [Self; 4] = [Self::None, Self::Horizontal, Self::Vertical, Self::Torus];
+
+    /// Tests whether the west and east edges are joined.
+    #[inline]
+    pub const fn wraps_horizontally(self) -> bool {
+        matches!(self, Self::Horizontal | Self::Torus)
+    }
+
+    /// Tests whether the north and south edges are joined.
+    #[inline]
+    pub const fn wraps_vertically(self) -> bool {
+        matches!(self, Self::Vertical | Self::Torus)
+    }
+
+    /// Returns the name used by the JSON format and the command line.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Horizontal => "horizontal",
+            Self::Vertical => "vertical",
+            Self::Torus => "torus",
+        }
+    }
+
+    #[cfg(feature = "binary-format")]
+    #[inline]
+    pub(crate) fn to_byte(self) -> u8 {
+        self as u8
+    }
+
+    #[cfg(feature = "binary-format")]
+    #[inline]
+    pub(crate) fn from_byte(byte: u8) -> Option<Self> {
+        Self::ALL.get(byte as usize).copied()
+    }
+
+    /// Maps a coordinate that may lie one step outside `0..len` back onto the grid.
+    #[inline]
+    pub(crate) fn wrap_axis(value: isize, len: usize, wraps: bool) -> Option<usize> {
+        if value >= 0 && (value as usize) < len {
+            Some(value as usize)
+        } else if wraps && len > 2 {
+            Some(value.rem_euclid(len as isize) as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of steps between two positions along one axis.
+    #[inline]
+    pub(crate) fn axis_distance(a: usize, b: usize, len: usize, wraps: bool) -> usize {
+        let direct = a.abs_diff(b);
+        if wraps && len > 2 {
+            direct.min(len - direct)
+        } else {
+            direct
+        }
+    }
+}
+
+impl std::fmt::Display for WrapMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for WrapMode {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|mode| mode.as_str() == input.to_lowercase())
+            .ok_or_else(|| {
+                format!(
+                    "Invalid wrap mode '{input}'. Valid modes are: none, horizontal, vertical, torus."
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn axes_wrap_by_mode() {
+        assert!(!WrapMode::None.wraps_horizontally());
+        assert!(!WrapMode::None.wraps_vertically());
+        assert!(WrapMode::Horizontal.wraps_horizontally());
+        assert!(!WrapMode::Horizontal.wraps_vertically());
+        assert!(!WrapMode::Vertical.wraps_horizontally());
+        assert!(WrapMode::Vertical.wraps_vertically());
+        assert!(WrapMode::Torus.wraps_horizontally());
+        assert!(WrapMode::Torus.wraps_vertically());
+    }
+
+    #[test]
+    fn wrap_axis_requires_three_cells() {
+        assert_eq!(WrapMode::wrap_axis(-1, 5, true), Some(4));
+        assert_eq!(WrapMode::wrap_axis(5, 5, true), Some(0));
+        assert_eq!(WrapMode::wrap_axis(5, 5, false), None);
+        assert_eq!(WrapMode::wrap_axis(2, 2, true), None);
+        assert_eq!(WrapMode::wrap_axis(1, 2, true), Some(1));
+    }
+
+    #[test]
+    fn axis_distance_takes_shorter_way() {
+        assert_eq!(WrapMode::axis_distance(0, 9, 10, true), 1);
+        assert_eq!(WrapMode::axis_distance(0, 9, 10, false), 9);
+        assert_eq!(WrapMode::axis_distance(2, 6, 10, true), 4);
+    }
+
+    #[test]
+    fn parses_names() {
+        for mode in WrapMode::ALL {
+            assert_eq!(mode.to_string().parse::<WrapMode>(), Ok(mode));
+        }
+        assert_eq!("Torus".parse::<WrapMode>(), Ok(WrapMode::Torus));
+        assert!("sphere".parse::<WrapMode>().is_err());
+    }
+}
@@ -0,0 +1,26 @@
+//! Helpers shared by the integration tests
+
+use amaze::preamble::MazeGrid;
+
+/// Helper function to verify a maze is a connected spanning tree
+pub fn assert_perfect<G: MazeGrid>(grid: &G) {
+    let cells = grid.coords().count();
+
+    // Every passage is listed by both of its cells.
+    let passages: usize = grid
+        .coords()
+        .map(|cell| grid.open_neighbors(cell).count())
+        .sum();
+    assert_eq!(
+        passages / 2,
+        cells - 1,
+        "maze should be a spanning tree with {} edges",
+        cells - 1
+    );
+
+    let start = grid.coords().next().expect("grid is not empty");
+    assert!(
+        grid.bfs_distances(start).iter().all(Option::is_some),
+        "all cells should be reachable"
+    );
+}
@@ -0,0 +1,158 @@
+//! Integration tests for distance maps and flow fields
+//!
+//! Tests multi-source distances, farthest cells, iso-distance bands and next-step
+//! queries on square, hex, passability and dungeon grids.
+
+#![cfg(feature = "representations")]
+
+use amaze::distance_map::DistanceMap;
+use amaze::generators::RecursiveBacktracker4;
+use amaze::maze_grid::MazeGrid;
+use amaze::preamble::*;
+
+fn c(x: usize, y: usize) -> GridCoord2D {
+    GridCoord2D::new(x, y)
+}
+
+/// A single-source map agrees with `MazeGrid::bfs_distances`.
+#[test]
+fn single_source_matches_bfs_distances() {
+    let maze = RecursiveBacktracker4::new_from_seed(42).generate(12, 9);
+    let map = DistanceMap::new(&maze, c(0, 0));
+    let expected = maze.bfs_distances(c(0, 0));
+
+    for cell in maze.coords() {
+        assert_eq!(map.distance(cell), expected[maze.linearize(cell)]);
+    }
+    assert_eq!(map.len(), maze.cell_count());
+}
+
+/// Each cell is as far as its closest source.
+#[test]
+fn multiple_sources_take_the_nearest() {
+    let grid = Wall4Grid::new_open(9, 1);
+    let map = DistanceMap::from_sources(&grid, [c(0, 0), c(8, 0)]);
+
+    let distances: Vec<_> = (0..9).map(|x| map.distance(c(x, 0)).unwrap()).collect();
+    assert_eq!(distances, [0, 1, 2, 3, 4, 3, 2, 1, 0]);
+    assert_eq!(map.farthest(), Some((c(4, 0), 4)));
+    assert_eq!(map.max_distance(), Some(4));
+}
+
+/// Following next steps from any cell reaches a source along a shortest path.
+#[test]
+fn flow_field_descends_to_a_source() {
+    let maze = RecursiveBacktracker4::new_from_seed(7).generate(15, 15);
+    let sources = [c(0, 0), c(14, 14), c(7, 7)];
+    let map = DistanceMap::from_sources(&maze, sources);
+
+    for cell in maze.coords() {
+        let distance = map.distance(cell).unwrap();
+        match map.next_step(cell) {
+            Some(next) => {
+                assert!(maze.open_neighbors(cell).any(|n| n == next));
+                assert_eq!(map.distance(next), Some(distance - 1));
+            }
+            None => assert!(sources.contains(&cell)),
+        }
+
+        let path = map.path_to_source(cell).unwrap();
+        assert_eq!(path.length, distance + 1);
+        assert!(sources.contains(&path.end().unwrap()));
+    }
+}
+
+/// Bands partition the reachable cells by distance.
+#[test]
+fn bands_group_cells_by_distance() {
+    let grid = Wall4Grid::new_open(4, 4);
+    let map = DistanceMap::new(&grid, c(0, 0));
+
+    assert_eq!(map.band(0), &[c(0, 0)]);
+    assert_eq!(map.band(3).len(), 4);
+    assert!(map.band(7).is_empty());
+
+    let bands: Vec<_> = map.bands(2).collect();
+    assert_eq!(bands.len(), 4);
+    assert_eq!(bands.iter().map(|b| b.len()).sum::<usize>(), 16);
+    for (k, band) in bands.iter().enumerate() {
+        for &cell in *band {
+            assert_eq!(map.distance(cell).unwrap() / 2, k);
+        }
+    }
+
+    let ordered: Vec<_> = map.iter().map(|(_, d)| d).collect();
+    assert!(ordered.windows(2).all(|w| w[0] <= w[1]));
+}
+
+/// Cells behind walls and sources outside the grid are unreachable.
+#[test]
+fn unreachable_cells_have_no_distance() {
+    let grid = Wall4Grid::new(3, 3);
+    let map = DistanceMap::new(&grid, c(1, 1));
+
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.distance(c(0, 0)), None);
+    assert_eq!(map.next_step(c(0, 0)), None);
+    assert_eq!(map.path_to_source(c(0, 0)), None);
+
+    let outside = DistanceMap::new(&grid, c(5, 5));
+    assert!(outside.is_empty());
+    assert_eq!(outside.farthest(), None);
+    assert_eq!(outside.bands(1).count(), 0);
+}
+
+/// Hex grids use their six-connected passages.
+#[test]
+fn hex_grids_use_hex_passages() {
+    let mut grid = Wall6Grid::new(3, 3);
+    for cell in grid.coords().collect::<Vec<_>>() {
+        for n in MazeGrid::neighbors(&grid, cell).collect::<Vec<_>>() {
+            grid.remove_wall_between(cell, n);
+        }
+    }
+    let center = HexCoord::new(1, 1);
+    let map = DistanceMap::new(&grid, center);
+
+    for cell in grid.coords() {
+        assert_eq!(map.distance(cell), Some(center.distance(cell)));
+    }
+}
+
+/// Passability grids are walked between orthogonally adjacent passable tiles.
+#[test]
+fn passability_grids_skip_walls() {
+    let maze = RecursiveBacktracker4::new_from_seed(3).generate(6, 6);
+    let passability = PassabilityGrid::from(&maze);
+    let (sx, sy) = PassabilityGrid::maze_to_passability(c(0, 0));
+    let map = DistanceMap::new(&passability, c(sx, sy));
+
+    let maze_map = DistanceMap::new(&maze, c(0, 0));
+    for cell in maze.coords() {
+        let (px, py) = PassabilityGrid::maze_to_passability(cell);
+        assert_eq!(
+            map.distance(c(px, py)),
+            maze_map.distance(cell).map(|d| 2 * d)
+        );
+    }
+    assert_eq!(map.distance(c(0, 0)), None);
+}
+
+/// Dungeons are walked between floor tiles; walls and empty tiles block.
+#[test]
+fn dungeon_grids_walk_floor_tiles() {
+    let mut dungeon = DungeonGrid::new(5, 3);
+    for x in 0..5 {
+        dungeon.set(c(x, 1), TileType::Floor);
+    }
+    dungeon.set(c(2, 0), TileType::Floor);
+    dungeon.place_walls();
+
+    let map = DistanceMap::from_sources(&dungeon, [c(0, 1), c(0, 0)]);
+
+    assert_eq!(map.len(), 6);
+    assert_eq!(map.distance(c(4, 1)), Some(4));
+    assert_eq!(map.distance(c(2, 0)), Some(3));
+    assert_eq!(map.next_step(c(2, 0)), Some(c(2, 1)));
+    assert_eq!(map.farthest(), Some((c(4, 1), 4)));
+}
@@ -0,0 +1,119 @@
+//! Integration tests for generator fingerprinting
+//!
+//! Tests that the shipped reference profiles match the current generators and that
+//! mazes are attributed to the generator that produced them.
+
+#![cfg(all(
+    feature = "generators",
+    feature = "generator-aldous-broder",
+    feature = "generator-recursive-division"
+))]
+
+use amaze::fingerprint::{
+    FEATURE_NAMES, GeneratorProfile, REFERENCE_PROFILES, REFERENCE_SAMPLES, REFERENCE_SIZE,
+    identify,
+};
+use amaze::generators::{
+    AldousBroder4, BinaryTree4, Eller4, GrowingTree4, HuntAndKill4, Kruskal4, MazeGenerator2D,
+    Prim4, RecursiveBacktracker4, RecursiveDivision4, Sidewinder4, Wilson4,
+};
+use amaze::preamble::Wall4Grid;
+
+/// Generates a square maze with the named generator.
+fn generate(generator: &str, seed: u64, size: usize) -> Wall4Grid {
+    match generator {
+        "recursive-backtracker" => RecursiveBacktracker4::new_from_seed(seed).generate(size, size),
+        "growing-tree" => {
+            <GrowingTree4 as MazeGenerator2D>::new_from_seed(seed).generate(size, size)
+        }
+        "kruskal" => <Kruskal4 as MazeGenerator2D>::new_from_seed(seed).generate(size, size),
+        "eller" => <Eller4 as MazeGenerator2D>::new_from_seed(seed).generate(size, size),
+        "wilson" => <Wilson4 as MazeGenerator2D>::new_from_seed(seed).generate(size, size),
+        "hunt-and-kill" => {
+            <HuntAndKill4 as MazeGenerator2D>::new_from_seed(seed).generate(size, size)
+        }
+        "sidewinder" => <Sidewinder4 as MazeGenerator2D>::new_from_seed(seed).generate(size, size),
+        "binary-tree" => <BinaryTree4 as MazeGenerator2D>::new_from_seed(seed).generate(size, size),
+        "prim" => <Prim4 as MazeGenerator2D>::new_from_seed(seed).generate(size, size),
+        "recursive-division" => RecursiveDivision4::new_from_seed(seed).generate(size, size),
+        "aldous-broder" => AldousBroder4::new_from_seed(seed).generate(size, size),
+        other => panic!("unknown generator {other}"),
+    }
+}
+
+fn measure(generator: &'static str) -> GeneratorProfile {
+    let mazes: Vec<_> = (1..=REFERENCE_SAMPLES)
+        .map(|seed| generate(generator, seed, REFERENCE_SIZE))
+        .collect();
+    GeneratorProfile::from_samples(generator, &mazes)
+}
+
+/// The shipped profiles match the current generators, so their texture has not drifted.
+#[test]
+fn reference_profiles_match_generators() {
+    for reference in REFERENCE_PROFILES {
+        let measured = measure(reference.generator);
+        let pairs = [
+            (reference.mean.features(), measured.mean.features()),
+            (reference.std_dev.features(), measured.std_dev.features()),
+        ];
+        for (expected, actual) in pairs {
+            for (i, name) in FEATURE_NAMES.iter().enumerate() {
+                assert!(
+                    (expected[i] - actual[i]).abs() < 1e-3,
+                    "{}: {name} drifted from {} to {}",
+                    reference.generator,
+                    expected[i],
+                    actual[i]
+                );
+            }
+        }
+    }
+}
+
+/// Generators with a distinctive texture are ranked first.
+#[test]
+fn distinctive_generators_are_identified() {
+    for generator in ["binary-tree", "sidewinder", "eller", "prim"] {
+        for seed in 100..105 {
+            let maze = generate(generator, seed, REFERENCE_SIZE);
+            assert_eq!(identify(&maze)[0].generator, generator, "seed {seed}");
+        }
+    }
+}
+
+/// Generators with similar textures are still ranked close to the top.
+#[test]
+fn similar_generators_rank_high() {
+    for generator in [
+        "recursive-backtracker",
+        "growing-tree",
+        "hunt-and-kill",
+        "kruskal",
+        "wilson",
+        "aldous-broder",
+        "recursive-division",
+    ] {
+        for seed in 200..203 {
+            let maze = generate(generator, seed, REFERENCE_SIZE);
+            let ranking = identify(&maze);
+            assert!(
+                ranking[..3].iter().any(|c| c.generator == generator),
+                "{generator} (seed {seed}) ranked {:?}",
+                ranking[..3].iter().map(|c| c.generator).collect::<Vec<_>>()
+            );
+        }
+    }
+}
+
+/// The ranking is sorted by distance and its probabilities sum to one.
+#[test]
+fn ranking_is_a_distribution() {
+    let maze = generate("wilson", 7, 24);
+    let ranking = identify(&maze);
+
+    assert_eq!(ranking.len(), REFERENCE_PROFILES.len());
+    assert!(ranking.windows(2).all(|w| w[0].distance <= w[1].distance));
+    let total: f64 = ranking.iter().map(|c| c.probability).sum();
+    assert!((total - 1.0).abs() < 1e-9);
+}
@@ -0,0 +1,200 @@
+//! Integration tests for distance heatmaps
+//!
+//! Tests the diameter-end distance maps, gradients, and the heatmaps drawn by the
+//! SVG, PNG and ANSI renderers for square mazes, hex mazes and dungeons.
+
+#![cfg(all(
+    feature = "svg-renderer",
+    feature = "png-renderer",
+    feature = "ansi-renderer"
+))]
+
+use amaze::preamble::*;
+use amaze::renderers::{AnsiHeatmapRenderer, DrawableGrid, Gradient, PngRenderer, SvgRenderer};
+
+/// A 3x2 maze whose only path snakes from (0, 0) east, down, and back west.
+fn snake() -> Wall4Grid {
+    let mut grid = Wall4Grid::new(3, 2);
+    for (a, b) in [
+        ((0, 0), (1, 0)),
+        ((1, 0), (2, 0)),
+        ((2, 0), (2, 1)),
+        ((2, 1), (1, 1)),
+        ((1, 1), (0, 1)),
+    ] {
+        grid.remove_wall_between(GridCoord2D::new(a.0, a.1), GridCoord2D::new(b.0, b.1));
+    }
+    grid
+}
+
+/// A gradient whose colors are easy to tell apart.
+fn red_to_blue() -> Gradient {
+    Gradient::new(vec![(255, 0, 0), (0, 0, 255)])
+}
+
+/// The distances start at an end of the longest path, whichever cell the search
+/// begins from.
+#[test]
+fn diameter_end_is_the_source() {
+    let grid = snake();
+    for start in grid.coords() {
+        let map = DistanceMap::from_diameter_end(&grid, start);
+        assert_eq!(map.max_distance(), Some(5));
+        let source = map.sources()[0];
+        assert!(source == GridCoord2D::new(0, 0) || source == GridCoord2D::new(0, 1));
+    }
+    assert_eq!(MazeStats::from_grid(&grid).longest_path, 5);
+
+    let dungeon = DungeonGrid::new(3, 3);
+    assert!(DistanceMap::from_diameter_end(&dungeon, GridCoord2D::new(1, 1)).is_empty());
+}
+
+/// Gradients interpolate between evenly spaced stops, and parse from presets and
+/// color lists.
+#[test]
+fn gradients_interpolate_and_parse() {
+    let gradient: Gradient = "#000000, #ff0000,#ffffff".parse().unwrap();
+    assert_eq!(gradient.stops().len(), 3);
+    assert_eq!(gradient.sample(-1.0), (0, 0, 0));
+    assert_eq!(gradient.sample(0.25), (128, 0, 0));
+    assert_eq!(gradient.sample(0.5), (255, 0, 0));
+    assert_eq!(gradient.sample(2.0), (255, 255, 255));
+    assert_eq!(gradient.sample_distance(3, 0), (0, 0, 0));
+
+    assert_eq!("viridis".parse::<Gradient>().unwrap(), Gradient::default());
+    assert_eq!("Gray".parse::<Gradient>().unwrap(), Gradient::grayscale());
+    assert!("#12345".parse::<Gradient>().is_err());
+    assert!("plasma".parse::<Gradient>().is_err());
+}
+
+/// Cell outlines tile the drawing: squares for square grids, hexagons for hex grids.
+#[test]
+fn cell_outlines() {
+    let square = Wall4Grid::new(2, 2).cell_outline(GridCoord2D::new(1, 0));
+    assert_eq!(square, vec![(1.0, 0.0), (2.0, 0.0), (2.0, 1.0), (1.0, 1.0)]);
+
+    let grid = Wall6Grid::new(2, 2);
+    let hexagon = grid.cell_outline(HexCoord::new(0, 0));
+    assert_eq!(hexagon.len(), 6);
+    let (cx, cy) = grid.center(HexCoord::new(0, 0));
+    for (x, y) in hexagon {
+        assert!(((x - cx).hypot(y - cy) - 0.5).abs() < 1e-9);
+    }
+}
+
+/// SVG heatmaps color the source with the start of the gradient and the farthest
+/// cell with its end.
+#[test]
+fn svg_heatmap_colors_by_distance() {
+    let grid = snake();
+    let distances = DistanceMap::new(&grid, GridCoord2D::new(0, 0));
+
+    let mut renderer = SvgRenderer::new();
+    renderer.style_mut().set_gradient(red_to_blue());
+    let svg = renderer.render_heatmap(&grid, &distances);
+
+    assert!(svg.contains(r##"fill="#ff0000""##));
+    assert!(svg.contains(r##"fill="#0000ff""##));
+    // Six distances, one path each, plus the walls.
+    assert_eq!(svg.matches("<path").count(), 6 + 1);
+    assert!(!SvgRenderer::new().render(&grid).contains("#ff0000"));
+}
+
+/// PNG heatmaps fill cells with their colors and leave unreachable cells blank.
+#[test]
+fn png_heatmap_fills_cells() {
+    let mut grid = Wall4Grid::new(3, 1);
+    grid.remove_wall_between(GridCoord2D::new(0, 0), GridCoord2D::new(1, 0));
+    let distances = DistanceMap::new(&grid, GridCoord2D::new(0, 0));
+
+    let mut renderer = PngRenderer::new();
+    let style = renderer.style_mut();
+    style.set_cell_size(10.0);
+    style.set_margin(0.0);
+    style.set_colors((0, 0, 0), (255, 255, 255));
+    style.set_gradient(red_to_blue());
+
+    let png = renderer.render_heatmap(&grid, &distances);
+    let mut reader = png::Decoder::new(std::io::Cursor::new(png))
+        .read_info()
+        .unwrap();
+    let mut pixels = vec![0; reader.output_buffer_size().unwrap()];
+    let info = reader.next_frame(&mut pixels).unwrap();
+    let pixel = |x: usize, y: usize| {
+        let i = (y * info.width as usize + x) * 3;
+        (pixels[i], pixels[i + 1], pixels[i + 2])
+    };
+
+    assert_eq!(pixel(5, 5), (255, 0, 0));
+    assert_eq!(pixel(15, 5), (0, 0, 255));
+    assert_eq!(pixel(25, 5), (255, 255, 255));
+}
+
+/// Hex heatmaps are drawn for every reachable hexagon.
+#[test]
+fn hex_heatmaps() {
+    let mut grid = Wall6Grid::new(3, 2);
+    grid.remove_wall_between(HexCoord::new(0, 0), HexCoord::new(1, 0));
+    grid.remove_wall_between(HexCoord::new(1, 0), HexCoord::new(1, 1));
+    let distances = DistanceMap::from_diameter_end(&grid, HexCoord::new(1, 0));
+    assert_eq!(distances.max_distance(), Some(2));
+
+    let mut renderer = SvgRenderer::new();
+    renderer.style_mut().set_gradient(red_to_blue());
+    let svg = renderer.render_heatmap(&grid, &distances);
+    assert!(svg.contains(r##"fill="#ff0000""##));
+    assert!(svg.contains(r##"fill="#800080""##));
+    assert!(svg.contains(r##"fill="#0000ff""##));
+
+    let text = AnsiHeatmapRenderer::new().render_hex(&grid, &distances);
+    let lines: Vec<_> = text.lines().collect();
+    assert_eq!(lines.len(), 2);
+    // The second row is shifted by half a cell.
+    assert!(lines[1].starts_with(" \x1b[48;2;"));
+}
+
+/// ANSI heatmaps show walls between cells, and blend the colors of open passages.
+#[test]
+fn ansi_heatmap_shows_walls_and_passages() {
+    let mut grid = Wall4Grid::new(2, 1);
+    grid.remove_wall_between(GridCoord2D::new(0, 0), GridCoord2D::new(1, 0));
+    let distances = DistanceMap::new(&grid, GridCoord2D::new(0, 0));
+
+    let mut renderer = AnsiHeatmapRenderer::new();
+    renderer.set_colors((1, 1, 1), (255, 255, 255));
+    renderer.set_gradient(Gradient::new(vec![(0, 0, 0), (200, 0, 0)]));
+    let text = renderer.render(&grid, &distances);
+
+    let wall = "\x1b[48;2;1;1;1m  ";
+    let lines: Vec<_> = text.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(
+        lines[0],
+        format!("\x1b[48;2;1;1;1m{}\x1b[0m", " ".repeat(10))
+    );
+    assert_eq!(
+        lines[1],
+        format!("{wall}\x1b[48;2;0;0;0m  \x1b[48;2;100;0;0m  \x1b[48;2;200;0;0m  {wall}\x1b[0m")
+    );
+}
+
+/// Dungeon heatmaps color floors, fill wall tiles, and leave empty tiles uncolored.
+#[test]
+fn ansi_dungeon_heatmap() {
+    let mut dungeon = DungeonGrid::new(3, 3);
+    dungeon.set(GridCoord2D::new(1, 1), TileType::Floor);
+    dungeon.place_walls();
+    let distances = DistanceMap::from_diameter_end(&dungeon, GridCoord2D::new(1, 1));
+
+    let mut renderer = AnsiHeatmapRenderer::new();
+    renderer.set_solid_color((9, 9, 9));
+    renderer.set_gradient(red_to_blue());
+    let text = renderer.render_dungeon(&dungeon, &distances);
+
+    let lines: Vec<_> = text.lines().collect();
+    assert_eq!(lines[0], "  \x1b[48;2;9;9;9m  \x1b[0m  ");
+    assert_eq!(
+        lines[1],
+        "\x1b[48;2;9;9;9m  \x1b[48;2;255;0;0m  \x1b[48;2;9;9;9m  \x1b[0m"
+    );
+}
@@ -0,0 +1,159 @@
+//! Integration tests for hex maze text and image rendering
+//!
+//! Tests the pointy and flat layouts of the ASCII and Unicode hex renderers at
+//! several cell sizes, the wall-bit dump, and PPM/PBM images of hex mazes.
+
+#![cfg(all(
+    feature = "ascii-renderer",
+    feature = "unicode-renderer",
+    feature = "pgm-renderer"
+))]
+
+use amaze::preamble::*;
+use amaze::renderers::{
+    HexAsciiRenderer, HexLayout, HexUnicodeRenderer, ImageFormat, ImageRenderer,
+};
+
+#[cfg(feature = "generator-hex-recursive-backtracker")]
+use amaze::generators::RecursiveBacktracker6;
+#[cfg(feature = "generator-hex-recursive-backtracker")]
+use amaze::renderers::AsciiRenderer;
+
+fn lines(text: &str) -> Vec<&str> {
+    text.lines().collect()
+}
+
+/// Flat hexagons stack cells of the same column and shift each column down by half a
+/// cell; the north-west wall is on top.
+#[test]
+fn flat_layout() {
+    let mut grid = Wall6Grid::new(2, 2);
+    grid.remove_wall_between(HexCoord::new(0, 0), HexCoord::new(0, 1));
+
+    let mut renderer = HexAsciiRenderer::new();
+    renderer.set_layout(HexLayout::Flat);
+    let expected = [
+        r" ___",
+        r"/   \___",
+        r"\   /   \",
+        r"/   \___/",
+        r"\___/   \",
+        r"    \___/",
+    ];
+    assert_eq!(lines(&renderer.render(&grid)), expected);
+
+    renderer.set_cell_size(5);
+    let expected = [
+        r"  _____",
+        r" /     \",
+        r"/       \_____",
+        r"\       /     \",
+        r" \     /       \",
+        r" /     \       /",
+        r"/       \_____/",
+        r"\       /     \",
+        r" \_____/       \",
+        r"       \       /",
+        r"        \_____/",
+    ];
+    assert_eq!(lines(&renderer.render(&grid)), expected);
+}
+
+/// Pointy hexagons match the ASCII renderer at every cell size.
+#[cfg(feature = "generator-hex-recursive-backtracker")]
+#[test]
+fn pointy_matches_ascii_renderer() {
+    let maze = RecursiveBacktracker6::new_from_seed(9).generate(5, 4);
+    for size in [1, 3, 4, 7] {
+        let mut hex = HexAsciiRenderer::new();
+        hex.set_cell_size(size);
+        let mut ascii = AsciiRenderer::new();
+        ascii.set_cell_width(size);
+        assert_eq!(hex.render(&maze), ascii.render_hex(&maze));
+    }
+}
+
+/// Pointy hexagons grow with the cell size.
+#[test]
+fn pointy_layout() {
+    let mut renderer = HexAsciiRenderer::new();
+    renderer.set_cell_size(5);
+    let expected = [
+        r"  / \", r" /   \", r"|     |", r"|     |", r" \   /", r"  \ /",
+    ];
+    assert_eq!(lines(&renderer.render(&Wall6Grid::new(1, 1))), expected);
+}
+
+/// The Unicode renderer draws the same outlines with box-drawing diagonals.
+#[test]
+fn unicode_glyphs() {
+    let mut maze = Wall6Grid::new(4, 3);
+    for (a, b) in [
+        ((0, 0), (1, 0)),
+        ((1, 0), (0, 1)),
+        ((0, 1), (0, 2)),
+        ((2, 1), (3, 1)),
+    ] {
+        maze.remove_wall_between(HexCoord::new(a.0, a.1), HexCoord::new(b.0, b.1));
+    }
+    for layout in [HexLayout::Pointy, HexLayout::Flat] {
+        let mut ascii = HexAsciiRenderer::new();
+        ascii.set_layout(layout);
+        let mut unicode = HexUnicodeRenderer::new();
+        unicode.set_layout(layout);
+
+        let translated: String = unicode
+            .render(&maze)
+            .chars()
+            .map(|c| match c {
+                '╱' => '/',
+                '╲' => '\\',
+                '│' => '|',
+                c => c,
+            })
+            .collect();
+        assert_eq!(translated, ascii.render(&maze));
+    }
+}
+
+/// Each cell prints its wall bits in hexadecimal, and odd rows are indented.
+#[test]
+fn wall_bits() {
+    let mut grid = Wall6Grid::new(2, 2);
+    grid.remove_wall_between(HexCoord::new(0, 0), HexCoord::new(1, 0));
+    let text = HexAsciiRenderer::new().render_wall_bits(&grid);
+    assert_eq!(text, "3E 3D\n 3F 3F\n");
+}
+
+/// Layouts parse from their names.
+#[test]
+fn layout_parsing() {
+    assert_eq!("Flat".parse::<HexLayout>(), Ok(HexLayout::Flat));
+    assert_eq!("pointy".parse::<HexLayout>(), Ok(HexLayout::Pointy));
+    assert!("square".parse::<HexLayout>().is_err());
+    assert_eq!(HexLayout::default(), HexLayout::Pointy);
+}
+
+/// Hex images outline every hexagon, and open walls leave gaps between cells.
+#[test]
+fn hex_images() {
+    let mut grid = Wall6Grid::new(2, 1);
+    let pbm = ImageRenderer::new(ImageFormat::PBM).render_hex(&grid);
+    let pixel = |pbm: &str, x: usize, y: usize| {
+        pbm.lines().nth(2 + y).unwrap().split_whitespace().nth(x) == Some("1")
+    };
+    assert!(pbm.starts_with("P1\n17 9\n"));
+    // The wall between both cells runs down x = 8, the corners at the top do not
+    // reach past the hexagons.
+    assert!((2..=6).all(|y| pixel(&pbm, 8, y)));
+    assert!(!pixel(&pbm, 0, 0));
+    assert!(pixel(&pbm, 4, 0));
+
+    grid.remove_wall_between(HexCoord::new(0, 0), HexCoord::new(1, 0));
+    let pbm = ImageRenderer::new(ImageFormat::PBM).render_hex(&grid);
+    assert!((3..=5).all(|y| !pixel(&pbm, 8, y)));
+    assert!(pixel(&pbm, 0, 4));
+
+    let ppm = ImageRenderer::new(ImageFormat::PPM).render_hex(&Wall6Grid::new(3, 2));
+    assert!(ppm.starts_with("P3\n29 15\n255\n"));
+}
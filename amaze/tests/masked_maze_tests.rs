@@ -0,0 +1,172 @@
+//! Integration tests for mask-aware maze generation
+//!
+//! Tests that masked generators leave masked cells fully walled and carve
+//! a spanning tree in every region of enabled cells.
+
+#![cfg(feature = "representations")]
+
+use amaze::generators::{
+    Braid4, BraidStrategy, GrowingTree4, Kruskal4, MaskedMazeGenerator2D, MazeGenerator2D, Prim4,
+    RecursiveBacktracker4, Wilson4,
+};
+use amaze::grid_mask::GridMask;
+use amaze::preamble::Wall4Grid;
+
+#[cfg(any(feature = "solvers", feature = "pgm-renderer"))]
+use amaze::preamble::GridCoord2D;
+#[cfg(feature = "solvers")]
+use amaze::solvers::{BfsSolver, MazeSolver};
+
+/// A ring with a separate island in its center.
+const RING: &str = "\
+.#######.
+##.....##
+#..###..#
+#..#.#..#
+#..###..#
+##.....##
+.#######.";
+
+fn generators() -> Vec<Box<dyn MaskedMazeGenerator2D>> {
+    vec![
+        Box::new(RecursiveBacktracker4::new_from_seed(42)),
+        Box::new(Kruskal4::new_from_seed(42)),
+        Box::new(Wilson4::new_from_seed(42)),
+        Box::new(Prim4::new_from_seed(42)),
+        Box::new(GrowingTree4::<amaze::generators::NewestCell>::new_from_seed(42)),
+    ]
+}
+
+/// Helper function to verify every region of the mask is a spanning tree
+fn assert_masked_forest(grid: &Wall4Grid, mask: &GridMask) {
+    for cell in grid.coords() {
+        if !mask.is_enabled(cell) {
+            assert!(grid[cell].is_all(), "{cell:?} should be walled");
+        }
+    }
+
+    let regions = mask.regions();
+    let stats = grid.stats_masked(mask);
+    assert_eq!(stats.loops, 0, "Masked maze should be perfect");
+
+    let edges: usize = mask
+        .enabled_cells()
+        .map(|c| grid.open_neighbors(c).count())
+        .sum::<usize>()
+        / 2;
+    assert_eq!(edges, mask.enabled_count() - regions.len());
+}
+
+#[test]
+fn masked_generators_carve_inside_mask() {
+    let mask = GridMask::from_ascii(RING);
+    assert_eq!(mask.regions().len(), 2);
+
+    for generator in generators() {
+        let grid = generator.generate_masked(&mask);
+        assert_eq!(grid.width(), mask.width());
+        assert_eq!(grid.height(), mask.height());
+        assert_masked_forest(&grid, &mask);
+    }
+}
+
+#[test]
+fn masked_steps_never_visit_masked_cells() {
+    let mask = GridMask::from_ascii(RING);
+    for generator in generators() {
+        for step in generator.generate_masked_steps(&mask) {
+            if let amaze::generators::GenerationStep::Carve { from, to } = step {
+                assert!(mask.is_enabled(from) && mask.is_enabled(to));
+            }
+        }
+    }
+}
+
+#[test]
+fn full_mask_matches_unmasked_generation() {
+    let mask = GridMask::new(12, 9);
+    let a = Wilson4::new_from_seed(7).generate(12, 9);
+    let b = Wilson4::new_from_seed(7).generate_masked(&mask);
+    for cell in a.coords() {
+        assert_eq!(a[cell], b[cell]);
+    }
+}
+
+#[test]
+fn masked_stats_ignore_masked_cells() {
+    let mask = GridMask::from_ascii(RING);
+    let grid = Kruskal4::new_from_seed(3).generate_masked(&mask);
+
+    let stats = grid.stats_masked(&mask);
+    let dead_ends = mask
+        .enabled_cells()
+        .filter(|&c| grid.open_neighbors(c).count() == 1)
+        .count();
+    assert_eq!(stats.dead_ends, dead_ends);
+    assert!(grid.stats().dead_ends > stats.dead_ends);
+}
+
+#[test]
+fn braiding_keeps_masked_cells_walled() {
+    let mask = GridMask::from_ascii(RING);
+    for strategy in [
+        BraidStrategy::Random,
+        BraidStrategy::PreferDeadEnd,
+        BraidStrategy::LongestLoop,
+    ] {
+        for generator in generators() {
+            let mut grid = generator.generate_masked(&mask);
+            Braid4::new_from_seed(13)
+                .with_ratio(1.0)
+                .with_strategy(strategy)
+                .braid(&mut grid);
+
+            for cell in grid.coords() {
+                if !mask.is_enabled(cell) {
+                    assert!(grid[cell].is_all(), "{cell:?} should be walled");
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "solvers")]
+#[test]
+fn solvers_route_around_masked_cells() {
+    let mask = GridMask::from_ascii(RING);
+    let grid = RecursiveBacktracker4::new_from_seed(9).generate_masked(&mask);
+
+    let start = GridCoord2D::new(1, 0);
+    let end = GridCoord2D::new(7, 6);
+    let path = BfsSolver
+        .solve(&grid, start, end)
+        .expect("ring is connected");
+    assert!(path.cells().iter().all(|&c| mask.is_enabled(c)));
+
+    // The island is a separate region and cannot be reached from the ring.
+    assert!(
+        BfsSolver
+            .solve(&grid, start, GridCoord2D::new(3, 2))
+            .is_none()
+    );
+    assert!(
+        BfsSolver
+            .solve(&grid, start, GridCoord2D::new(0, 0))
+            .is_none()
+    );
+}
+
+#[cfg(feature = "pgm-renderer")]
+#[test]
+fn mask_loads_from_rendered_pbm() {
+    use amaze::renderers::{ImageFormat, ImageRenderer};
+
+    let grid = Prim4::new_from_seed(5).generate(4, 3);
+    let pbm = ImageRenderer::new(ImageFormat::PBM).render(&grid);
+    let mask = GridMask::from_pbm(&pbm).expect("valid PBM");
+
+    assert_eq!(mask.width(), 9);
+    assert_eq!(mask.height(), 7);
+    assert!(mask.is_enabled(GridCoord2D::new(0, 0)));
+    assert!(!mask.is_enabled(GridCoord2D::new(1, 1)));
+}
@@ -0,0 +1,158 @@
+//! Integration tests for 3D mazes
+//!
+//! Tests that the 3D generators produce perfect mazes spanning all layers, that
+//! the solvers climb stairs between layers, and that the mazes can be rendered
+//! and stored.
+
+#![cfg(feature = "generators-3d")]
+
+mod common;
+
+use amaze::generators::{
+    GenerationStep3D, GrowingTree3D, Kruskal3D, MazeGenerator3D, RecursiveBacktracker3D,
+};
+use amaze::preamble::Direction3D;
+use common::assert_perfect;
+
+fn generators(seed: u64) -> Vec<Box<dyn MazeGenerator3D>> {
+    vec![
+        Box::new(RecursiveBacktracker3D::new_from_seed(seed)),
+        Box::new(<GrowingTree3D>::new_from_seed(seed)),
+        Box::new(Kruskal3D::new_from_seed(seed)),
+    ]
+}
+
+#[test]
+fn generators_produce_perfect_mazes() {
+    for generator in generators(42) {
+        for (width, height, depth) in [(1, 1, 1), (1, 1, 4), (3, 2, 2), (6, 5, 4)] {
+            let grid = generator.generate(width, height, depth);
+            assert_eq!(grid.width(), width, "{}", generator.name());
+            assert_eq!(grid.height(), height, "{}", generator.name());
+            assert_eq!(grid.depth(), depth, "{}", generator.name());
+            assert_perfect(&grid);
+        }
+    }
+}
+
+#[test]
+fn generators_connect_layers() {
+    for generator in generators(7) {
+        let grid = generator.generate(5, 5, 3);
+        let stairs = grid
+            .coords()
+            .filter(|&c| !grid[c].contains(Direction3D::UP))
+            .count();
+        assert!(stairs > 0, "{} never climbs", generator.name());
+        assert!(
+            grid.layer_coords(2)
+                .all(|c| grid[c].contains(Direction3D::UP)),
+            "{} opens the top layer's ceiling",
+            generator.name()
+        );
+    }
+}
+
+#[test]
+fn generation_steps_carve_every_cell() {
+    for generator in generators(3) {
+        let steps: Vec<_> = generator.generate_steps(4, 3, 2).collect();
+        let carves = steps
+            .iter()
+            .filter(|step| matches!(step, GenerationStep3D::Carve { .. }))
+            .count();
+        assert_eq!(carves, 4 * 3 * 2 - 1, "{}", generator.name());
+        assert_eq!(steps.last(), Some(&GenerationStep3D::Complete));
+    }
+}
+
+#[test]
+fn empty_grids_complete_immediately() {
+    for generator in generators(1) {
+        let steps: Vec<_> = generator.generate_steps(4, 4, 0).collect();
+        assert_eq!(steps, vec![GenerationStep3D::Complete]);
+    }
+}
+
+#[cfg(feature = "solvers")]
+#[test]
+fn solvers_climb_between_layers() {
+    use amaze::preamble::{GridCoord3D, MazeGrid};
+    use amaze::solvers::{AStarSolver, BfsSolver, DeadEndFillingSolver, DfsSolver, MazeSolver3D};
+
+    let grid = Kruskal3D::new_from_seed(11).generate(5, 4, 3);
+    let start = GridCoord3D::new(0, 0, 0);
+    let end = GridCoord3D::new(4, 3, 2);
+
+    let solvers: Vec<Box<dyn MazeSolver3D>> = vec![
+        Box::new(BfsSolver),
+        Box::new(DfsSolver),
+        Box::new(AStarSolver),
+        Box::new(DeadEndFillingSolver),
+    ];
+    let expected = grid.bfs_distances(start)[grid.linearize(end)].expect("reachable");
+    for solver in solvers {
+        let path = solver.solve(&grid, start, end).expect("path exists");
+        assert_eq!(path.start(), Some(start));
+        assert_eq!(path.end(), Some(end));
+        // A perfect maze has exactly one path.
+        assert_eq!(path.cells().len() - 1, expected);
+        for pair in path.cells().windows(2) {
+            assert!(grid.open_neighbors(pair[0]).any(|n| n == pair[1]));
+        }
+    }
+}
+
+#[cfg(feature = "unicode-renderer")]
+#[test]
+fn unicode_renderer_marks_stairs() {
+    use amaze::preamble::{GridCoord3D, Wall3DGrid};
+    use amaze::renderers::{UnicodeRenderStyle, UnicodeRenderer};
+
+    let mut grid = Wall3DGrid::new(2, 2, 2);
+    grid.remove_wall_between(GridCoord3D::new(0, 0, 0), GridCoord3D::new(0, 1, 0));
+    grid.remove_wall_between(GridCoord3D::new(0, 1, 0), GridCoord3D::new(0, 1, 1));
+    grid.remove_wall_between(GridCoord3D::new(0, 1, 1), GridCoord3D::new(1, 1, 1));
+
+    let heavy = UnicodeRenderer::new(UnicodeRenderStyle::Heavy, true);
+    assert_eq!(heavy.render_3d(&grid), "╻   \n╹↑  \n\n    \n╺↓╸ \n");
+
+    let hex = UnicodeRenderer::new(UnicodeRenderStyle::Hexadecimal, false);
+    assert_eq!(hex.render_3d(&grid), "0200110000002408");
+
+    let generated = Kruskal3D::new_from_seed(5).generate(4, 3, 3);
+    let rendered = heavy.render_3d(&generated);
+    assert_eq!(rendered.split("\n\n").count(), 3);
+    assert!(rendered.contains('↑') && rendered.contains('↓'));
+}
+
+#[cfg(feature = "binary-format")]
+#[test]
+fn binary_format_roundtrips_layers() {
+    use amaze::preamble::Wall3DGrid;
+    use amaze::storage::{FromBinary, ToBinary};
+
+    let maze = RecursiveBacktracker3D::new_from_seed(9).generate(5, 4, 3);
+    let bytes = maze.to_binary().expect("serializes");
+    assert_eq!(bytes[5], 4);
+    assert_eq!(bytes.len(), 13 + 5 * 4 * 3);
+
+    let restored = Wall3DGrid::from_binary(&bytes).expect("deserializes");
+    assert_eq!(restored.depth(), 3);
+    assert!(maze.coords().all(|c| maze[c] == restored[c]));
+    assert!(Wall3DGrid::from_binary(&bytes[..bytes.len() - 1]).is_err());
+}
+
+#[cfg(feature = "json-format")]
+#[test]
+fn json_format_roundtrips_layers() {
+    use amaze::preamble::Wall3DGrid;
+    use amaze::storage::{FromJson, ToJson};
+
+    let maze = <GrowingTree3D>::new_from_seed(9).generate(3, 3, 4);
+    let json = maze.to_json().expect("serializes");
+    assert!(json.contains(r#""type":"3d""#));
+    let restored = Wall3DGrid::from_json(&json).expect("deserializes");
+    assert_eq!(restored.depth(), 4);
+    assert!(maze.coords().all(|c| maze[c] == restored[c]));
+}
@@ -9,7 +9,12 @@ use amaze::generators::{
     BinaryTree4, Eller4, GrowingTree4, HuntAndKill4, Kruskal4, MazeGenerator2D, Prim4,
     RecursiveBacktracker4, Sidewinder4, Wilson4,
 };
-use amaze::preamble::{GridCoord2D, Wall4Grid};
+use amaze::preamble::{Direction4, GridCoord2D, Wall4Grid};
+
+#[cfg(feature = "generator-aldous-broder")]
+use amaze::generators::AldousBroder4;
+#[cfg(feature = "generator-recursive-division")]
+use amaze::generators::RecursiveDivision4;
 
 #[cfg(feature = "solvers")]
 use amaze::solvers::{BfsSolver, MazeSolver};
@@ -17,7 +22,9 @@ use amaze::solvers::{BfsSolver, MazeSolver};
 #[cfg(feature = "generators-hex")]
 use amaze::direction6::Direction6;
 #[cfg(feature = "generators-hex")]
-use amaze::generators::{AldousBroder6, GrowingTree6, MazeGenerator6D, RecursiveBacktracker6};
+use amaze::generators::{
+    AldousBroder6, GrowingTree6, Kruskal6, MazeGenerator6D, Prim6, RecursiveBacktracker6, Wilson6,
+};
 #[cfg(feature = "generators-hex")]
 use amaze::preamble::{HexCoord, Wall6Grid};
 
@@ -86,12 +93,80 @@ fn all_generators_produce_spanning_trees() {
     grids.push(<Sidewinder4 as MazeGenerator2D>::new_from_seed(42).generate(size.0, size.1));
     grids.push(<BinaryTree4 as MazeGenerator2D>::new_from_seed(42).generate(size.0, size.1));
     grids.push(<Prim4 as MazeGenerator2D>::new_from_seed(42).generate(size.0, size.1));
+    #[cfg(feature = "generator-recursive-division")]
+    grids.push(RecursiveDivision4::new_from_seed(42).generate(size.0, size.1));
+    #[cfg(feature = "generator-aldous-broder")]
+    grids.push(AldousBroder4::new_from_seed(42).generate(size.0, size.1));
 
     for grid in grids {
         assert_connected_tree(&grid);
     }
 }
 
+/// Test that step streams only run the generator as far as the consumed steps
+#[test]
+fn generation_steps_are_lazy() {
+    // Generating this maze eagerly would take far longer than the test.
+    let mut steps = RecursiveBacktracker4::new_from_seed(9).generate_steps(2000, 2000);
+    let first: Vec<_> = steps.by_ref().take(4).collect();
+
+    assert_eq!(first.len(), 4);
+    assert!(!steps.is_complete());
+    let start = steps
+        .grid()
+        .get(GridCoord2D::new(0, 0))
+        .expect("coord in bounds");
+    let walls = Direction4::CARDINALS
+        .iter()
+        .filter(|&&d| start.contains(d))
+        .count();
+    assert_eq!(walls, 3, "only one passage leaves the start");
+}
+
+/// Test that finishing a partially consumed step stream yields the generated maze
+#[test]
+fn finished_steps_match_generate() {
+    #[cfg(feature = "generator-recursive-division")]
+    let recursive_division: Option<Box<dyn MazeGenerator2D>> =
+        Some(Box::new(RecursiveDivision4::new_from_seed(42)));
+    #[cfg(not(feature = "generator-recursive-division"))]
+    let recursive_division = None;
+    #[cfg(feature = "generator-aldous-broder")]
+    let aldous_broder: Option<Box<dyn MazeGenerator2D>> =
+        Some(Box::new(AldousBroder4::new_from_seed(42)));
+    #[cfg(not(feature = "generator-aldous-broder"))]
+    let aldous_broder = None;
+
+    let generators: Vec<Box<dyn MazeGenerator2D>> = vec![
+        Box::new(RecursiveBacktracker4::new_from_seed(42)),
+        Box::new(<GrowingTree4 as MazeGenerator2D>::new_from_seed(42)),
+        Box::new(<Kruskal4 as MazeGenerator2D>::new_from_seed(42)),
+        Box::new(<Wilson4 as MazeGenerator2D>::new_from_seed(42)),
+        Box::new(<HuntAndKill4 as MazeGenerator2D>::new_from_seed(42)),
+        Box::new(<Sidewinder4 as MazeGenerator2D>::new_from_seed(42)),
+        Box::new(<BinaryTree4 as MazeGenerator2D>::new_from_seed(42)),
+        Box::new(<Prim4 as MazeGenerator2D>::new_from_seed(42)),
+    ];
+
+    for generator in generators
+        .into_iter()
+        .chain(recursive_division)
+        .chain(aldous_broder)
+    {
+        let expected = generator.generate(10, 8);
+        let mut steps = generator.generate_steps(10, 8);
+        steps.by_ref().take(20).for_each(drop);
+        let grid = steps.finish();
+
+        for y in 0..8 {
+            for x in 0..10 {
+                let coord = GridCoord2D::new(x, y);
+                assert_eq!(grid.get(coord), expected.get(coord), "{}", generator.name());
+            }
+        }
+    }
+}
+
 /// Test that Wall4Grid converts to room list correctly
 #[test]
 fn wall4grid_converts_to_room_list() {
@@ -173,6 +248,24 @@ fn prim_produces_valid_trees() {
     assert_connected_tree(&maze);
 }
 
+/// Test that Aldous-Broder produces spanning trees
+#[cfg(feature = "generator-aldous-broder")]
+#[test]
+fn aldous_broder_produces_valid_trees() {
+    let maze = AldousBroder4::new_from_seed(258).generate(10, 10);
+    assert_connected_tree(&maze);
+}
+
+/// Test that recursive division produces spanning trees
+#[cfg(feature = "generator-recursive-division")]
+#[test]
+fn recursive_division_produces_valid_trees() {
+    for seed in [1, 99, 4711] {
+        let maze = RecursiveDivision4::new_from_seed(seed).generate(11, 7);
+        assert_connected_tree(&maze);
+    }
+}
+
 #[cfg(feature = "generators-hex")]
 fn assert_connected_tree_hex(grid: &Wall6Grid) {
     let total_cells = grid.width() * grid.height();
@@ -236,6 +329,15 @@ fn hex_generators_produce_spanning_trees() {
 
     let grid3 = AldousBroder6::new_from_seed(42).generate(size.0, size.1);
     assert_connected_tree_hex(&grid3);
+
+    let grid4 = Wilson6::new_from_seed(42).generate(size.0, size.1);
+    assert_connected_tree_hex(&grid4);
+
+    let grid5 = Kruskal6::new_from_seed(42).generate(size.0, size.1);
+    assert_connected_tree_hex(&grid5);
+
+    let grid6 = Prim6::new_from_seed(42).generate(size.0, size.1);
+    assert_connected_tree_hex(&grid6);
 }
 
 #[cfg(feature = "generators-hex")]
@@ -260,3 +362,46 @@ fn aldous_broder6_produces_valid_trees() {
     let maze = AldousBroder6::new_from_seed(789).generate(10, 10);
     assert_connected_tree_hex(&maze);
 }
+
+#[cfg(feature = "generators-hex")]
+#[test]
+fn wilson6_produces_valid_trees() {
+    for size in [(1, 7), (8, 5), (12, 12)] {
+        let maze = Wilson6::new_from_seed(147).generate(size.0, size.1);
+        assert_connected_tree_hex(&maze);
+    }
+}
+
+#[cfg(feature = "generators-hex")]
+#[test]
+fn kruskal6_produces_valid_trees() {
+    let maze = Kruskal6::new_from_seed(258).generate(11, 9);
+    assert_connected_tree_hex(&maze);
+}
+
+#[cfg(feature = "generators-hex")]
+#[test]
+fn prim6_produces_valid_trees() {
+    let maze = Prim6::new_from_seed(369).generate(9, 11);
+    assert_connected_tree_hex(&maze);
+}
+
+#[cfg(feature = "generators-hex")]
+#[test]
+fn hex_generator_steps_end_with_complete() {
+    use amaze::generators::HexGenerationStep;
+
+    for steps in [
+        Wilson6::new_from_seed(3).generate_steps(6, 6),
+        Kruskal6::new_from_seed(3).generate_steps(6, 6),
+        Prim6::new_from_seed(3).generate_steps(6, 6),
+    ] {
+        let steps: Vec<_> = steps.collect();
+        let carves = steps
+            .iter()
+            .filter(|s| matches!(s, HexGenerationStep::Carve { .. }))
+            .count();
+        assert_eq!(carves, 35);
+        assert_eq!(steps.last(), Some(&HexGenerationStep::Complete));
+    }
+}
@@ -0,0 +1,222 @@
+//! Integration tests for the topology-generic `MazeGrid` trait
+//!
+//! Tests that solvers, statistics, representations and storage formats work on
+//! the built-in grids and on a custom topology implemented outside the crate.
+
+#![cfg(all(feature = "representations", feature = "solvers"))]
+
+use amaze::maze_grid::MazeGrid;
+use amaze::preamble::MazeStats;
+use amaze::representations;
+use amaze::solvers::{AStarSolver, BfsSolver, DeadEndFillingSolver, DfsSolver, MazeSolver};
+
+const CLOCKWISE: u8 = 0b01;
+const COUNTER_CLOCKWISE: u8 = 0b10;
+
+/// A single ring of cells where the last cell is adjacent to the first.
+struct RingGrid {
+    walls: Vec<u8>,
+}
+
+impl RingGrid {
+    fn new(len: usize) -> Self {
+        Self {
+            walls: vec![CLOCKWISE | COUNTER_CLOCKWISE; len],
+        }
+    }
+
+    fn next(&self, cell: usize) -> usize {
+        (cell + 1) % self.walls.len()
+    }
+
+    fn prev(&self, cell: usize) -> usize {
+        (cell + self.walls.len() - 1) % self.walls.len()
+    }
+
+    fn set_wall(&mut self, a: usize, b: usize, closed: bool) {
+        let (from, to) = if self.next(a) == b { (a, b) } else { (b, a) };
+        assert_eq!(self.next(from), to, "cells are not adjacent");
+        if closed {
+            self.walls[from] |= CLOCKWISE;
+            self.walls[to] |= COUNTER_CLOCKWISE;
+        } else {
+            self.walls[from] &= !CLOCKWISE;
+            self.walls[to] &= !COUNTER_CLOCKWISE;
+        }
+    }
+}
+
+impl MazeGrid for RingGrid {
+    type Coord = usize;
+    type Direction = u8;
+
+    fn width(&self) -> usize {
+        self.walls.len()
+    }
+
+    fn height(&self) -> usize {
+        1
+    }
+
+    fn contains(&self, coord: usize) -> bool {
+        coord < self.walls.len()
+    }
+
+    fn linearize(&self, coord: usize) -> usize {
+        coord
+    }
+
+    fn delinearize(&self, index: usize) -> usize {
+        index
+    }
+
+    fn walls(&self, coord: usize) -> u8 {
+        self.walls[coord]
+    }
+
+    fn neighbors(&self, cell: usize) -> impl Iterator<Item = usize> + '_ {
+        [self.next(cell), self.prev(cell)].into_iter()
+    }
+
+    fn open_neighbors(&self, cell: usize) -> impl Iterator<Item = usize> + '_ {
+        let walls = self.walls[cell];
+        [
+            (walls & CLOCKWISE == 0).then(|| self.next(cell)),
+            (walls & COUNTER_CLOCKWISE == 0).then(|| self.prev(cell)),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    fn remove_wall_between(&mut self, a: usize, b: usize) {
+        self.set_wall(a, b, false);
+    }
+
+    fn add_wall_between(&mut self, a: usize, b: usize) {
+        self.set_wall(a, b, true);
+    }
+}
+
+#[cfg(feature = "binary-format")]
+impl amaze::storage::StorableGrid for RingGrid {
+    const TYPE_ID: u8 = 0x80;
+    const TYPE_NAME: &'static str = "ring";
+    const WALL_BITS: u8 = CLOCKWISE | COUNTER_CLOCKWISE;
+
+    fn new_walled(width: usize, _height: usize) -> Self {
+        RingGrid::new(width)
+    }
+
+    fn cell_bits(&self, coord: usize) -> u8 {
+        self.walls[coord]
+    }
+
+    fn set_cell_bits(&mut self, coord: usize, bits: u8) {
+        self.walls[coord] = bits;
+    }
+}
+
+/// A ring of eight cells with every wall but one removed.
+fn open_ring() -> RingGrid {
+    let mut grid = RingGrid::new(8);
+    for cell in 0..7 {
+        grid.remove_wall_between(cell, cell + 1);
+    }
+    grid
+}
+
+#[test]
+fn solvers_work_on_custom_topology() {
+    let mut grid = open_ring();
+
+    let bfs = BfsSolver.solve(&grid, 1, 6).expect("bfs path");
+    assert_eq!(bfs.cells(), &[1, 2, 3, 4, 5, 6]);
+    let dfs = DfsSolver.solve(&grid, 1, 6).expect("dfs path");
+    assert_eq!(dfs, bfs);
+    let dead_end = DeadEndFillingSolver
+        .solve(&grid, 1, 6)
+        .expect("dead-end path");
+    assert_eq!(dead_end, bfs);
+
+    // Closing the ring lets the solvers take the shortcut across cell 0.
+    grid.remove_wall_between(7, 0);
+    let bfs = BfsSolver.solve(&grid, 1, 6).expect("bfs path");
+    assert_eq!(bfs.cells(), &[1, 0, 7, 6]);
+    let astar = AStarSolver.solve(&grid, 1, 6).expect("astar path");
+    assert_eq!(astar.length, bfs.length);
+
+    assert!(BfsSolver.solve(&grid, 1, 8).is_none());
+}
+
+#[test]
+fn stats_work_on_custom_topology() {
+    let mut grid = open_ring();
+    let stats = MazeStats::from_grid(&grid);
+    assert_eq!(stats.dead_ends, 2);
+    assert_eq!(stats.corridors, 6);
+    assert_eq!(stats.longest_path, 7);
+    assert_eq!(stats.loops, 0);
+
+    grid.remove_wall_between(0, 7);
+    let stats = MazeStats::from_grid(&grid);
+    assert_eq!(stats.dead_ends, 0);
+    assert_eq!(stats.longest_path, 4);
+    assert_eq!(stats.loops, 1);
+}
+
+#[test]
+fn representation_builders_work_on_custom_topology() {
+    let grid = open_ring();
+
+    let adjacency = representations::adjacency(&grid);
+    assert_eq!(adjacency[0], vec![1]);
+    assert_eq!(adjacency[3], vec![4, 2]);
+
+    let edges = representations::open_edges(&grid);
+    assert_eq!(edges.len(), 7);
+    assert!(edges.iter().all(|&(from, to)| from < to));
+
+    let weighted = representations::weighted_adjacency(&grid, |from, to| from + to);
+    assert_eq!(weighted[3], vec![(4, 7), (2, 5)]);
+}
+
+#[cfg(feature = "generator-hex-recursive-backtracker")]
+#[test]
+fn solvers_work_on_hex_grids() {
+    use amaze::generators::RecursiveBacktracker6;
+    use amaze::preamble::{HexCoord, Wall6Grid};
+
+    let grid: Wall6Grid = RecursiveBacktracker6::new_from_seed(17).generate(9, 7);
+    let start = HexCoord::new(0, 0);
+    let end = HexCoord::new(8, 6);
+
+    let bfs = BfsSolver.solve(&grid, start, end).expect("bfs path");
+    let dfs = DfsSolver.solve(&grid, start, end).expect("dfs path");
+    let astar = AStarSolver.solve(&grid, start, end).expect("astar path");
+    let dead_end = DeadEndFillingSolver
+        .solve(&grid, start, end)
+        .expect("dead-end path");
+
+    // A perfect maze has exactly one path between two cells.
+    assert_eq!(bfs, dfs);
+    assert_eq!(bfs, astar);
+    assert_eq!(bfs, dead_end);
+    for pair in bfs.cells().windows(2) {
+        assert!(grid.open_neighbors(pair[0]).any(|n| n == pair[1]));
+    }
+}
+
+#[cfg(feature = "binary-format")]
+#[test]
+fn custom_topology_roundtrips_through_binary_format() {
+    use amaze::preamble::Wall6Grid;
+    use amaze::storage::{FromBinary, ToBinary};
+
+    let grid = open_ring();
+    let bytes = grid.to_binary().expect("serializes");
+    assert_eq!(bytes[5], 0x80);
+
+    let restored = RingGrid::from_binary(&bytes).expect("deserializes");
+    assert_eq!(restored.walls, grid.walls);
+    assert!(Wall6Grid::from_binary(&bytes).is_err());
+}
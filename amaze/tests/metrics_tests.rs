@@ -0,0 +1,152 @@
+//! Integration tests for maze difficulty metrics
+//!
+//! Tests corridor runs, solution turns and branching, directional bias and
+//! dead-end regions on hand-built and generated square, hex and dungeon grids.
+
+use amaze::generators::RecursiveBacktracker4;
+use amaze::preamble::*;
+
+fn c(x: usize, y: usize) -> GridCoord2D {
+    GridCoord2D::new(x, y)
+}
+
+fn carve(grid: &mut Wall4Grid, cells: &[(usize, usize)]) {
+    for pair in cells.windows(2) {
+        grid.remove_wall_between(c(pair[0].0, pair[0].1), c(pair[1].0, pair[1].1));
+    }
+}
+
+/// A straight corridor is one long run solved without turns or decisions.
+#[test]
+fn straight_corridor() {
+    let mut grid = Wall4Grid::new(5, 1);
+    carve(&mut grid, &[(0, 0), (1, 0), (2, 0), (3, 0), (4, 0)]);
+
+    let metrics = MazeMetrics::from_grid(&grid, c(0, 0), c(4, 0));
+
+    assert_eq!(metrics.cells, 5);
+    assert_eq!(metrics.dead_ends, 2);
+    assert_eq!(metrics.loops, 0);
+    assert_eq!(metrics.river_factor, 4.0);
+    assert_eq!(metrics.horizontal_passages, 4);
+    assert_eq!(metrics.vertical_passages, 0);
+    assert_eq!(metrics.horizontal_bias(), 1.0);
+    assert_eq!(metrics.solution_length, Some(4));
+    assert_eq!(metrics.solution_turns, 0);
+    assert_eq!(metrics.solution_straightness, 1.0);
+    assert_eq!(metrics.decision_points, 0);
+    assert_eq!(metrics.branching_factor, 1.0);
+    assert!(metrics.dead_end_subtrees.is_empty());
+}
+
+/// A side branch adds a decision point and a dead-end subtree.
+#[test]
+fn branch_off_the_solution() {
+    let mut grid = Wall4Grid::new(3, 3);
+    carve(&mut grid, &[(0, 0), (1, 0), (2, 0)]);
+    carve(&mut grid, &[(1, 0), (1, 1), (1, 2)]);
+
+    let metrics = MazeMetrics::from_grid(&grid, c(0, 0), c(2, 0));
+
+    assert_eq!(metrics.solution_length, Some(2));
+    assert_eq!(metrics.decision_points, 1);
+    assert_eq!(metrics.branching_factor, 1.5);
+    assert_eq!(metrics.dead_end_subtrees, [2]);
+    assert_eq!(metrics.river_factor, 8.0 / 6.0);
+    assert_eq!(metrics.horizontal_passages, 2);
+    assert_eq!(metrics.vertical_passages, 2);
+    assert_eq!(metrics.loops, 0);
+}
+
+/// Every change of direction along the solution is a turn.
+#[test]
+fn turns_along_the_solution() {
+    let mut grid = Wall4Grid::new(3, 3);
+    carve(&mut grid, &[(0, 0), (1, 0), (1, 1), (2, 1), (2, 2)]);
+
+    let metrics = MazeMetrics::from_grid(&grid, c(0, 0), c(2, 2));
+
+    assert_eq!(metrics.solution_length, Some(4));
+    assert_eq!(metrics.solution_turns, 3);
+    assert_eq!(metrics.solution_straightness, 0.0);
+}
+
+/// Open grids have loops; a ring of corridor cells is a single run.
+#[test]
+fn loops_and_rings() {
+    let open = MazeMetrics::from_grid(&Wall4Grid::new_open(3, 3), c(0, 0), c(2, 2));
+    assert_eq!(open.loops, 4);
+
+    let ring = MazeMetrics::from_grid(&Wall4Grid::new_open(2, 2), c(0, 0), c(1, 1));
+    assert_eq!(ring.loops, 1);
+    assert_eq!(ring.river_factor, 4.0);
+    assert_eq!(ring.dead_ends, 0);
+}
+
+/// In a perfect maze the solution and its dead-end subtrees cover every cell.
+#[test]
+fn dead_end_subtrees_cover_perfect_mazes() {
+    let maze = RecursiveBacktracker4::new_from_seed(31).generate(16, 12);
+    let metrics = MazeMetrics::from_grid(&maze, c(0, 0), c(15, 11));
+
+    let solution_cells = metrics.solution_length.unwrap() + 1;
+    let off_solution: usize = metrics.dead_end_subtrees.iter().sum();
+    assert_eq!(solution_cells + off_solution, metrics.cells);
+    assert!(metrics.dead_end_subtrees.windows(2).all(|w| w[0] >= w[1]));
+    assert_eq!(metrics.loops, 0);
+    assert!(metrics.decision_points <= metrics.dead_end_subtrees.len());
+}
+
+/// Masked cells are left out of the cell, dead-end and component counts.
+#[test]
+fn masked_mazes() {
+    let mask = GridMask::from_ascii("##.\n##.\n...");
+    let mut grid = Wall4Grid::new(3, 3);
+    carve(&mut grid, &[(0, 1), (0, 0), (1, 0), (1, 1)]);
+
+    let metrics = MazeMetrics::from_grid_masked(&grid, &mask, c(0, 1), c(1, 1));
+    assert_eq!(metrics.cells, 4);
+    assert_eq!(metrics.dead_ends, 2);
+    assert_eq!(metrics.loops, 0);
+    assert_eq!(metrics.solution_length, Some(3));
+    assert_eq!(metrics.dead_end_subtrees, Vec::<usize>::new());
+
+    let unmasked = MazeMetrics::from_grid(&grid, c(0, 1), c(1, 1));
+    assert_eq!(unmasked.cells, 9);
+    assert_eq!(unmasked.dead_ends, 7);
+}
+
+/// Hex mazes count east-west passages as horizontal.
+#[test]
+#[cfg(feature = "generator-hex-recursive-backtracker")]
+fn hex_mazes() {
+    let maze = amaze::generators::RecursiveBacktracker6::new_from_seed(9).generate(10, 8);
+    let metrics = MazeMetrics::from_grid(&maze, HexCoord::new(0, 0), HexCoord::new(9, 7));
+
+    assert_eq!(metrics.cells, 80);
+    assert_eq!(metrics.loops, 0);
+    assert_eq!(metrics.horizontal_passages + metrics.vertical_passages, 79);
+    assert!(metrics.solution_length.is_some());
+}
+
+/// Dungeons are measured over their floor tiles.
+#[test]
+fn dungeon_floors() {
+    let mut dungeon = DungeonGrid::new(6, 4);
+    for x in 1..5 {
+        dungeon.set(c(x, 1), TileType::Floor);
+    }
+    dungeon.set(c(4, 2), TileType::Floor);
+    dungeon.set(c(1, 3), TileType::Floor);
+    dungeon.place_walls();
+
+    let metrics = MazeMetrics::from_grid(&dungeon, c(1, 1), c(4, 2));
+    assert_eq!(metrics.cells, 6);
+    assert_eq!(metrics.solution_length, Some(4));
+    assert_eq!(metrics.solution_turns, 1);
+    assert_eq!(metrics.vertical_passages, 1);
+
+    let unreachable = MazeMetrics::from_grid(&dungeon, c(1, 1), c(1, 3));
+    assert_eq!(unreachable.solution_length, None);
+    assert!(unreachable.dead_end_subtrees.is_empty());
+}
@@ -0,0 +1,158 @@
+//! Integration tests for the PNG renderer
+//!
+//! Tests decode the rendered images and check the size and the colors of walls,
+//! paths, dungeon rock, solution and markers.
+
+#![cfg(feature = "png-renderer")]
+
+use amaze::preamble::*;
+use amaze::renderers::{ImageStyle, PngRenderer, StrokeCap};
+
+/// A decoded RGB image.
+struct Image {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    fn decode(png: &[u8]) -> Self {
+        let mut reader = png::Decoder::new(std::io::Cursor::new(png))
+            .read_info()
+            .expect("valid PNG header");
+        let mut pixels = vec![0; reader.output_buffer_size().expect("image fits in memory")];
+        let info = reader.next_frame(&mut pixels).expect("valid PNG data");
+        assert_eq!(info.color_type, png::ColorType::Rgb);
+        pixels.truncate(info.buffer_size());
+        Self {
+            width: info.width,
+            height: info.height,
+            pixels,
+        }
+    }
+
+    fn pixel(&self, x: u32, y: u32) -> (u8, u8, u8) {
+        let i = ((y * self.width + x) * 3) as usize;
+        (self.pixels[i], self.pixels[i + 1], self.pixels[i + 2])
+    }
+}
+
+const WALL: (u8, u8, u8) = (0, 0, 0);
+const BACKGROUND: (u8, u8, u8) = (255, 255, 255);
+
+fn renderer() -> PngRenderer {
+    let mut style = ImageStyle::new();
+    style.set_cell_size(10.0);
+    style.set_stroke_width(2.0);
+    style.set_margin(4.0);
+    style.set_colors(WALL, BACKGROUND);
+
+    let mut renderer = PngRenderer::new();
+    renderer.set_style(style);
+    renderer
+}
+
+/// Closed walls are drawn at the cell boundaries, and open passages are not.
+#[test]
+fn square_walls_and_passages() {
+    let mut grid = Wall4Grid::new(3, 2);
+    grid.remove_wall_between(GridCoord2D::new(0, 0), GridCoord2D::new(1, 0));
+
+    let image = Image::decode(&renderer().render(&grid));
+    assert_eq!((image.width, image.height), (38, 28));
+
+    assert_eq!(image.pixel(0, 0), BACKGROUND);
+    assert_eq!(image.pixel(9, 9), BACKGROUND);
+    // The outer wall at x = 4 and the wall between (1, 0) and (2, 0) at x = 24.
+    assert_eq!(image.pixel(4, 9), WALL);
+    assert_eq!(image.pixel(24, 9), WALL);
+    // The passage between (0, 0) and (1, 0) at x = 14 is open.
+    assert_eq!(image.pixel(14, 9), BACKGROUND);
+}
+
+/// Rendering and writing produce the same bytes.
+#[test]
+fn render_matches_write() {
+    let grid = Wall4Grid::new(4, 4);
+    let mut renderer = renderer();
+    renderer.style_mut().set_stroke_cap(StrokeCap::Square);
+
+    let mut written = Vec::new();
+    renderer.write(&grid, &mut written).unwrap();
+    assert_eq!(written, renderer.render(&grid));
+    assert!(written.starts_with(b"\x89PNG\r\n\x1a\n"));
+}
+
+/// Hex images are sized to the skewed hexagon layout.
+#[test]
+fn hex_mazes() {
+    let grid = Wall6Grid::new(4, 3);
+    let image = Image::decode(&renderer().render(&grid));
+
+    // 4 + 2 / 2 hexagons of sqrt(3) / 2 cells wide, and 1 + 2 * 0.75 cells high.
+    assert_eq!(
+        image.width,
+        (5.0 * 3f64.sqrt() / 2.0 * 10.0 + 8.0).ceil() as u32
+    );
+    assert_eq!(image.height, 33);
+    assert_eq!(image.pixel(0, 0), BACKGROUND);
+    assert!(image.pixels.chunks(3).any(|p| p == [0, 0, 0]));
+}
+
+/// Dungeon wall tiles are filled with the solid color, floors stay clear.
+#[test]
+fn dungeon_rock_is_filled() {
+    let mut dungeon = DungeonGrid::new(3, 3);
+    dungeon.set(GridCoord2D::new(1, 1), TileType::Floor);
+    dungeon.place_walls();
+
+    let mut renderer = renderer();
+    renderer.style_mut().set_solid_color((100, 100, 100));
+    let image = Image::decode(&renderer.render(&dungeon));
+
+    assert_eq!((image.width, image.height), (38, 38));
+    // The centers of the wall tile north of the floor, and of the floor tile.
+    assert_eq!(image.pixel(19, 9), (100, 100, 100));
+    assert_eq!(image.pixel(19, 19), BACKGROUND);
+    // A corner tile is empty rather than rock.
+    assert_eq!(image.pixel(9, 9), BACKGROUND);
+}
+
+/// The solution runs through the cell centers, with markers on both ends.
+#[test]
+fn solution_and_markers() {
+    let mut grid = Wall4Grid::new(3, 1);
+    grid.remove_wall_between(GridCoord2D::new(0, 0), GridCoord2D::new(1, 0));
+    grid.remove_wall_between(GridCoord2D::new(1, 0), GridCoord2D::new(2, 0));
+    let (start, end) = (GridCoord2D::new(0, 0), GridCoord2D::new(2, 0));
+    let solution = Path::new(vec![start, GridCoord2D::new(1, 0), end]);
+
+    let mut renderer = renderer();
+    let style = renderer.style_mut();
+    style.set_solution_color((255, 0, 0));
+    style.set_marker_colors((0, 255, 0), (0, 0, 255));
+    let image = Image::decode(&renderer.render_with(&grid, Some(&solution), Some((start, end))));
+
+    assert_eq!(image.pixel(9, 9), (0, 255, 0));
+    assert_eq!(image.pixel(19, 9), (255, 0, 0));
+    assert_eq!(image.pixel(29, 9), (0, 0, 255));
+    assert_eq!(image.pixel(19, 6), BACKGROUND);
+}
+
+/// Steps across the joined edges of wrapped grids run out to both borders
+/// instead of straight through the maze.
+#[test]
+fn wrapped_solution() {
+    let mut grid = Wall4Grid::new_wrapped(5, 1, WrapMode::Horizontal);
+    let (start, end) = (GridCoord2D::new(0, 0), GridCoord2D::new(4, 0));
+    grid.remove_wall_between(start, end);
+
+    let mut renderer = renderer();
+    renderer.style_mut().set_solution_color((255, 0, 0));
+    let image =
+        Image::decode(&renderer.render_with(&grid, Some(&Path::new(vec![start, end])), None));
+
+    assert_eq!(image.pixel(6, 9), (255, 0, 0));
+    assert_eq!(image.pixel(51, 9), (255, 0, 0));
+    assert_eq!(image.pixel(29, 9), BACKGROUND);
+}
@@ -0,0 +1,101 @@
+//! Integration tests for circular mazes
+//!
+//! Tests that the polar generators produce perfect mazes, that the generic
+//! solvers find paths from the center to the rim, and that the SVG renderer
+//! draws arcs.
+
+#![cfg(feature = "generators-polar")]
+
+mod common;
+
+use amaze::generators::{
+    GrowingTreePolar, MazeGeneratorPolar, PolarGenerationStep, RandomCell,
+    RecursiveBacktrackerPolar, WilsonPolar,
+};
+use amaze::preamble::PolarGrid;
+use common::assert_perfect;
+
+#[test]
+fn polar_generators_produce_perfect_mazes() {
+    let generators: Vec<Box<dyn MazeGeneratorPolar>> = vec![
+        Box::new(RecursiveBacktrackerPolar::new_from_seed(42)),
+        Box::new(WilsonPolar::new_from_seed(42)),
+        Box::new(<GrowingTreePolar>::new_from_seed(42)),
+        Box::new(GrowingTreePolar::new_from_seed_with_selector(
+            42, RandomCell,
+        )),
+    ];
+
+    for generator in generators {
+        for rings in [1, 2, 5, 8] {
+            let grid = generator.generate(rings);
+            assert_eq!(grid.rings(), rings, "{}", generator.name());
+            assert_perfect(&grid);
+        }
+    }
+}
+
+#[test]
+fn polar_generators_are_deterministic() {
+    let a = WilsonPolar::new_from_seed(7).generate(6);
+    let b = WilsonPolar::new_from_seed(7).generate(6);
+    for cell in a.coords() {
+        assert_eq!(a.walls(cell), b.walls(cell));
+    }
+}
+
+#[test]
+fn polar_generation_steps_carve_every_cell() {
+    let grid = PolarGrid::new(5);
+    let steps: Vec<_> = RecursiveBacktrackerPolar::new_from_seed(3)
+        .generate_steps(5)
+        .collect();
+
+    let carves = steps
+        .iter()
+        .filter(|step| matches!(step, PolarGenerationStep::Carve { .. }))
+        .count();
+    assert_eq!(carves, grid.cell_count() - 1);
+    assert_eq!(steps.last(), Some(&PolarGenerationStep::Complete));
+}
+
+#[cfg(feature = "solvers")]
+#[test]
+fn solvers_reach_the_rim() {
+    use amaze::preamble::PolarCoord;
+    use amaze::solvers::{AStarSolver, BfsSolver, MazeSolver};
+
+    let grid = RecursiveBacktrackerPolar::new_from_seed(11).generate(7);
+    let start = PolarCoord::new(0, 0);
+    let end = PolarCoord::new(6, grid.ring_len(6) / 2);
+
+    let bfs = BfsSolver.solve(&grid, start, end).expect("bfs path");
+    let astar = AStarSolver.solve(&grid, start, end).expect("astar path");
+
+    // A perfect maze has exactly one path between two cells.
+    assert_eq!(bfs, astar);
+    assert_eq!(bfs.cells().first(), Some(&start));
+    assert_eq!(bfs.cells().last(), Some(&end));
+    for pair in bfs.cells().windows(2) {
+        assert!(grid.open_neighbors(pair[0]).any(|n| n == pair[1]));
+    }
+}
+
+#[cfg(feature = "polar-renderer")]
+#[test]
+fn svg_renderer_draws_arcs() {
+    use amaze::renderers::PolarSvgRenderer;
+
+    let grid = GrowingTreePolar::new_from_seed_with_selector(5, RandomCell).generate(4);
+    let mut renderer = PolarSvgRenderer::new();
+    renderer.set_cell_size(10.0);
+    let svg = renderer.render(&grid);
+
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert!(svg.contains(" A"), "inward walls should be drawn as arcs");
+    assert!(
+        svg.contains(r#"r="40.00""#),
+        "outer boundary should be a circle"
+    );
+}
@@ -5,8 +5,12 @@
 #![cfg(all(feature = "representations", feature = "solvers"))]
 
 use amaze::generators::RecursiveBacktracker4;
-use amaze::preamble::GridCoord2D;
-use amaze::solvers::{AStarSolver, BfsSolver, DeadEndFillingSolver, DfsSolver, MazeSolver};
+use amaze::preamble::{Direction4, GridCoord2D, Wall4Grid, WeightedAdjacencyList};
+use amaze::solvers::{
+    AStarSolver, AgentSolver, BfsSolver, DeadEndFillingSolver, DfsSolver, DijkstraSolver, Hand,
+    MazeSolver, PledgeSolver, SolveStep, TremauxSolver, WalkOutcome, WallFollowerSolver,
+    WeightedAStarSolver, WeightedMazeSolver,
+};
 
 /// Test that all solvers successfully find paths through a generated maze
 #[test]
@@ -85,3 +89,284 @@ fn dead_end_filling_finds_optimal_path() {
     // Dead-end filling should find optimal path
     assert_eq!(bfs_path.length, dead_end_path.length);
 }
+
+/// Test that agent solvers find the unique path of a perfect maze the long way round
+#[test]
+fn agent_solvers_walk_perfect_mazes() {
+    let maze = RecursiveBacktracker4::new_from_seed(7788).generate(15, 15);
+    let start = GridCoord2D::new(0, 0);
+    let end = GridCoord2D::new(14, 14);
+    let shortest = BfsSolver.solve(&maze, start, end).expect("bfs path");
+
+    let agents: Vec<Box<dyn AgentSolver>> = vec![
+        Box::new(WallFollowerSolver::new(Hand::Left)),
+        Box::new(WallFollowerSolver::new(Hand::Right)),
+        Box::new(TremauxSolver::new()),
+    ];
+    for agent in agents {
+        let walk = agent.walk(&maze, start, end);
+
+        assert_eq!(walk.outcome, WalkOutcome::Reached);
+        assert_eq!(walk.path.as_ref(), Some(&shortest));
+        assert_eq!(walk.trajectory.first(), Some(&start));
+        assert_eq!(walk.trajectory.last(), Some(&end));
+        assert!(walk.steps() >= shortest.length - 1);
+        for pair in walk.trajectory.windows(2) {
+            assert!(maze.open_neighbors(pair[0]).any(|n| n == pair[1]));
+        }
+    }
+
+    assert_eq!(
+        WallFollowerSolver::default().solve(&maze, start, end),
+        Some(shortest)
+    );
+}
+
+/// Test that agent solvers stop instead of circling a loop around an unreachable cell
+#[test]
+fn agent_solvers_detect_endless_walks() {
+    // A ring of eight cells around a walled-off center.
+    let ring = [
+        (0, 0),
+        (1, 0),
+        (2, 0),
+        (2, 1),
+        (2, 2),
+        (1, 2),
+        (0, 2),
+        (0, 1),
+    ];
+    let mut maze = Wall4Grid::new(3, 3);
+    for (i, &(x, y)) in ring.iter().enumerate() {
+        let (nx, ny) = ring[(i + 1) % ring.len()];
+        maze.remove_wall_between(GridCoord2D::new(x, y), GridCoord2D::new(nx, ny));
+    }
+    let start = GridCoord2D::new(0, 0);
+    let center = GridCoord2D::new(1, 1);
+
+    let follower = WallFollowerSolver::new(Hand::Left).walk(&maze, start, center);
+    assert_eq!(follower.outcome, WalkOutcome::Trapped);
+    assert_eq!(follower.path, None);
+
+    let tremaux = TremauxSolver::new().walk(&maze, start, center);
+    assert_eq!(tremaux.outcome, WalkOutcome::Trapped);
+    assert_eq!(tremaux.trajectory.last(), Some(&start));
+
+    let pledge = PledgeSolver::default().walk(&maze, start, center);
+    assert_ne!(pledge.outcome, WalkOutcome::Reached);
+
+    let limited = PledgeSolver::new(Direction4::EAST, Hand::Right)
+        .with_max_steps(5)
+        .walk(&maze, start, center);
+    assert_eq!(limited.outcome, WalkOutcome::StepLimit);
+    assert_eq!(limited.steps(), 5);
+}
+
+/// Test that the Pledge agent escapes through an exit on the boundary
+#[test]
+fn pledge_reaches_boundary_exit() {
+    let maze = RecursiveBacktracker4::new_from_seed(9900).generate(12, 12);
+    let start = GridCoord2D::new(6, 6);
+    let exit = GridCoord2D::new(6, 0);
+
+    let walk = PledgeSolver::new(Direction4::NORTH, Hand::Left).walk(&maze, start, exit);
+
+    assert_eq!(walk.outcome, WalkOutcome::Reached);
+    assert_eq!(walk.path, BfsSolver.solve(&maze, start, exit));
+}
+
+/// Test that every solver's step stream ends with the path it solves for
+#[test]
+fn solve_steps_end_with_solution() {
+    let maze = RecursiveBacktracker4::new_from_seed(1122).generate(12, 12);
+    let start = GridCoord2D::new(0, 0);
+    let end = GridCoord2D::new(11, 11);
+
+    let solvers: Vec<Box<dyn MazeSolver>> = vec![
+        Box::new(BfsSolver),
+        Box::new(DfsSolver),
+        Box::new(AStarSolver),
+        Box::new(DeadEndFillingSolver),
+    ];
+    for solver in solvers {
+        let expected = solver.solve(&maze, start, end).expect("path exists");
+        let steps: Vec<_> = solver.solve_steps(&maze, start, end).collect();
+
+        assert_eq!(steps.last(), Some(&SolveStep::PathFound { path: expected }));
+        let found = steps
+            .iter()
+            .filter(|step| matches!(step, SolveStep::PathFound { .. }))
+            .count();
+        assert_eq!(found, 1);
+    }
+}
+
+/// Test that the step kinds match the search each solver performs
+#[test]
+fn solve_steps_report_search_events() {
+    let maze = RecursiveBacktracker4::new_from_seed(3344).generate(12, 12);
+    let start = GridCoord2D::new(0, 0);
+    let end = GridCoord2D::new(11, 11);
+
+    let bfs: Vec<_> = BfsSolver.solve_steps(&maze, start, end).collect();
+    assert_eq!(bfs[0], SolveStep::Explore { cell: start });
+    assert!(
+        bfs.iter()
+            .any(|s| matches!(s, SolveStep::Enqueue { from, .. } if *from == start))
+    );
+
+    let dfs: Vec<_> = DfsSolver.solve_steps(&maze, start, end).collect();
+    assert!(dfs.iter().any(|s| matches!(s, SolveStep::Backtrack { .. })));
+
+    let filled: Vec<_> = DeadEndFillingSolver
+        .solve_steps(&maze, start, end)
+        .filter_map(|s| match s {
+            SolveStep::FillDeadEnd { cell } => Some(cell),
+            _ => None,
+        })
+        .collect();
+    let path = BfsSolver.solve(&maze, start, end).expect("path exists");
+    // In a perfect maze every cell off the solution is filled in.
+    assert_eq!(filled.len(), 12 * 12 - path.length);
+    assert!(filled.iter().all(|cell| !path.cells().contains(cell)));
+}
+
+/// Test that a visitor receives the same steps as the iterator
+#[test]
+fn solve_visitor_receives_every_step() {
+    let maze = RecursiveBacktracker4::new_from_seed(5566).generate(8, 8);
+    let start = GridCoord2D::new(0, 0);
+    let end = GridCoord2D::new(7, 7);
+
+    let mut visited = Vec::new();
+    let path = AStarSolver
+        .solve_steps(&maze, start, end)
+        .visit(&mut visited);
+    let collected: Vec<_> = AStarSolver.solve_steps(&maze, start, end).collect();
+
+    assert_eq!(visited, collected);
+    assert_eq!(path, AStarSolver.solve(&maze, start, end));
+}
+
+/// Test that every solver finds the single path through a hex maze
+#[test]
+#[cfg(feature = "generator-hex-kruskal")]
+fn hex_solvers_find_paths() {
+    use amaze::generators::Kruskal6;
+    use amaze::preamble::HexCoord;
+    use amaze::solvers::MazeSolver6;
+
+    let maze = Kruskal6::new_from_seed(4321).generate(12, 10);
+    let start = HexCoord::new(0, 0);
+    let end = HexCoord::new(11, 9);
+
+    let solvers: Vec<Box<dyn MazeSolver6>> = vec![
+        Box::new(BfsSolver),
+        Box::new(DfsSolver),
+        Box::new(AStarSolver),
+        Box::new(DeadEndFillingSolver),
+    ];
+    let bfs = BfsSolver.solve(&maze, start, end).expect("bfs path");
+    for solver in solvers {
+        let path = solver.solve(&maze, start, end).expect("hex path");
+        assert_eq!(path, bfs);
+    }
+    assert_eq!(bfs.start(), Some(start));
+    assert_eq!(bfs.end(), Some(end));
+}
+
+/// Test that Dijkstra and weighted A* match BFS when every passage costs one
+#[test]
+fn weighted_solvers_match_bfs_on_unit_weights() {
+    let maze = RecursiveBacktracker4::new_from_seed(2468).generate(16, 16);
+    let graph = WeightedAdjacencyList::from(&maze);
+    let start = GridCoord2D::new(0, 0);
+    let end = GridCoord2D::new(15, 15);
+
+    let bfs = BfsSolver.solve(&maze, start, end).expect("bfs path");
+    let dijkstra = DijkstraSolver
+        .solve(&graph, start, end)
+        .expect("dijkstra path");
+    let astar = WeightedAStarSolver::with_min_cost(1.0)
+        .solve(&graph, start, end)
+        .expect("weighted astar path");
+
+    assert_eq!(dijkstra.path, bfs);
+    assert_eq!(astar.path, bfs);
+    assert_eq!(dijkstra.cost, (bfs.length - 1) as f32);
+    assert_eq!(astar.cost, dijkstra.cost);
+}
+
+/// Test that weighted solvers detour around expensive terrain
+#[test]
+fn weighted_solvers_avoid_expensive_passages() {
+    // A 3x2 loop: the direct route along the top row crosses mud.
+    let mut maze = Wall4Grid::new(3, 2);
+    for (a, b) in [
+        ((0, 0), (1, 0)),
+        ((1, 0), (2, 0)),
+        ((0, 0), (0, 1)),
+        ((0, 1), (1, 1)),
+        ((1, 1), (2, 1)),
+        ((2, 1), (2, 0)),
+    ] {
+        maze.remove_wall_between(GridCoord2D::new(a.0, a.1), GridCoord2D::new(b.0, b.1));
+    }
+    let mud = GridCoord2D::new(1, 0);
+    let graph = WeightedAdjacencyList::from_wall_grid_with(&maze, |from, to| {
+        if from == mud || to == mud { 5.0 } else { 1.0 }
+    });
+    let start = GridCoord2D::new(0, 0);
+    let end = GridCoord2D::new(2, 0);
+
+    let dijkstra = DijkstraSolver
+        .solve(&graph, start, end)
+        .expect("dijkstra path");
+    let astar = WeightedAStarSolver::with_min_cost(1.0)
+        .solve(&graph, start, end)
+        .expect("weighted astar path");
+
+    assert_eq!(dijkstra.cost, 4.0);
+    assert!(!dijkstra.path.cells().contains(&mud));
+    assert_eq!(astar, dijkstra);
+}
+
+/// Test that weighted A* with a custom heuristic finds the same cost as Dijkstra
+#[test]
+#[cfg(feature = "generator-hex-recursive-backtracker")]
+fn weighted_astar_matches_dijkstra_cost_on_hex_grids() {
+    use amaze::generators::{Braid6, RecursiveBacktracker6};
+    use amaze::preamble::{HexCoord, WeightedHexAdjacencyList};
+
+    let mut maze = RecursiveBacktracker6::new_from_seed(1357).generate(9, 9);
+    Braid6::new_from_seed(1357).braid(&mut maze);
+    let graph = WeightedHexAdjacencyList::from_wall_grid_with(&maze, |from, to| {
+        1.0 + ((from.q * 7 + to.r * 3).rem_euclid(5)) as f32
+    });
+    let start = HexCoord::new(0, 0);
+    let end = HexCoord::new(8, 8);
+
+    let dijkstra = DijkstraSolver
+        .solve(&graph, start, end)
+        .expect("dijkstra path");
+    let astar = WeightedAStarSolver::new(|a: HexCoord, b: HexCoord| a.distance(b) as f32)
+        .solve(&graph, start, end)
+        .expect("weighted astar path");
+
+    assert_eq!(astar.cost, dijkstra.cost);
+    assert_eq!(dijkstra.path.start(), Some(start));
+    assert_eq!(dijkstra.path.end(), Some(end));
+}
+
+/// Test that weighted solvers reject cells outside the graph
+#[test]
+fn weighted_solvers_reject_out_of_bounds_cells() {
+    let maze = RecursiveBacktracker4::new_from_seed(1).generate(4, 4);
+    let graph = WeightedAdjacencyList::from(&maze);
+
+    assert!(
+        DijkstraSolver
+            .solve(&graph, GridCoord2D::new(0, 0), GridCoord2D::new(4, 0))
+            .is_none()
+    );
+}
@@ -0,0 +1,99 @@
+//! Integration tests for maze statistics
+//!
+//! Tests that the exact, spanning-tree and sampled path length modes agree
+//! where they should on square and hex mazes.
+
+use amaze::generators::{Braid4, RecursiveBacktracker4};
+use amaze::preamble::{MazeStats, PathLengthMode, StatsOptions};
+
+/// The spanning-tree sum is exact on perfect mazes and used by default.
+#[test]
+fn tree_mode_is_exact_for_perfect_mazes() {
+    let maze = RecursiveBacktracker4::new_from_seed(11).generate(14, 9);
+
+    let exact = maze.stats_with(StatsOptions::exact());
+    let tree = maze.stats_with(StatsOptions::tree());
+    let auto = maze.stats();
+
+    assert!((exact.average_path_length - tree.average_path_length).abs() < 1e-9);
+    assert!((exact.average_path_length - auto.average_path_length).abs() < 1e-9);
+    assert_eq!(auto.average_path_length_margin, None);
+}
+
+/// Hex mazes take the same fast path.
+#[test]
+#[cfg(feature = "generator-hex-recursive-backtracker")]
+fn tree_mode_is_exact_for_perfect_hex_mazes() {
+    let maze = amaze::generators::RecursiveBacktracker6::new_from_seed(5).generate(10, 10);
+
+    let exact = maze.stats_with(StatsOptions::exact());
+    let tree = maze.stats_with(StatsOptions::tree());
+
+    assert!((exact.average_path_length - tree.average_path_length).abs() < 1e-9);
+}
+
+/// Braided mazes have shortcuts that the spanning tree does not take.
+#[test]
+fn tree_mode_is_an_upper_bound_with_loops() {
+    let mut maze = RecursiveBacktracker4::new_from_seed(13).generate(12, 12);
+    Braid4::new_from_seed(2).braid(&mut maze);
+
+    let exact = maze.stats_with(StatsOptions::exact());
+    let tree = maze.stats_with(StatsOptions::tree());
+
+    assert!(exact.loops > 0);
+    assert!(tree.average_path_length > exact.average_path_length);
+}
+
+/// Sampling estimates the exact average within a reasonable margin.
+#[test]
+fn sampled_mode_estimates_the_average() {
+    let mut maze = RecursiveBacktracker4::new_from_seed(17).generate(24, 24);
+    Braid4::new_from_seed(3).braid(&mut maze);
+
+    let exact = maze.stats_with(StatsOptions::exact());
+    let sampled = maze.stats_with(StatsOptions::sampled(64, 99));
+    let margin = sampled.average_path_length_margin.unwrap();
+
+    assert!(margin > 0.0);
+    assert!((sampled.average_path_length - exact.average_path_length).abs() < 2.0 * margin);
+    assert_eq!(sampled.loops, exact.loops);
+    assert_eq!(sampled.dead_ends, exact.dead_ends);
+}
+
+/// Sampling every cell is the exact computation.
+#[test]
+fn sampling_all_cells_is_exact() {
+    let mut maze = RecursiveBacktracker4::new_from_seed(19).generate(6, 6);
+    Braid4::new_from_seed(4).braid(&mut maze);
+
+    let exact = maze.stats_with(StatsOptions::exact());
+    let sampled = maze.stats_with(StatsOptions::sampled(36, 1));
+
+    assert_eq!(sampled.average_path_length, exact.average_path_length);
+    assert_eq!(sampled.average_path_length_margin, None);
+}
+
+/// Sampling zero sources still samples one and reports an unbounded margin.
+#[test]
+fn sampling_no_sources_samples_one() {
+    let mut maze = RecursiveBacktracker4::new_from_seed(19).generate(6, 6);
+    Braid4::new_from_seed(4).braid(&mut maze);
+
+    let sampled = maze.stats_with(StatsOptions::sampled(0, 1));
+    assert!(sampled.average_path_length > 0.0);
+    assert_eq!(sampled.average_path_length_margin, Some(f64::INFINITY));
+}
+
+/// Large mazes with loops are sampled by default.
+#[test]
+fn auto_mode_samples_large_braided_mazes() {
+    let mut maze = RecursiveBacktracker4::new_from_seed(23).generate(80, 80);
+    Braid4::new_from_seed(5).braid(&mut maze);
+
+    let stats = MazeStats::from_grid(&maze);
+
+    assert!(stats.loops > 0);
+    assert!(stats.average_path_length_margin.is_some());
+    assert_eq!(StatsOptions::default().path_length, PathLengthMode::Auto);
+}
@@ -0,0 +1,148 @@
+//! Integration tests for the SVG renderer
+//!
+//! Tests the drawn geometry of square mazes, hex mazes and dungeons, and the
+//! solution overlay and markers.
+
+#![cfg(feature = "svg-renderer")]
+
+use amaze::preamble::*;
+use amaze::renderers::{DrawableGrid, StrokeCap, SvgRenderer};
+
+/// Counts the `M` commands of the wall path, one per segment.
+fn wall_count(svg: &str) -> usize {
+    svg.lines()
+        .find(|line| line.contains("stroke-linecap") && line.starts_with("<path"))
+        .map_or(0, |line| line.matches('M').count())
+}
+
+/// Every wall is drawn once, including the walls shared by two cells.
+#[test]
+fn square_walls_are_drawn_once() {
+    let closed = Wall4Grid::new(3, 2);
+    // 4 horizontal lines of 3 walls, 3 vertical lines of 2 walls.
+    assert_eq!(closed.wall_segments().len(), 3 * 3 + 4 * 2);
+
+    let mut grid = Wall4Grid::new(3, 2);
+    grid.remove_wall_between(GridCoord2D::new(0, 0), GridCoord2D::new(1, 0));
+    grid.remove_wall_between(GridCoord2D::new(1, 0), GridCoord2D::new(1, 1));
+    assert_eq!(grid.wall_segments().len(), 17 - 2);
+
+    let svg = SvgRenderer::new().render(&grid);
+    assert_eq!(wall_count(&svg), 15);
+}
+
+/// The image size follows the cell size and margin.
+#[test]
+fn size_and_style_are_configurable() {
+    let mut renderer = SvgRenderer::new();
+    let style = renderer.style_mut();
+    style.set_cell_size(10.0);
+    style.set_margin(5.0);
+    style.set_stroke_width(3.0);
+    style.set_stroke_cap(StrokeCap::Square);
+    style.set_colors((255, 0, 0), (0, 0, 0));
+
+    let svg = renderer.render(&Wall4Grid::new(4, 3));
+    assert!(
+        svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="50.00" height="40.00""#)
+    );
+    assert!(svg.contains(r##"stroke="#ff0000" stroke-width="3.00" stroke-linecap="square""##));
+    assert!(svg.contains(r##"fill="#000000""##));
+    assert!(svg.trim_end().ends_with("</svg>"));
+}
+
+/// Closed hex grids draw every shared edge once.
+#[test]
+fn hex_walls_are_drawn_once() {
+    let grid = Wall6Grid::new(3, 2);
+    // 6 * 6 edges, less the 9 edges shared between neighbors.
+    assert_eq!(grid.wall_segments().len(), 6 * 6 - 9);
+
+    let (width, height) = grid.extent();
+    assert!((width - 3.5 * 3f64.sqrt() / 2.0).abs() < 1e-9);
+    assert!((height - 1.75).abs() < 1e-9);
+    assert_eq!(wall_count(&SvgRenderer::new().render(&grid)), 27);
+}
+
+/// Dungeon walls outline the floor, and wall tiles are filled.
+#[test]
+fn dungeon_floors_are_outlined() {
+    let mut dungeon = DungeonGrid::new(4, 3);
+    dungeon.set(GridCoord2D::new(1, 1), TileType::Floor);
+    dungeon.set(GridCoord2D::new(2, 1), TileType::Floor);
+    dungeon.place_walls();
+
+    assert_eq!(dungeon.wall_segments().len(), 6);
+    // `place_walls` surrounds the two floor tiles orthogonally.
+    assert_eq!(dungeon.solid_areas().len(), 6);
+
+    let mut renderer = SvgRenderer::new();
+    renderer.style_mut().set_solid_color((1, 2, 3));
+    let svg = renderer.render(&dungeon);
+    assert!(svg.contains(r##"fill="#010203""##));
+    assert_eq!(wall_count(&svg), 6);
+}
+
+/// The solution runs through the cell centers between the start and end markers.
+#[test]
+fn solution_and_markers_are_overlaid() {
+    let mut grid = Wall4Grid::new(3, 1);
+    grid.remove_wall_between(GridCoord2D::new(0, 0), GridCoord2D::new(1, 0));
+    grid.remove_wall_between(GridCoord2D::new(1, 0), GridCoord2D::new(2, 0));
+    let (start, end) = (GridCoord2D::new(0, 0), GridCoord2D::new(2, 0));
+    let solution = Path::new(vec![start, GridCoord2D::new(1, 0), end]);
+
+    let mut renderer = SvgRenderer::new();
+    let style = renderer.style_mut();
+    style.set_margin(0.0);
+    style.set_marker_colors((0, 255, 0), (0, 0, 255));
+    let svg = renderer.render_with(&grid, Some(&solution), Some((start, end)));
+
+    assert!(svg.contains(r#"<polyline points="10.00,10.00 30.00,10.00 50.00,10.00""#));
+    assert!(svg.contains(r##"<circle cx="10.00" cy="10.00" r="6.00" fill="#00ff00"/>"##));
+    assert!(svg.contains(r##"<circle cx="50.00" cy="10.00" r="6.00" fill="#0000ff"/>"##));
+    assert!(!SvgRenderer::new().render(&grid).contains("<polyline"));
+}
+
+/// Hex solutions are drawn through hexagon centers.
+#[test]
+fn hex_solution_is_overlaid() {
+    let mut grid = Wall6Grid::new(2, 2);
+    let (start, end) = (HexCoord::new(0, 0), HexCoord::new(0, 1));
+    grid.remove_wall_between(start, end);
+
+    let center = grid.center(end);
+    assert!((center.0 - 3f64.sqrt() / 2.0).abs() < 1e-9);
+    assert!((center.1 - 1.25).abs() < 1e-9);
+
+    let svg = SvgRenderer::new().render_with(&grid, Some(&HexPath::new(vec![start, end])), None);
+    assert!(svg.contains("<polyline"));
+    assert!(!svg.contains("<circle"));
+}
+
+/// Steps across the joined edges of wrapped grids are drawn out to both borders
+/// instead of straight through the maze.
+#[test]
+fn wrapped_solution_ends_at_the_borders() {
+    let mut grid = Wall4Grid::new_wrapped(5, 1, WrapMode::Horizontal);
+    let (start, end) = (GridCoord2D::new(0, 0), GridCoord2D::new(4, 0));
+    grid.remove_wall_between(start, end);
+    assert_eq!(grid.wrap_exit(start, end), Some((0.0, 0.5)));
+    assert_eq!(grid.wrap_exit(end, start), Some((5.0, 0.5)));
+
+    let mut renderer = SvgRenderer::new();
+    renderer.style_mut().set_margin(0.0);
+    let svg = renderer.render_with(&grid, Some(&Path::new(vec![start, end])), None);
+
+    assert!(!svg.contains("10.00,10.00 90.00,10.00"));
+    assert!(svg.contains(r#"<polyline points="10.00,10.00 0.00,10.00""#));
+    assert!(svg.contains(r#"<polyline points="100.00,10.00 90.00,10.00""#));
+
+    let mut grid = Wall6Grid::new_wrapped(3, 2, WrapMode::Horizontal);
+    let (start, end) = (HexCoord::new(0, 0), HexCoord::new(2, 0));
+    grid.remove_wall_between(start, end);
+    let (exit, entry) = (grid.wrap_exit(start, end), grid.wrap_exit(end, start));
+    assert!(exit.is_some_and(|(x, _)| x.abs() < 1e-9));
+    assert!(entry.is_some_and(|(x, _)| (x - 1.5 * 3f64.sqrt()).abs() < 1e-9));
+    assert_eq!(grid.wrap_exit(start, HexCoord::new(1, 0)), None);
+}
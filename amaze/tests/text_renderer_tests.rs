@@ -0,0 +1,122 @@
+//! Integration tests for the plain-text renderers
+//!
+//! Tests the classic ASCII layout of square and hex mazes at several cell widths,
+//! and the Braille patterns packed from the maze bitmap.
+
+#![cfg(all(feature = "ascii-renderer", feature = "braille-renderer"))]
+
+use amaze::generators::RecursiveBacktracker4;
+use amaze::preamble::*;
+use amaze::renderers::{AsciiRenderer, BrailleRenderer, RenderStyle};
+
+/// The cell width stretches the corridors but keeps one row per cell.
+#[test]
+fn ascii_cell_width() {
+    let mut grid = Wall4Grid::new(2, 1);
+    grid.remove_wall_between(GridCoord2D::new(0, 0), GridCoord2D::new(1, 0));
+
+    let mut renderer = AsciiRenderer::new();
+    renderer.set_cell_width(1);
+    assert_eq!(renderer.render(&grid), "+-+-+\n|   |\n+-+-+\n");
+    renderer.set_cell_width(0);
+    assert_eq!(renderer.render(&grid), "+-+-+\n|   |\n+-+-+\n");
+    renderer.set_cell_width(5);
+    assert_eq!(
+        renderer.render(&grid),
+        "+-----+-----+\n|           |\n+-----+-----+\n"
+    );
+}
+
+/// Every cell of a generated maze has exactly the walls drawn around it.
+#[test]
+fn ascii_matches_walls() {
+    let grid = RecursiveBacktracker4::new_from_seed(11).generate(7, 5);
+    let text = AsciiRenderer::new().render(&grid);
+    let lines: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+    assert_eq!(lines.len(), 2 * 5 + 1);
+
+    for cell in grid.coords() {
+        let (x, y) = (4 * cell.x, 2 * cell.y);
+        let walls = grid[cell];
+        assert_eq!(lines[y][x + 2] == '-', walls.contains(Direction4::NORTH));
+        assert_eq!(
+            lines[y + 2][x + 2] == '-',
+            walls.contains(Direction4::SOUTH)
+        );
+        assert_eq!(lines[y + 1][x] == '|', walls.contains(Direction4::WEST));
+        assert_eq!(lines[y + 1][x + 4] == '|', walls.contains(Direction4::EAST));
+    }
+}
+
+/// A closed hexagon is drawn with slanted edges that grow with the cell width.
+#[test]
+fn ascii_hexagons() {
+    let grid = Wall6Grid::new(1, 1);
+    let mut renderer = AsciiRenderer::new();
+    assert_eq!(renderer.render_hex(&grid), " / \\\n|   |\n \\ /\n");
+
+    renderer.set_cell_width(5);
+    let expected = [
+        r"  / \", r" /   \", r"|     |", r"|     |", r" \   /", r"  \ /",
+    ];
+    assert_eq!(
+        renderer.render_hex(&grid).lines().collect::<Vec<_>>(),
+        expected
+    );
+}
+
+/// Hex rows are shifted half a cell, and a fully closed hex maze is a honeycomb.
+#[test]
+fn ascii_hex_layout() {
+    let grid = Wall6Grid::new(3, 2);
+    let expected = [
+        r" / \ / \ / \",
+        r"|   |   |   |",
+        r" \ / \ / \ / \",
+        r"  |   |   |   |",
+        r"   \ / \ / \ /",
+    ];
+    let text = AsciiRenderer::new().render_hex(&grid);
+    assert_eq!(text.lines().collect::<Vec<_>>(), expected);
+}
+
+/// A generated hex maze stays plain ASCII and has fewer walls than the honeycomb.
+#[cfg(feature = "generator-hex-recursive-backtracker")]
+#[test]
+fn ascii_hex_maze() {
+    use amaze::generators::RecursiveBacktracker6;
+
+    let maze = RecursiveBacktracker6::new_from_seed(5).generate(6, 4);
+    let text = AsciiRenderer::new().render_hex(&maze);
+    assert_eq!(text.lines().count(), 2 * 4 + 1);
+    assert!(text.is_ascii());
+    // A perfect maze has fewer walls than the honeycomb.
+    let walls = |text: &str| text.chars().filter(|c| !c.is_whitespace()).count();
+    assert!(walls(&text) < walls(&AsciiRenderer::new().render_hex(&Wall6Grid::new(6, 4))));
+}
+
+/// Each character packs two columns and four rows of the maze bitmap.
+#[test]
+fn braille_dots() {
+    let grid = Wall4Grid::new(1, 1);
+    assert_eq!(BrailleRenderer::new().render(&grid), "\u{282F}\u{2807}\n");
+
+    let mut renderer = BrailleRenderer::new();
+    renderer.set_inverted(true);
+    assert_eq!(renderer.render(&grid), "\u{2810}\u{2800}\n");
+
+    let maze = RecursiveBacktracker4::new_from_seed(3).generate(10, 6);
+    let text = BrailleRenderer::new().render(&maze);
+    assert_eq!(text.lines().count(), 13usize.div_ceil(4));
+    assert!(
+        text.lines()
+            .all(|line| line.chars().count() == 21usize.div_ceil(2))
+    );
+}
+
+/// The text renderers are selectable as render styles.
+#[test]
+fn render_styles() {
+    assert_eq!("ascii".parse::<RenderStyle>(), Ok(RenderStyle::Ascii));
+    assert_eq!("Braille".parse::<RenderStyle>(), Ok(RenderStyle::Braille));
+}
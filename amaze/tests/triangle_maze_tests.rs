@@ -0,0 +1,105 @@
+//! Integration tests for triangle mazes
+//!
+//! Tests that the triangle generators produce perfect mazes, that the generic
+//! solvers find paths through them, and that they can be rendered and stored.
+
+#![cfg(feature = "generators-tri")]
+
+mod common;
+
+use amaze::generators::{Kruskal3, MazeGeneratorTri, RecursiveBacktracker3, TriGenerationStep};
+use common::assert_perfect;
+
+#[test]
+fn triangle_generators_produce_perfect_mazes() {
+    let generators: Vec<Box<dyn MazeGeneratorTri>> = vec![
+        Box::new(RecursiveBacktracker3::new_from_seed(42)),
+        Box::new(Kruskal3::new_from_seed(42)),
+    ];
+
+    for generator in generators {
+        for (width, height) in [(1, 1), (2, 1), (7, 3), (16, 9)] {
+            let grid = generator.generate(width, height);
+            assert_eq!(grid.width(), width, "{}", generator.name());
+            assert_eq!(grid.height(), height, "{}", generator.name());
+            assert_perfect(&grid);
+        }
+    }
+}
+
+#[test]
+fn triangle_generation_steps_carve_every_cell() {
+    for generator in [
+        Box::new(RecursiveBacktracker3::new_from_seed(3)) as Box<dyn MazeGeneratorTri>,
+        Box::new(Kruskal3::new_from_seed(3)),
+    ] {
+        let steps: Vec<_> = generator.generate_steps(8, 4).collect();
+        let carves = steps
+            .iter()
+            .filter(|step| matches!(step, TriGenerationStep::Carve { .. }))
+            .count();
+        assert_eq!(carves, 8 * 4 - 1, "{}", generator.name());
+        assert_eq!(steps.last(), Some(&TriGenerationStep::Complete));
+    }
+}
+
+#[cfg(feature = "solvers")]
+#[test]
+fn bfs_solves_triangle_mazes() {
+    use amaze::preamble::TriCoord;
+    use amaze::solvers::{AStarSolver, BfsSolver, MazeSolver};
+
+    let grid = Kruskal3::new_from_seed(11).generate(12, 6);
+    let start = TriCoord::new(0, 0);
+    let end = TriCoord::new(11, 5);
+
+    let bfs = BfsSolver.solve(&grid, start, end).expect("bfs path");
+    let astar = AStarSolver.solve(&grid, start, end).expect("astar path");
+
+    // A perfect maze has exactly one path between two cells.
+    assert_eq!(bfs, astar);
+    for pair in bfs.cells().windows(2) {
+        assert!(grid.open_neighbors(pair[0]).any(|n| n == pair[1]));
+    }
+}
+
+#[cfg(feature = "triangle-renderer")]
+#[test]
+fn renderer_draws_svg_and_images() {
+    use amaze::renderers::{ImageFormat, TriangleRenderer};
+
+    let grid = RecursiveBacktracker3::new_from_seed(5).generate(6, 3);
+    let renderer = TriangleRenderer::new();
+
+    let svg = renderer.render_svg(&grid);
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.contains("<path"));
+
+    let pbm = renderer.render_image(&grid, ImageFormat::PBM);
+    let mut lines = pbm.lines();
+    assert_eq!(lines.next(), Some("P1"));
+    let dims: Vec<usize> = lines
+        .next()
+        .expect("dimensions")
+        .split(' ')
+        .map(|v| v.parse().expect("number"))
+        .collect();
+    assert_eq!(lines.count(), dims[1]);
+
+    let ppm = renderer.render_image(&grid, ImageFormat::PPM);
+    assert!(ppm.starts_with("P3"));
+}
+
+#[cfg(feature = "binary-format")]
+#[test]
+fn triangle_maze_roundtrips_through_binary_format() {
+    use amaze::preamble::Wall3Grid;
+    use amaze::storage::{FromBinary, ToBinary};
+
+    let grid = RecursiveBacktracker3::new_from_seed(9).generate(7, 4);
+    let bytes = grid.to_binary().expect("serializes");
+    let restored = Wall3Grid::from_binary(&bytes).expect("deserializes");
+    for cell in grid.coords() {
+        assert_eq!(grid[cell], restored[cell]);
+    }
+}
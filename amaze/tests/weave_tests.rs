@@ -0,0 +1,215 @@
+//! Integration tests for weave mazes
+//!
+//! Tests that the weave generator places under-crossings while still carving
+//! perfect mazes, that solvers pass through tunnels, and that renderers and
+//! storage formats handle the crossing data.
+
+#![cfg(feature = "generator-weave-kruskal")]
+
+use amaze::generators::{GenerationStep, MazeGeneratorWeave, WeaveKruskal4};
+use amaze::preamble::{Direction4, GridCoord2D, MazeGrid};
+
+#[cfg(any(
+    feature = "solvers",
+    feature = "unicode-renderer",
+    feature = "pgm-renderer"
+))]
+/// A 5×3 corridor along the middle row that tunnels under a vertical passage at (2, 1).
+fn tunnel_corridor() -> amaze::preamble::WeaveGrid {
+    let mut grid = amaze::preamble::WeaveGrid::new(5, 3);
+    let center = GridCoord2D::new(2, 1);
+    grid.remove_wall_between(center, GridCoord2D::new(2, 0));
+    grid.remove_wall_between(center, GridCoord2D::new(2, 2));
+    grid.remove_wall_between(GridCoord2D::new(0, 1), GridCoord2D::new(1, 1));
+    grid.remove_wall_between(GridCoord2D::new(1, 1), GridCoord2D::new(3, 1));
+    grid.remove_wall_between(GridCoord2D::new(3, 1), GridCoord2D::new(4, 1));
+    grid
+}
+
+#[test]
+fn generator_places_crossings_in_perfect_maze() {
+    for seed in [1, 7, 42] {
+        let grid = WeaveKruskal4::new_from_seed(seed)
+            .with_density(0.8)
+            .generate(16, 12);
+        assert!(grid.crossings().count() > 0, "seed {seed} has no crossings");
+
+        let stats = grid.stats();
+        assert_eq!(stats.loops, 0, "seed {seed} contains loops");
+        let start = GridCoord2D::new(0, 0);
+        assert!(grid.bfs_distances(start).iter().all(Option::is_some));
+
+        for cell in grid.crossings() {
+            let tunnel = grid.tunnel_directions(cell);
+            assert!(
+                tunnel == Direction4::EAST + Direction4::WEST
+                    || tunnel == Direction4::NORTH + Direction4::SOUTH
+            );
+            assert_eq!(grid.open_neighbors(cell).count(), 2);
+        }
+    }
+}
+
+#[test]
+fn zero_density_has_no_crossings() {
+    let grid = WeaveKruskal4::new_from_seed(3)
+        .with_density(0.0)
+        .generate(10, 10);
+    assert_eq!(grid.crossings().count(), 0);
+    assert_eq!(grid.stats().loops, 0);
+}
+
+#[test]
+fn generation_is_deterministic_and_reports_tunnels() {
+    let generator = WeaveKruskal4::new_from_seed(9).with_density(1.0);
+    let a = generator.generate(12, 12);
+    let b = generator.generate(12, 12);
+    assert!(
+        a.coords()
+            .all(|c| a[c] == b[c] && a.is_crossing(c) == b.is_crossing(c))
+    );
+
+    let steps: Vec<_> = generator.generate_steps(12, 12).collect();
+    let mut tunnels: Vec<_> = steps
+        .iter()
+        .filter_map(|step| match step {
+            GenerationStep::Tunnel { under, .. } => Some(*under),
+            _ => None,
+        })
+        .collect();
+    let mut crossings: Vec<_> = a.crossings().collect();
+    crossings.sort_by_key(|c| (c.y, c.x));
+    tunnels.sort_by_key(|c| (c.y, c.x));
+    assert_eq!(tunnels, crossings);
+    assert_eq!(steps.last(), Some(&GenerationStep::Complete));
+}
+
+#[test]
+fn empty_grid_completes_immediately() {
+    let steps: Vec<_> = WeaveKruskal4::new_from_seed(1)
+        .generate_steps(0, 0)
+        .collect();
+    assert_eq!(steps, vec![GenerationStep::Complete]);
+}
+
+#[cfg(feature = "solvers")]
+#[test]
+fn solvers_pass_under_crossings() {
+    use amaze::preamble::WeaveGrid;
+    use amaze::solvers::{AStarSolver, BfsSolver, DeadEndFillingSolver, DfsSolver, MazeSolver};
+
+    let grid = tunnel_corridor();
+    let start = GridCoord2D::new(0, 1);
+    let end = GridCoord2D::new(4, 1);
+    let expected = [start, GridCoord2D::new(1, 1), GridCoord2D::new(3, 1), end];
+
+    let solvers: Vec<Box<dyn MazeSolver<WeaveGrid>>> = vec![
+        Box::new(BfsSolver),
+        Box::new(DfsSolver),
+        Box::new(AStarSolver),
+        Box::new(DeadEndFillingSolver),
+    ];
+    for solver in solvers {
+        let path = solver.solve(&grid, start, end).expect("path exists");
+        assert_eq!(path.cells(), &expected);
+    }
+
+    let over = BfsSolver
+        .solve(&grid, GridCoord2D::new(2, 0), GridCoord2D::new(2, 2))
+        .expect("over passage");
+    assert_eq!(over.cells().len(), 3);
+    assert!(
+        BfsSolver
+            .solve(&grid, start, GridCoord2D::new(2, 0))
+            .is_none()
+    );
+}
+
+#[cfg(feature = "solvers")]
+#[test]
+fn solvers_cross_generated_weave_mazes() {
+    use amaze::solvers::{AStarSolver, BfsSolver, MazeSolver};
+
+    let grid = WeaveKruskal4::new_from_seed(5)
+        .with_density(1.0)
+        .generate(14, 14);
+    let start = GridCoord2D::new(0, 0);
+    let end = GridCoord2D::new(13, 13);
+    let bfs = BfsSolver.solve(&grid, start, end).expect("bfs path");
+    let astar = AStarSolver.solve(&grid, start, end).expect("astar path");
+    assert_eq!(bfs, astar);
+    assert_eq!(
+        bfs.cells().len() - 1,
+        grid.bfs_distances(start)[grid.linearize(end)].expect("reachable")
+    );
+}
+
+#[cfg(feature = "unicode-renderer")]
+#[test]
+fn unicode_renderer_draws_crossings() {
+    use amaze::renderers::{UnicodeRenderStyle, UnicodeRenderer};
+
+    let grid = tunnel_corridor();
+    let heavy = UnicodeRenderer::new(UnicodeRenderStyle::Heavy, true).render_weave(&grid);
+    assert_eq!(heavy, "  ╻  \n╺━╂━╸\n  ╹  \n");
+    let double = UnicodeRenderer::new(UnicodeRenderStyle::Double, false).render_weave(&grid);
+    assert_eq!(double, "  ╥  ╞═╫═╡  ╨  ");
+    let hex = UnicodeRenderer::new(UnicodeRenderStyle::Hexadecimal, false).render_weave(&grid);
+    assert_eq!(hex, "002004C3C800100");
+}
+
+#[cfg(feature = "pgm-renderer")]
+#[test]
+fn image_renderer_colors_tunnel_entrances() {
+    use amaze::renderers::{ImageFormat, ImageRenderer};
+
+    let grid = tunnel_corridor();
+    let mut renderer = ImageRenderer::new(ImageFormat::PPM);
+    renderer.set_colors((0, 0, 0), (255, 255, 255));
+    renderer.set_tunnel_color((1, 2, 3));
+    let ppm = renderer.render_weave(&grid);
+    assert_eq!(ppm.matches("1 2 3 ").count(), 2);
+    assert!(ppm.starts_with("P3\n11 7\n255\n"));
+    assert_eq!(renderer.render(grid.grid()).matches("1 2 3 ").count(), 0);
+
+    let pbm = ImageRenderer::new(ImageFormat::PBM);
+    assert_eq!(pbm.render_weave(&grid), pbm.render(grid.grid()));
+}
+
+#[cfg(feature = "binary-format")]
+#[test]
+fn binary_format_roundtrips_crossings() {
+    use amaze::preamble::WeaveGrid;
+    use amaze::storage::{FromBinary, ToBinary};
+
+    let maze = WeaveKruskal4::new_from_seed(11)
+        .with_density(1.0)
+        .generate(10, 8);
+    let bytes = maze.to_binary().expect("serializes");
+    assert_eq!(bytes[5], 3);
+    let restored = WeaveGrid::from_binary(&bytes).expect("deserializes");
+    assert!(maze.coords().all(|c| maze[c] == restored[c]));
+    assert_eq!(
+        maze.crossings().collect::<Vec<_>>(),
+        restored.crossings().collect::<Vec<_>>()
+    );
+}
+
+#[cfg(feature = "json-format")]
+#[test]
+fn json_format_roundtrips_crossings() {
+    use amaze::preamble::WeaveGrid;
+    use amaze::storage::{FromJson, ToJson};
+
+    let maze = WeaveKruskal4::new_from_seed(11)
+        .with_density(1.0)
+        .generate(10, 8);
+    let json = maze.to_json().expect("serializes");
+    assert!(json.contains(r#""type":"weave""#));
+    let restored = WeaveGrid::from_json(&json).expect("deserializes");
+    assert!(maze.coords().all(|c| maze[c] == restored[c]));
+    assert_eq!(
+        maze.crossings().collect::<Vec<_>>(),
+        restored.crossings().collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,263 @@
+//! Integration tests for cylinder and torus mazes
+//!
+//! Tests that the wrapping generators carve perfect mazes across joined edges,
+//! that solvers and statistics follow wrapped passages, and that the storage
+//! formats preserve the wrap mode.
+
+mod common;
+
+use amaze::generators::{
+    GrowingTree4, Kruskal4, MazeGenerator2D, Prim4, RecursiveBacktracker4, Wilson4,
+    WrappingMazeGenerator2D,
+};
+use amaze::preamble::{Direction4, GridCoord2D, Wall4Grid, WrapMode};
+use common::assert_perfect;
+
+/// Counts the passages leaving the grid through its west and north borders.
+fn edge_crossings(grid: &Wall4Grid) -> (usize, usize) {
+    let west = (0..grid.height())
+        .filter(|&y| !grid[GridCoord2D::new(0, y)].contains(Direction4::WEST))
+        .count();
+    let north = (0..grid.width())
+        .filter(|&x| !grid[GridCoord2D::new(x, 0)].contains(Direction4::NORTH))
+        .count();
+    (west, north)
+}
+
+fn square_generators() -> Vec<Box<dyn WrappingMazeGenerator2D>> {
+    #[cfg(feature = "generator-aldous-broder")]
+    let aldous_broder: Option<Box<dyn WrappingMazeGenerator2D>> = Some(Box::new(
+        amaze::generators::AldousBroder4::new_from_seed(42),
+    ));
+    #[cfg(not(feature = "generator-aldous-broder"))]
+    let aldous_broder = None;
+
+    let generators: Vec<Box<dyn WrappingMazeGenerator2D>> = vec![
+        Box::new(RecursiveBacktracker4::new_from_seed(42)),
+        Box::new(<GrowingTree4>::new_from_seed(42)),
+        Box::new(Kruskal4::new_from_seed(42)),
+        Box::new(Wilson4::new_from_seed(42)),
+        Box::new(Prim4::new_from_seed(42)),
+    ];
+    generators.into_iter().chain(aldous_broder).collect()
+}
+
+#[test]
+fn wrapped_neighbors_cross_edges() {
+    let corner = GridCoord2D::new(0, 0);
+
+    let grid = Wall4Grid::new(4, 3);
+    assert_eq!(grid.neighbors(corner).count(), 2);
+
+    let grid = Wall4Grid::new_wrapped(4, 3, WrapMode::Horizontal);
+    let neighbors: Vec<_> = grid.neighbors(corner).collect();
+    assert_eq!(
+        neighbors,
+        vec![
+            GridCoord2D::new(1, 0),
+            GridCoord2D::new(0, 1),
+            GridCoord2D::new(3, 0)
+        ]
+    );
+
+    let grid = Wall4Grid::new_wrapped(4, 3, WrapMode::Torus);
+    assert_eq!(
+        grid.neighbor(corner, Direction4::NORTH),
+        Some(GridCoord2D::new(0, 2))
+    );
+    assert_eq!(grid.neighbors(corner).count(), 4);
+}
+
+#[test]
+fn unwrapped_generation_is_unchanged() {
+    for generator in square_generators() {
+        let plain = generator.generate(9, 7);
+        let wrapped = generator.generate_wrapped(9, 7, WrapMode::None);
+        for cell in plain.coords() {
+            assert_eq!(
+                plain[cell],
+                wrapped[cell],
+                "{} differs at {cell:?}",
+                generator.name()
+            );
+        }
+    }
+}
+
+#[test]
+fn square_generators_carve_across_wrapped_edges() {
+    for generator in square_generators() {
+        let torus = generator.generate_wrapped(12, 10, WrapMode::Torus);
+        assert_eq!(torus.wrap_mode(), WrapMode::Torus);
+        assert_perfect(&torus);
+        let (west, north) = edge_crossings(&torus);
+        assert!(west + north > 0, "{} never wraps", generator.name());
+
+        let cylinder = generator.generate_wrapped(12, 10, WrapMode::Horizontal);
+        assert_perfect(&cylinder);
+        assert_eq!(edge_crossings(&cylinder).1, 0, "{}", generator.name());
+    }
+}
+
+#[test]
+fn wrapped_steps_end_with_complete() {
+    use amaze::generators::GenerationStep;
+
+    for generator in square_generators() {
+        let steps = generator.generate_wrapped_steps(6, 6, WrapMode::Vertical);
+        assert_eq!(steps.last(), Some(GenerationStep::Complete));
+    }
+}
+
+#[test]
+fn narrow_axes_do_not_wrap() {
+    let grid = Wall4Grid::new_wrapped(2, 5, WrapMode::Torus);
+    let corner = GridCoord2D::new(0, 0);
+    assert_eq!(grid.neighbor(corner, Direction4::WEST), None);
+    assert_eq!(
+        grid.neighbor(corner, Direction4::NORTH),
+        Some(GridCoord2D::new(0, 4))
+    );
+
+    let maze = Kruskal4::new_from_seed(3).generate_wrapped(2, 5, WrapMode::Torus);
+    assert_perfect(&maze);
+}
+
+#[test]
+fn stats_count_wrapped_passages() {
+    let mut ring = Wall4Grid::new_wrapped(4, 1, WrapMode::Horizontal);
+    for x in 0..4 {
+        ring.remove_wall_between(GridCoord2D::new(x, 0), GridCoord2D::new((x + 1) % 4, 0));
+    }
+
+    let stats = ring.stats();
+    assert_eq!(stats.dead_ends, 0);
+    assert_eq!(stats.loops, 1);
+    assert_eq!(stats.longest_path, 2);
+}
+
+#[cfg(feature = "solvers")]
+#[test]
+fn solvers_follow_wrapped_passages() {
+    use amaze::preamble::MazeGrid;
+    use amaze::solvers::{AStarSolver, BfsSolver, DfsSolver, MazeSolver};
+
+    let mut grid = Wall4Grid::new_wrapped(6, 1, WrapMode::Horizontal);
+    for x in 0..5 {
+        grid.remove_wall_between(GridCoord2D::new(x, 0), GridCoord2D::new(x + 1, 0));
+    }
+    grid.remove_wall_between(GridCoord2D::new(5, 0), GridCoord2D::new(0, 0));
+
+    let start = GridCoord2D::new(1, 0);
+    let end = GridCoord2D::new(5, 0);
+    let bfs = BfsSolver.solve(&grid, start, end).expect("bfs path");
+    assert_eq!(
+        bfs.cells(),
+        &[start, GridCoord2D::new(0, 0), GridCoord2D::new(5, 0)]
+    );
+    let astar = AStarSolver.solve(&grid, start, end).expect("astar path");
+    assert_eq!(astar, bfs);
+    assert!(DfsSolver.solve(&grid, start, end).is_some());
+
+    assert_eq!(grid.distance_estimate(start, end), 2);
+    assert_eq!(grid.bfs_distances(start)[5], Some(2));
+}
+
+#[cfg(all(
+    feature = "generator-hex-recursive-backtracker",
+    feature = "generator-hex-growing-tree",
+    feature = "generator-hex-kruskal",
+    feature = "generator-hex-wilson",
+))]
+#[test]
+fn hex_generators_carve_across_wrapped_edges() {
+    use amaze::generators::{
+        GrowingTree6, Kruskal6, MazeGenerator6D, RecursiveBacktracker6, Wilson6,
+        WrappingMazeGenerator6D,
+    };
+    use amaze::preamble::{Direction6, HexCoord, MazeGrid};
+
+    #[cfg(feature = "generator-hex-prim")]
+    let prim: Option<Box<dyn WrappingMazeGenerator6D>> =
+        Some(Box::new(amaze::generators::Prim6::new_from_seed(42)));
+    #[cfg(not(feature = "generator-hex-prim"))]
+    let prim = None;
+    #[cfg(feature = "generator-hex-aldous-broder")]
+    let aldous_broder: Option<Box<dyn WrappingMazeGenerator6D>> = Some(Box::new(
+        amaze::generators::AldousBroder6::new_from_seed(42),
+    ));
+    #[cfg(not(feature = "generator-hex-aldous-broder"))]
+    let aldous_broder = None;
+
+    let generators: Vec<Box<dyn WrappingMazeGenerator6D>> = vec![
+        Box::new(RecursiveBacktracker6::new_from_seed(42)),
+        Box::new(<GrowingTree6>::new_from_seed(42)),
+        Box::new(Kruskal6::new_from_seed(42)),
+        Box::new(Wilson6::new_from_seed(42)),
+    ];
+
+    for generator in generators.into_iter().chain(prim).chain(aldous_broder) {
+        let plain = generator.generate(7, 5);
+        let unwrapped = generator.generate_wrapped(7, 5, WrapMode::None);
+        assert!(plain.coords().all(|c| plain[c] == unwrapped[c]));
+
+        let grid = generator.generate_wrapped(9, 7, WrapMode::Torus);
+        assert_eq!(grid.wrap_mode(), WrapMode::Torus);
+        assert_perfect(&grid);
+
+        let crossings = grid
+            .coords()
+            .filter(|c| c.q == 0 || c.r == 0)
+            .flat_map(|c| grid.open_neighbors(c).map(move |n| (c, n)))
+            .filter(|(c, n)| c.distance(*n) > 1)
+            .count();
+        assert!(crossings > 0, "{} never wraps", generator.name());
+    }
+
+    let grid = amaze::preamble::Wall6Grid::new_wrapped(5, 4, WrapMode::Vertical);
+    let top = HexCoord::new(2, 0);
+    assert_eq!(
+        grid.neighbor(top, Direction6::NW),
+        Some(HexCoord::new(2, 3))
+    );
+    assert_eq!(grid.neighbor(HexCoord::new(0, 1), Direction6::WEST), None);
+    assert_eq!(grid.distance_estimate(top, HexCoord::new(2, 3)), 1);
+}
+
+#[cfg(feature = "binary-format")]
+#[test]
+fn binary_format_preserves_wrap_mode() {
+    use amaze::storage::{FromBinary, ToBinary};
+
+    let maze = Wilson4::new_from_seed(11).generate_wrapped(8, 8, WrapMode::Torus);
+    let bytes = maze.to_binary().expect("serializes");
+    let restored = Wall4Grid::from_binary(&bytes).expect("deserializes");
+    assert_eq!(restored.wrap_mode(), WrapMode::Torus);
+    assert!(maze.coords().all(|c| maze[c] == restored[c]));
+    assert_eq!(restored.stats().loops, 0);
+}
+
+#[cfg(feature = "json-format")]
+#[test]
+fn json_format_preserves_wrap_mode() {
+    use amaze::storage::{FromJson, ToJson};
+
+    let maze = Kruskal4::new_from_seed(11).generate_wrapped(8, 5, WrapMode::Vertical);
+    let json = maze.to_json().expect("serializes");
+    assert!(json.contains(r#""wrap":"vertical""#));
+    let restored = Wall4Grid::from_json(&json).expect("deserializes");
+    assert_eq!(restored.wrap_mode(), WrapMode::Vertical);
+    assert!(maze.coords().all(|c| maze[c] == restored[c]));
+}
+
+#[cfg(feature = "binary-format")]
+#[test]
+fn grids_without_wrap_support_reject_wrapped_data() {
+    use amaze::preamble::Wall3Grid;
+    use amaze::storage::{FromBinary, ToBinary};
+
+    let mut bytes = Wall3Grid::new(3, 2).to_binary().expect("serializes");
+    assert!(Wall3Grid::from_binary(&bytes).is_ok());
+    bytes[10] = 3;
+    assert!(Wall3Grid::from_binary(&bytes).is_err());
+}